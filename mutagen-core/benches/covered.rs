@@ -0,0 +1,57 @@
+//! Benchmarks the runtime overhead of mutated code when no mutation is active.
+//!
+//! Compare the results with and without coverage tracking:
+//!
+//! ```text
+//! cargo bench -p mutagen-core --bench covered
+//! cargo bench -p mutagen-core --bench covered --features no_coverage
+//! ```
+
+#![feature(test)]
+
+extern crate test;
+
+use mutagen_core::mutator::mutator_binop_num;
+use mutagen_core::MutagenRuntimeConfig;
+use test::{black_box, Bencher};
+
+#[bench]
+fn covered_without_mutation(b: &mut Bencher) {
+    let config = MutagenRuntimeConfig::Pass;
+    b.iter(|| {
+        for mutator_id in 1..=1000 {
+            config.covered(black_box(mutator_id));
+            black_box(config.is_mutation_active(mutator_id));
+        }
+    });
+}
+
+#[bench]
+fn covered_other_mutation(b: &mut Bencher) {
+    let config = MutagenRuntimeConfig::Mutation(10_000);
+    b.iter(|| {
+        for mutator_id in 1..=1000 {
+            config.covered(black_box(mutator_id));
+            black_box(config.is_mutation_active(mutator_id));
+        }
+    });
+}
+
+#[bench]
+fn binop_add_without_mutation(b: &mut Bencher) {
+    let config = MutagenRuntimeConfig::Pass;
+    b.iter(|| {
+        (1..=1000).fold(0u64, |sum, x| {
+            mutator_binop_num::run_add(1, black_box(sum), x, &config)
+        })
+    });
+}
+
+#[bench]
+fn binop_add_global_config(b: &mut Bencher) {
+    b.iter(|| {
+        (1..=1000).fold(0u64, |sum, x| {
+            mutator_binop_num::run_add(1, black_box(sum), x, MutagenRuntimeConfig::get_default())
+        })
+    });
+}
@@ -5,15 +5,27 @@
 //! * The procedural macro informs the runner about all baked mutations
 //! * The runner informs the test-suite about its mode (mutation or coverage) and additional required information (mutation_id, num_mutations)
 //! * The mutators in the test suite inform the runner about coverage-hits
+//! * The driver of the test runs records which tests killed each mutation
 //!
 //! Currently, communication from the procedural macro and test-suite is implemented via files in the `target/mutagen` directory.
 //! The communication to the test-suite is implemented via environemnt variables
 mod coverage;
+mod killing_tests;
 mod mutagen_files;
 mod mutation;
+mod mutation_info;
+mod mutation_sink;
 mod report;
+mod result_sink;
 
 pub use coverage::{CoverageCollection, CoverageHit};
+pub use killing_tests::KillingTests;
 pub use mutagen_files::*;
-pub use mutation::{BakedMutation, Mutation};
-pub use report::{MutagenReport, MutantStatus};
+pub use mutation::{BakedMutation, Mutation, OptimisticPolicy};
+pub use mutation_info::MutationInfo;
+pub use mutation_sink::{FileSink, MutationSink};
+pub use report::{
+    should_stop, MutagenReport, MutantStatus, MutatorScore, ReportSummary,
+    RECURSION_LIMIT_EXIT_CODE,
+};
+pub use result_sink::{CoverageFileSink, MemoryResultSink, NoResultSink, ResultEvent, ResultSink};
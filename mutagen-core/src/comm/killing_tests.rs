@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A collection that tracks which tests have killed each mutation.
+///
+/// The driver of the test runs records the result of each test that has been run with an active mutation.
+/// A mutation is killed by a test if this test fails while the mutation is active.
+///
+/// `cargo mutagen` records the results printed by the test suites and writes them to `target/mutagen/killing_tests.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct KillingTests {
+    killed_by: BTreeMap<usize, BTreeSet<String>>,
+}
+
+impl KillingTests {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the result of a single test that has been run while the given mutation was active.
+    ///
+    /// Only failed tests are recorded, since passing tests did not kill the mutation.
+    pub fn record_test_result(&mut self, mutation_id: usize, test_name: &str, failed: bool) {
+        if failed {
+            self.killed_by
+                .entry(mutation_id)
+                .or_default()
+                .insert(test_name.to_owned());
+        }
+    }
+
+    /// Records the results of all tests in the output of a test suite that has been run while the given mutation was active.
+    ///
+    /// The output is expected in the default format of the test harness, i.e. lines like `test tests::name ... FAILED`.
+    pub fn record_test_output(&mut self, mutation_id: usize, output: &str) {
+        for line in output.lines() {
+            let result = line
+                .strip_prefix("test ")
+                .and_then(|line| line.rsplit_once(" ... "));
+            match result {
+                Some((test_name, "ok")) => self.record_test_result(mutation_id, test_name, false),
+                Some((test_name, "FAILED")) => {
+                    self.record_test_result(mutation_id, test_name, true)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the names of all tests that killed the given mutation, in alphabetical order.
+    pub fn killing_tests(&self, mutation_id: usize) -> impl Iterator<Item = &str> {
+        self.killed_by
+            .get(&mutation_id)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Checks if any test killed the given mutation.
+    pub fn is_killed(&self, mutation_id: usize) -> bool {
+        self.killed_by.contains_key(&mutation_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn killing_tests_empty() {
+        let k = KillingTests::new();
+
+        assert!(!k.is_killed(1));
+        assert_eq!(k.killing_tests(1).count(), 0);
+    }
+
+    #[test]
+    fn killing_tests_single_failing_test() {
+        let mut k = KillingTests::new();
+        k.record_test_result(1, "tests::passing", false);
+        k.record_test_result(1, "tests::failing", true);
+        k.record_test_result(2, "tests::passing", false);
+        k.record_test_result(2, "tests::failing", false);
+
+        assert!(k.is_killed(1));
+        assert_eq!(
+            k.killing_tests(1).collect::<Vec<_>>(),
+            vec!["tests::failing"]
+        );
+        assert!(!k.is_killed(2));
+        assert_eq!(k.killing_tests(2).count(), 0);
+    }
+
+    #[test]
+    fn killing_tests_from_output() {
+        let mut k = KillingTests::new();
+        let output = "\nrunning 3 tests\n\
+                      test tests::passing ... ok\n\
+                      test tests::failing ... FAILED\n\
+                      test tests::ignored ... ignored\n\
+                      \n\
+                      test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out\n";
+        k.record_test_output(1, output);
+
+        assert_eq!(
+            k.killing_tests(1).collect::<Vec<_>>(),
+            vec!["tests::failing"]
+        );
+    }
+
+    #[test]
+    fn killing_tests_sorted() {
+        let mut k = KillingTests::new();
+        k.record_test_result(1, "tests::b", true);
+        k.record_test_result(1, "tests::a", true);
+        k.record_test_result(1, "tests::b", true);
+
+        assert_eq!(
+            k.killing_tests(1).collect::<Vec<_>>(),
+            vec!["tests::a", "tests::b"]
+        );
+    }
+}
@@ -1,17 +1,24 @@
 use failure::{bail, format_err, Fallible};
 use serde::{de::DeserializeOwned, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str;
 
+use super::{BakedMutation, Mutation};
+
 const DEFAULT_MUTAGEN_DIR: &str = "target/mutagen";
 const DEFAULT_MUTAGEN_FILENAME: &str = "mutations";
 const JSON_MUTAGEN_FILENAME: &str = "mutations.json";
 
 const DEFAULT_COVERAGE_FILENAME: &str = "coverage";
 
+const KILLING_TESTS_FILENAME: &str = "killing_tests.json";
+
+const MUTATOR_SCORES_FILENAME: &str = "mutator_scores.json";
+
 /// Finds the file that contains the descriptions of all mutations as written by the procedural macro
 pub fn get_mutations_file() -> Fallible<PathBuf> {
     Ok(mutagen_dir()?.join(DEFAULT_MUTAGEN_FILENAME))
@@ -25,6 +32,16 @@ pub fn get_coverage_file() -> Fallible<PathBuf> {
     Ok(mutagen_dir()?.join(DEFAULT_COVERAGE_FILENAME))
 }
 
+/// Finds the file that lists the tests that killed each mutation, as written by the runner
+pub fn get_killing_tests_file() -> Fallible<PathBuf> {
+    Ok(mutagen_dir()?.join(KILLING_TESTS_FILENAME))
+}
+
+/// Finds the file that contains the number of killed and surviving mutants of each mutator, as written by the runner
+pub fn get_mutator_scores_file() -> Fallible<PathBuf> {
+    Ok(mutagen_dir()?.join(MUTATOR_SCORES_FILENAME))
+}
+
 /// queries `cargo` for the workspace root and locates the directory to write mutagen-specific information
 fn mutagen_dir() -> Fallible<PathBuf> {
     let metadata = Command::new("cargo").arg("metadata").output()?;
@@ -49,9 +66,81 @@ pub fn read_items<T: DeserializeOwned>(filepath: &Path) -> Fallible<Vec<T>> {
         .collect()
 }
 
-pub fn append_item<T: Serialize + ?Sized>(file: &mut File, item: &T) -> Fallible<()> {
+pub fn append_item<T: Serialize + ?Sized>(file: impl Write, item: &T) -> Fallible<()> {
     let mut w = BufWriter::new(file);
     serde_json::to_writer(&mut w, item)?;
     writeln!(&mut w)?; // write newline
     Ok(())
 }
+
+/// Writes the catalog of all mutations, which maps the id of each mutation to its description.
+///
+/// This is the format of the file `target/mutagen/mutations.json`.
+pub fn write_mutations_catalog(file: impl Write, mutations: &[BakedMutation]) -> Fallible<()> {
+    let mutations_map = mutations
+        .iter()
+        .map(|m| (m.id(), m.as_ref()))
+        .collect::<HashMap<_, _>>();
+    serde_json::to_writer(BufWriter::new(file), &mutations_map)?;
+    Ok(())
+}
+
+/// Reads a catalog of mutations written by `write_mutations_catalog`.
+///
+/// Returns the ids of all mutations together with their descriptions, sorted by id.
+/// Each id can be activated via the environment variable `MUTATION_ID`.
+pub fn read_mutations_catalog(filepath: &Path) -> Fallible<Vec<(usize, Mutation)>> {
+    let mutations: BTreeMap<usize, Mutation> =
+        serde_json::from_reader(BufReader::new(File::open(filepath)?))
+            .map_err(|e| format_err!("mutation catalog format error in {:?}: {}", filepath, e))?;
+    Ok(mutations.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn mutations_catalog_round_trip() {
+        let mutations = vec![
+            Mutation {
+                mutator: "binop_num".to_owned(),
+                location_in_file: "1:5-1:6".to_owned(),
+                ..Mutation::new_stub()
+            }
+            .with_id(1, 1),
+            Mutation {
+                mutator: "lit_int".to_owned(),
+                location_in_file: "2:9-2:10".to_owned(),
+                ..Mutation::new_stub()
+            }
+            .with_id(2, 2),
+            Mutation {
+                mutator: "lit_int".to_owned(),
+                location_in_file: "2:9-2:10".to_owned(),
+                ..Mutation::new_stub()
+            }
+            .with_id(3, 2),
+        ];
+        let file = std::env::temp_dir().join("mutagen-test-catalog.json");
+        write_mutations_catalog(File::create(&file).unwrap(), &mutations).unwrap();
+
+        let catalog = read_mutations_catalog(&file).unwrap();
+
+        let ids = catalog.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![1, 2, 3]);
+        for ((_, loaded), original) in catalog.iter().zip(&mutations) {
+            assert_eq!(loaded, original.as_ref());
+            assert_eq!(loaded.mutator, original.mutator_name());
+        }
+    }
+
+    #[test]
+    fn mutations_catalog_invalid() {
+        let file = std::env::temp_dir().join("mutagen-test-catalog-invalid.json");
+        std::fs::write(&file, "[1, 2]").unwrap();
+
+        assert!(read_mutations_catalog(&file).is_err());
+    }
+}
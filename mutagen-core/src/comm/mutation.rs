@@ -26,6 +26,63 @@ pub struct Mutation {
     pub mutated_code: String,
     pub source_file: PathBuf,
     pub location_in_file: String,
+    /// what happens when an optimistic assumption of this mutation fails
+    #[serde(default)]
+    pub optimistic_policy: OptimisticPolicy,
+}
+
+/// The outcome of a mutant whose optimistic assumption fails at runtime.
+///
+/// The policy is registered by the mutator alongside the mutation and passed to the test suite via the environment variable `MUTAGEN_OPTIMISTIC_POLICY`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptimisticPolicy {
+    /// the test panics, which kills the mutant
+    #[default]
+    Killed,
+    /// the mutant is skipped and counts neither as killed nor as survived
+    Skip,
+    /// the run of all mutations is aborted
+    Fatal,
+}
+
+impl OptimisticPolicy {
+    /// the name of the policy, as used in the environment variable `MUTAGEN_OPTIMISTIC_POLICY`
+    pub fn name(self) -> &'static str {
+        match self {
+            OptimisticPolicy::Killed => "killed",
+            OptimisticPolicy::Skip => "skip",
+            OptimisticPolicy::Fatal => "fatal",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "killed" => Some(OptimisticPolicy::Killed),
+            "skip" => Some(OptimisticPolicy::Skip),
+            "fatal" => Some(OptimisticPolicy::Fatal),
+            _ => None,
+        }
+    }
+
+    /// The exit code of a test suite that stops because of a failed optimistic assumption.
+    ///
+    /// Returns `None` for the policy `Killed`, since the test suite panics in this case.
+    pub fn exit_code(self) -> Option<i32> {
+        match self {
+            OptimisticPolicy::Killed => None,
+            OptimisticPolicy::Skip => Some(97),
+            OptimisticPolicy::Fatal => Some(98),
+        }
+    }
+
+    /// Returns the policy that causes a test suite to exit with the given code, if any.
+    pub fn from_exit_code(code: i32) -> Option<Self> {
+        [OptimisticPolicy::Skip, OptimisticPolicy::Fatal]
+            .iter()
+            .copied()
+            .find(|policy| policy.exit_code() == Some(code))
+    }
 }
 
 impl Mutation {
@@ -54,6 +111,15 @@ impl Mutation {
             mutated_code,
             source_file,
             location_in_file,
+            optimistic_policy: OptimisticPolicy::default(),
+        }
+    }
+
+    /// Sets what happens when an optimistic assumption of this mutation fails.
+    pub fn with_optimistic_policy(self, optimistic_policy: OptimisticPolicy) -> Self {
+        Self {
+            optimistic_policy,
+            ..self
         }
     }
 
@@ -82,12 +148,45 @@ impl Mutation {
         }
     }
 
+    /// a hash of the mutation that does not depend on its id or its location in the source file.
+    ///
+    /// The hash is computed with FNV-1a, which gives the same results for all compiler versions.
+    pub fn stable_hash(&self) -> u64 {
+        let fields = [
+            self.impl_name.as_deref(),
+            self.fn_name.as_deref(),
+            Some(&*self.mutator),
+            Some(&*self.original_code),
+            Some(&*self.mutated_code),
+        ];
+        let mut hash = 0xcbf2_9ce4_8422_2325u64;
+        for field in &fields {
+            // distinguish missing fields from empty fields and separate all fields
+            let bytes = field.map(str::as_bytes).unwrap_or(&[0xff]);
+            for &b in bytes.iter().chain(&[0]) {
+                hash ^= u64::from(b);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        hash
+    }
+
+    /// the path of the function that contains the mutation, like `push` or `Stack::push` for methods.
+    ///
+    /// Mutations can be grouped by this path, since all mutations of a function have the same path.
+    pub fn fn_path(&self) -> Option<String> {
+        let fn_name = self.fn_name.as_ref()?;
+        Some(match &self.impl_name {
+            Some(impl_name) => format!("{}::{}", impl_name, fn_name),
+            None => fn_name.clone(),
+        })
+    }
+
     pub fn context_description_in_brackets(&self) -> String {
-        match (&self.fn_name, &self.impl_name) {
+        match (self.fn_path(), &self.impl_name) {
             (None, None) => format!(""),
-            (Some(fn_name), None) => format!("(fn {})", fn_name),
             (None, Some(impl_name)) => format!("(impl {})", impl_name),
-            (Some(fn_name), Some(impl_name)) => format!("(fn {}::{})", impl_name, fn_name),
+            (Some(fn_path), _) => format!("(fn {})", fn_path),
         }
     }
 }
@@ -110,6 +209,14 @@ impl BakedMutation {
         self.mutation.fn_name.as_ref().map(String::deref)
     }
 
+    pub fn impl_name(&self) -> Option<&str> {
+        self.mutation.impl_name.as_deref()
+    }
+
+    pub fn fn_path(&self) -> Option<String> {
+        self.mutation.fn_path()
+    }
+
     pub fn original_code(&self) -> &str {
         self.mutation.original_code.deref()
     }
@@ -130,6 +237,12 @@ impl BakedMutation {
     pub fn context_description_in_brackets(&self) -> String {
         self.mutation.context_description_in_brackets()
     }
+    pub fn stable_hash(&self) -> u64 {
+        self.mutation.stable_hash()
+    }
+    pub fn optimistic_policy(&self) -> OptimisticPolicy {
+        self.mutation.optimistic_policy
+    }
 }
 
 impl AsRef<Mutation> for BakedMutation {
@@ -150,6 +263,93 @@ impl Mutation {
             mutated_code: "stub".to_owned(),
             source_file: PathBuf::new(),
             location_in_file: "stub".to_owned(),
+            optimistic_policy: OptimisticPolicy::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn stable_hash_ignores_location() {
+        let mut moved = Mutation::new_stub();
+        moved.location_in_file = "1:1-1:2".to_owned();
+        assert_eq!(moved.stable_hash(), Mutation::new_stub().stable_hash());
+    }
+
+    #[test]
+    fn optimistic_policy_names() {
+        for &policy in &[
+            OptimisticPolicy::Killed,
+            OptimisticPolicy::Skip,
+            OptimisticPolicy::Fatal,
+        ] {
+            assert_eq!(OptimisticPolicy::from_name(policy.name()), Some(policy));
         }
+        assert_eq!(OptimisticPolicy::from_name("other"), None);
+    }
+
+    #[test]
+    fn optimistic_policy_exit_codes() {
+        assert_eq!(
+            OptimisticPolicy::from_exit_code(97),
+            Some(OptimisticPolicy::Skip)
+        );
+        assert_eq!(
+            OptimisticPolicy::from_exit_code(98),
+            Some(OptimisticPolicy::Fatal)
+        );
+        assert_eq!(OptimisticPolicy::from_exit_code(101), None);
+    }
+
+    #[test]
+    fn optimistic_policy_defaults_to_killed() {
+        let json = r#"{"impl_name":null,"fn_name":null,"mutator":"stub","original_code":"stub","mutated_code":"stub","source_file":"","location_in_file":"stub"}"#;
+        let mutation: Mutation = serde_json::from_str(json).unwrap();
+        assert_eq!(mutation.optimistic_policy, OptimisticPolicy::Killed);
+    }
+
+    #[test]
+    fn stable_hash_depends_on_mutated_code() {
+        let mut other = Mutation::new_stub();
+        other.mutated_code = "other".to_owned();
+        assert_ne!(other.stable_hash(), Mutation::new_stub().stable_hash());
+    }
+
+    #[test]
+    fn stable_hash_distinguishes_missing_and_empty_fn_name() {
+        let mut empty = Mutation::new_stub();
+        empty.fn_name = Some("".to_owned());
+        assert_ne!(empty.stable_hash(), Mutation::new_stub().stable_hash());
+    }
+
+    #[test]
+    fn fn_path_of_fn() {
+        let mut mutation = Mutation::new_stub();
+        mutation.fn_name = Some("negated".to_owned());
+        assert_eq!(mutation.fn_path().as_deref(), Some("negated"));
+        assert_eq!(mutation.context_description_in_brackets(), "(fn negated)");
+    }
+
+    #[test]
+    fn fn_path_of_method() {
+        let mut mutation = Mutation::new_stub();
+        mutation.impl_name = Some("Stack".to_owned());
+        mutation.fn_name = Some("push".to_owned());
+        let baked = mutation.with_id(1, 1);
+        assert_eq!(baked.impl_name(), Some("Stack"));
+        assert_eq!(baked.fn_path().as_deref(), Some("Stack::push"));
+        assert_eq!(baked.context_description_in_brackets(), "(fn Stack::push)");
+    }
+
+    #[test]
+    fn fn_path_outside_of_fn() {
+        let mut mutation = Mutation::new_stub();
+        mutation.impl_name = Some("Stack".to_owned());
+        assert_eq!(mutation.fn_path(), None);
+        assert_eq!(mutation.context_description_in_brackets(), "(impl Stack)");
     }
 }
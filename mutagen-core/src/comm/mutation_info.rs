@@ -0,0 +1,32 @@
+/// Description of a baked mutation that is embedded into the code under test.
+///
+/// The table of these descriptions for all mutations in a binary is returned by `MutagenRuntimeConfig::mutation_table`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MutationInfo {
+    pub id: usize,
+    /// id of the mutator that generates this mutation
+    pub mutator_id: usize,
+    pub mutator: &'static str,
+    pub impl_name: Option<&'static str>,
+    pub fn_name: Option<&'static str>,
+    pub original_code: &'static str,
+    pub mutated_code: &'static str,
+    pub source_file: &'static str,
+    pub location_in_file: &'static str,
+}
+
+impl MutationInfo {
+    /// construct a string representation of the mutation, like `cargo mutagen` does in its report
+    pub fn mutation_description(&self) -> String {
+        if self.mutated_code.is_empty() {
+            format!("remove `{}`", self.original_code)
+        } else if self.original_code.is_empty() {
+            format!("insert `{}`", self.mutated_code)
+        } else {
+            format!(
+                "replace `{}` with `{}`",
+                self.original_code, self.mutated_code
+            )
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::fs::File;
+
+use super::{append_item, BakedMutation};
+
+/// A destination for the records of all mutations that are inserted into the code under test.
+///
+/// By default, mutations are recorded in the file `target/mutagen/mutations`, which is read by the runner.
+/// Build systems can provide other sinks, e.g. to write the records to a socket or a custom location.
+pub trait MutationSink: Send {
+    /// Records a single mutation.
+    ///
+    /// Mutations are recorded in the order of their ids.
+    fn record(&self, mutation: &BakedMutation);
+}
+
+/// Records mutations as json-lines in a file.
+pub struct FileSink {
+    file: File,
+}
+
+impl FileSink {
+    pub fn new(file: File) -> Self {
+        Self { file }
+    }
+}
+
+impl MutationSink for FileSink {
+    fn record(&self, mutation: &BakedMutation) {
+        append_item(&self.file, mutation).expect("unable to write to mutagen file");
+    }
+}
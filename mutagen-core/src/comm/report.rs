@@ -1,13 +1,20 @@
+use failure::{bail, Fallible};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 
-use super::BakedMutation;
+use super::{BakedMutation, OptimisticPolicy};
 
 #[derive(Serialize, Deserialize, Default)]
 pub struct MutagenReport {
     mutant_results: HashMap<BakedMutation, MutantStatus>,
     summary: ReportSummary,
+    /// mutators whose surviving mutants are expected to be equivalent to the original code
+    #[serde(default)]
+    expected_equivalent: HashSet<String>,
+    /// the number of killed and surviving mutants of each mutator
+    #[serde(default)]
+    mutator_scores: BTreeMap<String, MutatorScore>,
 }
 
 #[derive(Copy, Clone, Default, Serialize, Deserialize)]
@@ -17,6 +24,40 @@ pub struct ReportSummary {
     timeout: usize,
     survived: usize,
     not_covered: usize,
+    #[serde(default)]
+    informational: usize,
+    #[serde(default)]
+    skipped: usize,
+}
+
+/// The number of killed and surviving mutants of a single mutator.
+///
+/// `cargo mutagen` writes the scores of all mutators to `target/mutagen/mutator_scores.json`.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MutatorScore {
+    pub killed: usize,
+    pub survived: usize,
+}
+
+impl MutatorScore {
+    fn add_mutation_result(&mut self, status: MutantStatus) {
+        match status {
+            MutantStatus::Killed(_) | MutantStatus::Timeout => self.killed += 1,
+            MutantStatus::Survived | MutantStatus::NotCovered => self.survived += 1,
+            MutantStatus::Skipped => {}
+        }
+    }
+
+    /// The fraction of the mutants of this mutator that survived.
+    ///
+    /// Returns `None` if no mutant of this mutator has been killed or survived.
+    pub fn survival_rate(&self) -> Option<f64> {
+        let total = self.killed + self.survived;
+        if total == 0 {
+            return None;
+        }
+        Some(self.survived as f64 / total as f64)
+    }
 }
 
 impl MutagenReport {
@@ -24,11 +65,39 @@ impl MutagenReport {
         Self::default()
     }
 
+    /// Creates a report where surviving mutants of the given mutators are informational.
+    ///
+    /// Some mutators (e.g. changing a capacity) often produce mutants that are equivalent to the original code.
+    /// Survivors of these mutators are not counted as survived but listed as informational.
+    pub fn with_expected_equivalent(mutators: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            expected_equivalent: mutators.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
     pub fn add_mutation_result(&mut self, mutation: BakedMutation, status: MutantStatus) {
         assert!(!self.mutant_results.contains_key(&mutation));
         // TODO: instead use: .expect_none("mutation already added");
+        let informational = self.is_informational(&mutation, status);
+        self.mutator_scores
+            .entry(mutation.mutator_name().to_owned())
+            .or_default()
+            .add_mutation_result(status);
         self.mutant_results.insert(mutation, status);
-        self.summary.add_mutation_result(status);
+        self.summary.add_mutation_result(status, informational);
+    }
+
+    /// Checks if the mutator of the given mutation is expected to produce equivalent mutants.
+    pub fn is_expected_equivalent(&self, mutation: &BakedMutation) -> bool {
+        self.expected_equivalent.contains(mutation.mutator_name())
+    }
+
+    /// Checks if the result of the mutation is only informational.
+    ///
+    /// This is the case for covered mutants that survived and whose mutator is expected to produce equivalent mutants.
+    pub fn is_informational(&self, mutation: &BakedMutation, status: MutantStatus) -> bool {
+        status == MutantStatus::Survived && self.is_expected_equivalent(mutation)
     }
 
     /// creates a map of mutations per file.
@@ -60,6 +129,10 @@ impl MutagenReport {
                 .into_iter()
                 .filter(|(_, s)| s.survived())
                 .collect::<Vec<_>>();
+            let num_informational = survived
+                .iter()
+                .filter(|(m, s)| self.is_informational(m, *s))
+                .count();
             let num_survived = survived.len();
 
             println!("    {}", file.display());
@@ -84,33 +157,58 @@ impl MutagenReport {
                     m.context_description_in_brackets(),
                     if s == MutantStatus::NotCovered {
                         format!(" {}", MutantStatus::NotCovered)
+                    } else if self.is_informational(m, s) {
+                        " (expected equivalent)".to_owned()
                     } else {
                         "".to_owned()
                     },
                 );
             }
+            if num_informational != 0 {
+                println!(
+                    "            {} of the surviving mutants are expected to be equivalent",
+                    num_informational
+                );
+            }
         }
     }
 
     pub fn summary(&self) -> ReportSummary {
         self.summary
     }
+
+    /// the number of killed and surviving mutants of each mutator, ordered by the name of the mutator
+    pub fn mutator_scores(&self) -> &BTreeMap<String, MutatorScore> {
+        &self.mutator_scores
+    }
+
+    pub fn print_mutator_scores(&self) {
+        println!("MUTATORS");
+        for (mutator, score) in &self.mutator_scores {
+            println!(
+                "    {}: {} killed, {} survived",
+                mutator, score.killed, score.survived
+            );
+        }
+    }
 }
 
 impl ReportSummary {
-    fn add_mutation_result(&mut self, status: MutantStatus) {
+    fn add_mutation_result(&mut self, status: MutantStatus, informational: bool) {
         self.num_mutations += 1;
         match status {
             MutantStatus::NotCovered => {
                 self.not_covered += 1;
                 self.survived += 1;
             }
+            MutantStatus::Survived if informational => self.informational += 1,
             MutantStatus::Survived => self.survived += 1,
             MutantStatus::Killed(_) => self.killed += 1,
             MutantStatus::Timeout => {
                 self.timeout += 1;
                 self.killed += 1;
             }
+            MutantStatus::Skipped => self.skipped += 1,
         }
     }
 
@@ -133,9 +231,38 @@ impl ReportSummary {
             self.not_covered,
             percent_mutations_not_covered,
         );
+        if self.informational != 0 {
+            println!(
+                "{}({:.2}%) mutants survived as expected equivalent",
+                self.informational,
+                compute_percent(self.num_mutations, self.informational),
+            );
+        }
+        if self.skipped != 0 {
+            println!(
+                "{}({:.2}%) mutants skipped after a failed optimistic assumption",
+                self.skipped,
+                compute_percent(self.num_mutations, self.skipped),
+            );
+        }
+    }
+
+    /// Returns the number of mutants that survived, excluding expected equivalent mutants.
+    pub fn num_survived(&self) -> usize {
+        self.survived
+    }
+
+    /// Returns the number of surviving mutants that are expected to be equivalent.
+    pub fn num_informational(&self) -> usize {
+        self.informational
     }
 }
 
+/// The exit code of a test suite that stops because a mutated function exceeds the recursion limit.
+///
+/// The limit is set by the environment variable `MUTAGEN_RECURSION_LIMIT`.
+pub const RECURSION_LIMIT_EXIT_CODE: i32 = 96;
+
 /// Result from a test run
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub enum MutantStatus {
@@ -147,9 +274,24 @@ pub enum MutantStatus {
     Killed(Option<i32>),
     /// the test timed out
     Timeout,
+    /// an optimistic assumption of the mutation failed and the mutation is skipped
+    Skipped,
 }
 
 impl MutantStatus {
+    /// Determines the status of a mutant from the exit code of a failing test suite.
+    ///
+    /// Test suites exit with special codes if an optimistic assumption fails and the mutation does not count as killed.
+    /// Returns an error if the failed assumption is fatal.
+    /// Exceeding the recursion limit kills the mutant.
+    pub fn of_failed_run(code: Option<i32>) -> Fallible<Self> {
+        match code.and_then(OptimisticPolicy::from_exit_code) {
+            Some(OptimisticPolicy::Skip) => Ok(Self::Skipped),
+            Some(OptimisticPolicy::Fatal) => bail!("optimistic assumption failed"),
+            _ => Ok(Self::Killed(code)),
+        }
+    }
+
     fn survived(self) -> bool {
         self == Self::NotCovered || self == Self::Survived
     }
@@ -160,12 +302,196 @@ impl fmt::Display for MutantStatus {
         match self {
             Self::NotCovered => write!(f, "NOT COVERED"),
             Self::Survived => write!(f, "SURVIVED"),
+            Self::Killed(Some(RECURSION_LIMIT_EXIT_CODE)) => write!(f, "killed (recursion limit)"),
             Self::Killed(_) => write!(f, "killed"),
             Self::Timeout => write!(f, "killed (timeout)"),
+            Self::Skipped => write!(f, "skipped (optimistic assumption failed)"),
         }
     }
 }
 
+/// Checks if a run in fail-fast mode should stop after the given outcomes.
+///
+/// The run stops as soon as a mutant survived. Mutants that are not covered count as survived, while survivors that are expected to be equivalent do not.
+pub fn should_stop(outcomes: &ReportSummary) -> bool {
+    outcomes.num_survived() != 0
+}
+
 fn compute_percent(total: usize, num: usize) -> f64 {
     100.0 * num as f64 / total as f64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comm::Mutation;
+
+    fn mutation_of(mutator: &str, id: usize) -> BakedMutation {
+        Mutation {
+            mutator: mutator.to_owned(),
+            ..Mutation::new_stub()
+        }
+        .with_id(id, id)
+    }
+
+    #[test]
+    fn survived_without_expected_equivalent() {
+        let mut report = MutagenReport::new();
+        report.add_mutation_result(mutation_of("box_new", 1), MutantStatus::Survived);
+
+        assert_eq!(report.summary().num_survived(), 1);
+        assert_eq!(report.summary().num_informational(), 0);
+    }
+
+    #[test]
+    fn survived_expected_equivalent_is_informational() {
+        let mut report = MutagenReport::with_expected_equivalent(vec!["box_new".to_owned()]);
+        let m = mutation_of("box_new", 1);
+
+        assert!(report.is_informational(&m, MutantStatus::Survived));
+        report.add_mutation_result(m, MutantStatus::Survived);
+
+        assert_eq!(report.summary().num_survived(), 0);
+        assert_eq!(report.summary().num_informational(), 1);
+    }
+
+    #[test]
+    fn survived_other_mutator_is_not_informational() {
+        let mut report = MutagenReport::with_expected_equivalent(vec!["box_new".to_owned()]);
+        report.add_mutation_result(mutation_of("binop_num", 1), MutantStatus::Survived);
+        report.add_mutation_result(mutation_of("box_new", 2), MutantStatus::Survived);
+
+        assert_eq!(report.summary().num_survived(), 1);
+        assert_eq!(report.summary().num_informational(), 1);
+    }
+
+    #[test]
+    fn mutator_scores_per_mutator() {
+        let mut report = MutagenReport::new();
+        report.add_mutation_result(mutation_of("binop_num", 1), MutantStatus::Killed(None));
+        report.add_mutation_result(mutation_of("binop_num", 2), MutantStatus::Timeout);
+        report.add_mutation_result(mutation_of("binop_num", 3), MutantStatus::Survived);
+        report.add_mutation_result(mutation_of("lit_int", 4), MutantStatus::NotCovered);
+        report.add_mutation_result(mutation_of("lit_int", 5), MutantStatus::Skipped);
+
+        let scores = report.mutator_scores();
+
+        assert_eq!(
+            scores["binop_num"],
+            MutatorScore {
+                killed: 2,
+                survived: 1
+            }
+        );
+        assert_eq!(
+            scores["lit_int"],
+            MutatorScore {
+                killed: 0,
+                survived: 1
+            }
+        );
+        assert_eq!(scores.len(), 2);
+    }
+
+    #[test]
+    fn mutator_score_survival_rate() {
+        let score = MutatorScore {
+            killed: 3,
+            survived: 1,
+        };
+
+        assert_eq!(score.survival_rate(), Some(0.25));
+        assert_eq!(MutatorScore::default().survival_rate(), None);
+    }
+
+    #[test]
+    fn should_stop_on_first_survivor() {
+        let mut report = MutagenReport::new();
+        report.add_mutation_result(mutation_of("binop_num", 1), MutantStatus::Killed(Some(101)));
+        assert!(!should_stop(&report.summary()));
+
+        report.add_mutation_result(mutation_of("binop_num", 2), MutantStatus::Survived);
+        assert!(should_stop(&report.summary()));
+    }
+
+    #[test]
+    fn should_not_stop_on_kills() {
+        let mut report = MutagenReport::new();
+        report.add_mutation_result(mutation_of("binop_num", 1), MutantStatus::Killed(None));
+        report.add_mutation_result(mutation_of("binop_num", 2), MutantStatus::Timeout);
+
+        assert!(!should_stop(&report.summary()));
+    }
+
+    #[test]
+    fn should_stop_on_not_covered() {
+        let mut report = MutagenReport::new();
+        report.add_mutation_result(mutation_of("binop_num", 1), MutantStatus::NotCovered);
+
+        assert!(should_stop(&report.summary()));
+    }
+
+    #[test]
+    fn should_not_stop_on_expected_equivalent() {
+        let mut report = MutagenReport::with_expected_equivalent(vec!["box_new".to_owned()]);
+        report.add_mutation_result(mutation_of("box_new", 1), MutantStatus::Survived);
+
+        assert!(!should_stop(&report.summary()));
+    }
+
+    /// the status of a mutant whose optimistic assumption failed with the given policy
+    fn status_after_optimistic_failure(policy: OptimisticPolicy) -> Fallible<MutantStatus> {
+        // a panicking test suite exits with code 101
+        MutantStatus::of_failed_run(Some(policy.exit_code().unwrap_or(101)))
+    }
+
+    #[test]
+    fn optimistic_failure_with_different_policies() {
+        let killed = Mutation::new_stub().with_optimistic_policy(OptimisticPolicy::Killed);
+        let skipped = Mutation::new_stub().with_optimistic_policy(OptimisticPolicy::Skip);
+
+        let mut report = MutagenReport::new();
+        for (id, mutation) in vec![killed, skipped].into_iter().enumerate() {
+            let m = mutation.with_id(id + 1, id + 1);
+            let status = status_after_optimistic_failure(m.optimistic_policy()).unwrap();
+            report.add_mutation_result(m, status);
+        }
+
+        assert_eq!(report.summary().killed, 1);
+        assert_eq!(report.summary().skipped, 1);
+        assert_eq!(report.summary().num_survived(), 0);
+    }
+
+    #[test]
+    fn optimistic_failure_fatal() {
+        assert!(status_after_optimistic_failure(OptimisticPolicy::Fatal).is_err());
+    }
+
+    #[test]
+    fn failed_run_recursion_limit() {
+        assert_eq!(
+            MutantStatus::of_failed_run(Some(RECURSION_LIMIT_EXIT_CODE)).unwrap(),
+            MutantStatus::Killed(Some(RECURSION_LIMIT_EXIT_CODE))
+        );
+    }
+
+    #[test]
+    fn failed_run_without_code() {
+        assert_eq!(
+            MutantStatus::of_failed_run(None).unwrap(),
+            MutantStatus::Killed(None)
+        );
+    }
+
+    #[test]
+    fn not_covered_expected_equivalent_is_not_informational() {
+        let mut report = MutagenReport::with_expected_equivalent(vec!["box_new".to_owned()]);
+        let m = mutation_of("box_new", 1);
+
+        assert!(!report.is_informational(&m, MutantStatus::NotCovered));
+        report.add_mutation_result(m, MutantStatus::NotCovered);
+
+        assert_eq!(report.summary().num_survived(), 1);
+        assert_eq!(report.summary().num_informational(), 0);
+    }
+}
@@ -0,0 +1,89 @@
+use std::fs::File;
+use std::sync::Mutex;
+
+use super::{append_item, CoverageHit};
+
+/// A destination for the events that are reported by mutated code while the test suite runs.
+///
+/// By default, coverage is recorded in the file `target/mutagen/coverage`, which is read by the runner.
+/// Test harnesses can provide other sinks, e.g. to collect the events in memory or to send them to another process.
+pub trait ResultSink: Send + Sync {
+    /// Records that the mutator with the given id has been covered for the first time.
+    fn on_covered(&self, mutator_id: usize);
+
+    /// Records that an optimistic assumption failed for the given mutation.
+    ///
+    /// This is called before the test suite is aborted.
+    fn on_optimistic_failed(&self, mutation_id: usize);
+}
+
+/// An event reported to a `ResultSink`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ResultEvent {
+    Covered(usize),
+    OptimisticFailed(usize),
+}
+
+/// Records coverage hits as json-lines in a file.
+///
+/// Failed optimistic assumptions are not recorded, since they are reported via the exit code of the test suite.
+pub struct CoverageFileSink {
+    file: File,
+}
+
+impl CoverageFileSink {
+    pub fn new(file: File) -> Self {
+        Self { file }
+    }
+}
+
+impl ResultSink for CoverageFileSink {
+    fn on_covered(&self, mutator_id: usize) {
+        append_item(&self.file, &CoverageHit { mutator_id })
+            .expect("unable to write to coverage file");
+    }
+
+    fn on_optimistic_failed(&self, _mutation_id: usize) {}
+}
+
+/// Collects all events in memory.
+#[derive(Default)]
+pub struct MemoryResultSink {
+    events: Mutex<Vec<ResultEvent>>,
+}
+
+impl MemoryResultSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The events that have been recorded so far, in the order they have been reported.
+    pub fn events(&self) -> Vec<ResultEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl ResultSink for MemoryResultSink {
+    fn on_covered(&self, mutator_id: usize) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(ResultEvent::Covered(mutator_id));
+    }
+
+    fn on_optimistic_failed(&self, mutation_id: usize) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(ResultEvent::OptimisticFailed(mutation_id));
+    }
+}
+
+/// Discards all events.
+pub struct NoResultSink;
+
+impl ResultSink for NoResultSink {
+    fn on_covered(&self, _mutator_id: usize) {}
+
+    fn on_optimistic_failed(&self, _mutation_id: usize) {}
+}
@@ -2,12 +2,34 @@
 #![feature(proc_macro_span)]
 #![feature(specialization)]
 
+extern crate proc_macro;
+
 mod runtime_config;
 mod transformer;
 
 pub mod comm;
 pub mod mutator;
 
-pub use runtime_config::MutagenRuntimeConfig;
+pub use runtime_config::{
+    replay, ConfigGuard, ConfigSnapshot, MutagenRuntimeConfig, RecursionGuard, ReplayGuard,
+};
 
 pub use transformer::do_transform_item;
+
+/// registers the mutations of transformed items when the binary is loaded
+#[doc(hidden)]
+pub use ctor::ctor;
+
+/// Sets the sink that records all mutations of `#[mutate]` attributes with a global configuration.
+///
+/// This has to be called before the first item is transformed.
+pub fn set_mutation_sink(sink: impl comm::MutationSink + 'static) {
+    transformer::transform_info::SharedTransformInfo::set_global_sink(Box::new(sink))
+}
+
+/// Sets the sink that receives coverage and failed optimistic assumptions reported by mutated code.
+///
+/// This has to be called before the first mutated code is executed.
+pub fn set_result_sink(sink: impl comm::ResultSink + 'static) {
+    runtime_config::set_result_sink(std::sync::Arc::new(sink))
+}
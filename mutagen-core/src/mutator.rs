@@ -1,10 +1,76 @@
 // the modules below are public to enable the use of types in that modules at runtime
+pub mod mutator_abs_signum;
+pub mod mutator_array_len;
 pub mod mutator_binop_bit;
 pub mod mutator_binop_bool;
 pub mod mutator_binop_cmp;
 pub mod mutator_binop_eq;
 pub mod mutator_binop_num;
+pub mod mutator_binop_shift;
+pub mod mutator_bit_assign;
+pub mod mutator_bool_query;
+pub mod mutator_bool_return_negate;
+pub mod mutator_bool_then;
+pub mod mutator_borrow;
+pub mod mutator_bound_inclusive;
+pub mod mutator_box_new;
+pub mod mutator_ceil_div;
+pub mod mutator_checked_none;
+pub mod mutator_checked_unwrap;
+pub mod mutator_cmp_chain;
+pub mod mutator_cmp_cloned;
+pub mod mutator_collect_target;
+pub mod mutator_combinator_order;
+pub mod mutator_const_init;
+pub mod mutator_constructor;
+pub mod mutator_copied_cloned;
+pub mod mutator_counter_step;
+pub mod mutator_debug_assert;
+pub mod mutator_dedup;
+pub mod mutator_duration_threshold;
+pub mod mutator_early_return;
+pub mod mutator_extend;
+pub mod mutator_find;
+pub mod mutator_first;
+pub mod mutator_first_last;
+pub mod mutator_flag_ops;
+pub mod mutator_flatten;
+pub mod mutator_float_eps;
+pub mod mutator_get_index;
+pub mod mutator_get_unchecked;
+pub mod mutator_hash_seed;
+pub mod mutator_int_cast;
+pub mod mutator_iter_count;
+pub mod mutator_iter_rev;
+pub mod mutator_iter_source;
 pub mod mutator_lit_bool;
+pub mod mutator_lit_char;
 pub mod mutator_lit_int;
+pub mod mutator_lit_sign;
+pub mod mutator_loop_bound;
+pub mod mutator_map_entry;
+pub mod mutator_map_identity;
+pub mod mutator_match_result;
+pub mod mutator_min_max;
+pub mod mutator_ordering_then;
+pub mod mutator_overflow_policy;
+pub mod mutator_pow;
+pub mod mutator_repeat_count;
+pub mod mutator_resize_fill;
+pub mod mutator_result_ok;
+pub mod mutator_retain;
+pub mod mutator_reverse;
+pub mod mutator_rotate;
+pub mod mutator_slice_window;
 pub mod mutator_stmt_call;
+pub mod mutator_str_case;
+pub mod mutator_str_split;
+pub mod mutator_str_trim;
+pub mod mutator_struct_update;
+pub mod mutator_take;
+pub mod mutator_try;
+pub mod mutator_try_bypass;
 pub mod mutator_unop_not;
+pub mod mutator_vec_remove;
+pub mod mutator_while_iterations;
+pub mod mutator_zip_swap;
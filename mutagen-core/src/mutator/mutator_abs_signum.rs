@@ -0,0 +1,248 @@
+//! Mutator for the sign-related numeric methods `abs()` and `signum()`.
+//!
+//! The call of `abs` is removed and the result of `signum` is negated.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the result of `abs`, or the receiver itself if the mutation is active
+pub fn run_abs<T, U>(
+    mutator_id: usize,
+    receiver: T,
+    abs: impl FnOnce(T) -> U,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> U {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        MayCast::<U>::may_cast(receiver)
+    } else {
+        abs(receiver)
+    }
+}
+
+/// returns the result of `signum`, which is negated if the mutation is active
+pub fn run_signum<T>(
+    mutator_id: usize,
+    signum: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        signum.may_negate()
+    } else {
+        signum
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprAbsSignum::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the receiver is described as written in the original code, before other mutators have been applied to it
+    let original_receiver = match context.original_expr.clone().map(ExprAbsSignum::try_from) {
+        Some(Ok(original)) => original.call.receiver,
+        _ => e.call.receiver.clone(),
+    };
+    let original_receiver = original_receiver.to_token_stream().to_string();
+    let method = e.call.method.to_string();
+    let original_code = format!("{}.{}()", original_receiver, method);
+    let mutated_code = if method == "abs" {
+        original_receiver
+    } else {
+        format!("-{}", original_code)
+    };
+
+    // methods with these names can be defined on any type. These mutants are skipped.
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "abs_signum".to_owned(),
+            original_code,
+            mutated_code,
+            e.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip),
+    );
+
+    let span = e.span;
+    let call = &e.call;
+    if method == "abs" {
+        // parentheses around the receiver are not needed as argument of `run_abs`
+        let receiver = match &*e.call.receiver {
+            Expr::Paren(paren) => &paren.expr,
+            receiver => receiver,
+        };
+        let method = &e.call.method;
+        syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_abs_signum::run_abs(
+                    #mutator_id,
+                    #receiver,
+                    |__mutagen_receiver| __mutagen_receiver.#method(),
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid")
+    } else {
+        syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_abs_signum::run_signum(
+                    #mutator_id,
+                    #call,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid")
+    }
+}
+
+/// trait that is used to optimistically convert the receiver of `abs` into the type of its result.
+///
+/// Removing `abs` only type-checks if `abs` returns the type of its receiver, like for numbers.
+/// Otherwise, the optimistic assumption fails.
+trait MayCast<U> {
+    fn may_cast(self) -> U;
+}
+
+impl<T, U> MayCast<U> for T {
+    default fn may_cast(self) -> U {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T> MayCast<T> for T {
+    fn may_cast(self) -> T {
+        self
+    }
+}
+
+/// trait that is used to optimistically negate the result of `signum`.
+///
+/// Only signed integers and floats can be negated, otherwise the optimistic assumption fails.
+trait SignumMutable {
+    fn may_negate(self) -> Self;
+}
+
+impl<T> SignumMutable for T {
+    default fn may_negate(self) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+macro_rules! signum_mutable {
+    ($($t:ty),*) => {
+        $(
+            impl SignumMutable for $t {
+                fn may_negate(self) -> Self {
+                    -self
+                }
+            }
+        )*
+    };
+}
+
+signum_mutable!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[derive(Clone, Debug)]
+struct ExprAbsSignum {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprAbsSignum {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (call.method == "abs" || call.method == "signum")
+                    && call.args.is_empty()
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprAbsSignum {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn abs_inactive() {
+        let result = run_abs(
+            1,
+            -3i32,
+            i32::abs,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, 3);
+    }
+    #[test]
+    fn abs_active() {
+        let result = run_abs(
+            1,
+            -3i32,
+            i32::abs,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, -3);
+    }
+    #[test]
+    #[should_panic]
+    fn abs_different_type_active() {
+        run_abs(
+            1,
+            -3i32,
+            i32::unsigned_abs,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+    #[test]
+    fn signum_inactive() {
+        let result = run_signum(1, -1i32, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, -1);
+    }
+    #[test]
+    fn signum_active() {
+        let result = run_signum(1, -1.0f64, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 1.0);
+    }
+    #[test]
+    #[should_panic]
+    fn signum_unsigned_active() {
+        run_signum(1, 1u32, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_abs() {
+        let expr: Expr = parse_quote! {(a - b).abs()};
+        assert!(ExprAbsSignum::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_abs_with_args() {
+        let expr: Expr = parse_quote! {x.abs(y)};
+        assert!(ExprAbsSignum::try_from(expr).is_err());
+    }
+}
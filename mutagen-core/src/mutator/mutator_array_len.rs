@@ -0,0 +1,136 @@
+//! Mutator for literal lengths of array expressions, like `[0u8; 16]`.
+//!
+//! The length is incremented or decremented by 1.
+//! Array lengths are constant and cannot depend on the runtime configuration.
+//! Like for `const_init`, the active mutation is read from the environment variable `MUTATION_ID` (or the one named by `MUTAGEN_ID_VAR`) when compiling the code under test.
+
+use std::convert::TryFrom;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, ExprLit, ExprRepeat, Lit, LitInt};
+
+use crate::comm::Mutation;
+use crate::mutator::mutator_const_init::{compile_time_mutation_id, mutated_lit};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprArrayLen::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutated_lens = possible_mutations(e.len);
+    if mutated_lens.is_empty() {
+        return Expr::Repeat(e.repeat);
+    }
+
+    let span = e.span;
+    let mutator_id = transform_info.add_mutations(mutated_lens.iter().map(|len| {
+        Mutation::new_spanned(
+            context,
+            "array_len".to_owned(),
+            e.len.to_string(),
+            len.to_string(),
+            span,
+        )
+    }));
+
+    let num_mutations = mutated_lens.len();
+    let arms = mutated_lens.iter().enumerate().map(|(i, &len)| {
+        let i = i + 1;
+        // the mutated literals are not spanned to the original literal, lints would compare them to its source code
+        let mutated = mutated_lit(len, &e.lit, Span::call_site());
+        quote_spanned! {span=> #i => #mutated,}
+    });
+    let lit = &e.lit;
+    let mutation_id = compile_time_mutation_id(span);
+    e.repeat.len = syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_const_init::active_mutation(
+            #mutation_id,
+            #mutator_id,
+            #num_mutations,
+        ) {
+            #(#arms)*
+            _ => #lit,
+        }
+    })
+    .expect("transformed code invalid");
+    Expr::Repeat(e.repeat)
+}
+
+/// the mutated lengths of an array, an array of length 0 is only made longer
+fn possible_mutations(len: i128) -> Vec<i128> {
+    if len == 0 {
+        vec![1]
+    } else {
+        vec![len + 1, len - 1]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprArrayLen {
+    repeat: ExprRepeat,
+    lit: LitInt,
+    len: i128,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprArrayLen {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Repeat(repeat) => {
+                let lit = match &*repeat.len {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Int(lit), ..
+                    }) => lit.clone(),
+                    _ => return Err(Expr::Repeat(repeat)),
+                };
+                match lit.base10_parse::<i128>() {
+                    Ok(len) => Ok(ExprArrayLen {
+                        span: lit.span(),
+                        repeat,
+                        lit,
+                        len,
+                    }),
+                    Err(_) => Err(Expr::Repeat(repeat)),
+                }
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn possible_mutations_len() {
+        assert_eq!(possible_mutations(16), vec![17, 15]);
+    }
+    #[test]
+    fn possible_mutations_empty() {
+        assert_eq!(possible_mutations(0), vec![1]);
+    }
+
+    #[test]
+    fn detect_literal_len() {
+        let expr: Expr = parse_quote! {[0u8; 16]};
+        let array_len = ExprArrayLen::try_from(expr).unwrap();
+        assert_eq!(array_len.len, 16);
+    }
+    #[test]
+    fn ignore_const_len() {
+        let expr: Expr = parse_quote! {[0u8; N]};
+        assert!(ExprArrayLen::try_from(expr).is_err());
+    }
+}
@@ -1,4 +1,6 @@
 //! Mutator for binary bit-operations `|`, `&`, `^`.
+//!
+//! Masks given as integer literals to `&` and `|`, like `x & 0xff`, are additionally replaced with `0` and `!0`.
 
 use std::convert::TryFrom;
 use std::ops::Deref;
@@ -7,7 +9,7 @@ use std::ops::{BitAnd, BitOr, BitXor};
 use proc_macro2::{Span, TokenStream};
 use quote::quote_spanned;
 use syn::spanned::Spanned;
-use syn::{BinOp, Expr};
+use syn::{BinOp, Expr, Lit, LitInt};
 
 use crate::comm::Mutation;
 use crate::transformer::transform_info::SharedTransformInfo;
@@ -89,12 +91,26 @@ where
     }
 }
 
+/// Returns the index of the active mutation of the mask, if any.
+///
+/// The indices are the positions of the mutations in `MutationBitMask::ALL`.
+pub fn run_mask(
+    mutator_id: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<usize> {
+    runtime.covered(mutator_id);
+    let mutations = (0..MutationBitMask::ALL.len()).collect::<Vec<_>>();
+    runtime
+        .get_mutation_for_mutator(mutator_id, &mutations)
+        .copied()
+}
+
 pub fn transform(
     e: Expr,
     transform_info: &SharedTransformInfo,
     context: &TransformContext,
 ) -> Expr {
-    let e = match ExprBinopBit::try_from(e) {
+    let mut e = match ExprBinopBit::try_from(e) {
         Ok(e) => e,
         Err(e) => return e,
     };
@@ -105,6 +121,22 @@ pub fn transform(
             .map(|m| m.to_mutation(&e, context)),
     );
 
+    // the mask is detected in the original code, since the literal may have been mutated by `lit_int`
+    let original_mask = match context.original_expr.clone().map(ExprBinopBit::try_from) {
+        Some(Ok(original)) => original.mask(),
+        _ => None,
+    };
+    if let Some((mask, mask_left)) = original_mask {
+        let mask_id = transform_info.add_mutations(
+            MutationBitMask::ALL
+                .iter()
+                .map(|m| m.to_mutation(&mask, e.span, context)),
+        );
+        let operand = if mask_left { &mut e.left } else { &mut e.right };
+        let mutated = MutationBitMask::mutated_mask(mask_id, operand, &mask, e.span);
+        *operand = mutated;
+    }
+
     let left = &e.left;
     let right = &e.right;
 
@@ -171,6 +203,63 @@ impl MutationBinopBit {
     }
 }
 
+/// the replacements of a mask given as integer literal
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationBitMask {
+    Zero,
+    AllOnes,
+}
+
+impl MutationBitMask {
+    const ALL: [MutationBitMask; 2] = [MutationBitMask::Zero, MutationBitMask::AllOnes];
+
+    fn describe(self) -> &'static str {
+        match self {
+            MutationBitMask::Zero => "0",
+            MutationBitMask::AllOnes => "!0",
+        }
+    }
+
+    fn to_mutation(self, mask: &LitInt, span: Span, context: &TransformContext) -> Mutation {
+        Mutation::new_spanned(
+            context,
+            "binop_bit".to_owned(),
+            mask.to_string(),
+            self.describe().to_owned(),
+            span,
+        )
+    }
+
+    /// the operand of the mask, which is replaced depending on the active mutation.
+    ///
+    /// The replacements are literals with the suffix of the original mask, such that their type is inferred like the mask.
+    fn mutated_mask(mutator_id: usize, operand: &Expr, mask: &LitInt, span: Span) -> Expr {
+        let zero = LitInt::new(&format!("0{}", mask.suffix()), span);
+        syn::parse2(quote_spanned! {span=>
+            match ::mutagen::mutator::mutator_binop_bit::run_mask(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+                ::core::option::Option::Some(0) => #zero,
+                ::core::option::Option::Some(1) => !#zero,
+                _ => #operand,
+            }
+        })
+        .expect("transformed code invalid")
+    }
+}
+
+/// returns the literal if the expression is a non-zero integer literal
+fn int_lit(e: &Expr) -> Option<&LitInt> {
+    match e {
+        Expr::Lit(expr) => match &expr.lit {
+            Lit::Int(lit) if lit.base10_parse::<u128>().is_ok_and(|v| v != 0) => Some(lit),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[derive(Clone, Debug)]
 struct ExprBinopBit {
     op: BinopBit,
@@ -210,6 +299,20 @@ impl TryFrom<Expr> for ExprBinopBit {
 }
 
 impl ExprBinopBit {
+    /// Returns the mask of `&` and `|` and whether it is the left operand.
+    ///
+    /// Operations with two literals are constants rather than masks and have no mask.
+    fn mask(&self) -> Option<(LitInt, bool)> {
+        if self.op == BinopBit::Xor {
+            return None;
+        }
+        match (int_lit(&self.left), int_lit(&self.right)) {
+            (Some(mask), None) => Some((mask.clone(), true)),
+            (None, Some(mask)) => Some((mask.clone(), false)),
+            _ => None,
+        }
+    }
+
     fn op_tokens(&self) -> TokenStream {
         let mut tokens = TokenStream::new();
         tokens.extend(quote_spanned!(self.span=>
@@ -302,6 +405,7 @@ binary_x_to_y!(
 mod tests {
 
     use super::*;
+    use syn::parse_quote;
 
     #[test]
     fn and_inactive() {
@@ -351,6 +455,36 @@ mod tests {
         assert_eq!(result, 0b11);
     }
 
+    #[test]
+    fn mask_inactive() {
+        assert_eq!(run_mask(1, &MutagenRuntimeConfig::without_mutation()), None);
+    }
+    #[test]
+    fn mask_active2() {
+        assert_eq!(
+            run_mask(1, &MutagenRuntimeConfig::with_mutation_id(2)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn mask_right() {
+        let expr: Expr = parse_quote! {x & 0xffu8};
+        let (mask, mask_left) = ExprBinopBit::try_from(expr).unwrap().mask().unwrap();
+        assert_eq!(mask.suffix(), "u8");
+        assert!(!mask_left);
+    }
+    #[test]
+    fn no_mask_for_xor() {
+        let expr: Expr = parse_quote! {x ^ 0xff};
+        assert!(ExprBinopBit::try_from(expr).unwrap().mask().is_none());
+    }
+    #[test]
+    fn no_mask_for_two_literals() {
+        let expr: Expr = parse_quote! {0b10 & 0b11};
+        assert!(ExprBinopBit::try_from(expr).unwrap().mask().is_none());
+    }
+
     #[test]
     fn or_native_inactive() {
         let result = run_native_num(
@@ -160,7 +160,7 @@ pub enum BinopCmp {
 }
 
 impl BinopCmp {
-    fn cmp<L: PartialOrd<R>, R>(self, left: L, right: R) -> bool {
+    pub(crate) fn cmp<L: PartialOrd<R>, R>(self, left: L, right: R) -> bool {
         match self {
             BinopCmp::Lt => left < right,
             BinopCmp::Le => left <= right,
@@ -144,7 +144,7 @@ pub enum BinopEq {
 }
 
 impl BinopEq {
-    fn eq<L: PartialEq<R>, R>(self, left: L, right: R) -> bool {
+    pub(crate) fn eq<L: PartialEq<R>, R>(self, left: L, right: R) -> bool {
         match self {
             BinopEq::Eq => left == right,
             BinopEq::Ne => left != right,
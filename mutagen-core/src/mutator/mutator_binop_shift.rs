@@ -0,0 +1,247 @@
+//! Mutator for the amount of binary shift-operations `<<` and `>>` by constant amounts.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+use std::ops::{Shl, Shr};
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary, Lit};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::mutator::mutator_lit_int::IntMutable;
+use crate::MutagenRuntimeConfig;
+
+pub fn run_shl<L: Shl<R>, R: IntMutable>(
+    mutator_id: usize,
+    left: L,
+    right: R,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> <L as Shl<R>>::Output {
+    left << run_amount(mutator_id, right, runtime)
+}
+pub fn run_shr<L: Shr<R>, R: IntMutable>(
+    mutator_id: usize,
+    left: L,
+    right: R,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> <L as Shr<R>>::Output {
+    left >> run_amount(mutator_id, right, runtime)
+}
+
+fn run_amount<R: IntMutable>(
+    mutator_id: usize,
+    amount: R,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> R {
+    runtime.covered(mutator_id);
+    let mutations = MutationBinopShift::possible_mutations();
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        m.mutate(amount)
+    } else {
+        amount
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the shift amount is read before other mutators have been applied to it
+    let amount = match context.original_expr.as_ref().and_then(shift_amount) {
+        Some(amount) if amount > 0 => amount,
+        _ => return e,
+    };
+
+    let e = match ExprBinopShift::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutator_id = transform_info.add_mutations(
+        MutationBinopShift::possible_mutations()
+            .iter()
+            .map(|m| m.to_mutation(&e, amount, context)),
+    );
+
+    let left = &e.left;
+    let right = &e.right;
+    let run_fn = match e.op {
+        BinopShift::Shl => quote_spanned! {e.span=> run_shl},
+        BinopShift::Shr => quote_spanned! {e.span=> run_shr},
+    };
+
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_binop_shift::#run_fn(
+                #mutator_id,
+                #left,
+                #right,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// the amount of a shift-operation, if it is an integer literal.
+fn shift_amount(e: &Expr) -> Option<u128> {
+    match e {
+        Expr::Binary(ExprBinary {
+            op: BinOp::Shl(_),
+            right,
+            ..
+        })
+        | Expr::Binary(ExprBinary {
+            op: BinOp::Shr(_),
+            right,
+            ..
+        }) => match &**right {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit.base10_parse::<u128>().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct MutationBinopShift {
+    delta: i128,
+}
+
+impl MutationBinopShift {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationBinopShift { delta: 1 },
+            MutationBinopShift { delta: -1 },
+        ]
+    }
+
+    fn mutate<R: IntMutable>(self, amount: R) -> R {
+        let amount = amount.as_u128();
+        R::from_u128(if self.delta < 0 {
+            amount.saturating_sub(self.delta.wrapping_neg() as u128)
+        } else {
+            amount.saturating_add(self.delta as u128)
+        })
+    }
+
+    fn to_mutation(
+        self,
+        original_op: &ExprBinopShift,
+        amount: u128,
+        context: &TransformContext,
+    ) -> Mutation {
+        Mutation::new_spanned(
+            context,
+            "binop_shift".to_owned(),
+            format!("{} {}", original_op.op, amount),
+            format!("{} {}", original_op.op, self.mutate(amount)),
+            original_op.span,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprBinopShift {
+    op: BinopShift,
+    left: Expr,
+    right: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprBinopShift {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Binary(expr) => match expr.op {
+                BinOp::Shl(t) => Ok(ExprBinopShift {
+                    op: BinopShift::Shl,
+                    left: *expr.left,
+                    right: *expr.right,
+                    span: t.span(),
+                }),
+                BinOp::Shr(t) => Ok(ExprBinopShift {
+                    op: BinopShift::Shr,
+                    left: *expr.left,
+                    right: *expr.right,
+                    span: t.span(),
+                }),
+                _ => Err(Expr::Binary(expr)),
+            },
+            _ => Err(expr),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum BinopShift {
+    Shl,
+    Shr,
+}
+
+use std::fmt;
+
+impl fmt::Display for BinopShift {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BinopShift::Shl => write!(f, "<<"),
+            BinopShift::Shr => write!(f, ">>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn shl_inactive() {
+        let result = run_shl(1, 1u32, 3u32, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 8);
+    }
+    #[test]
+    fn shl_active1() {
+        let result = run_shl(1, 1u32, 3u32, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 16);
+    }
+    #[test]
+    fn shl_active2() {
+        let result = run_shl(1, 1u32, 3u32, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn shr_inactive() {
+        let result = run_shr(1, 8u32, 3u32, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 1);
+    }
+    #[test]
+    fn shr_active1() {
+        let result = run_shr(1, 8u32, 3u32, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 0);
+    }
+    #[test]
+    fn shr_active2() {
+        let result = run_shr(1, 8u32, 3u32, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn shift_amount_lit() {
+        let e = parse_quote! {x << 2};
+        assert_eq!(shift_amount(&e), Some(2));
+    }
+    #[test]
+    fn shift_amount_var() {
+        let e = parse_quote! {x >> y};
+        assert_eq!(shift_amount(&e), None);
+    }
+}
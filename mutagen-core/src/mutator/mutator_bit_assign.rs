@@ -0,0 +1,310 @@
+//! Mutator for compound bit-assignments `&=` and `|=`, like `flags |= MASK`.
+//!
+//! The assignment is swapped with the other one or with `^=`, and the mask is inverted.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+use std::ops::{BitAndAssign, BitOrAssign, BitXorAssign, Not};
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprAssignOp};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run_and<L: BitAndAssign<R>, R>(
+    mutator_id: usize,
+    left: &mut L,
+    right: R,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) {
+    runtime.covered(mutator_id);
+    let mutations = MutationBitAssign::possible_mutations(BitAssign::And);
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(&m) => left.may_assign(m, right),
+        None => *left &= right,
+    }
+}
+
+pub fn run_or<L: BitOrAssign<R>, R>(
+    mutator_id: usize,
+    left: &mut L,
+    right: R,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) {
+    runtime.covered(mutator_id);
+    let mutations = MutationBitAssign::possible_mutations(BitAssign::Or);
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(&m) => left.may_assign(m, right),
+        None => *left |= right,
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprBitAssign::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the mask is described as written in the original code, before other mutators have been applied to it
+    let original_mask = match context.original_expr.clone().map(ExprBitAssign::try_from) {
+        Some(Ok(original)) => original.expr.right.to_token_stream().to_string(),
+        _ => e.expr.right.to_token_stream().to_string(),
+    };
+    let mutator_id = transform_info.add_mutations(
+        MutationBitAssign::possible_mutations(e.op)
+            .iter()
+            .map(|m| m.to_mutation(e.op, &original_mask, e.span, context)),
+    );
+
+    let span = e.span;
+    let left = &e.expr.left;
+    let right = &e.expr.right;
+    let run_fn = match e.op {
+        BitAssign::And => quote_spanned! {span=> run_and},
+        BitAssign::Or => quote_spanned! {span=> run_or},
+        BitAssign::Xor => unreachable!(),
+    };
+
+    // the mask is evaluated before the assigned place, like for compound assignments of primitive types.
+    // The place is borrowed only once, such that it is evaluated once.
+    syn::parse2(quote_spanned! {span=>
+        {
+            let __mutagen_mask = #right;
+            ::mutagen::mutator::mutator_bit_assign::#run_fn(
+                    #mutator_id,
+                    &mut #left,
+                    __mutagen_mask,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct MutationBitAssign {
+    op: BitAssign,
+    invert_mask: bool,
+}
+
+impl MutationBitAssign {
+    fn possible_mutations(original_op: BitAssign) -> Vec<Self> {
+        [BitAssign::And, BitAssign::Or, BitAssign::Xor]
+            .iter()
+            .copied()
+            .filter(|&op| op != original_op)
+            .map(|op| MutationBitAssign {
+                op,
+                invert_mask: false,
+            })
+            .chain(std::iter::once(MutationBitAssign {
+                op: original_op,
+                invert_mask: true,
+            }))
+            .collect()
+    }
+
+    fn to_mutation(
+        self,
+        original_op: BitAssign,
+        mask: &str,
+        span: Span,
+        context: &TransformContext,
+    ) -> Mutation {
+        let mutated_mask = if self.invert_mask {
+            format!("!({})", mask)
+        } else {
+            mask.to_owned()
+        };
+        Mutation::new_spanned(
+            context,
+            "bit_assign".to_owned(),
+            format!("{} {}", original_op, mask),
+            format!("{} {}", self.op, mutated_mask),
+            span,
+        )
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BitAssign {
+    And,
+    Or,
+    Xor,
+}
+
+impl BitAssign {
+    fn assign<L, R>(self, left: &mut L, right: R)
+    where
+        L: BitAndAssign<R> + BitOrAssign<R> + BitXorAssign<R>,
+    {
+        match self {
+            BitAssign::And => *left &= right,
+            BitAssign::Or => *left |= right,
+            BitAssign::Xor => *left ^= right,
+        }
+    }
+}
+
+impl fmt::Display for BitAssign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BitAssign::And => write!(f, "&="),
+            BitAssign::Or => write!(f, "|="),
+            BitAssign::Xor => write!(f, "^="),
+        }
+    }
+}
+
+/// trait that is used to optimistically perform a mutated bit-assignment.
+///
+/// The place is required to implement all bit-assignments and the mask is required to implement `Not`.
+/// This is the case for integers, `bool` and flag types like bitflags.
+/// Otherwise, the optimistic assumption fails.
+trait MayBitAssign<R> {
+    fn may_assign(&mut self, m: MutationBitAssign, right: R);
+}
+
+impl<L, R> MayBitAssign<R> for L {
+    default fn may_assign(&mut self, _m: MutationBitAssign, _right: R) {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<L, R> MayBitAssign<R> for L
+where
+    L: BitAndAssign<R> + BitOrAssign<R> + BitXorAssign<R>,
+    R: Not<Output = R>,
+{
+    fn may_assign(&mut self, m: MutationBitAssign, right: R) {
+        let right = if m.invert_mask { !right } else { right };
+        m.op.assign(self, right)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprBitAssign {
+    expr: ExprAssignOp,
+    op: BitAssign,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprBitAssign {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::AssignOp(expr) => {
+                let op = match expr.op {
+                    BinOp::BitAndEq(_) => BitAssign::And,
+                    BinOp::BitOrEq(_) => BitAssign::Or,
+                    _ => return Err(Expr::AssignOp(expr)),
+                };
+                Ok(ExprBitAssign {
+                    span: expr.op.span(),
+                    expr,
+                    op,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn and_inactive() {
+        let mut flags = 0b1100u8;
+        run_and(
+            1,
+            &mut flags,
+            0b1010,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(flags, 0b1000);
+    }
+    #[test]
+    fn and_active1() {
+        let mut flags = 0b1100u8;
+        run_and(
+            1,
+            &mut flags,
+            0b1010,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(flags, 0b1110);
+    }
+    #[test]
+    fn and_active2() {
+        let mut flags = 0b1100u8;
+        run_and(
+            1,
+            &mut flags,
+            0b1010,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(flags, 0b0110);
+    }
+    #[test]
+    fn and_active3() {
+        let mut flags = 0b1100u8;
+        run_and(
+            1,
+            &mut flags,
+            0b1010,
+            &MutagenRuntimeConfig::with_mutation_id(3),
+        );
+        assert_eq!(flags, 0b0100);
+    }
+    #[test]
+    fn or_active1() {
+        let mut flags = 0b1100u8;
+        run_or(
+            1,
+            &mut flags,
+            0b1010,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(flags, 0b1000);
+    }
+
+    #[test]
+    fn possible_mutations_or() {
+        let mutations = MutationBitAssign::possible_mutations(BitAssign::Or);
+        let ops = mutations.iter().map(|m| m.op).collect::<Vec<_>>();
+        assert_eq!(ops, vec![BitAssign::And, BitAssign::Xor, BitAssign::Or]);
+        assert!(mutations[2].invert_mask);
+    }
+
+    #[test]
+    fn detect_and_assign() {
+        let expr: Expr = parse_quote! {self.flags &= !Flags::DIRTY};
+        assert_eq!(ExprBitAssign::try_from(expr).unwrap().op, BitAssign::And);
+    }
+    #[test]
+    fn ignore_xor_assign() {
+        let expr: Expr = parse_quote! {flags ^= MASK};
+        assert!(ExprBitAssign::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_add_assign() {
+        let expr: Expr = parse_quote! {count += 1};
+        assert!(ExprBitAssign::try_from(expr).is_err());
+    }
+}
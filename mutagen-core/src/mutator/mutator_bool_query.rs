@@ -0,0 +1,209 @@
+//! Mutator for query methods returning `bool`, like `v.is_empty()`, `x.is_some()` or `s.contains(c)`.
+//!
+//! The mutation negates the result of the call.
+//! For the paired queries of `Option` and `Result`, this is described as swapping the method, e.g. `is_ok()` with `is_err()`.
+//! Further methods can be added with the setting `bool_query_methods` in the file `mutagen.json`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::crate_conf::CrateConf;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// query methods that are negated by default
+const DEFAULT_BOOL_QUERY_METHODS: &[&str] = &[
+    "is_empty",
+    "is_some",
+    "is_none",
+    "is_ok",
+    "is_err",
+    "contains",
+    "contains_key",
+    "starts_with",
+    "ends_with",
+];
+
+/// returns the result of the query, negated if the mutation is active
+pub fn run<T>(
+    mutator_id: usize,
+    result: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        result.may_negate()
+    } else {
+        result
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprBoolQuery::try_from(e) {
+        Ok(e) if is_bool_query(&e.call.method, CrateConf::get().bool_query_methods()) => e,
+        Ok(e) => return Expr::MethodCall(e.call),
+        Err(e) => return e,
+    };
+    // configured methods may not return `bool`, which is no weakness of the tests
+    let policy = if is_bool_query(&e.call.method, &[]) {
+        OptimisticPolicy::Killed
+    } else {
+        OptimisticPolicy::Skip
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original = match &context.original_expr {
+        Some(Expr::MethodCall(original)) => original.clone(),
+        _ => e.call.clone(),
+    };
+    let original_call = original.to_token_stream().to_string();
+    let mutated_call = match paired_query(&original) {
+        Some(paired) => {
+            let mut swapped = original;
+            swapped.method = syn::Ident::new(paired, swapped.method.span());
+            swapped.to_token_stream().to_string()
+        }
+        None => format!("!{}", original_call),
+    };
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "bool_query".to_owned(),
+            original_call,
+            mutated_call,
+            e.span,
+        )
+        .with_optimistic_policy(policy),
+    );
+
+    let call = &e.call;
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_bool_query::run(
+                #mutator_id,
+                #call,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// checks if the method is one of the default query methods or one of the configured methods
+fn is_bool_query(method: &syn::Ident, configured_methods: &[String]) -> bool {
+    DEFAULT_BOOL_QUERY_METHODS.iter().any(|m| method == m)
+        || configured_methods.iter().any(|m| method == m)
+}
+
+/// returns the query with the opposite result for the queries of `Option` and `Result`, like `is_err` for `is_ok`
+fn paired_query(call: &ExprMethodCall) -> Option<&'static str> {
+    if !call.args.is_empty() {
+        return None;
+    }
+    match &*call.method.to_string() {
+        "is_ok" => Some("is_err"),
+        "is_err" => Some("is_ok"),
+        "is_some" => Some("is_none"),
+        "is_none" => Some("is_some"),
+        _ => None,
+    }
+}
+
+/// trait that is used to optimistically negate the result of a query.
+///
+/// Configured query methods may return types other than `bool`, in which case the optimistic assumption fails.
+trait MayNegate {
+    fn may_negate(self) -> Self;
+}
+
+impl<T> MayNegate for T {
+    default fn may_negate(self) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl MayNegate for bool {
+    fn may_negate(self) -> Self {
+        !self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprBoolQuery {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprBoolQuery {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call) if call.turbofish.is_none() => Ok(ExprBoolQuery {
+                span: call.method.span(),
+                call,
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn bool_query_inactive() {
+        let result = run(1, true, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn bool_query_active() {
+        let result = run(1, true, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+    #[test]
+    #[should_panic]
+    fn bool_query_non_bool_active() {
+        run(1, 1, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn default_query_method() {
+        let method: syn::Ident = parse_quote! {is_empty};
+        assert!(is_bool_query(&method, &[]));
+    }
+    #[test]
+    fn configured_query_method() {
+        let method: syn::Ident = parse_quote! {is_valid};
+        assert!(!is_bool_query(&method, &[]));
+        assert!(is_bool_query(&method, &["is_valid".to_owned()]));
+    }
+
+    #[test]
+    fn paired_query_is_ok() {
+        let call: ExprMethodCall = parse_quote! {r.is_ok()};
+        assert_eq!(paired_query(&call), Some("is_err"));
+    }
+    #[test]
+    fn paired_query_is_none() {
+        let call: ExprMethodCall = parse_quote! {self.head.is_none()};
+        assert_eq!(paired_query(&call), Some("is_some"));
+    }
+    #[test]
+    fn paired_query_other_method() {
+        let call: ExprMethodCall = parse_quote! {v.is_empty()};
+        assert_eq!(paired_query(&call), None);
+    }
+}
@@ -0,0 +1,118 @@
+//! Mutator for functions returning `bool`, like `fn is_valid(&self) -> bool { ... }`.
+//!
+//! The mutation negates the tail expression of the body, which inverts the result of the whole predicate.
+
+use std::ops::Deref;
+
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Block, Expr, ReturnType, Stmt, Type};
+
+use crate::comm::Mutation;
+use crate::transformer::ast_inspect;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the result of the function, negated if the mutation is active
+pub fn run(
+    mutator_id: usize,
+    result: bool,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        !result
+    } else {
+        result
+    }
+}
+
+pub fn transform(
+    mut block: Block,
+    output: &ReturnType,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Block {
+    if !returns_bool(output) {
+        return block;
+    }
+    let tail = match block.stmts.pop() {
+        // an explicit `return` as tail is left unchanged
+        Some(Stmt::Expr(tail)) if !matches!(tail, Expr::Return(_)) => tail,
+        Some(stmt) => {
+            block.stmts.push(stmt);
+            return block;
+        }
+        None => return block,
+    };
+    let original_tail = context.original_expr.as_ref().unwrap_or(&tail);
+    if ast_inspect::is_macro_generated(original_tail.to_token_stream()) {
+        block.stmts.push(Stmt::Expr(tail));
+        return block;
+    }
+
+    let original_code = original_tail.to_token_stream().to_string();
+    let span = original_tail.span();
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "bool_return_negate".to_owned(),
+        original_code.clone(),
+        format!("!({})", original_code),
+        span,
+    ));
+
+    let tail: Expr = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_bool_return_negate::run(
+                #mutator_id,
+                #tail,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    block.stmts.push(Stmt::Expr(tail));
+    block
+}
+
+/// checks if the declared return type is `bool`
+fn returns_bool(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(path) => path.qself.is_none() && path.path.is_ident("bool"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn bool_return_negate_inactive() {
+        let result = run(1, true, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn bool_return_negate_active() {
+        let result = run(1, true, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+
+    #[test]
+    fn returns_bool_detected() {
+        let output: ReturnType = parse_quote! {-> bool};
+        assert!(returns_bool(&output));
+    }
+    #[test]
+    fn returns_other_type() {
+        let output: ReturnType = parse_quote! {-> Option<bool>};
+        assert!(!returns_bool(&output));
+        assert!(!returns_bool(&ReturnType::Default));
+    }
+}
@@ -0,0 +1,218 @@
+//! Mutator for optional values created from a condition, like `cond.then(|| x)` or `cond.then_some(x)`.
+//!
+//! The condition is negated or replaced, such that the result is always `None` or always `Some`.
+//! Forcing `Some` for `then` calls the closure even if the condition is false.
+//! Therefore, this mutation is only generated for `then` with the setting `bool_then_force_some` in the file `mutagen.json`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::crate_conf::CrateConf;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the condition, which is changed if one of the mutations is active
+pub fn run<T>(
+    mutator_id: usize,
+    condition: T,
+    num_mutations: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    let mutations = MutationBoolThen::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations[..num_mutations]) {
+        Some(m) => condition.may_change(*m),
+        None => condition,
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprBoolThen::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original = match context.original_expr.clone().map(ExprBoolThen::try_from) {
+        Some(Ok(original)) => original.call,
+        _ => e.call.clone(),
+    };
+    let original_code = original.to_token_stream().to_string();
+    let condition = match &*original.receiver {
+        Expr::Paren(paren) => paren.expr.to_token_stream().to_string(),
+        receiver => receiver.to_token_stream().to_string(),
+    };
+    let method = original.method.to_string();
+    let arg = original.args[0].to_token_stream().to_string();
+
+    // the value of `then_some` is computed anyway, but the closure of `then` may have side effects
+    let mut mutations = MutationBoolThen::possible_mutations();
+    if method == "then" && !CrateConf::get().bool_then_force_some() {
+        mutations.pop();
+    }
+    let num_mutations = mutations.len();
+
+    let mutator_id = transform_info.add_mutations(mutations.into_iter().map(|m| {
+        Mutation::new_spanned(
+            context,
+            "bool_then".to_owned(),
+            original_code.clone(),
+            m.describe(&condition, &method, &arg),
+            e.span,
+        )
+    }));
+
+    // parentheses around the condition are not needed as argument of `run`
+    let receiver = match &*e.call.receiver {
+        Expr::Paren(paren) => &paren.expr,
+        _ => &e.call.receiver,
+    };
+    e.call.receiver = syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_bool_then::run(
+                #mutator_id,
+                #receiver,
+                #num_mutations,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    Expr::MethodCall(e.call)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationBoolThen {
+    Negate,
+    AlwaysNone,
+    AlwaysSome,
+}
+
+impl MutationBoolThen {
+    /// the possible mutations, `AlwaysSome` has to be the last one since it is not always generated
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationBoolThen::Negate,
+            MutationBoolThen::AlwaysNone,
+            MutationBoolThen::AlwaysSome,
+        ]
+    }
+
+    fn describe(self, condition: &str, method: &str, arg: &str) -> String {
+        match self {
+            MutationBoolThen::Negate => format!("(!({})).{}({})", condition, method, arg),
+            MutationBoolThen::AlwaysNone => format!("false.{}({})", method, arg),
+            MutationBoolThen::AlwaysSome => format!("true.{}({})", method, arg),
+        }
+    }
+}
+
+/// trait that is used to optimistically change a condition.
+///
+/// Only conditions of type `bool` can be changed, otherwise the optimistic assumption fails.
+trait ConditionMutable {
+    fn may_change(self, m: MutationBoolThen) -> Self;
+}
+
+impl<T> ConditionMutable for T {
+    default fn may_change(self, _m: MutationBoolThen) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl ConditionMutable for bool {
+    fn may_change(self, m: MutationBoolThen) -> Self {
+        match m {
+            MutationBoolThen::Negate => !self,
+            MutationBoolThen::AlwaysNone => false,
+            MutationBoolThen::AlwaysSome => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprBoolThen {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprBoolThen {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            // `Ordering::then` takes another ordering instead of a closure
+            Expr::MethodCall(call)
+                if call.args.len() == 1
+                    && call.turbofish.is_none()
+                    && (call.method == "then_some"
+                        || call.method == "then"
+                            && matches!(&call.args[0], Expr::Closure(c) if c.inputs.is_empty())) =>
+            {
+                Ok(ExprBoolThen {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn bool_then_inactive() {
+        let result = run(1, true, 3, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn bool_then_active1() {
+        let result = run(1, true, 3, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+    #[test]
+    fn bool_then_active2() {
+        let result = run(1, true, 3, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert!(!result);
+    }
+    #[test]
+    fn bool_then_active3() {
+        let result = run(1, false, 3, &MutagenRuntimeConfig::with_mutation_id(3));
+        assert!(result);
+    }
+    #[test]
+    fn bool_then_without_always_some() {
+        let result = run(1, false, 2, &MutagenRuntimeConfig::with_mutation_id(3));
+        assert!(!result);
+    }
+
+    #[test]
+    fn detect_then_some() {
+        let expr: Expr = parse_quote! {(x > 0).then_some(x)};
+        assert!(ExprBoolThen::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_then_with_closure() {
+        let expr: Expr = parse_quote! {(x > 0).then(|| x)};
+        assert!(ExprBoolThen::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_ordering_then() {
+        let expr: Expr = parse_quote! {a.cmp(&b).then(c.cmp(&d))};
+        assert!(ExprBoolThen::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! Mutator for mutable borrows passed as arguments, like `f(&mut x)`.
+
+use std::ops::Deref;
+
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprReference};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<T: ?Sized>(
+    mutator_id: usize,
+    reference: &mut T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> &mut T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        T::may_copy(reference)
+    } else {
+        reference
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    match e {
+        Expr::Call(mut call) => {
+            for arg in call.args.iter_mut() {
+                transform_arg(arg, transform_info, context);
+            }
+            Expr::Call(call)
+        }
+        Expr::MethodCall(mut call) => {
+            for arg in call.args.iter_mut() {
+                transform_arg(arg, transform_info, context);
+            }
+            Expr::MethodCall(call)
+        }
+        _ => e,
+    }
+}
+
+/// wraps a single argument of a call if it is a mutable borrow of a place
+fn transform_arg(arg: &mut Expr, transform_info: &SharedTransformInfo, context: &TransformContext) {
+    let borrowed = match &*arg {
+        Expr::Reference(ExprReference {
+            mutability: Some(_),
+            expr,
+            ..
+        }) if is_place(expr) => expr,
+        _ => return,
+    };
+    let span = arg.span();
+    let borrowed_code = borrowed.to_token_stream().to_string();
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "borrow".to_owned(),
+        format!("&mut {}", borrowed_code),
+        format!("&mut {}.clone()", borrowed_code),
+        span,
+    ));
+
+    *arg = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_borrow::run(
+                #mutator_id,
+                #arg,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+}
+
+/// checks if the expression is a variable or a field of a variable.
+///
+/// Borrows of temporary values are not mutated since changes to them are discarded anyway.
+fn is_place(e: &Expr) -> bool {
+    match e {
+        Expr::Path(_) => true,
+        Expr::Field(field) => is_place(&field.base),
+        Expr::Paren(paren) => is_place(&paren.expr),
+        _ => false,
+    }
+}
+
+/// trait that is used to optimistically replace a mutable borrow with a borrow of a copy.
+///
+/// Changes made through the copy are not visible to the caller, which simulates passing the value by shared borrow.
+/// If the value cannot be cloned, the optimistic assumption fails.
+pub trait MayCopy {
+    fn may_copy(reference: &mut Self) -> &mut Self;
+}
+
+impl<T: ?Sized> MayCopy for T {
+    default fn may_copy(_reference: &mut Self) -> &mut Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Clone> MayCopy for T {
+    fn may_copy(reference: &mut Self) -> &mut Self {
+        // the copy lives until the end of the mutated test run
+        Box::leak(Box::new(reference.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn borrow_inactive() {
+        let mut x = 1;
+        *run(1, &mut x, &MutagenRuntimeConfig::without_mutation()) += 1;
+        assert_eq!(x, 2);
+    }
+    #[test]
+    fn borrow_active() {
+        let mut x = 1;
+        *run(1, &mut x, &MutagenRuntimeConfig::with_mutation_id(1)) += 1;
+        assert_eq!(x, 1);
+    }
+
+    #[test]
+    fn place_field() {
+        let e: Expr = parse_quote! {self.buf};
+        assert!(is_place(&e));
+    }
+    #[test]
+    fn place_not_temporary() {
+        let e: Expr = parse_quote! {vec![1]};
+        assert!(!is_place(&e));
+    }
+}
@@ -0,0 +1,176 @@
+//! Mutator for the bounds of range checks like `lo <= x && x < hi`.
+//!
+//! The inclusivity of each bound is toggled on its own, i.e. `<` is replaced with `<=` and vice versa.
+//! This probes off-by-one errors of half-open intervals.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use super::mutator_cmp_chain::is_cmp_chain;
+
+use crate::MutagenRuntimeConfig;
+
+/// Returns the index of the bound whose inclusivity is toggled, if any.
+///
+/// The index `0` refers to the left comparison and `1` to the right comparison.
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> Option<usize> {
+    runtime.covered(mutator_id);
+    runtime
+        .get_mutation_for_mutator(mutator_id, &[0, 1])
+        .copied()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the mutated chains are built from the original code, since other mutations are inactive while a bound is toggled
+    let original = match context.original_expr.clone().map(ExprBoundChain::try_from) {
+        Some(Ok(original)) => original,
+        _ => return e,
+    };
+    if !matches!(
+        &e,
+        Expr::Binary(ExprBinary {
+            op: BinOp::And(_),
+            ..
+        })
+    ) {
+        return e;
+    }
+
+    let original_code = original.to_token_stream().to_string();
+    let toggled_left = original.with_toggled(0);
+    let toggled_right = original.with_toggled(1);
+    let mutator_id =
+        transform_info.add_mutations([&toggled_left, &toggled_right].iter().map(|mutated| {
+            Mutation::new_spanned(
+                context,
+                "bound_inclusive".to_owned(),
+                original_code.clone(),
+                mutated.to_token_stream().to_string(),
+                original.span,
+            )
+        }));
+
+    syn::parse2(quote_spanned! {original.span=>
+        match ::mutagen::mutator::mutator_bound_inclusive::run(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+            ::core::option::Option::Some(0) => #toggled_left,
+            ::core::option::Option::Some(_) => #toggled_right,
+            ::core::option::Option::None => #e,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// replaces a strict comparison with an inclusive one and vice versa
+fn toggle_inclusive(op: BinOp) -> BinOp {
+    match op {
+        BinOp::Lt(t) => BinOp::Le(syn::Token![<=](t.span())),
+        BinOp::Le(t) => BinOp::Lt(syn::Token![<](t.span())),
+        BinOp::Gt(t) => BinOp::Ge(syn::Token![>=](t.span())),
+        BinOp::Ge(t) => BinOp::Gt(syn::Token![>](t.span())),
+        op => op,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprBoundChain {
+    chain: ExprBinary,
+    span: Span,
+}
+
+impl ExprBoundChain {
+    /// the chain where the comparison with the given index has a toggled inclusivity
+    fn with_toggled(&self, index: usize) -> ExprBinary {
+        let mut chain = self.chain.clone();
+        let cmp = if index == 0 {
+            &mut chain.left
+        } else {
+            &mut chain.right
+        };
+        if let Expr::Binary(cmp) = &mut **cmp {
+            cmp.op = toggle_inclusive(cmp.op);
+        }
+        chain
+    }
+}
+
+impl ToTokens for ExprBoundChain {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.chain.to_tokens(tokens)
+    }
+}
+
+impl TryFrom<Expr> for ExprBoundChain {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        if !is_cmp_chain(&expr) {
+            return Err(expr);
+        }
+        match expr {
+            Expr::Binary(chain) => Ok(ExprBoundChain {
+                span: chain.op.span(),
+                chain,
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn bound_inclusive_inactive() {
+        let result = run(1, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, None);
+    }
+    #[test]
+    fn bound_inclusive_active1() {
+        let result = run(1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, Some(0));
+    }
+    #[test]
+    fn bound_inclusive_active2() {
+        let result = run(1, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn toggle_left_bound() {
+        let expr: Expr = parse_quote! {lo <= x && x < hi};
+        let chain = ExprBoundChain::try_from(expr).unwrap();
+        let expected: ExprBinary = parse_quote! {lo < x && x < hi};
+        assert_eq!(chain.with_toggled(0), expected);
+    }
+    #[test]
+    fn toggle_right_bound() {
+        let expr: Expr = parse_quote! {x >= lo && x < hi};
+        let chain = ExprBoundChain::try_from(expr).unwrap();
+        let expected: ExprBinary = parse_quote! {x >= lo && x <= hi};
+        assert_eq!(chain.with_toggled(1), expected);
+    }
+    #[test]
+    fn ignore_unrelated_comparisons() {
+        let expr: Expr = parse_quote! {a < b && c < d};
+        assert!(ExprBoundChain::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+//! Mutator for smart-pointer allocations `Box::new(x)`, `Rc::new(x)` and `Arc::new(x)`.
+//!
+//! The allocation is removed, such that the bare value is used.
+//! A box that is dereferenced immediately, like `*Box::new(x)`, is replaced with `x`.
+//!
+//! This mutator is opt-in, since it does not generate mutants that can be killed.
+//! `new` always returns the smart pointer, such that removing a plain allocation fails the optimistic assumption and the mutant is skipped.
+//! Removing the allocation of a dereferenced box gives an equivalent mutant, which only shows that the allocation is not needed.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Expr, UnOp};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::ast_inspect;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<T, P>(
+    mutator_id: usize,
+    val: T,
+    alloc: impl FnOnce(T) -> P,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> P {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        <T as BoxNewToBare<P>>::may_bare(val)
+    } else {
+        alloc(val)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprBoxNew::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return transform_deref(e, transform_info, context),
+    };
+
+    // the value is described as written in the original code, before other mutators have been applied to it
+    let val_code = original_val(context).unwrap_or_else(|| e.val.to_token_stream().to_string());
+
+    // `new` returns the smart pointer, which is not accepted where the bare value is expected. These mutants are skipped.
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "box_new".to_owned(),
+            format!("{}({})", e.alloc, val_code),
+            val_code,
+            e.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip),
+    );
+
+    let alloc = &e.alloc;
+    let val = &e.val;
+
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_box_new::run(
+                #mutator_id,
+                #val,
+                #alloc,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// Transforms a box that is dereferenced immediately, like `*Box::new(x)`, where the bare value has the expected type.
+///
+/// The allocation has already been transformed, since expressions are transformed bottom-up.
+/// The value is taken out of the transformed allocation, such that the allocation is only performed if the mutation is inactive.
+fn transform_deref(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let original_val = match context.original_expr.clone().map(boxed_deref_val) {
+        Some(Some(val)) => val,
+        _ => return e,
+    };
+    let mut unary = match e {
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Deref(_)) => unary,
+        _ => return e,
+    };
+    let alloc = match &mut *unary.expr {
+        Expr::Call(call)
+            if call.args.len() == 4
+                && ast_inspect::path_ends_with(&call.func, &["mutator_box_new", "run"]) =>
+        {
+            call
+        }
+        _ => return Expr::Unary(unary),
+    };
+    let span = alloc.span();
+    let val = std::mem::replace(
+        &mut alloc.args[1],
+        syn::parse2(quote_spanned! {span=> __mutagen_val}).expect("transformed code invalid"),
+    );
+
+    let val_code = original_val.to_token_stream().to_string();
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "box_new".to_owned(),
+        format!("*Box::new({})", val_code),
+        val_code,
+        span,
+    ));
+
+    syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_box_new::run(
+                #mutator_id,
+                #val,
+                |__mutagen_val| #unary,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// the value of an immediately dereferenced box, like `x` in `*Box::new(x)`
+fn boxed_deref_val(e: Expr) -> Option<Expr> {
+    match e {
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Deref(_)) => match *unary.expr {
+            Expr::Call(call)
+                if call.args.len() == 1
+                    && ast_inspect::path_ends_with(&call.func, &["Box", "new"]) =>
+            {
+                call.args.into_iter().next()
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// the allocated value as written in the original code
+fn original_val(context: &TransformContext) -> Option<String> {
+    match context.original_expr.clone().map(ExprBoxNew::try_from) {
+        Some(Ok(e)) => Some(e.val.to_token_stream().to_string()),
+        _ => None,
+    }
+}
+
+/// names of the types whose `new` function allocates
+const SMART_POINTERS: &[&str] = &["Box", "Rc", "Arc"];
+
+#[derive(Clone, Debug)]
+struct ExprBoxNew {
+    alloc: TokenStream,
+    val: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprBoxNew {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Call(call)
+                if call.args.len() == 1
+                    && SMART_POINTERS
+                        .iter()
+                        .any(|p| ast_inspect::path_ends_with(&call.func, &[p, "new"])) =>
+            {
+                let span = call.span();
+                let alloc = call.func.into_token_stream();
+                let val = call.args.into_iter().next().unwrap();
+                Ok(ExprBoxNew { alloc, val, span })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+/// trait that is used to optimistically remove the allocation of a smart pointer
+///
+/// The value can only be used without allocation if the surrounding code expects the bare value, like for `*Box::new(x)`.
+/// Otherwise, the optimistic assumption fails.
+pub trait BoxNewToBare<P> {
+    fn may_bare(self) -> P;
+}
+
+impl<T, P> BoxNewToBare<P> for T {
+    default fn may_bare(self) -> P {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T> BoxNewToBare<T> for T {
+    fn may_bare(self) -> T {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::rc::Rc;
+    use syn::parse_quote;
+
+    #[test]
+    fn box_new_inactive() {
+        let result = run(1, 5, Box::new, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(*result, 5);
+    }
+    #[test]
+    #[should_panic]
+    fn box_new_active() {
+        run(1, 5, Box::new, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn rc_new_inactive() {
+        let result = run(1, 5, Rc::new, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(Rc::strong_count(&result), 1);
+    }
+    #[test]
+    #[should_panic]
+    fn rc_new_active() {
+        run(1, 5, Rc::new, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn bare_value_active() {
+        let result = run(1, 5, |x| x, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn detect_std_box_new() {
+        let expr: Expr = syn::parse_quote! {std::boxed::Box::new(1)};
+        assert!(ExprBoxNew::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_other_new() {
+        let expr: Expr = syn::parse_quote! {Vec::new(1)};
+        assert!(ExprBoxNew::try_from(expr).is_err());
+    }
+
+    #[test]
+    fn detect_boxed_deref() {
+        let expr: Expr = parse_quote! {*Box::new(v)};
+        assert_eq!(boxed_deref_val(expr), Some(parse_quote! {v}));
+    }
+    #[test]
+    fn ignore_rc_deref() {
+        let expr: Expr = parse_quote! {*Rc::new(v)};
+        assert_eq!(boxed_deref_val(expr), None);
+    }
+
+    #[test]
+    fn original_val_unfolded() {
+        let context = TransformContext {
+            original_expr: Some(parse_quote! {Box::new(x)}),
+            ..TransformContext::default()
+        };
+        assert_eq!(original_val(&context), Some("x".to_owned()));
+    }
+}
@@ -0,0 +1,227 @@
+//! Mutator for ceiling divisions, like `(total + size - 1) / size`.
+//!
+//! The rounding adjustment `+ size - 1` is replaced with `+ size` or removed.
+//! This tests the off-by-one in ceiling divisions, like when counting pages or chunks.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary, Lit};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Returns the index of the active mutation, if any.
+///
+/// The indices are the positions of the mutations in `MutationCeilDiv::ALL`.
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> Option<usize> {
+    runtime.covered(mutator_id);
+    let mutations = (0..MutationCeilDiv::ALL.len()).collect::<Vec<_>>();
+    runtime
+        .get_mutation_for_mutator(mutator_id, &mutations)
+        .copied()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the pattern is detected in the original code, since its operators may have been transformed by `binop_num`
+    let original = match context.original_expr.clone().map(ExprCeilDiv::try_from) {
+        Some(Ok(original)) => original,
+        _ => return e,
+    };
+
+    let total = original.total.to_token_stream().to_string();
+    let size = original.size.to_token_stream().to_string();
+    let mutator_id = transform_info.add_mutations(MutationCeilDiv::ALL.iter().map(|m| {
+        Mutation::new_spanned(
+            context,
+            "ceil_div".to_owned(),
+            original.expr.to_token_stream().to_string(),
+            m.describe(&total, &size),
+            original.span,
+        )
+    }));
+
+    // the mutated divisions are built from the original operands, the transformed expression is kept for the original division
+    let span = original.span;
+    let total = &original.total;
+    let size = &original.size;
+    syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_ceil_div::run(
+            #mutator_id,
+            ::mutagen::MutagenRuntimeConfig::get_default()
+        ) {
+            ::core::option::Option::Some(0) => (#total + #size) / #size,
+            ::core::option::Option::Some(1) => (#total) / #size,
+            _ => #e,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationCeilDiv {
+    DropDecrement,
+    DropAdjustment,
+}
+
+impl MutationCeilDiv {
+    const ALL: [MutationCeilDiv; 2] = [
+        MutationCeilDiv::DropDecrement,
+        MutationCeilDiv::DropAdjustment,
+    ];
+
+    fn describe(self, total: &str, size: &str) -> String {
+        match self {
+            MutationCeilDiv::DropDecrement => format!("({} + {}) / {}", total, size, size),
+            MutationCeilDiv::DropAdjustment => format!("({}) / {}", total, size),
+        }
+    }
+}
+
+/// checks if the expression is the integer literal `1`
+fn is_one(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(expr) => match &expr.lit {
+            Lit::Int(lit) => lit.base10_digits() == "1",
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// removes any parentheses around the expression
+fn unparen(e: &Expr) -> &Expr {
+    match e {
+        Expr::Paren(paren) => unparen(&paren.expr),
+        _ => e,
+    }
+}
+
+/// checks if the expression is `size - 1` for the given size
+fn is_size_minus_one(e: &Expr, size: &Expr) -> bool {
+    match unparen(e) {
+        Expr::Binary(expr) => {
+            matches!(expr.op, BinOp::Sub(_))
+                && unparen(&expr.left) == unparen(size)
+                && is_one(&expr.right)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the total of a rounded dividend for the given size.
+///
+/// The dividends `total + size - 1`, `total + (size - 1)` and `size - 1 + total` are recognized.
+fn rounded_total(dividend: &Expr, size: &Expr) -> Option<Expr> {
+    match unparen(dividend) {
+        // `total + size - 1` is parsed as `(total + size) - 1`
+        Expr::Binary(expr) if matches!(expr.op, BinOp::Sub(_)) && is_one(&expr.right) => {
+            match unparen(&expr.left) {
+                Expr::Binary(sum) if matches!(sum.op, BinOp::Add(_)) => {
+                    if unparen(&sum.right) == unparen(size) {
+                        Some((*sum.left).clone())
+                    } else if unparen(&sum.left) == unparen(size) {
+                        Some((*sum.right).clone())
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+        Expr::Binary(expr) if matches!(expr.op, BinOp::Add(_)) => {
+            if is_size_minus_one(&expr.right, size) {
+                Some((*expr.left).clone())
+            } else if is_size_minus_one(&expr.left, size) {
+                Some((*expr.right).clone())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprCeilDiv {
+    expr: ExprBinary,
+    total: Expr,
+    size: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprCeilDiv {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Binary(expr) if matches!(expr.op, BinOp::Div(_)) => {
+                let size = (*expr.right).clone();
+                match rounded_total(&expr.left, &size) {
+                    Some(total) => Ok(ExprCeilDiv {
+                        total,
+                        size,
+                        span: expr.op.span(),
+                        expr,
+                    }),
+                    None => Err(Expr::Binary(expr)),
+                }
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn ceil_div_inactive() {
+        assert_eq!(run(1, &MutagenRuntimeConfig::without_mutation()), None);
+    }
+    #[test]
+    fn ceil_div_active2() {
+        assert_eq!(run(1, &MutagenRuntimeConfig::with_mutation_id(2)), Some(1));
+    }
+
+    #[test]
+    fn detect_ceil_div() {
+        let expr: Expr = parse_quote! {(total + size - 1) / size};
+        let e = ExprCeilDiv::try_from(expr).unwrap();
+        let total: Expr = parse_quote! {total};
+        assert_eq!(e.total, total);
+    }
+    #[test]
+    fn detect_ceil_div_grouped() {
+        let expr: Expr = parse_quote! {(self.len() + (PAGE_SIZE - 1)) / PAGE_SIZE};
+        assert!(ExprCeilDiv::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_ceil_div_size_first() {
+        let expr: Expr = parse_quote! {(size - 1 + total) / size};
+        assert!(ExprCeilDiv::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_other_divisor() {
+        let expr: Expr = parse_quote! {(total + size - 1) / other};
+        assert!(ExprCeilDiv::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_plain_division() {
+        let expr: Expr = parse_quote! {(total + size) / size};
+        assert!(ExprCeilDiv::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! Mutator for checked arithmetic, like `a.checked_add(b)`.
+//!
+//! The mutation forces the result `None`, as if the operation had overflowed.
+//! Checked operations that are unwrapped immediately are mutated by `checked_unwrap` instead.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the checked operations of integers that return `None` on overflow
+const CHECKED_METHODS: &[&str] = &[
+    "checked_add",
+    "checked_sub",
+    "checked_mul",
+    "checked_div",
+    "checked_rem",
+    "checked_div_euclid",
+    "checked_rem_euclid",
+    "checked_pow",
+    "checked_neg",
+    "checked_abs",
+    "checked_shl",
+    "checked_shr",
+    "checked_next_power_of_two",
+];
+
+/// returns the result of the checked operation, which is replaced with `None` if the mutation is active
+pub fn run<T>(
+    mutator_id: usize,
+    result: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        <T as MayNone>::may_none()
+    } else {
+        result
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    if context.is_unwrapped() {
+        return e;
+    }
+    let e = match ExprCheckedNone::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the arguments are described as written in the original code, before other mutators have been applied to them
+    let original_args = match context.original_expr.clone().map(ExprCheckedNone::try_from) {
+        Some(Ok(original)) => original.call.args,
+        _ => e.call.args.clone(),
+    };
+
+    // methods with these names can be defined on any type. These mutants are skipped.
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "checked_none".to_owned(),
+            format!("{}({})", e.call.method, original_args.to_token_stream()),
+            "None".to_owned(),
+            e.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip),
+    );
+
+    let span = e.span;
+    let call = &e.call;
+    syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_checked_none::run(
+                #mutator_id,
+                #call,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// trait that is used to optimistically replace the result of a checked operation with `None`.
+///
+/// Only results of type `Option` can be replaced, otherwise the optimistic assumption fails.
+trait MayNone {
+    fn may_none() -> Self;
+}
+
+impl<T> MayNone for T {
+    default fn may_none() -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T> MayNone for Option<T> {
+    fn may_none() -> Self {
+        None
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprCheckedNone {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprCheckedNone {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if CHECKED_METHODS.iter().any(|m| call.method == m)
+                    && call.args.len() <= 1
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprCheckedNone {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn checked_none_inactive() {
+        let result = run(
+            1,
+            5u8.checked_add(1),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, Some(6));
+    }
+    #[test]
+    fn checked_none_active() {
+        let result = run(
+            1,
+            5u8.checked_add(1),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, None);
+    }
+    #[test]
+    #[should_panic]
+    fn checked_none_not_option_active() {
+        run(1, 6u8, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_checked_neg() {
+        let expr: Expr = parse_quote! {x.checked_neg()};
+        assert!(ExprCheckedNone::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_other_checked_method() {
+        let expr: Expr = parse_quote! {x.checked_out(y)};
+        assert!(ExprCheckedNone::try_from(expr).is_err());
+    }
+}
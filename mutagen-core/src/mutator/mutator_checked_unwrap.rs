@@ -0,0 +1,217 @@
+//! Mutator for checked arithmetic that is unwrapped immediately, like `a.checked_add(b).unwrap()`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<L, R, O>(
+    mutator_id: usize,
+    left: L,
+    right: R,
+    checked: impl FnOnce(L, R) -> O,
+    unchecked: impl FnOnce(L, R) -> O,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> O {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        unchecked(left, right)
+    } else {
+        checked(left, right)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprCheckedUnwrap::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "checked_unwrap".to_owned(),
+        format!("{}(_).unwrap()", e.op.checked_method()),
+        e.op.to_string(),
+        e.span,
+    ));
+
+    let left = &e.left;
+    let right = &e.right;
+    let checked_method = syn::Ident::new(e.op.checked_method(), e.span);
+    let unchecked = e.op.unchecked_tokens(e.span);
+
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_checked_unwrap::run(
+                #mutator_id,
+                #left,
+                #right,
+                |__mutagen_left, __mutagen_right| __mutagen_left.#checked_method(__mutagen_right).unwrap(),
+                |__mutagen_left, __mutagen_right| #unchecked,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Clone, Debug)]
+struct ExprCheckedUnwrap {
+    op: CheckedOp,
+    left: Expr,
+    right: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprCheckedUnwrap {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        let (checked_call, span) = match &expr {
+            Expr::MethodCall(unwrap_call)
+                if unwrap_call.method == "unwrap"
+                    && unwrap_call.args.is_empty()
+                    && unwrap_call.turbofish.is_none() =>
+            {
+                match &*unwrap_call.receiver {
+                    Expr::MethodCall(call) => (call, unwrap_call.method.span()),
+                    _ => return Err(expr),
+                }
+            }
+            _ => return Err(expr),
+        };
+        let op = match CheckedOp::from_method(&checked_call.method.to_string()) {
+            Some(op) if checked_call.args.len() == 1 && checked_call.turbofish.is_none() => op,
+            _ => return Err(expr),
+        };
+        let ExprMethodCall { receiver, args, .. } = checked_call.clone();
+        Ok(ExprCheckedUnwrap {
+            op,
+            left: *receiver,
+            right: args.into_iter().next().unwrap(),
+            span,
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum CheckedOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+impl CheckedOp {
+    fn from_method(method: &str) -> Option<Self> {
+        match method {
+            "checked_add" => Some(CheckedOp::Add),
+            "checked_sub" => Some(CheckedOp::Sub),
+            "checked_mul" => Some(CheckedOp::Mul),
+            "checked_div" => Some(CheckedOp::Div),
+            "checked_rem" => Some(CheckedOp::Rem),
+            "checked_pow" => Some(CheckedOp::Pow),
+            _ => None,
+        }
+    }
+
+    fn checked_method(self) -> &'static str {
+        match self {
+            CheckedOp::Add => "checked_add",
+            CheckedOp::Sub => "checked_sub",
+            CheckedOp::Mul => "checked_mul",
+            CheckedOp::Div => "checked_div",
+            CheckedOp::Rem => "checked_rem",
+            CheckedOp::Pow => "checked_pow",
+        }
+    }
+
+    /// the unchecked operation on the closure arguments `__mutagen_left` and `__mutagen_right`
+    fn unchecked_tokens(self, span: Span) -> TokenStream {
+        match self {
+            CheckedOp::Add => quote_spanned!(span=> __mutagen_left + __mutagen_right),
+            CheckedOp::Sub => quote_spanned!(span=> __mutagen_left - __mutagen_right),
+            CheckedOp::Mul => quote_spanned!(span=> __mutagen_left * __mutagen_right),
+            CheckedOp::Div => quote_spanned!(span=> __mutagen_left / __mutagen_right),
+            CheckedOp::Rem => quote_spanned!(span=> __mutagen_left % __mutagen_right),
+            CheckedOp::Pow => quote_spanned!(span=> __mutagen_left.pow(__mutagen_right)),
+        }
+    }
+}
+
+use std::fmt;
+
+impl fmt::Display for CheckedOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckedOp::Add => write!(f, "+"),
+            CheckedOp::Sub => write!(f, "-"),
+            CheckedOp::Mul => write!(f, "*"),
+            CheckedOp::Div => write!(f, "/"),
+            CheckedOp::Rem => write!(f, "%"),
+            CheckedOp::Pow => write!(f, "pow(_)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    fn add_u8(x: u8, y: u8, runtime: &MutagenRuntimeConfig) -> u8 {
+        run(
+            1,
+            x,
+            y,
+            |l, r| l.checked_add(r).unwrap(),
+            |l, r| l.wrapping_add(r),
+            runtime,
+        )
+    }
+
+    #[test]
+    fn checked_add_inactive() {
+        let result = add_u8(1, 2, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 3);
+    }
+    #[test]
+    #[should_panic]
+    fn checked_add_inactive_overflow() {
+        add_u8(255, 1, &MutagenRuntimeConfig::without_mutation());
+    }
+    #[test]
+    fn checked_add_active() {
+        let result = add_u8(255, 1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn detect_checked_sub_unwrap() {
+        let expr: Expr = parse_quote! {x.checked_sub(1).unwrap()};
+        let e = ExprCheckedUnwrap::try_from(expr).unwrap();
+        assert_eq!(e.op, CheckedOp::Sub);
+    }
+    #[test]
+    fn ignore_checked_without_unwrap() {
+        let expr: Expr = parse_quote! {x.checked_sub(1).unwrap_or(0)};
+        assert!(ExprCheckedUnwrap::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_other_method_unwrap() {
+        let expr: Expr = parse_quote! {x.overflowing_sub(1).unwrap()};
+        assert!(ExprCheckedUnwrap::try_from(expr).is_err());
+    }
+}
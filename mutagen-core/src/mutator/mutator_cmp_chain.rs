@@ -0,0 +1,252 @@
+//! Mutator for chains of comparisons like `a < b && b < c`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// evaluates the left comparison of the chain.
+///
+/// Returns `Some(result)` if the right comparison should not be evaluated and `None` if the result of the chain is the right comparison.
+pub fn run_left(
+    mutator_id: usize,
+    left: bool,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<bool> {
+    runtime.covered(mutator_id);
+    let mutations = MutationCmpChain::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        None if !left => Some(false),
+        None => None,
+        Some(&MutationCmpChain::DropLeft) => None,
+        Some(&MutationCmpChain::DropRight) => Some(left),
+        Some(&MutationCmpChain::AndToOr) if left => Some(true),
+        Some(&MutationCmpChain::AndToOr) => None,
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the operands of the chain are inspected before other mutators have been applied to them
+    let original = match context.original_expr.clone().map(ExprCmpChain::try_from) {
+        Some(Ok(original)) if original.shares_operand() => original,
+        _ => return e,
+    };
+
+    let e = match ExprCmpChain::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutator_id = transform_info.add_mutations(
+        MutationCmpChain::possible_mutations()
+            .iter()
+            .map(|m| m.to_mutation(&original, context)),
+    );
+
+    let left = &e.left;
+    let right = &e.right;
+
+    syn::parse2(quote_spanned! {e.span=>
+        if let Some(x) = ::mutagen::mutator::mutator_cmp_chain::run_left(
+                #mutator_id,
+                #left,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+            x
+        } else {
+            #right
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationCmpChain {
+    DropLeft,
+    DropRight,
+    AndToOr,
+}
+
+impl MutationCmpChain {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationCmpChain::DropLeft,
+            MutationCmpChain::DropRight,
+            MutationCmpChain::AndToOr,
+        ]
+    }
+
+    fn to_mutation(self, original: &ExprCmpChain, context: &TransformContext) -> Mutation {
+        let left = original.left.to_token_stream().to_string();
+        let right = original.right.to_token_stream().to_string();
+        let mutated = match self {
+            MutationCmpChain::DropLeft => right.clone(),
+            MutationCmpChain::DropRight => left.clone(),
+            MutationCmpChain::AndToOr => format!("{} || {}", left, right),
+        };
+        Mutation::new_spanned(
+            context,
+            "cmp_chain".to_owned(),
+            format!("{} && {}", left, right),
+            mutated,
+            original.span,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprCmpChain {
+    left: Expr,
+    right: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprCmpChain {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Binary(ExprBinary {
+                left,
+                op: BinOp::And(t),
+                right,
+                ..
+            }) => Ok(ExprCmpChain {
+                left: *left,
+                right: *right,
+                span: t.span(),
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+impl ExprCmpChain {
+    /// checks that both sides are relational comparisons that share an operand.
+    fn shares_operand(&self) -> bool {
+        match (
+            relational_operands(&self.left),
+            relational_operands(&self.right),
+        ) {
+            (Some(left), Some(right)) => left.iter().any(|l| right.contains(l)),
+            _ => false,
+        }
+    }
+}
+
+/// checks if the expression is a chain of two comparisons that share an operand, like `lo <= x && x < hi`.
+pub(crate) fn is_cmp_chain(e: &Expr) -> bool {
+    match ExprCmpChain::try_from(e.clone()) {
+        Ok(chain) => chain.shares_operand(),
+        Err(_) => false,
+    }
+}
+
+/// the operands of a comparison with `<`, `<=`, `>=` or `>`, in their textual form.
+///
+/// Literals are not included, since comparisons like `0 < a && 0 < b` do not form a chain.
+fn relational_operands(e: &Expr) -> Option<Vec<String>> {
+    match e {
+        Expr::Binary(ExprBinary {
+            left,
+            op: BinOp::Lt(_),
+            right,
+            ..
+        })
+        | Expr::Binary(ExprBinary {
+            left,
+            op: BinOp::Le(_),
+            right,
+            ..
+        })
+        | Expr::Binary(ExprBinary {
+            left,
+            op: BinOp::Ge(_),
+            right,
+            ..
+        })
+        | Expr::Binary(ExprBinary {
+            left,
+            op: BinOp::Gt(_),
+            right,
+            ..
+        }) => Some(
+            [&**left, &**right]
+                .iter()
+                .filter(|op| !matches!(op, Expr::Lit(_)))
+                .map(|op| op.to_token_stream().to_string())
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    fn chain(e: Expr) -> ExprCmpChain {
+        ExprCmpChain::try_from(e).unwrap()
+    }
+
+    #[test]
+    fn shares_operand_range_check() {
+        let e = chain(parse_quote! {lo <= x && x <= hi});
+        assert!(e.shares_operand());
+    }
+    #[test]
+    fn shares_operand_unrelated() {
+        let e = chain(parse_quote! {a < b && c < d});
+        assert!(!e.shares_operand());
+    }
+    #[test]
+    fn shares_operand_literal() {
+        let e = chain(parse_quote! {0 < a && 0 < b});
+        assert!(!e.shares_operand());
+    }
+    #[test]
+    fn shares_operand_no_comparison() {
+        let e = chain(parse_quote! {x && x <= hi});
+        assert!(!e.shares_operand());
+    }
+
+    #[test]
+    fn chain_inactive() {
+        let runtime = MutagenRuntimeConfig::without_mutation();
+        assert_eq!(run_left(1, false, &runtime), Some(false));
+        assert_eq!(run_left(1, true, &runtime), None);
+    }
+    #[test]
+    fn chain_drop_left() {
+        let runtime = MutagenRuntimeConfig::with_mutation_id(1);
+        assert_eq!(run_left(1, false, &runtime), None);
+        assert_eq!(run_left(1, true, &runtime), None);
+    }
+    #[test]
+    fn chain_drop_right() {
+        let runtime = MutagenRuntimeConfig::with_mutation_id(2);
+        assert_eq!(run_left(1, false, &runtime), Some(false));
+        assert_eq!(run_left(1, true, &runtime), Some(true));
+    }
+    #[test]
+    fn chain_and_to_or() {
+        let runtime = MutagenRuntimeConfig::with_mutation_id(3);
+        assert_eq!(run_left(1, false, &runtime), None);
+        assert_eq!(run_left(1, true, &runtime), Some(true));
+    }
+}
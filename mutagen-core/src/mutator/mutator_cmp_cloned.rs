@@ -0,0 +1,439 @@
+//! Mutator for `.cloned()` and `.copied()` calls that feed into a comparison, like `x.cloned() == y`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::mutator::mutator_binop_cmp::BinopCmp;
+use crate::mutator::mutator_binop_eq::BinopEq;
+use crate::MutagenRuntimeConfig;
+
+pub fn run_eq<R, C: PartialEq<B>, B>(
+    mutator_id: usize,
+    receiver: R,
+    cloned: impl FnOnce(R) -> C,
+    other: &B,
+    op: BinopEq,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        receiver.may_eq_uncloned(cloned, other, op)
+    } else {
+        op.eq(&cloned(receiver), other)
+    }
+}
+
+pub fn run_cmp<R, C: PartialOrd<B>, B>(
+    mutator_id: usize,
+    receiver: R,
+    cloned: impl FnOnce(R) -> C,
+    other: &B,
+    op: BinopCmp,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        receiver.may_cmp_uncloned(cloned, other, op)
+    } else {
+        op.cmp(&cloned(receiver), other)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the `.cloned()` call is searched in the original expression
+    let side = match &context.original_expr {
+        Some(Expr::Binary(original)) => ClonedSide::of(original),
+        _ => None,
+    };
+    let side = match side {
+        Some(side) => side,
+        None => return e,
+    };
+
+    let e = match ExprCmpCloned::try_from((e, side)) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the receiver is described as written in the original code, before other mutators have been applied to it
+    let receiver_code = original_receiver(context, side)
+        .unwrap_or_else(|| e.receiver.to_token_stream().to_string());
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "cmp_cloned".to_owned(),
+        format!("{}.{}()", receiver_code, e.method),
+        receiver_code,
+        e.span,
+    ));
+
+    let receiver = &e.receiver;
+    let method = &e.method;
+    let other = &e.other;
+    let (run_fn, op) = e.op_tokens();
+
+    let run = quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_cmp_cloned::#run_fn
+    };
+    let rt = quote_spanned! {e.span=>
+        ::mutagen::MutagenRuntimeConfig::get_default()
+    };
+
+    // the operands are evaluated in their original order
+    syn::parse2(match e.side {
+        ClonedSide::Left => quote_spanned! {e.span=>
+            #run(#mutator_id, #receiver, |r| r.#method(), &(#other), #op, #rt)
+        },
+        ClonedSide::Right => quote_spanned! {e.span=>
+            {
+                let __mutagen_other = &(#other);
+                #run(#mutator_id, #receiver, |r| r.#method(), __mutagen_other, #op, #rt)
+            }
+        },
+    })
+    .expect("transformed code invalid")
+}
+
+/// the receiver of `.cloned()` as written in the original code
+fn original_receiver(context: &TransformContext, side: ClonedSide) -> Option<String> {
+    let original = ExprCmpCloned::try_from((context.original_expr.clone()?, side)).ok()?;
+    Some(original.receiver.to_token_stream().to_string())
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ClonedSide {
+    Left,
+    Right,
+}
+
+impl ClonedSide {
+    /// the side of the comparison that is a call to `.cloned()` or `.copied()`.
+    ///
+    /// The left side is preferred if both sides are such calls.
+    fn of(e: &ExprBinary) -> Option<Self> {
+        if CmpOp::try_from(&e.op).is_err() {
+            return None;
+        }
+        if is_cloned_call(&e.left) {
+            Some(ClonedSide::Left)
+        } else if is_cloned_call(&e.right) {
+            Some(ClonedSide::Right)
+        } else {
+            None
+        }
+    }
+}
+
+/// check if the expression is a call `x.cloned()` or `x.copied()`
+fn is_cloned_call(e: &Expr) -> bool {
+    match e {
+        Expr::MethodCall(call) => {
+            (call.method == "cloned" || call.method == "copied")
+                && call.args.is_empty()
+                && call.turbofish.is_none()
+        }
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CmpOp {
+    Eq(BinopEq),
+    Cmp(BinopCmp),
+}
+
+impl TryFrom<&BinOp> for CmpOp {
+    type Error = ();
+    fn try_from(op: &BinOp) -> Result<Self, ()> {
+        match op {
+            BinOp::Eq(_) => Ok(CmpOp::Eq(BinopEq::Eq)),
+            BinOp::Ne(_) => Ok(CmpOp::Eq(BinopEq::Ne)),
+            BinOp::Lt(_) => Ok(CmpOp::Cmp(BinopCmp::Lt)),
+            BinOp::Le(_) => Ok(CmpOp::Cmp(BinopCmp::Le)),
+            BinOp::Ge(_) => Ok(CmpOp::Cmp(BinopCmp::Ge)),
+            BinOp::Gt(_) => Ok(CmpOp::Cmp(BinopCmp::Gt)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl CmpOp {
+    /// the operator with swapped operands, such that `a < b` is equivalent to `b > a`
+    fn flipped(self) -> Self {
+        match self {
+            CmpOp::Eq(op) => CmpOp::Eq(op),
+            CmpOp::Cmp(BinopCmp::Lt) => CmpOp::Cmp(BinopCmp::Gt),
+            CmpOp::Cmp(BinopCmp::Le) => CmpOp::Cmp(BinopCmp::Ge),
+            CmpOp::Cmp(BinopCmp::Ge) => CmpOp::Cmp(BinopCmp::Le),
+            CmpOp::Cmp(BinopCmp::Gt) => CmpOp::Cmp(BinopCmp::Lt),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprCmpCloned {
+    receiver: Expr,
+    method: syn::Ident,
+    other: Expr,
+    // the operator as seen from the receiver on the left side
+    op: CmpOp,
+    side: ClonedSide,
+    span: Span,
+}
+
+impl TryFrom<(Expr, ClonedSide)> for ExprCmpCloned {
+    type Error = Expr;
+    fn try_from((expr, side): (Expr, ClonedSide)) -> Result<Self, Expr> {
+        match expr {
+            Expr::Binary(expr) => {
+                let op = match CmpOp::try_from(&expr.op) {
+                    Ok(op) => op,
+                    Err(()) => return Err(Expr::Binary(expr)),
+                };
+                let cloned = match side {
+                    ClonedSide::Left => &expr.left,
+                    ClonedSide::Right => &expr.right,
+                };
+                if !is_cloned_call(cloned) {
+                    return Err(Expr::Binary(expr));
+                }
+                let span = expr.op.span();
+                let (cloned, other, op) = match side {
+                    ClonedSide::Left => (*expr.left, *expr.right, op),
+                    ClonedSide::Right => (*expr.right, *expr.left, op.flipped()),
+                };
+                match cloned {
+                    Expr::MethodCall(ExprMethodCall {
+                        receiver, method, ..
+                    }) => Ok(ExprCmpCloned {
+                        receiver: *receiver,
+                        method,
+                        other,
+                        op,
+                        side,
+                        span,
+                    }),
+                    _ => unreachable!(),
+                }
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+impl ExprCmpCloned {
+    fn op_tokens(&self) -> (TokenStream, TokenStream) {
+        match self.op {
+            CmpOp::Eq(op) => {
+                let mut tokens = TokenStream::new();
+                tokens.extend(quote_spanned!(self.span=>
+                    ::mutagen::mutator::mutator_binop_eq::BinopEq::));
+                tokens.extend(match op {
+                    BinopEq::Eq => quote_spanned!(self.span=> Eq),
+                    BinopEq::Ne => quote_spanned!(self.span=> Ne),
+                });
+                (quote_spanned!(self.span=> run_eq), tokens)
+            }
+            CmpOp::Cmp(op) => {
+                let mut tokens = TokenStream::new();
+                tokens.extend(quote_spanned!(self.span=>
+                    ::mutagen::mutator::mutator_binop_cmp::BinopCmp::));
+                tokens.extend(match op {
+                    BinopCmp::Lt => quote_spanned!(self.span=> Lt),
+                    BinopCmp::Le => quote_spanned!(self.span=> Le),
+                    BinopCmp::Ge => quote_spanned!(self.span=> Ge),
+                    BinopCmp::Gt => quote_spanned!(self.span=> Gt),
+                });
+                (quote_spanned!(self.span=> run_cmp), tokens)
+            }
+        }
+    }
+}
+
+/// trait that is used to compare the receiver of `.cloned()` without cloning.
+///
+/// If the receiver cannot be compared, the comparison of the cloned value is used and the mutation is skipped.
+trait UnclonedEq<B> {
+    fn may_eq_uncloned<C: PartialEq<B>>(
+        self,
+        cloned: impl FnOnce(Self) -> C,
+        other: &B,
+        op: BinopEq,
+    ) -> bool
+    where
+        Self: Sized;
+}
+
+impl<R, B> UnclonedEq<B> for R {
+    default fn may_eq_uncloned<C: PartialEq<B>>(
+        self,
+        cloned: impl FnOnce(Self) -> C,
+        other: &B,
+        op: BinopEq,
+    ) -> bool {
+        op.eq(&cloned(self), other)
+    }
+}
+
+impl<R: PartialEq<B>, B> UnclonedEq<B> for R {
+    fn may_eq_uncloned<C: PartialEq<B>>(
+        self,
+        _cloned: impl FnOnce(Self) -> C,
+        other: &B,
+        op: BinopEq,
+    ) -> bool {
+        op.eq(&self, other)
+    }
+}
+
+/// trait that is used to compare the receiver of `.cloned()` without cloning.
+///
+/// If the receiver cannot be compared, the comparison of the cloned value is used and the mutation is skipped.
+trait UnclonedCmp<B> {
+    fn may_cmp_uncloned<C: PartialOrd<B>>(
+        self,
+        cloned: impl FnOnce(Self) -> C,
+        other: &B,
+        op: BinopCmp,
+    ) -> bool
+    where
+        Self: Sized;
+}
+
+impl<R, B> UnclonedCmp<B> for R {
+    default fn may_cmp_uncloned<C: PartialOrd<B>>(
+        self,
+        cloned: impl FnOnce(Self) -> C,
+        other: &B,
+        op: BinopCmp,
+    ) -> bool {
+        op.cmp(&cloned(self), other)
+    }
+}
+
+impl<R: PartialOrd<B>, B> UnclonedCmp<B> for R {
+    fn may_cmp_uncloned<C: PartialOrd<B>>(
+        self,
+        _cloned: impl FnOnce(Self) -> C,
+        other: &B,
+        op: BinopCmp,
+    ) -> bool {
+        op.cmp(&self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::Cell;
+    use syn::parse_quote;
+
+    #[test]
+    fn eq_inactive() {
+        let x = 5;
+        let result = run_eq(
+            1,
+            Some(&x),
+            |r| r.cloned(),
+            &Some(5),
+            BinopEq::Eq,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert!(result);
+    }
+
+    // `Option<&i32>` cannot be compared to `Option<i32>`, the mutation is skipped
+    #[test]
+    fn eq_active_skipped() {
+        let x = 5;
+        let result = run_eq(
+            1,
+            Some(&x),
+            |r| r.cloned(),
+            &Some(5),
+            BinopEq::Eq,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert!(result);
+    }
+
+    // the receiver can be compared directly, the clone is not called
+    #[test]
+    fn eq_active_uncloned() {
+        let clone_called = Cell::new(false);
+        let result = run_eq(
+            1,
+            5,
+            |r| {
+                clone_called.set(true);
+                r
+            },
+            &5,
+            BinopEq::Eq,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert!(result);
+        assert!(!clone_called.get());
+    }
+
+    #[test]
+    fn cmp_active_uncloned() {
+        let clone_called = Cell::new(false);
+        let result = run_cmp(
+            1,
+            3,
+            |r| {
+                clone_called.set(true);
+                r
+            },
+            &5,
+            BinopCmp::Lt,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert!(result);
+        assert!(!clone_called.get());
+    }
+
+    #[test]
+    fn cloned_side_right() {
+        let e: ExprBinary = syn::parse_quote! {x < y.copied()};
+        assert_eq!(ClonedSide::of(&e), Some(ClonedSide::Right));
+    }
+    #[test]
+    fn cloned_side_none_for_add() {
+        let e: ExprBinary = syn::parse_quote! {x.cloned() + y};
+        assert_eq!(ClonedSide::of(&e), None);
+    }
+
+    #[test]
+    fn flipped_lt() {
+        assert_eq!(CmpOp::Cmp(BinopCmp::Lt).flipped(), CmpOp::Cmp(BinopCmp::Gt));
+    }
+
+    #[test]
+    fn original_receiver_right() {
+        let context = TransformContext {
+            original_expr: Some(parse_quote! {y == x.cloned()}),
+            ..TransformContext::default()
+        };
+        assert_eq!(
+            original_receiver(&context, ClonedSide::Right),
+            Some("x".to_owned())
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Mutator for the target of `collect::<Vec<_>>()`.
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, GenericMethodArgument, Type};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<I: Iterator>(
+    mutator_id: usize,
+    iter: I,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Vec<I::Item> {
+    runtime.covered(mutator_id);
+    let mutations = MutationCollectTarget::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(MutationCollectTarget::HashSet) => I::Item::may_collect_hash_set(iter),
+        Some(MutationCollectTarget::BTreeSet) => I::Item::may_collect_btree_set(iter),
+        None => iter.collect(),
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let (receiver, target, span) = match e {
+        Expr::MethodCall(call) if is_collect_into_vec(&call) => {
+            let target = call.turbofish.unwrap().args[0].clone();
+            (call.receiver, target, call.method.span())
+        }
+        _ => return e,
+    };
+
+    let mutator_id = transform_info.add_mutations(
+        MutationCollectTarget::possible_mutations()
+            .iter()
+            .map(|m| m.to_mutation(span, context)),
+    );
+
+    // the target type is kept since it may be required to infer the type of the elements
+    syn::parse2(quote_spanned! {span=>
+        {
+            let __mutagen_collected: #target = ::mutagen::mutator::mutator_collect_target::run(
+                    #mutator_id,
+                    #receiver,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                );
+            __mutagen_collected
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// checks if the call is `collect::<Vec<_>>()`, with any element type
+fn is_collect_into_vec(call: &syn::ExprMethodCall) -> bool {
+    if call.method != "collect" || !call.args.is_empty() {
+        return false;
+    }
+    let args = match &call.turbofish {
+        Some(turbofish) if turbofish.args.len() == 1 => &turbofish.args[0],
+        _ => return false,
+    };
+    match args {
+        GenericMethodArgument::Type(Type::Path(ty)) if ty.qself.is_none() => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Vec")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationCollectTarget {
+    HashSet,
+    BTreeSet,
+}
+
+impl MutationCollectTarget {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationCollectTarget::HashSet,
+            MutationCollectTarget::BTreeSet,
+        ]
+    }
+
+    fn to_mutation(self, span: Span, context: &TransformContext) -> Mutation {
+        Mutation::new_spanned(
+            context,
+            "collect_target".to_owned(),
+            "collect::<Vec<_>>()".to_owned(),
+            format!("collect::<{}<_>>()", self),
+            span,
+        )
+    }
+}
+
+impl fmt::Display for MutationCollectTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MutationCollectTarget::HashSet => write!(f, "HashSet"),
+            MutationCollectTarget::BTreeSet => write!(f, "BTreeSet"),
+        }
+    }
+}
+
+/// trait that is used to optimistically collect elements into a `HashSet`.
+///
+/// Duplicates are removed and the order of the elements is lost.
+/// If the elements cannot be hashed, the optimistic assumption fails.
+trait HashCollectable: Sized {
+    fn may_collect_hash_set(iter: impl Iterator<Item = Self>) -> Vec<Self>;
+}
+
+impl<T> HashCollectable for T {
+    default fn may_collect_hash_set(_iter: impl Iterator<Item = Self>) -> Vec<Self> {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Hash + Eq> HashCollectable for T {
+    fn may_collect_hash_set(iter: impl Iterator<Item = Self>) -> Vec<Self> {
+        iter.collect::<HashSet<_>>().into_iter().collect()
+    }
+}
+
+/// trait that is used to optimistically collect elements into a `BTreeSet`.
+///
+/// Duplicates are removed and the elements are sorted.
+/// If the elements cannot be ordered, the optimistic assumption fails.
+trait OrdCollectable: Sized {
+    fn may_collect_btree_set(iter: impl Iterator<Item = Self>) -> Vec<Self>;
+}
+
+impl<T> OrdCollectable for T {
+    default fn may_collect_btree_set(_iter: impl Iterator<Item = Self>) -> Vec<Self> {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Ord> OrdCollectable for T {
+    fn may_collect_btree_set(iter: impl Iterator<Item = Self>) -> Vec<Self> {
+        iter.collect::<BTreeSet<_>>().into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn collect_inactive() {
+        let result = run(
+            1,
+            vec![2, 1, 2].into_iter(),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, vec![2, 1, 2]);
+    }
+    #[test]
+    fn collect_active1() {
+        let mut result = run(
+            1,
+            vec![2, 1, 2].into_iter(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        result.sort();
+        assert_eq!(result, vec![1, 2]);
+    }
+    #[test]
+    fn collect_active2() {
+        let result = run(
+            1,
+            vec![2, 1, 2].into_iter(),
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, vec![1, 2]);
+    }
+    #[test]
+    #[should_panic]
+    fn collect_unhashable_active1() {
+        run(
+            1,
+            vec![1.0, 2.0].into_iter(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+
+    #[test]
+    fn detect_collect_vec() {
+        let call: syn::ExprMethodCall = parse_quote! {v.iter().collect::<std::vec::Vec<u8>>()};
+        assert!(is_collect_into_vec(&call));
+    }
+    #[test]
+    fn ignore_collect_string() {
+        let call: syn::ExprMethodCall = parse_quote! {v.iter().collect::<String>()};
+        assert!(!is_collect_into_vec(&call));
+    }
+    #[test]
+    fn ignore_collect_without_turbofish() {
+        let call: syn::ExprMethodCall = parse_quote! {v.iter().collect()};
+        assert!(!is_collect_into_vec(&call));
+    }
+}
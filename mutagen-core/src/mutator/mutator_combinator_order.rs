@@ -0,0 +1,289 @@
+//! Mutator for the order of adjacent combinators on options, like `opt.filter(p).map(f)`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::Expr;
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// runs `option.filter(p).map(f)`, or `option.map(f).filter(p)` if the mutation is active
+pub fn run_filter_map<T, U, P: FnOnce(&T) -> bool, F: FnOnce(T) -> U>(
+    mutator_id: usize,
+    option: Option<T>,
+    p: P,
+    f: F,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<U> {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        let mapped = MayCast::<Option<T>>::may_cast(option.map(f));
+        MayCast::<Option<U>>::may_cast(mapped.filter(p))
+    } else {
+        option.filter(p).map(f)
+    }
+}
+
+/// runs `option.map(f).filter(p)`, or `option.filter(p).map(f)` if the mutation is active
+pub fn run_map_filter<T, U, F: FnOnce(T) -> U, P: FnOnce(&U) -> bool>(
+    mutator_id: usize,
+    option: Option<T>,
+    f: F,
+    p: P,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<U> {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        let option = MayCast::<Option<U>>::may_cast(option);
+        let filtered = MayCast::<Option<T>>::may_cast(option.filter(p));
+        filtered.map(f)
+    } else {
+        option.map(f).filter(p)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprCombinatorChain::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "combinator_order".to_owned(),
+        e.order.describe(),
+        e.order.swapped().describe(),
+        e.span,
+    ));
+
+    let receiver = &e.receiver;
+    let first = &e.first_arg;
+    let second = &e.second_arg;
+    let run_fn = match e.order {
+        CombinatorOrder::FilterMap => quote_spanned! {e.span=> run_filter_map},
+        CombinatorOrder::MapFilter => quote_spanned! {e.span=> run_map_filter},
+    };
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_combinator_order::#run_fn(
+                #mutator_id,
+                #receiver,
+                #first,
+                #second,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// methods that return options.
+///
+/// The combinators `filter` and `map` exist on iterators as well. Chains are only mutated if the receiver is known to be an option.
+const OPTION_METHODS: &[&str] = &[
+    "get",
+    "get_mut",
+    "first",
+    "last",
+    "pop",
+    "next",
+    "peek",
+    "nth",
+    "find",
+    "position",
+    "max",
+    "min",
+    "ok",
+    "err",
+    "checked_add",
+    "checked_sub",
+    "checked_mul",
+    "checked_div",
+    "checked_rem",
+    "strip_prefix",
+    "strip_suffix",
+];
+
+/// checks if the receiver of the combinators is known to be an option
+fn is_option(e: &Expr) -> bool {
+    match e {
+        Expr::MethodCall(call) => OPTION_METHODS.iter().any(|m| call.method == m),
+        Expr::Call(call) => match &*call.func {
+            Expr::Path(path) => path.path.is_ident("Some"),
+            _ => false,
+        },
+        Expr::Paren(paren) => is_option(&paren.expr),
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CombinatorOrder {
+    FilterMap,
+    MapFilter,
+}
+
+impl CombinatorOrder {
+    fn from_methods(first: &str, second: &str) -> Option<Self> {
+        match (first, second) {
+            ("filter", "map") => Some(CombinatorOrder::FilterMap),
+            ("map", "filter") => Some(CombinatorOrder::MapFilter),
+            _ => None,
+        }
+    }
+
+    fn swapped(self) -> Self {
+        match self {
+            CombinatorOrder::FilterMap => CombinatorOrder::MapFilter,
+            CombinatorOrder::MapFilter => CombinatorOrder::FilterMap,
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            CombinatorOrder::FilterMap => "filter(p).map(f)".to_owned(),
+            CombinatorOrder::MapFilter => "map(f).filter(p)".to_owned(),
+        }
+    }
+}
+
+/// trait that is used to optimistically convert a value into a value of another type.
+///
+/// Reordering `filter` and `map` only type-checks if `map` does not change the type of the value.
+/// Otherwise, the optimistic assumption fails.
+trait MayCast<U> {
+    fn may_cast(self) -> U;
+}
+
+impl<T, U> MayCast<U> for T {
+    default fn may_cast(self) -> U {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T> MayCast<T> for T {
+    fn may_cast(self) -> T {
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprCombinatorChain {
+    receiver: Expr,
+    order: CombinatorOrder,
+    first_arg: Expr,
+    second_arg: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprCombinatorChain {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        let order = match &expr {
+            Expr::MethodCall(second) if second.args.len() == 1 && second.turbofish.is_none() => {
+                match &*second.receiver {
+                    Expr::MethodCall(first)
+                        if first.args.len() == 1
+                            && first.turbofish.is_none()
+                            && is_option(&first.receiver) =>
+                    {
+                        CombinatorOrder::from_methods(
+                            &first.method.to_string(),
+                            &second.method.to_string(),
+                        )
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        match (order, expr) {
+            (Some(order), Expr::MethodCall(second)) => match *second.receiver {
+                Expr::MethodCall(first) => Ok(ExprCombinatorChain {
+                    receiver: *first.receiver,
+                    order,
+                    first_arg: first.args[0].clone(),
+                    second_arg: second.args[0].clone(),
+                    span: second.method.span(),
+                }),
+                _ => unreachable!(),
+            },
+            (_, expr) => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn filter_map_inactive() {
+        let result = run_filter_map(
+            1,
+            Some(1),
+            |x| *x == 1,
+            |x| x + 1,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, Some(2));
+    }
+    #[test]
+    fn filter_map_active() {
+        let result = run_filter_map(
+            1,
+            Some(1),
+            |x| *x == 1,
+            |x| x + 1,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, None);
+    }
+    #[test]
+    fn map_filter_active() {
+        let result = run_map_filter(
+            1,
+            Some(1),
+            |x| x + 1,
+            |x| *x == 1,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, Some(2));
+    }
+    #[test]
+    #[should_panic]
+    fn filter_map_different_types_active() {
+        run_filter_map(
+            1,
+            Some(1),
+            |x| *x == 1,
+            |x| x.to_string(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+
+    #[test]
+    fn detect_filter_map() {
+        let expr: Expr = parse_quote! {v.first().filter(p).map(f)};
+        let e = ExprCombinatorChain::try_from(expr).unwrap();
+        assert_eq!(e.order, CombinatorOrder::FilterMap);
+        assert_eq!(e.receiver, parse_quote! {v.first()});
+        assert_eq!(e.first_arg, parse_quote! {p});
+        assert_eq!(e.second_arg, parse_quote! {f});
+    }
+    #[test]
+    fn ignore_iterator() {
+        let expr: Expr = parse_quote! {v.iter().filter(p).map(f)};
+        assert!(ExprCombinatorChain::try_from(expr).is_err());
+    }
+}
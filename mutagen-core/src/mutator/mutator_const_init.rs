@@ -0,0 +1,238 @@
+//! Mutator for integer initializers of `const` and `static` items, like `const MAX: usize = 100;`.
+//!
+//! Initializers of constants are evaluated at compile time and cannot depend on the runtime configuration.
+//! Instead, the active mutation is read from the environment variable `MUTATION_ID` when compiling the code under test.
+//! If `MUTAGEN_ID_VAR` is set during compilation, the variable with the given name is read instead.
+//! The compiler tracks both variables, such that the code is recompiled if they change.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Expr, ExprLit, Lit, LitInt, LitStr, Type};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+use crate::MutagenRuntimeConfig;
+
+/// Returns the number of the active mutation of the given mutator, starting at 1, or 0 if no mutation of this mutator is active.
+///
+/// The argument `mutation_id` is the value of the environment variable `MUTATION_ID` (or the one named by `MUTAGEN_ID_VAR`) at compile time.
+pub const fn active_mutation(
+    mutation_id: Option<&str>,
+    mutator_id: usize,
+    num_mutations: usize,
+) -> usize {
+    let bytes = match mutation_id {
+        Some(s) => s.as_bytes(),
+        None => return 0,
+    };
+    if bytes.is_empty() {
+        return 0;
+    }
+    let mut m_id = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] < b'0' || bytes[i] > b'9' {
+            return 0;
+        }
+        m_id = m_id
+            .wrapping_mul(10)
+            .wrapping_add((bytes[i] - b'0') as usize);
+        i += 1;
+    }
+    if mutator_id == 0 || m_id < mutator_id || m_id - mutator_id >= num_mutations {
+        0
+    } else {
+        m_id - mutator_id + 1
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    ty: &Type,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let lit = match &e {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.clone(),
+        _ => return e,
+    };
+    let value = match lit.base10_parse::<i128>() {
+        Ok(value) => value,
+        Err(_) => return e,
+    };
+
+    let mutations = MutationConstInit::possible_mutations(value, is_unsigned(ty, &lit));
+    if mutations.is_empty() {
+        return e;
+    }
+
+    let span = lit.span();
+    let mutator_id = transform_info.add_mutations(mutations.iter().map(|m| {
+        Mutation::new_spanned(
+            context,
+            "const_init".to_owned(),
+            value.to_string(),
+            m.mutate(value).to_string(),
+            span,
+        )
+    }));
+
+    let num_mutations = mutations.len();
+    let arms = mutations.iter().enumerate().map(|(i, m)| {
+        let i = i + 1;
+        let mutated = mutated_lit(m.mutate(value), &lit, span);
+        quote_spanned! {span=> #i => #mutated,}
+    });
+
+    let mutation_id = compile_time_mutation_id(span);
+    syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_const_init::active_mutation(
+            #mutation_id,
+            #mutator_id,
+            #num_mutations,
+        ) {
+            #(#arms)*
+            _ => #e,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// Generates code that reads the id of the active mutation at compile time.
+///
+/// The name of the variable is read from `MUTAGEN_ID_VAR` while transforming the code, like `MutagenRuntimeConfig` does at runtime.
+/// `MUTAGEN_ID_VAR` is read in the generated code as well, such that the code is transformed again if it changes.
+pub(crate) fn compile_time_mutation_id(span: Span) -> TokenStream {
+    let var = LitStr::new(&MutagenRuntimeConfig::mutation_id_var(), span);
+    quote_spanned! {span=>
+        {
+            let _ = ::core::option_env!("MUTAGEN_ID_VAR");
+            ::core::option_env!(#var)
+        }
+    }
+}
+
+/// checks if the constant is unsigned, either by its type or by the suffix of the literal
+fn is_unsigned(ty: &Type, lit: &LitInt) -> bool {
+    if lit.suffix().starts_with('u') {
+        return true;
+    }
+    match ty {
+        Type::Path(path) => path
+            .path
+            .get_ident()
+            .map(|ident| ident.to_string().starts_with('u'))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// a literal with the given value and the suffix of the original literal
+pub(crate) fn mutated_lit(value: i128, original: &LitInt, span: Span) -> TokenStream {
+    let lit = LitInt::new(
+        &format!("{}{}", value.wrapping_abs(), original.suffix()),
+        span,
+    );
+    if value < 0 {
+        quote! {-#lit}
+    } else {
+        quote! {#lit}
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationConstInit {
+    Relative(i128),
+    Zero,
+}
+
+impl MutationConstInit {
+    /// the mutations of a constant, leaving out mutations that do not change the value or duplicate other mutations
+    fn possible_mutations(value: i128, unsigned: bool) -> Vec<Self> {
+        let mut mutations = vec![];
+        let mut mutated_values = vec![value];
+        for &m in &[
+            MutationConstInit::Relative(1),
+            MutationConstInit::Relative(-1),
+            MutationConstInit::Zero,
+        ] {
+            let mutated = m.mutate(value);
+            if unsigned && mutated < 0 {
+                continue;
+            }
+            if !mutated_values.contains(&mutated) {
+                mutated_values.push(mutated);
+                mutations.push(m);
+            }
+        }
+        mutations
+    }
+
+    fn mutate(self, value: i128) -> i128 {
+        match self {
+            MutationConstInit::Relative(r) => value.wrapping_add(r),
+            MutationConstInit::Zero => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn active_mutation_not_set() {
+        assert_eq!(active_mutation(None, 1, 2), 0);
+    }
+    #[test]
+    fn active_mutation_of_mutator() {
+        assert_eq!(active_mutation(Some("3"), 2, 2), 2);
+    }
+    #[test]
+    fn active_mutation_other_mutator() {
+        assert_eq!(active_mutation(Some("4"), 2, 2), 0);
+        assert_eq!(active_mutation(Some("1"), 2, 2), 0);
+    }
+    #[test]
+    fn active_mutation_invalid() {
+        assert_eq!(active_mutation(Some("x"), 1, 2), 0);
+    }
+    #[test]
+    fn active_mutation_in_const() {
+        const ACTIVE: usize = active_mutation(Some("12"), 10, 3);
+        assert_eq!(ACTIVE, 3);
+    }
+
+    #[test]
+    fn possible_mutations_value() {
+        assert_eq!(
+            MutationConstInit::possible_mutations(100, true),
+            vec![
+                MutationConstInit::Relative(1),
+                MutationConstInit::Relative(-1),
+                MutationConstInit::Zero
+            ]
+        );
+    }
+    #[test]
+    fn possible_mutations_one() {
+        assert_eq!(
+            MutationConstInit::possible_mutations(1, true),
+            vec![
+                MutationConstInit::Relative(1),
+                MutationConstInit::Relative(-1)
+            ]
+        );
+    }
+    #[test]
+    fn possible_mutations_zero_unsigned() {
+        assert_eq!(
+            MutationConstInit::possible_mutations(0, true),
+            vec![MutationConstInit::Relative(1)]
+        );
+    }
+}
@@ -0,0 +1,356 @@
+//! Mutator for constructors of std collections, like `Vec::new()`, `HashMap::default()` or `String::with_capacity(n)`.
+//!
+//! Strings created by `format!` are preallocated with an estimated capacity. They are mutated to start with the capacity `0` instead.
+//!
+//! The constructors create equal values, so most mutants are expected to be equivalent.
+//! Mutants that are killed reveal logic that depends on the capacity of a collection.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprCall, ExprMacro, ExprPath, Ident, Path, Token};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the collection created by the swapped constructor if the mutation is active.
+///
+/// Both collections are created in advance. Constructing an empty collection with `new` or `default` does not allocate.
+pub fn run<T>(
+    mutator_id: usize,
+    original: T,
+    swapped: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        swapped
+    } else {
+        original
+    }
+}
+
+/// formats the given arguments like `format!`, but starts with an empty string without capacity if the mutation is active.
+pub fn run_format(
+    mutator_id: usize,
+    args: fmt::Arguments,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> String {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        let mut s = String::new();
+        fmt::Write::write_fmt(&mut s, args)
+            .expect("a formatting trait implementation returned an error");
+        s
+    } else {
+        fmt::format(args)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprFormat::try_from(e) {
+        Ok(e) => return transform_format(e, transform_info, context),
+        Err(e) => e,
+    };
+    let e = match ExprConstructor::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let type_path = e.type_path.to_token_stream().to_string();
+    let swapped = e.constructor.swapped();
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "constructor".to_owned(),
+        format!("{}::{}", type_path, e.constructor.describe()),
+        format!("{}::{}", type_path, swapped.describe()),
+        e.span,
+    ));
+
+    let original = &e.call;
+    let type_path = &e.type_path;
+    let swapped = Ident::new(swapped.name(), e.span);
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_constructor::run(
+                #mutator_id,
+                #original,
+                #type_path::#swapped(),
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+fn transform_format(
+    e: ExprFormat,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "constructor".to_owned(),
+        "format!(..)".to_owned(),
+        "format!(..) starting with capacity 0".to_owned(),
+        e.span,
+    ));
+
+    let args = &e.mac.mac.tokens;
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_constructor::run_format(
+                #mutator_id,
+                ::core::format_args!(#args),
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// std collections that have the constructors `new` and `default`.
+///
+/// The second component tells whether the collection has a constructor `with_capacity`.
+const COLLECTION_TYPES: &[(&str, bool)] = &[
+    ("Vec", true),
+    ("VecDeque", true),
+    ("String", true),
+    ("HashMap", true),
+    ("HashSet", true),
+    ("BinaryHeap", true),
+    ("BTreeMap", false),
+    ("BTreeSet", false),
+    ("LinkedList", false),
+];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Constructor {
+    New,
+    Default,
+    WithCapacity,
+}
+
+impl Constructor {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "new" => Some(Constructor::New),
+            "default" => Some(Constructor::Default),
+            "with_capacity" => Some(Constructor::WithCapacity),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Constructor::New => "new",
+            Constructor::Default => "default",
+            Constructor::WithCapacity => "with_capacity",
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Constructor::WithCapacity => "with_capacity(_)".to_owned(),
+            _ => format!("{}()", self.name()),
+        }
+    }
+
+    /// the constructor that is used instead.
+    ///
+    /// Creating a collection with capacity is replaced with `new`.
+    fn swapped(self) -> Self {
+        match self {
+            Constructor::New => Constructor::Default,
+            Constructor::Default | Constructor::WithCapacity => Constructor::New,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprConstructor {
+    call: ExprCall,
+    /// the path of the collection type, without the constructor
+    type_path: Path,
+    constructor: Constructor,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprConstructor {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        let call = match expr {
+            Expr::Call(call) => call,
+            _ => return Err(expr),
+        };
+        let path = match &*call.func {
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) if path.segments.len() >= 2 => path,
+            _ => return Err(Expr::Call(call)),
+        };
+        let mut segments = path.segments.iter().rev();
+        let constructor_segment = segments.next().unwrap();
+        let type_segment = segments.next().unwrap();
+
+        let constructor = match Constructor::from_name(&constructor_segment.ident.to_string()) {
+            Some(constructor) if constructor_segment.arguments.is_empty() => constructor,
+            _ => return Err(Expr::Call(call)),
+        };
+        let has_capacity = match COLLECTION_TYPES
+            .iter()
+            .find(|(ty, _)| type_segment.ident == ty)
+        {
+            Some((_, has_capacity)) => *has_capacity,
+            None => return Err(Expr::Call(call)),
+        };
+        let num_args = match constructor {
+            Constructor::WithCapacity if has_capacity => 1,
+            Constructor::WithCapacity => return Err(Expr::Call(call)),
+            _ => 0,
+        };
+        if call.args.len() != num_args {
+            return Err(Expr::Call(call));
+        }
+
+        let type_path = Path {
+            leading_colon: path.leading_colon,
+            segments: path
+                .segments
+                .iter()
+                .take(path.segments.len() - 1)
+                .cloned()
+                .collect::<Punctuated<_, Token![::]>>(),
+        };
+        Ok(ExprConstructor {
+            span: constructor_segment.ident.span(),
+            call,
+            type_path,
+            constructor,
+        })
+    }
+}
+
+/// a call of `format!`, `std::format!` or `alloc::format!`
+#[derive(Clone, Debug)]
+struct ExprFormat {
+    mac: ExprMacro,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFormat {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Macro(mac) if is_format_path(&mac.mac.path) => Ok(ExprFormat {
+                span: mac.mac.path.segments.last().unwrap().ident.span(),
+                mac,
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+fn is_format_path(path: &Path) -> bool {
+    let idents = path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>();
+    idents == ["format"] || idents == ["std", "format"] || idents == ["alloc", "format"]
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn constructor_inactive() {
+        let result = run(
+            1,
+            Vec::<u8>::with_capacity(8),
+            Vec::new(),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert!(result.capacity() >= 8);
+    }
+    #[test]
+    fn constructor_active() {
+        let result = run(
+            1,
+            Vec::<u8>::with_capacity(8),
+            Vec::new(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result.capacity(), 0);
+    }
+
+    #[test]
+    fn format_inactive() {
+        let result = run_format(
+            1,
+            format_args!("{}-{}", 1, 2),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, "1-2");
+    }
+    #[test]
+    fn format_active() {
+        let result = run_format(
+            1,
+            format_args!("{}-{}", 1, 2),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, "1-2");
+    }
+
+    #[test]
+    fn detect_format() {
+        let expr: Expr = parse_quote! {std::format!("{}", x)};
+        assert!(ExprFormat::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_other_macros() {
+        let expr: Expr = parse_quote! {println!("{}", x)};
+        assert!(ExprFormat::try_from(expr).is_err());
+    }
+
+    #[test]
+    fn detect_vec_with_capacity() {
+        let expr: Expr = parse_quote! {std::vec::Vec::<u8>::with_capacity(n)};
+        let e = ExprConstructor::try_from(expr).unwrap();
+        assert_eq!(e.constructor, Constructor::WithCapacity);
+        assert_eq!(e.type_path, parse_quote! {std::vec::Vec::<u8>});
+    }
+    #[test]
+    fn detect_hash_map_default() {
+        let expr: Expr = parse_quote! {HashMap::default()};
+        let e = ExprConstructor::try_from(expr).unwrap();
+        assert_eq!(e.constructor.swapped(), Constructor::New);
+    }
+    #[test]
+    fn ignore_btree_map_with_capacity() {
+        let expr: Expr = parse_quote! {BTreeMap::with_capacity(n)};
+        assert!(ExprConstructor::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_other_types() {
+        let expr: Expr = parse_quote! {Foo::new()};
+        assert!(ExprConstructor::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_new_with_args() {
+        let expr: Expr = parse_quote! {Vec::new(x)};
+        assert!(ExprConstructor::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+//! Mutator for copying and cloning of references, like `v.iter().copied()` or `opt.cloned()`.
+//!
+//! `copied()` is swapped with `cloned()` and vice versa.
+//! For types that implement `Copy`, both give the same result, such that the mutants are expected to survive.
+//! Killed mutants point to a `Clone` implementation that differs from copying.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, ExprMethodCall, Ident};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// copies the referenced value, or clones it if the mutation is active
+#[allow(clippy::clone_on_copy)]
+pub fn run_copied<T: Copy>(
+    mutator_id: usize,
+    value: &T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        value.clone()
+    } else {
+        *value
+    }
+}
+
+/// clones the referenced value, or copies it if the mutation is active
+pub fn run_cloned<T: Clone>(
+    mutator_id: usize,
+    value: &T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        value.may_copy()
+    } else {
+        value.clone()
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprCopiedCloned::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let swapped = if e.copied { "cloned" } else { "copied" };
+    let mutation = Mutation::new_spanned(
+        context,
+        "copied_cloned".to_owned(),
+        format!("{}()", e.call.method),
+        format!("{}()", swapped),
+        e.span,
+    );
+    // a type that can be cloned but not copied is no weakness of the tests
+    let mutator_id = transform_info.add_mutation(if e.copied {
+        mutation
+    } else {
+        mutation.with_optimistic_policy(OptimisticPolicy::Skip)
+    });
+
+    // `copied()` and `cloned()` are replaced with `map`, since iterators have different types for both methods
+    let span = e.span;
+    let run_fn = if e.copied {
+        Ident::new("run_copied", span)
+    } else {
+        Ident::new("run_cloned", span)
+    };
+    e.call.method = Ident::new("map", span);
+    e.call.args.push(
+        syn::parse2(quote_spanned! {span=>
+            |__mutagen_item| ::mutagen::mutator::mutator_copied_cloned::#run_fn(
+                    #mutator_id,
+                    __mutagen_item,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid"),
+    );
+    Expr::MethodCall(e.call)
+}
+
+/// trait that is used to optimistically copy a value.
+///
+/// Only values of types that implement `Copy` can be copied.
+/// Otherwise, the optimistic assumption fails.
+trait MayCopy: Sized {
+    fn may_copy(&self) -> Self;
+}
+
+impl<T> MayCopy for T {
+    default fn may_copy(&self) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Copy> MayCopy for T {
+    fn may_copy(&self) -> Self {
+        *self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprCopiedCloned {
+    call: ExprMethodCall,
+    copied: bool,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprCopiedCloned {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (call.method == "copied" || call.method == "cloned")
+                    && call.args.is_empty()
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprCopiedCloned {
+                    copied: call.method == "copied",
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn copied_inactive() {
+        assert_eq!(
+            run_copied(1, &5, &MutagenRuntimeConfig::without_mutation()),
+            5
+        );
+    }
+    #[test]
+    fn copied_active() {
+        assert_eq!(
+            run_copied(1, &5, &MutagenRuntimeConfig::with_mutation_id(1)),
+            5
+        );
+    }
+    #[test]
+    fn cloned_active() {
+        assert_eq!(
+            run_cloned(1, &5, &MutagenRuntimeConfig::with_mutation_id(1)),
+            5
+        );
+    }
+    #[test]
+    #[should_panic]
+    fn cloned_active_not_copy() {
+        run_cloned(
+            1,
+            &"a".to_owned(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+
+    #[test]
+    fn detect_copied() {
+        let expr: Expr = parse_quote! {v.iter().copied()};
+        assert!(ExprCopiedCloned::try_from(expr).unwrap().copied);
+    }
+    #[test]
+    fn detect_cloned() {
+        let expr: Expr = parse_quote! {opt.cloned()};
+        assert!(!ExprCopiedCloned::try_from(expr).unwrap().copied);
+    }
+    #[test]
+    fn ignore_clone() {
+        let expr: Expr = parse_quote! {v.clone()};
+        assert!(ExprCopiedCloned::try_from(expr).is_err());
+    }
+}
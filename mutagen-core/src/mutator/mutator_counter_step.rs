@@ -0,0 +1,213 @@
+//! Mutator for counter increments in loop bodies, like `count += 1` or `remaining -= 1`.
+//!
+//! The step of the counter is changed to 2 or 0, and the direction of the counter is flipped.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprAssignOp, Lit, LitInt};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Returns the index of the active mutation, if any.
+///
+/// The indices are the positions of the mutations in `MutationCounterStep::ALL`.
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> Option<usize> {
+    runtime.covered(mutator_id);
+    let mutations = (0..MutationCounterStep::ALL.len()).collect::<Vec<_>>();
+    runtime
+        .get_mutation_for_mutator(mutator_id, &mutations)
+        .copied()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // only counters in the body of a loop are mutated
+    if !context.in_loop_body {
+        return e;
+    }
+
+    let e = match ExprCounterStep::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the step is checked in the original code, since it may have been mutated by `lit_int`
+    let step = match context.original_expr.clone().map(ExprCounterStep::try_from) {
+        Some(Ok(original)) => match unit_step(&original.expr.right) {
+            Some(step) => step,
+            None => return Expr::AssignOp(e.expr),
+        },
+        _ => return Expr::AssignOp(e.expr),
+    };
+
+    let original_op = if e.increment { "+=" } else { "-=" };
+    let mutator_id = transform_info.add_mutations(MutationCounterStep::ALL.iter().map(|m| {
+        Mutation::new_spanned(
+            context,
+            "counter_step".to_owned(),
+            format!("{} 1", original_op),
+            m.describe(e.increment),
+            e.span,
+        )
+    }));
+
+    let span = e.span;
+    let left = &e.expr.left;
+    let original = &e.expr;
+    let arms = MutationCounterStep::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            let mutated = m.mutated_code(left, &step, e.increment, span);
+            quote_spanned! {span=> ::core::option::Option::Some(#i) => #mutated,}
+        })
+        .collect::<TokenStream>();
+    syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_counter_step::run(
+            #mutator_id,
+            ::mutagen::MutagenRuntimeConfig::get_default()
+        ) {
+            #arms
+            _ => #original,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// returns the literal if the expression is the integer literal `1`, with any suffix
+fn unit_step(e: &Expr) -> Option<LitInt> {
+    match e {
+        Expr::Lit(expr) => match &expr.lit {
+            Lit::Int(lit) if lit.base10_digits() == "1" => Some(lit.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationCounterStep {
+    Two,
+    Zero,
+    FlipDirection,
+}
+
+impl MutationCounterStep {
+    const ALL: [MutationCounterStep; 3] = [
+        MutationCounterStep::Two,
+        MutationCounterStep::Zero,
+        MutationCounterStep::FlipDirection,
+    ];
+
+    fn describe(self, increment: bool) -> String {
+        let op = if increment { "+=" } else { "-=" };
+        let flipped_op = if increment { "-=" } else { "+=" };
+        match self {
+            MutationCounterStep::Two => format!("{} 2", op),
+            MutationCounterStep::Zero => format!("{} 0", op),
+            MutationCounterStep::FlipDirection => format!("{} 1", flipped_op),
+        }
+    }
+
+    /// the mutated assignment, the suffix of the original step is kept
+    fn mutated_code(self, left: &Expr, step: &LitInt, increment: bool, span: Span) -> TokenStream {
+        let lit = |value: &str| LitInt::new(&format!("{}{}", value, step.suffix()), span);
+        let (increment, step) = match self {
+            MutationCounterStep::Two => (increment, lit("2")),
+            MutationCounterStep::Zero => (increment, lit("0")),
+            MutationCounterStep::FlipDirection => (!increment, lit("1")),
+        };
+        if increment {
+            quote_spanned! {span=> #left += #step}
+        } else {
+            quote_spanned! {span=> #left -= #step}
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprCounterStep {
+    expr: ExprAssignOp,
+    increment: bool,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprCounterStep {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::AssignOp(expr) => {
+                let increment = match expr.op {
+                    BinOp::AddEq(_) => true,
+                    BinOp::SubEq(_) => false,
+                    _ => return Err(Expr::AssignOp(expr)),
+                };
+                Ok(ExprCounterStep {
+                    span: expr.op.span(),
+                    expr,
+                    increment,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn counter_step_inactive() {
+        assert_eq!(run(1, &MutagenRuntimeConfig::without_mutation()), None);
+    }
+    #[test]
+    fn counter_step_active1() {
+        assert_eq!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)), Some(0));
+    }
+    #[test]
+    fn counter_step_active3() {
+        assert_eq!(run(1, &MutagenRuntimeConfig::with_mutation_id(3)), Some(2));
+    }
+
+    #[test]
+    fn unit_step_with_suffix() {
+        let step = unit_step(&parse_quote! {1u8}).unwrap();
+        assert_eq!(step.suffix(), "u8");
+    }
+    #[test]
+    fn unit_step_other_literal() {
+        assert!(unit_step(&parse_quote! {2}).is_none());
+        assert!(unit_step(&parse_quote! {n}).is_none());
+    }
+
+    #[test]
+    fn describe_decrement() {
+        assert_eq!(MutationCounterStep::Two.describe(false), "-= 2");
+        assert_eq!(MutationCounterStep::FlipDirection.describe(false), "+= 1");
+    }
+
+    #[test]
+    fn detect_increment() {
+        let expr: Expr = parse_quote! {count += 1};
+        assert!(ExprCounterStep::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_multiplication() {
+        let expr: Expr = parse_quote! {count *= 1};
+        assert!(ExprCounterStep::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+//! Mutator for assertions, like `debug_assert!(x < max);` or `assert_eq!(a, b);`.
+//!
+//! Debug assertions are promoted to assertions that are checked in every build, and assertions are demoted to debug assertions.
+//! Both mutations only change the behavior of builds without debug assertions, like release builds.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, Ident, Item, Macro, Stmt};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the debug assertions and the corresponding assertions that are always checked
+const ASSERTIONS: &[(&str, &str)] = &[
+    ("debug_assert", "assert"),
+    ("debug_assert_eq", "assert_eq"),
+    ("debug_assert_ne", "assert_ne"),
+];
+
+/// returns `true` if the toggled assertion is checked instead of the original one
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+pub fn transform(
+    s: Stmt,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Stmt {
+    let s = match StmtAssertion::try_from(s) {
+        Ok(s) => s,
+        Err(s) => return s,
+    };
+
+    let name = &s.mac.path.segments.last().unwrap().ident;
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "debug_assert".to_owned(),
+        format!("{}!", name),
+        format!("{}!", s.toggled),
+        s.span,
+    ));
+
+    let original = &s.mac;
+    let mut mutated = s.mac.clone();
+    mutated.path.segments.last_mut().unwrap().ident = Ident::new(s.toggled, s.span);
+    let toggled: Expr = syn::parse2(quote_spanned! {s.span=>
+        if ::mutagen::mutator::mutator_debug_assert::run(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+        {
+            #mutated;
+        } else {
+            #original;
+        }
+    })
+    .expect("transformed code invalid");
+    Stmt::Expr(toggled)
+}
+
+/// the assertion that replaces the given one, if it is an assertion
+fn toggled_assertion(name: &str) -> Option<&'static str> {
+    ASSERTIONS.iter().find_map(|&(debug, always)| {
+        if name == debug {
+            Some(always)
+        } else if name == always {
+            Some(debug)
+        } else {
+            None
+        }
+    })
+}
+
+#[derive(Clone, Debug)]
+struct StmtAssertion {
+    mac: Macro,
+    toggled: &'static str,
+    span: Span,
+}
+
+impl TryFrom<Stmt> for StmtAssertion {
+    type Error = Stmt;
+    fn try_from(stmt: Stmt) -> Result<Self, Stmt> {
+        // macros in statement position are parsed as items
+        let mac = match &stmt {
+            Stmt::Item(Item::Macro(item)) if item.ident.is_none() => item.mac.clone(),
+            Stmt::Semi(Expr::Macro(expr), _) => expr.mac.clone(),
+            _ => return Err(stmt),
+        };
+        let toggled = mac
+            .path
+            .segments
+            .last()
+            .and_then(|segment| toggled_assertion(&segment.ident.to_string()));
+        match toggled {
+            Some(toggled) => Ok(StmtAssertion {
+                span: mac.path.segments.last().unwrap().ident.span(),
+                mac,
+                toggled,
+            }),
+            None => Err(stmt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn debug_assert_inactive() {
+        assert!(!run(1, &MutagenRuntimeConfig::without_mutation()));
+    }
+    #[test]
+    fn debug_assert_active() {
+        assert!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)));
+    }
+
+    #[test]
+    fn toggled_debug_assert_eq() {
+        assert_eq!(toggled_assertion("debug_assert_eq"), Some("assert_eq"));
+    }
+    #[test]
+    fn toggled_assert() {
+        assert_eq!(toggled_assertion("assert"), Some("debug_assert"));
+    }
+    #[test]
+    fn toggled_other_macro() {
+        assert_eq!(toggled_assertion("println"), None);
+    }
+
+    #[test]
+    fn detect_debug_assert() {
+        let stmt: Stmt = parse_quote! {debug_assert!(x < 10);};
+        assert!(StmtAssertion::try_from(stmt).is_ok());
+    }
+    #[test]
+    fn detect_assert_with_path() {
+        let stmt: Stmt = parse_quote! {std::assert_ne!(x, 0);};
+        assert!(StmtAssertion::try_from(stmt).is_ok());
+    }
+    #[test]
+    fn ignore_other_macro() {
+        let stmt: Stmt = parse_quote! {println!("{}", x);};
+        assert!(StmtAssertion::try_from(stmt).is_err());
+    }
+}
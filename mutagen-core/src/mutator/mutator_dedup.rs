@@ -0,0 +1,125 @@
+//! Mutator for statements removing consecutive duplicates, like `v.dedup();` or `v.dedup_by_key(|x| x.id);`.
+//!
+//! The mutation skips the call, such that the duplicates are kept.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, Stmt};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns whether the call should be made, which is not the case if the mutation is active
+pub fn should_run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    !runtime.is_mutation_active(mutator_id)
+}
+
+pub fn transform(
+    s: Stmt,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Stmt {
+    let s = match StmtDedup::try_from(s) {
+        Ok(s) => s,
+        Err(s) => return s,
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original_call = match context.original_stmt.clone().map(StmtDedup::try_from) {
+        Some(Ok(original)) => original.call,
+        _ => s.call.clone(),
+    };
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "dedup".to_owned(),
+        format!("{};", original_call.to_token_stream()),
+        "".to_owned(),
+        s.span,
+    ));
+
+    let call = &s.call;
+    let skippable: Expr = syn::parse2(quote_spanned! {s.span=>
+        if ::mutagen::mutator::mutator_dedup::should_run(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+        {
+            #call;
+        }
+    })
+    .expect("transformed code invalid");
+    Stmt::Expr(skippable)
+}
+
+#[derive(Clone, Debug)]
+struct StmtDedup {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Stmt> for StmtDedup {
+    type Error = Stmt;
+    fn try_from(stmt: Stmt) -> Result<Self, Stmt> {
+        match stmt {
+            Stmt::Semi(Expr::MethodCall(call), _)
+                if (call.method == "dedup" && call.args.is_empty()
+                    || (call.method == "dedup_by" || call.method == "dedup_by_key")
+                        && call.args.len() == 1)
+                    && call.turbofish.is_none() =>
+            {
+                Ok(StmtDedup {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(stmt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn dedup_inactive() {
+        let result = should_run(1, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn dedup_active() {
+        let result = should_run(1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+
+    #[test]
+    fn detect_dedup() {
+        let stmt: Stmt = parse_quote! {v.dedup();};
+        assert!(StmtDedup::try_from(stmt).is_ok());
+    }
+    #[test]
+    fn detect_dedup_by_key() {
+        let stmt: Stmt = parse_quote! {v.dedup_by_key(|x| x.id);};
+        assert!(StmtDedup::try_from(stmt).is_ok());
+    }
+    #[test]
+    fn ignore_dedup_with_args() {
+        let stmt: Stmt = parse_quote! {v.dedup(1);};
+        assert!(StmtDedup::try_from(stmt).is_err());
+    }
+    #[test]
+    fn ignore_dedup_as_expression() {
+        let stmt: Stmt = parse_quote! {let x = v.dedup();};
+        assert!(StmtDedup::try_from(stmt).is_err());
+    }
+}
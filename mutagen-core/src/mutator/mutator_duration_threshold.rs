@@ -0,0 +1,275 @@
+//! Mutator for thresholds of comparisons with durations, like `elapsed > Duration::from_secs(5)`.
+//!
+//! The threshold is doubled, halved or replaced with zero.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+use std::time::Duration;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::ast_inspect::path_ends_with;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the constructors of `Duration` that are recognized as thresholds
+const DURATION_CONSTRUCTORS: &[&str] = &[
+    "new",
+    "from_secs",
+    "from_millis",
+    "from_micros",
+    "from_nanos",
+    "from_secs_f32",
+    "from_secs_f64",
+];
+
+/// returns the threshold, perturbed if one of the mutations is active
+pub fn run<T>(
+    mutator_id: usize,
+    threshold: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    match runtime.get_mutation_for_mutator(mutator_id, &MutationDurationThreshold::ALL) {
+        Some(&m) => threshold.may_perturb(m),
+        None => threshold,
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the threshold is detected in the original code, since its arguments may have been transformed by other mutators
+    let original = match context
+        .original_expr
+        .clone()
+        .map(ExprDurationThreshold::try_from)
+    {
+        Some(Ok(original)) => original,
+        _ => return e,
+    };
+    let mut e = match e {
+        Expr::Binary(e) => e,
+        e => return e,
+    };
+
+    let original_threshold = original.threshold().to_token_stream().to_string();
+    let mutator_id = transform_info.add_mutations(MutationDurationThreshold::ALL.iter().map(|m| {
+        // a `Duration` of another crate is no weakness of the tests
+        Mutation::new_spanned(
+            context,
+            "duration_threshold".to_owned(),
+            original_threshold.clone(),
+            m.describe(&original_threshold),
+            original.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip)
+    }));
+
+    // only the threshold is replaced, such that the comparison can still be mutated by `binop_cmp`
+    let span = original.span;
+    let threshold = if original.threshold_left {
+        &mut e.left
+    } else {
+        &mut e.right
+    };
+    let value = &**threshold;
+    let mutated = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_duration_threshold::run(
+                #mutator_id,
+                #value,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    **threshold = mutated;
+    Expr::Binary(e)
+}
+
+/// checks if the expression constructs a `Duration`, like `Duration::from_secs(5)`
+fn is_duration_construction(e: &Expr) -> bool {
+    match e {
+        Expr::Call(call) => DURATION_CONSTRUCTORS
+            .iter()
+            .any(|c| path_ends_with(&call.func, &["Duration", c])),
+        Expr::Paren(paren) => is_duration_construction(&paren.expr),
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationDurationThreshold {
+    Double,
+    Halve,
+    Zero,
+}
+
+impl MutationDurationThreshold {
+    const ALL: [MutationDurationThreshold; 3] = [
+        MutationDurationThreshold::Double,
+        MutationDurationThreshold::Halve,
+        MutationDurationThreshold::Zero,
+    ];
+
+    fn describe(self, threshold: &str) -> String {
+        match self {
+            MutationDurationThreshold::Double => format!("({}) * 2", threshold),
+            MutationDurationThreshold::Halve => format!("({}) / 2", threshold),
+            MutationDurationThreshold::Zero => "Duration::from_secs(0)".to_owned(),
+        }
+    }
+
+    fn perturb(self, threshold: Duration) -> Duration {
+        match self {
+            MutationDurationThreshold::Double => threshold * 2,
+            MutationDurationThreshold::Halve => threshold / 2,
+            MutationDurationThreshold::Zero => Duration::from_secs(0),
+        }
+    }
+}
+
+/// trait that is used to optimistically perturb a threshold.
+///
+/// Only thresholds of type `std::time::Duration` can be perturbed.
+/// Otherwise, the optimistic assumption fails.
+trait MayPerturb {
+    fn may_perturb(self, m: MutationDurationThreshold) -> Self;
+}
+
+impl<T> MayPerturb for T {
+    default fn may_perturb(self, _m: MutationDurationThreshold) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl MayPerturb for Duration {
+    fn may_perturb(self, m: MutationDurationThreshold) -> Self {
+        m.perturb(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprDurationThreshold {
+    expr: ExprBinary,
+    threshold_left: bool,
+    span: Span,
+}
+
+impl ExprDurationThreshold {
+    fn threshold(&self) -> &Expr {
+        if self.threshold_left {
+            &self.expr.left
+        } else {
+            &self.expr.right
+        }
+    }
+}
+
+impl TryFrom<Expr> for ExprDurationThreshold {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Binary(expr)
+                if matches!(
+                    expr.op,
+                    BinOp::Lt(_) | BinOp::Le(_) | BinOp::Ge(_) | BinOp::Gt(_)
+                ) =>
+            {
+                let threshold_left = if is_duration_construction(&expr.right) {
+                    false
+                } else if is_duration_construction(&expr.left) {
+                    true
+                } else {
+                    return Err(Expr::Binary(expr));
+                };
+                Ok(ExprDurationThreshold {
+                    span: expr.op.span(),
+                    expr,
+                    threshold_left,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn duration_threshold_inactive() {
+        let result = run(
+            1,
+            Duration::from_secs(4),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, Duration::from_secs(4));
+    }
+    #[test]
+    fn duration_threshold_active1() {
+        let result = run(
+            1,
+            Duration::from_secs(4),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, Duration::from_secs(8));
+    }
+    #[test]
+    fn duration_threshold_active2() {
+        let result = run(
+            1,
+            Duration::from_secs(4),
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, Duration::from_secs(2));
+    }
+    #[test]
+    fn duration_threshold_active3() {
+        let result = run(
+            1,
+            Duration::from_secs(4),
+            &MutagenRuntimeConfig::with_mutation_id(3),
+        );
+        assert_eq!(result, Duration::from_secs(0));
+    }
+    #[test]
+    #[should_panic]
+    fn duration_threshold_other_type() {
+        run(1, 4u64, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_threshold_right() {
+        let expr: Expr = parse_quote! {elapsed > Duration::from_secs(5)};
+        let threshold = ExprDurationThreshold::try_from(expr).unwrap();
+        assert!(!threshold.threshold_left);
+    }
+    #[test]
+    fn detect_threshold_left_with_path() {
+        let expr: Expr = parse_quote! {std::time::Duration::from_millis(100) <= start.elapsed()};
+        let threshold = ExprDurationThreshold::try_from(expr).unwrap();
+        assert!(threshold.threshold_left);
+    }
+    #[test]
+    fn ignore_comparison_without_duration() {
+        let expr: Expr = parse_quote! {elapsed > timeout};
+        assert!(ExprDurationThreshold::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_equality() {
+        let expr: Expr = parse_quote! {elapsed == Duration::from_secs(5)};
+        assert!(ExprDurationThreshold::try_from(expr).is_err());
+    }
+}
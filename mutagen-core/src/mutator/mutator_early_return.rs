@@ -0,0 +1,170 @@
+//! Mutator for guards that return an error early, like `if x > max { return Err(e); }`.
+//!
+//! The guard is removed, such that execution continues with the following code even if the condition holds.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprIf, Stmt};
+
+use crate::comm::Mutation;
+use crate::transformer::ast_inspect;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the condition of the guard, which is `false` if the mutation is active
+pub fn run(
+    mutator_id: usize,
+    condition: bool,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        false
+    } else {
+        condition
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprEarlyReturn::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the guard is described as written in the original code, before other mutators have been applied to it
+    let original_code = match context.original_expr.clone().map(ExprEarlyReturn::try_from) {
+        Some(Ok(original)) => original.expr_if.to_token_stream(),
+        _ => e.expr_if.to_token_stream(),
+    }
+    .to_string()
+    .replace("\n", " ");
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "early_return".to_owned(),
+        original_code,
+        "".to_owned(),
+        e.span,
+    ));
+
+    // the condition is still evaluated, parentheses around it are not needed as argument of `run`
+    let condition = match &*e.expr_if.cond {
+        Expr::Paren(paren) => &paren.expr,
+        cond => cond,
+    };
+    e.expr_if.cond = syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_early_return::run(
+                #mutator_id,
+                #condition,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    Expr::If(e.expr_if)
+}
+
+/// checks if the expression is `return Err(..)` or `return None`
+fn is_error_return(e: &Expr) -> bool {
+    let value = match e {
+        Expr::Return(ret) => match &ret.expr {
+            Some(value) => value,
+            None => return false,
+        },
+        _ => return false,
+    };
+    match &**value {
+        Expr::Call(call) => {
+            call.args.len() == 1 && ast_inspect::path_ends_with(&call.func, &["Err"])
+        }
+        value => ast_inspect::path_ends_with(value, &["None"]),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprEarlyReturn {
+    expr_if: ExprIf,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprEarlyReturn {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            // the condition of `if let` binds variables and cannot be replaced
+            Expr::If(expr_if)
+                if expr_if.else_branch.is_none()
+                    && !matches!(&*expr_if.cond, Expr::Let(_))
+                    && matches!(
+                        &*expr_if.then_branch.stmts,
+                        [Stmt::Semi(ret, _)] | [Stmt::Expr(ret)] if is_error_return(ret)
+                    ) =>
+            {
+                Ok(ExprEarlyReturn {
+                    span: expr_if.cond.span(),
+                    expr_if,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn early_return_inactive() {
+        let result = run(1, true, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn early_return_active() {
+        let result = run(1, true, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+
+    #[test]
+    fn detect_return_err() {
+        let expr: Expr = parse_quote! {if x > 10 { return Err(Error::TooLarge); }};
+        assert!(ExprEarlyReturn::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_return_none() {
+        let expr: Expr = parse_quote! {if v.is_empty() { return None }};
+        assert!(ExprEarlyReturn::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_return_ok() {
+        let expr: Expr = parse_quote! {if x > 10 { return Ok(x); }};
+        assert!(ExprEarlyReturn::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_if_else() {
+        let expr: Expr = parse_quote! {if x > 10 { return Err(e); } else { y += 1; }};
+        assert!(ExprEarlyReturn::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_if_let() {
+        let expr: Expr = parse_quote! {if let Some(e) = error { return Err(e); }};
+        assert!(ExprEarlyReturn::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_further_statements() {
+        let expr: Expr = parse_quote! {if x > 10 { log(x); return Err(e); }};
+        assert!(ExprEarlyReturn::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+//! Mutator for statements adding elements to a collection, like `v.extend(iter);` or `v.append(&mut other);`.
+//!
+//! The mutation skips the call. The argument is still evaluated, such that only the added elements are missing.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, Stmt};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns whether the call should be made, which is not the case if the mutation is active
+pub fn should_run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    !runtime.is_mutation_active(mutator_id)
+}
+
+pub fn transform(
+    s: Stmt,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Stmt {
+    let s = match StmtExtend::try_from(s) {
+        Ok(s) => s,
+        Err(s) => return s,
+    };
+
+    // the argument is described as written in the original code, before other mutators have been applied to it
+    let original_call = match context.original_stmt.clone().map(StmtExtend::try_from) {
+        Some(Ok(original)) => original.call,
+        _ => s.call.clone(),
+    };
+    let original_arg = original_call.args[0].to_token_stream().to_string();
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "extend".to_owned(),
+        format!("{};", original_call.to_token_stream()),
+        format!("{};", original_arg),
+        s.span,
+    ));
+
+    // the argument is bound first, such that its type can be inferred from the call.
+    // If the call is skipped, the argument is dropped at the end of the block.
+    let receiver = &s.call.receiver;
+    let method = &s.call.method;
+    let arg = &s.call.args[0];
+    let block: Expr = syn::parse2(quote_spanned! {s.span=>
+        {
+            let __mutagen_arg = #arg;
+            if ::mutagen::mutator::mutator_extend::should_run(
+                    #mutator_id,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+            {
+                #receiver.#method(__mutagen_arg);
+            }
+        }
+    })
+    .expect("transformed code invalid");
+    Stmt::Expr(block)
+}
+
+#[derive(Clone, Debug)]
+struct StmtExtend {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Stmt> for StmtExtend {
+    type Error = Stmt;
+    fn try_from(stmt: Stmt) -> Result<Self, Stmt> {
+        match stmt {
+            Stmt::Semi(Expr::MethodCall(call), _)
+                if (call.method == "extend" || call.method == "append")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                Ok(StmtExtend {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(stmt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn extend_inactive() {
+        let result = should_run(1, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn extend_active() {
+        let result = should_run(1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+
+    #[test]
+    fn detect_append() {
+        let stmt: Stmt = parse_quote! {v.append(&mut other);};
+        assert!(StmtExtend::try_from(stmt).is_ok());
+    }
+    #[test]
+    fn ignore_extend_as_expression() {
+        let stmt: Stmt = parse_quote! {let x = v.extend(iter);};
+        assert!(StmtExtend::try_from(stmt).is_err());
+    }
+}
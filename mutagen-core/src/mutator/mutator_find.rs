@@ -0,0 +1,252 @@
+//! Mutator for the predicates of searching iterator methods, like `iter.find(|x| ...)` or `iter.any(|x| ...)`.
+//!
+//! The mutation inverts the predicate, such that the search matches the elements that were not matched before.
+//! The predicates of `find` and `position` are also replaced with `true` and `false`, and the closure of `find_map` with `None`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprClosure, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::ast_inspect::contains_return;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the methods whose predicate is inverted
+const SEARCH_METHODS: &[&str] = &["find", "position", "any", "all"];
+
+/// the methods whose predicate is also replaced with a constant
+const FORCED_METHODS: &[&str] = &["find", "position"];
+
+/// returns the result of the predicate, inverted or replaced with a constant if one of the mutations is active
+pub fn run(
+    mutator_id: usize,
+    matches: bool,
+    num_mutations: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    let mutations = &MutationFind::ALL[..num_mutations];
+    match runtime.get_mutation_for_mutator(mutator_id, mutations) {
+        Some(m) => m.mutate(matches),
+        None => matches,
+    }
+}
+
+/// returns the result of the closure of `find_map`, which is replaced with `None` if the mutation is active
+pub fn run_find_map<B>(
+    mutator_id: usize,
+    result: Option<B>,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<B> {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        None
+    } else {
+        result
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprFind::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the predicate is described as written in the original code, before other mutators have been applied to it
+    let original_predicate = match context.original_expr.clone().map(ExprFind::try_from) {
+        Some(Ok(original)) => original.closure,
+        _ => e.closure.clone(),
+    };
+    let method = &e.call.method;
+    let inputs = original_predicate.inputs.to_token_stream();
+    let body = original_predicate.body.to_token_stream();
+    let original_code = format!("{}(|{}| {})", method, inputs, body);
+    let span = e.span;
+
+    if method == "find_map" {
+        let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+            context,
+            "find".to_owned(),
+            original_code,
+            format!("{}(|{}| None)", method, inputs),
+            span,
+        ));
+        let body = &e.closure.body;
+        *e.closure.body = syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_find::run_find_map(
+                    #mutator_id,
+                    #body,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid");
+        e.call.args[0] = Expr::Closure(e.closure);
+        return Expr::MethodCall(e.call);
+    }
+
+    let num_mutations = if FORCED_METHODS.iter().any(|m| method == m) {
+        MutationFind::ALL.len()
+    } else {
+        1
+    };
+    let mutator_id =
+        transform_info.add_mutations(MutationFind::ALL[..num_mutations].iter().map(|m| {
+            Mutation::new_spanned(
+                context,
+                "find".to_owned(),
+                original_code.clone(),
+                format!("{}(|{}| {})", method, inputs, m.describe(&body.to_string())),
+                span,
+            )
+        }));
+
+    let body = &e.closure.body;
+    *e.closure.body = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_find::run(
+                #mutator_id,
+                #body,
+                #num_mutations,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    e.call.args[0] = Expr::Closure(e.closure);
+    Expr::MethodCall(e.call)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationFind {
+    Invert,
+    True,
+    False,
+}
+
+impl MutationFind {
+    /// all mutations, the constants are only used for `find` and `position`
+    const ALL: [MutationFind; 3] = [
+        MutationFind::Invert,
+        MutationFind::True,
+        MutationFind::False,
+    ];
+
+    fn mutate(self, matches: bool) -> bool {
+        match self {
+            MutationFind::Invert => !matches,
+            MutationFind::True => true,
+            MutationFind::False => false,
+        }
+    }
+
+    fn describe(self, body: &str) -> String {
+        match self {
+            MutationFind::Invert => format!("!({})", body),
+            MutationFind::True => "true".to_owned(),
+            MutationFind::False => "false".to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprFind {
+    call: ExprMethodCall,
+    closure: ExprClosure,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFind {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (SEARCH_METHODS.iter().any(|m| call.method == m)
+                    || call.method == "find_map")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                let closure = match &call.args[0] {
+                    // a `return` in the body would skip the inversion of the result
+                    Expr::Closure(closure) if !contains_return(closure.body.to_token_stream()) => {
+                        closure.clone()
+                    }
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                Ok(ExprFind {
+                    span: call.method.span(),
+                    call,
+                    closure,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn find_inactive() {
+        let result = run(1, true, 3, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn find_active() {
+        let result = run(1, true, 3, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+    #[test]
+    fn find_active_true() {
+        let result = run(1, false, 3, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert!(result);
+    }
+    #[test]
+    fn find_active_false() {
+        let result = run(1, true, 3, &MutagenRuntimeConfig::with_mutation_id(3));
+        assert!(!result);
+    }
+    #[test]
+    fn any_without_constants() {
+        let result = run(1, true, 1, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert!(result);
+    }
+    #[test]
+    fn find_map_active() {
+        let result = run_find_map(1, Some(1), &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn detect_position() {
+        let expr: Expr = parse_quote! {v.iter().position(|x| *x == 0)};
+        assert!(ExprFind::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_find_map() {
+        let expr: Expr = parse_quote! {v.iter().find_map(|x| x.checked_sub(1))};
+        assert!(ExprFind::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_find_with_pattern() {
+        let expr: Expr = parse_quote! {s.find("needle")};
+        assert!(ExprFind::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_predicate_with_return() {
+        let expr: Expr = parse_quote! {v.iter().any(|x| { if *x > 3 { return true; } false })};
+        assert!(ExprFind::try_from(expr).is_err());
+    }
+}
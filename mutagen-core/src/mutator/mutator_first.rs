@@ -0,0 +1,109 @@
+//! Mutator for accessors of the first element, like `v.first()` or `v.first_mut()`.
+//!
+//! The accessor is replaced with `get(0)`, whose index is shifted like by the mutator `get_index`.
+//! This tests the assumption that the first element is the right one.
+
+use std::convert::TryFrom;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, ExprMethodCall, Ident};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use super::mutator_get_index::MutationGetIndex;
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprFirst::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let method = e.call.method.to_string();
+    let get = if method == "first" { "get" } else { "get_mut" };
+
+    let mutator_id =
+        transform_info.add_mutations(MutationGetIndex::possible_mutations().iter().map(|m| {
+            Mutation::new_spanned(
+                context,
+                "first".to_owned(),
+                format!("{}()", method),
+                m.describe(get, "0"),
+                e.span,
+            )
+        }));
+
+    // the index is perturbed by the runtime of `get_index`, since both mutators shift an index
+    let span = e.span;
+    e.call.method = Ident::new(get, span);
+    e.call.args.push(
+        syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_get_index::run(
+                    #mutator_id,
+                    0usize,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid"),
+    );
+    Expr::MethodCall(e.call)
+}
+
+#[derive(Clone, Debug)]
+struct ExprFirst {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFirst {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (call.method == "first" || call.method == "first_mut")
+                    && call.args.is_empty()
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprFirst {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn detect_first() {
+        let expr: Expr = parse_quote! {v.first()};
+        assert!(ExprFirst::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_first_mut() {
+        let expr: Expr = parse_quote! {v.first_mut()};
+        assert!(ExprFirst::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_first_with_args() {
+        let expr: Expr = parse_quote! {v.first(2)};
+        assert!(ExprFirst::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_last() {
+        let expr: Expr = parse_quote! {v.last()};
+        assert!(ExprFirst::try_from(expr).is_err());
+    }
+}
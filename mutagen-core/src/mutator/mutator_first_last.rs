@@ -0,0 +1,166 @@
+//! Mutator for accessors of the ends of a collection, like `v.first()` or `deque.back()`.
+//!
+//! The accessor is swapped with the accessor of the other end, like `first()` with `last()` and `front()` with `back()`.
+//! This tests that the selected end of the collection is checked.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, ExprMethodCall, Ident};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the pairs of accessors of opposite ends, which are swapped with each other
+const SWAPPED_ACCESSORS: &[(&str, &str)] = &[
+    ("first", "last"),
+    ("first_mut", "last_mut"),
+    ("front", "back"),
+    ("front_mut", "back_mut"),
+];
+
+/// returns `true` if the accessor is swapped with the accessor of the other end
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the accessor is detected in the original code, since it may have been replaced by the mutator `first`
+    let original = match context.original_expr.clone().map(ExprFirstLast::try_from) {
+        Some(Ok(original)) => original,
+        _ => return e,
+    };
+    let call = match e {
+        Expr::MethodCall(call) => call,
+        e => return e,
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "first_last".to_owned(),
+        format!("{}()", original.call.method),
+        format!("{}()", original.swapped),
+        original.span,
+    ));
+
+    // the receiver is taken from the transformed call, such that its mutations are kept
+    let span = original.span;
+    let mut swapped = call.clone();
+    swapped.method = Ident::new(original.swapped, span);
+    swapped.args.clear();
+    syn::parse2(quote_spanned! {span=>
+        if ::mutagen::mutator::mutator_first_last::run(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+        {
+            #swapped
+        } else {
+            #call
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// returns the accessor of the opposite end, if the method is an accessor of an end
+fn swapped_accessor(method: &Ident) -> Option<&'static str> {
+    SWAPPED_ACCESSORS.iter().find_map(|&(a, b)| {
+        if method == a {
+            Some(b)
+        } else if method == b {
+            Some(a)
+        } else {
+            None
+        }
+    })
+}
+
+#[derive(Clone, Debug)]
+struct ExprFirstLast {
+    call: ExprMethodCall,
+    swapped: &'static str,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFirstLast {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call) if call.args.is_empty() && call.turbofish.is_none() => {
+                let swapped = match swapped_accessor(&call.method) {
+                    Some(swapped) => swapped,
+                    None => return Err(Expr::MethodCall(call)),
+                };
+                // iterators have a method `last()`, but no method `first()`.
+                // Iterators are usually created by a method call, like `s.split(' ').last()`
+                if call.method == "last" && matches!(&*call.receiver, Expr::MethodCall(_)) {
+                    return Err(Expr::MethodCall(call));
+                }
+                Ok(ExprFirstLast {
+                    span: call.method.span(),
+                    call,
+                    swapped,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn first_last_inactive() {
+        assert!(!run(1, &MutagenRuntimeConfig::without_mutation()));
+    }
+    #[test]
+    fn first_last_active() {
+        assert!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)));
+    }
+
+    #[test]
+    fn swapped_accessor_both_directions() {
+        assert_eq!(
+            swapped_accessor(&parse_quote! {first_mut}),
+            Some("last_mut")
+        );
+        assert_eq!(swapped_accessor(&parse_quote! {back}), Some("front"));
+        assert_eq!(swapped_accessor(&parse_quote! {get}), None);
+    }
+
+    #[test]
+    fn detect_first() {
+        let expr: Expr = parse_quote! {v.first()};
+        let e = ExprFirstLast::try_from(expr).unwrap();
+        assert_eq!(e.swapped, "last");
+    }
+    #[test]
+    fn detect_last_of_variable() {
+        let expr: Expr = parse_quote! {v.last()};
+        assert!(ExprFirstLast::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_last_of_iterator() {
+        let expr: Expr = parse_quote! {s.split(' ').last()};
+        assert!(ExprFirstLast::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_front_with_args() {
+        let expr: Expr = parse_quote! {v.front(1)};
+        assert!(ExprFirstLast::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,478 @@
+//! Mutator for operations on flag sets, like `flags.insert(Flags::A)` or `flags.contains(Flags::B)`.
+//!
+//! The operations `insert`, `remove` and `toggle` are swapped with each other and `contains` is negated.
+//! The flag tested by `contains` or by a check like `flags & FLAG != 0` is replaced with its neighbors, i.e. the flag shifted by one bit.
+//! Swapping the operations only compiles for types like bitflags, where all operations take the same argument.
+//! Therefore, this mutator is only used if it is listed explicitly with `mutators = only(flag_ops)`.
+//! The mutated methods can be restricted with the setting `flag_methods` in the file `mutagen.json`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary, ExprLit, ExprMethodCall, ExprPath, Ident, Lit};
+
+use crate::comm::Mutation;
+use crate::transformer::crate_conf::CrateConf;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the operations that change a flag set and are swapped with each other
+const FLAG_OPERATIONS: &[&str] = &["insert", "remove", "toggle"];
+
+/// Returns the index of the operation used instead of the original one, if any.
+///
+/// The indices refer to the other operations in the order `insert`, `remove`, `toggle`.
+pub fn run(
+    mutator_id: usize,
+    num_mutations: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<usize> {
+    runtime.covered(mutator_id);
+    runtime
+        .get_mutation_for_mutator(mutator_id, &[0, 1][..num_mutations])
+        .copied()
+}
+
+/// returns the result of `contains`, which is negated if the mutation is active
+pub fn run_contains(
+    mutator_id: usize,
+    contains: bool,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        !contains
+    } else {
+        contains
+    }
+}
+
+/// Returns the direction of the neighbor that is tested instead of the original flag, if any.
+///
+/// The index `0` refers to the flag shifted to the left and `1` to the flag shifted to the right.
+pub fn run_neighbor(
+    mutator_id: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<usize> {
+    runtime.covered(mutator_id);
+    runtime
+        .get_mutation_for_mutator(mutator_id, &[0, 1])
+        .copied()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let enabled = enabled_methods(CrateConf::get().flag_methods());
+
+    // checks like `flags & FLAG != 0` are mutated like `contains`
+    let e = match ExprFlagTest::try_from(e) {
+        Ok(e) if enabled.contains(&"contains") => {
+            return transform_flag_test(e, transform_info, context)
+        }
+        Ok(e) => return Expr::Binary(e.expr),
+        Err(e) => e,
+    };
+    let e = match ExprFlagOp::try_from(e) {
+        Ok(e) if enabled.contains(&&*e.call.method.to_string()) => e,
+        Ok(e) => return Expr::MethodCall(e.call),
+        Err(e) => return e,
+    };
+
+    // the argument is described as written in the original code, before other mutators have been applied to it
+    let original_arg = match context.original_expr.clone().map(ExprFlagOp::try_from) {
+        Some(Ok(original)) => original.call.args[0].to_token_stream().to_string(),
+        _ => e.call.args[0].to_token_stream().to_string(),
+    };
+    let method = e.call.method.to_string();
+    let span = e.span;
+
+    if method == "contains" {
+        let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+            context,
+            "flag_ops".to_owned(),
+            format!("contains({})", original_arg),
+            format!("!contains({})", original_arg),
+            span,
+        ));
+
+        // the neighbors of a flag constant like `Flags::A` are created with the methods of bitflags on the type `Flags`
+        let mut call = e.call;
+        if let Some(flag_type) = flag_type(&call.args[0]) {
+            let neighbor_id = transform_info.add_mutations(["<<", ">>"].iter().map(|shift| {
+                Mutation::new_spanned(
+                    context,
+                    "flag_ops".to_owned(),
+                    format!("contains({})", original_arg),
+                    format!("contains({} {} 1)", original_arg, shift),
+                    span,
+                )
+            }));
+            let flag = &call.args[0];
+            let neighbor = |shift: TokenStream| {
+                quote_spanned! {span=> #flag_type::from_bits_truncate((#flag).bits() #shift 1)}
+            };
+            call.args[0] = neighbor_flag(
+                neighbor_id,
+                flag,
+                neighbor(quote_spanned! {span=> <<}),
+                neighbor(quote_spanned! {span=> >>}),
+                span,
+            );
+        }
+        return syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_flag_ops::run_contains(
+                    #mutator_id,
+                    #call,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid");
+    }
+
+    // only enabled operations are used instead, since the others may not exist on the type
+    let replacements = FLAG_OPERATIONS
+        .iter()
+        .copied()
+        .filter(|op| *op != method && enabled.contains(op))
+        .collect::<Vec<_>>();
+    if replacements.is_empty() {
+        return Expr::MethodCall(e.call);
+    }
+    let num_mutations = replacements.len();
+    let mutator_id = transform_info.add_mutations(replacements.iter().map(|op| {
+        Mutation::new_spanned(
+            context,
+            "flag_ops".to_owned(),
+            format!("{}({})", method, original_arg),
+            format!("{}({})", op, original_arg),
+            span,
+        )
+    }));
+
+    // each operation is called in its own arm, such that the receiver and argument are evaluated once
+    let call_with = |op: &str| -> TokenStream {
+        let mut call = e.call.clone();
+        call.method = Ident::new(op, span);
+        call.into_token_stream()
+    };
+    let first = call_with(replacements[0]);
+    let second = replacements.get(1).map(|op| {
+        let call = call_with(op);
+        quote_spanned! {span=> ::core::option::Option::Some(1) => #call, }
+    });
+    let call = &e.call;
+    syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_flag_ops::run(
+                #mutator_id,
+                #num_mutations,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+            #second
+            ::core::option::Option::Some(_) => #first,
+            ::core::option::Option::None => #call,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+fn transform_flag_test(
+    mut e: ExprFlagTest,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the flag is described as written in the original code, before other mutators have been applied to it
+    let (original_test, original_flag) =
+        match context.original_expr.clone().map(ExprFlagTest::try_from) {
+            Some(Ok(original)) => (original.expr, original.flag),
+            _ => (e.expr.clone(), e.flag.clone()),
+        };
+    let original_test = original_test.to_token_stream().to_string();
+    let original_flag = original_flag.to_token_stream().to_string();
+    let span = e.span;
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "flag_ops".to_owned(),
+        original_test.clone(),
+        format!("!({})", original_test),
+        span,
+    ));
+    let neighbor_id = transform_info.add_mutations(["<<", ">>"].iter().map(|shift| {
+        Mutation::new_spanned(
+            context,
+            "flag_ops".to_owned(),
+            format!("& {}", original_flag),
+            format!("& ({} {} 1)", original_flag, shift),
+            span,
+        )
+    }));
+
+    let flag = e.flag;
+    *tested_flag(&mut e.expr).unwrap() = neighbor_flag(
+        neighbor_id,
+        &flag,
+        quote_spanned! {span=> (#flag) << 1},
+        quote_spanned! {span=> (#flag) >> 1},
+        span,
+    );
+    let test = &e.expr;
+    syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_flag_ops::run_contains(
+                #mutator_id,
+                #test,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// generates the flag that is tested, which is replaced with one of the given neighbors if one of the mutations is active
+fn neighbor_flag(
+    mutator_id: usize,
+    flag: &Expr,
+    left: TokenStream,
+    right: TokenStream,
+    span: Span,
+) -> Expr {
+    syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_flag_ops::run_neighbor(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+            ::core::option::Option::Some(0) => #left,
+            ::core::option::Option::Some(_) => #right,
+            ::core::option::Option::None => #flag,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// checks if the expression is a path to a constant, like `FLAG` or `Flags::FLAG`
+fn is_flag_const(e: &Expr) -> bool {
+    match e {
+        Expr::Path(ExprPath {
+            qself: None, path, ..
+        }) => {
+            let name = path.segments.last().unwrap().ident.to_string();
+            name.chars().any(|c| c.is_ascii_uppercase())
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// returns the type of a flag constant like `Flags::FLAG`, which is the path without the name of the constant
+fn flag_type(e: &Expr) -> Option<TokenStream> {
+    match e {
+        Expr::Path(expr) if is_flag_const(e) && expr.path.segments.len() >= 2 => {
+            let leading_colon = &expr.path.leading_colon;
+            let segments = expr.path.segments.iter().take(expr.path.segments.len() - 1);
+            Some(quote! {#leading_colon #(#segments)::*})
+        }
+        _ => None,
+    }
+}
+
+fn is_zero(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_digits() == "0",
+        _ => false,
+    }
+}
+
+fn unparen_mut(e: &mut Expr) -> &mut Expr {
+    match e {
+        Expr::Paren(paren) => unparen_mut(&mut paren.expr),
+        e => e,
+    }
+}
+
+/// returns the flag that is tested in a check like `flags & FLAG != 0` or `0 == FLAG & flags`
+fn tested_flag(test: &mut ExprBinary) -> Option<&mut Expr> {
+    if !matches!(test.op, BinOp::Eq(_) | BinOp::Ne(_)) {
+        return None;
+    }
+    let masked = if is_zero(&test.right) {
+        &mut *test.left
+    } else if is_zero(&test.left) {
+        &mut *test.right
+    } else {
+        return None;
+    };
+    match unparen_mut(masked) {
+        Expr::Binary(masked) if matches!(masked.op, BinOp::BitAnd(_)) => {
+            if is_flag_const(&masked.right) {
+                Some(&mut *masked.right)
+            } else if is_flag_const(&masked.left) {
+                Some(&mut *masked.left)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// the methods mutated by this mutator, given by the crate configuration or all methods by default
+fn enabled_methods(configured: Option<&[String]>) -> Vec<&str> {
+    match configured {
+        Some(methods) => methods.iter().map(|m| &**m).collect(),
+        None => vec!["insert", "remove", "toggle", "contains"],
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprFlagOp {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFlagOp {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (FLAG_OPERATIONS.iter().any(|op| call.method == op)
+                    || call.method == "contains")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprFlagOp {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprFlagTest {
+    expr: ExprBinary,
+    flag: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFlagTest {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Binary(mut expr) => {
+                let flag = match tested_flag(&mut expr) {
+                    Some(flag) => flag.clone(),
+                    None => return Err(Expr::Binary(expr)),
+                };
+                Ok(ExprFlagTest {
+                    span: expr.op.span(),
+                    expr,
+                    flag,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn flag_ops_inactive() {
+        let result = run(1, 2, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, None);
+    }
+    #[test]
+    fn flag_ops_active1() {
+        let result = run(1, 2, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, Some(0));
+    }
+    #[test]
+    fn flag_ops_active2() {
+        let result = run(1, 2, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, Some(1));
+    }
+    #[test]
+    fn contains_active() {
+        let result = run_contains(1, true, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+
+    #[test]
+    fn neighbor_inactive() {
+        let result = run_neighbor(1, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, None);
+    }
+    #[test]
+    fn neighbor_active2() {
+        let result = run_neighbor(1, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn enabled_methods_default() {
+        assert_eq!(enabled_methods(None).len(), 4);
+    }
+    #[test]
+    fn enabled_methods_configured() {
+        let configured = vec!["toggle".to_owned()];
+        assert_eq!(enabled_methods(Some(&configured)), vec!["toggle"]);
+    }
+
+    #[test]
+    fn detect_toggle() {
+        let expr: Expr = parse_quote! {flags.toggle(Flags::A)};
+        assert!(ExprFlagOp::try_from(expr).is_ok());
+    }
+    #[test]
+    fn flag_type_of_const() {
+        let expr: Expr = parse_quote! {Flags::WRITE};
+        assert_eq!(flag_type(&expr).unwrap().to_string(), "Flags");
+    }
+    #[test]
+    fn flag_type_of_local() {
+        let expr: Expr = parse_quote! {flag};
+        assert!(flag_type(&expr).is_none());
+    }
+
+    #[test]
+    fn detect_flag_test() {
+        let expr: Expr = parse_quote! {(mode & S_IFDIR) != 0};
+        let flag: Expr = parse_quote! {S_IFDIR};
+        assert_eq!(ExprFlagTest::try_from(expr).unwrap().flag, flag);
+    }
+    #[test]
+    fn detect_flag_test_zero_left() {
+        let expr: Expr = parse_quote! {0 == self.mode & Mode::EXEC};
+        let flag: Expr = parse_quote! {Mode::EXEC};
+        assert_eq!(ExprFlagTest::try_from(expr).unwrap().flag, flag);
+    }
+    #[test]
+    fn ignore_flag_test_with_literal() {
+        let expr: Expr = parse_quote! {x & 1 != 0};
+        assert!(ExprFlagTest::try_from(expr).is_err());
+    }
+
+    #[test]
+    fn ignore_map_insert() {
+        let expr: Expr = parse_quote! {map.insert(k, v)};
+        assert!(ExprFlagOp::try_from(expr).is_err());
+    }
+}
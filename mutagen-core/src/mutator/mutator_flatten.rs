@@ -0,0 +1,244 @@
+//! Mutator for flattening nested iterators, like `lines.iter().flatten()` or `v.iter().flat_map(f)`.
+//!
+//! Only the first element of each inner iterator is kept, which has the same effect as mapping each element to `once`.
+//! This tests the handling of nested collections with more than one element.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns an iterator over the given inner collection, which yields only its first element if the mutation is active
+pub fn run<U: IntoIterator>(
+    mutator_id: usize,
+    inner: U,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> FlattenInner<U::IntoIter> {
+    runtime.covered(mutator_id);
+    FlattenInner {
+        inner: inner.into_iter(),
+        first_only: runtime.is_mutation_active(mutator_id),
+        exhausted: false,
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprFlatten::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original_call = match context.original_expr.clone().map(ExprFlatten::try_from) {
+        Some(Ok(original)) => original.call,
+        _ => e.call.clone(),
+    };
+    let original_code = format!(
+        "{}({})",
+        original_call.method,
+        original_call.args.to_token_stream()
+    );
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "flatten".to_owned(),
+        original_code.clone(),
+        format!(
+            "{} with only the first element of each inner iterator",
+            original_code
+        ),
+        e.span,
+    ));
+
+    // `flat_map(f)` is split into `map(f)` and flattening, such that the type of the closure is still inferred from the iterator
+    let span = e.span;
+    let receiver = &e.call.receiver;
+    let mapped = if e.call.method == "flat_map" {
+        let f = &e.call.args[0];
+        quote_spanned! {span=> #receiver.map(#f)}
+    } else {
+        quote_spanned! {span=> #receiver}
+    };
+    syn::parse2(quote_spanned! {span=>
+        #mapped.flat_map(|__mutagen_inner| ::mutagen::mutator::mutator_flatten::run(
+                #mutator_id,
+                __mutagen_inner,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ))
+    })
+    .expect("transformed code invalid")
+}
+
+/// iterator over an inner collection of a flattened iterator, which stops after the first element if the mutation is active.
+#[derive(Clone, Debug)]
+pub struct FlattenInner<I> {
+    inner: I,
+    first_only: bool,
+    exhausted: bool,
+}
+
+impl<I: Iterator> Iterator for FlattenInner<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.exhausted {
+            return None;
+        }
+        self.exhausted = self.first_only;
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        if self.exhausted {
+            (0, Some(0))
+        } else if self.first_only {
+            (lower.min(1), Some(upper.map_or(1, |upper| upper.min(1))))
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for FlattenInner<I> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        if self.first_only {
+            // the only element that is kept is the first one
+            self.next()
+        } else {
+            self.inner.next_back()
+        }
+    }
+}
+
+/// methods that create iterators.
+const ITERATOR_METHODS: &[&str] = &[
+    "iter",
+    "iter_mut",
+    "into_iter",
+    "keys",
+    "values",
+    "values_mut",
+    "into_values",
+    "windows",
+    "chunks",
+    "rev",
+    "skip",
+    "step_by",
+    "chain",
+    "peekable",
+    "skip_while",
+    "take_while",
+    "filter_map",
+    "flat_map",
+    "flatten",
+];
+
+/// methods that exist on iterators and on `Option`.
+const ITERATOR_ADAPTERS: &[&str] = &["map", "filter", "take", "inspect", "cloned", "copied"];
+
+/// checks if the receiver of `flatten` or `flat_map` is known to be an iterator.
+///
+/// `Option` has a method `flatten` as well. Adapters that also exist on `Option`, like `map`, are only accepted if their receiver is an iterator.
+fn is_iterator(e: &Expr) -> bool {
+    match e {
+        Expr::MethodCall(call) if ITERATOR_METHODS.iter().any(|m| call.method == m) => true,
+        Expr::MethodCall(call) if ITERATOR_ADAPTERS.iter().any(|m| call.method == m) => {
+            is_iterator(&call.receiver)
+        }
+        Expr::Range(_) => true,
+        Expr::Paren(paren) => is_iterator(&paren.expr),
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprFlatten {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFlatten {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if ((call.method == "flatten" && call.args.is_empty())
+                    || (call.method == "flat_map" && call.args.len() == 1))
+                    && call.turbofish.is_none()
+                    && is_iterator(&call.receiver) =>
+            {
+                Ok(ExprFlatten {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn flatten_inactive() {
+        let v = vec![vec![1, 2], vec![3]];
+        let flat: Vec<i32> = v
+            .into_iter()
+            .flat_map(|inner| run(1, inner, &MutagenRuntimeConfig::without_mutation()))
+            .collect();
+        assert_eq!(flat, vec![1, 2, 3]);
+    }
+    #[test]
+    fn flatten_active() {
+        let v = vec![vec![1, 2], vec![3]];
+        let flat: Vec<i32> = v
+            .into_iter()
+            .flat_map(|inner| run(1, inner, &MutagenRuntimeConfig::with_mutation_id(1)))
+            .collect();
+        assert_eq!(flat, vec![1, 3]);
+    }
+    #[test]
+    fn flatten_active_rev() {
+        let v = vec![vec![1, 2], vec![3, 4]];
+        let flat: Vec<i32> = v
+            .into_iter()
+            .flat_map(|inner| run(1, inner, &MutagenRuntimeConfig::with_mutation_id(1)))
+            .rev()
+            .collect();
+        assert_eq!(flat, vec![3, 1]);
+    }
+
+    #[test]
+    fn detect_flatten() {
+        let expr: Expr = parse_quote! {lines.iter().map(|l| l.split(',')).flatten()};
+        assert!(ExprFlatten::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_flat_map() {
+        let expr: Expr = parse_quote! {posts.iter().flat_map(|p| p.tags.iter())};
+        assert!(ExprFlatten::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_option_flatten() {
+        let expr: Expr = parse_quote! {opt.map(parse).flatten()};
+        assert!(ExprFlatten::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+//! Mutator for approximate comparisons of floats, like `(a - b).abs() < EPSILON`.
+//!
+//! The tolerance is scaled by a factor of ten in both directions and the comparison is negated.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Compares the difference with the tolerance, which are given in the order of the original code.
+///
+/// The comparison is `difference < tolerance`, or `difference <= tolerance` if `inclusive` is set.
+pub fn run<T: PartialOrd>(
+    mutator_id: usize,
+    left: T,
+    right: T,
+    inclusive: bool,
+    tolerance_left: bool,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    let (difference, tolerance) = if tolerance_left {
+        (right, left)
+    } else {
+        (left, right)
+    };
+    let mutations = MutationFloatEps::possible_mutations();
+    let m = runtime.get_mutation_for_mutator(mutator_id, &mutations);
+    let tolerance = match m {
+        Some(&m) if m != MutationFloatEps::Negate => tolerance.may_scale(m),
+        _ => tolerance,
+    };
+    let result = if inclusive {
+        difference <= tolerance
+    } else {
+        difference < tolerance
+    };
+    if m == Some(&MutationFloatEps::Negate) {
+        !result
+    } else {
+        result
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the idiom is detected in the original code, since the difference may have been transformed by other mutators
+    let original = match context.original_expr.clone().map(ExprFloatEps::try_from) {
+        Some(Ok(original)) => original,
+        _ => return e,
+    };
+    let e = match e {
+        Expr::Binary(e) => e,
+        e => return e,
+    };
+
+    let mutator_id = transform_info.add_mutations(
+        MutationFloatEps::possible_mutations()
+            .iter()
+            .map(|m| m.to_mutation(&original, context)),
+    );
+
+    let left = &e.left;
+    let right = &e.right;
+    let inclusive = original.inclusive;
+    let tolerance_left = original.tolerance_left;
+    syn::parse2(quote_spanned! {original.span=>
+        ::mutagen::mutator::mutator_float_eps::run(
+                #mutator_id,
+                #left,
+                #right,
+                #inclusive,
+                #tolerance_left,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// checks if the expression is the absolute value of a difference, like `(a - b).abs()`
+fn is_abs_difference(e: &Expr) -> bool {
+    match e {
+        Expr::MethodCall(call) if call.method == "abs" && call.args.is_empty() => {
+            is_difference(&call.receiver)
+        }
+        Expr::Paren(paren) => is_abs_difference(&paren.expr),
+        _ => false,
+    }
+}
+
+fn is_difference(e: &Expr) -> bool {
+    match e {
+        Expr::Binary(binary) => matches!(binary.op, BinOp::Sub(_)),
+        Expr::Paren(paren) => is_difference(&paren.expr),
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationFloatEps {
+    ScaleUp,
+    ScaleDown,
+    Negate,
+}
+
+impl MutationFloatEps {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationFloatEps::ScaleUp,
+            MutationFloatEps::ScaleDown,
+            MutationFloatEps::Negate,
+        ]
+    }
+
+    fn to_mutation(self, original: &ExprFloatEps, context: &TransformContext) -> Mutation {
+        let left = original.left.to_token_stream().to_string();
+        let right = original.right.to_token_stream().to_string();
+        let op = match (original.inclusive, original.tolerance_left) {
+            (false, false) => "<",
+            (true, false) => "<=",
+            (false, true) => ">",
+            (true, true) => ">=",
+        };
+        let original_code = format!("{} {} {}", left, op, right);
+        let scaled = |factor: &str| {
+            if original.tolerance_left {
+                format!("({}) {} {} {}", left, factor, op, right)
+            } else {
+                format!("{} {} ({}) {}", left, op, right, factor)
+            }
+        };
+        let mutated_code = match self {
+            MutationFloatEps::ScaleUp => scaled("* 10"),
+            MutationFloatEps::ScaleDown => scaled("/ 10"),
+            MutationFloatEps::Negate => format!("!({})", original_code),
+        };
+        Mutation::new_spanned(
+            context,
+            "float_eps".to_owned(),
+            original_code,
+            mutated_code,
+            original.span,
+        )
+    }
+}
+
+/// trait that is used to optimistically scale a tolerance.
+///
+/// The tolerance can only be scaled if it is a `f32` or `f64`, otherwise the optimistic assumption fails.
+trait MayScale {
+    fn may_scale(self, m: MutationFloatEps) -> Self;
+}
+
+impl<T> MayScale for T {
+    default fn may_scale(self, _m: MutationFloatEps) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl MayScale for f32 {
+    fn may_scale(self, m: MutationFloatEps) -> Self {
+        match m {
+            MutationFloatEps::ScaleDown => self / 10.0,
+            _ => self * 10.0,
+        }
+    }
+}
+
+impl MayScale for f64 {
+    fn may_scale(self, m: MutationFloatEps) -> Self {
+        match m {
+            MutationFloatEps::ScaleDown => self / 10.0,
+            _ => self * 10.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprFloatEps {
+    left: Expr,
+    right: Expr,
+    /// whether the comparison includes the tolerance, i.e. `<=` or `>=`
+    inclusive: bool,
+    /// whether the tolerance is on the left, like in `EPSILON > (a - b).abs()`
+    tolerance_left: bool,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprFloatEps {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        let binary = match expr {
+            Expr::Binary(binary) => binary,
+            _ => return Err(expr),
+        };
+        let (inclusive, tolerance_left) = match binary.op {
+            BinOp::Lt(_) if is_abs_difference(&binary.left) => (false, false),
+            BinOp::Le(_) if is_abs_difference(&binary.left) => (true, false),
+            BinOp::Gt(_) if is_abs_difference(&binary.right) => (false, true),
+            BinOp::Ge(_) if is_abs_difference(&binary.right) => (true, true),
+            _ => return Err(Expr::Binary(binary)),
+        };
+        Ok(ExprFloatEps {
+            span: binary.op.span(),
+            left: *binary.left,
+            right: *binary.right,
+            inclusive,
+            tolerance_left,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn float_eps_inactive() {
+        let result = run(
+            1,
+            0.05,
+            0.1,
+            false,
+            false,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert!(result);
+    }
+    #[test]
+    fn float_eps_active1() {
+        let result = run(
+            1,
+            0.5,
+            0.1,
+            false,
+            false,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert!(result);
+    }
+    #[test]
+    fn float_eps_active2() {
+        let result = run(
+            1,
+            0.05f32,
+            0.1f32,
+            false,
+            false,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert!(!result);
+    }
+    #[test]
+    fn float_eps_active3() {
+        let result = run(
+            1,
+            0.05,
+            0.1,
+            false,
+            false,
+            &MutagenRuntimeConfig::with_mutation_id(3),
+        );
+        assert!(!result);
+    }
+    #[test]
+    fn float_eps_tolerance_left_active2() {
+        let result = run(
+            1,
+            0.1,
+            0.05,
+            false,
+            true,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert!(!result);
+    }
+
+    #[test]
+    fn detect_tolerance_right() {
+        let expr: Expr = parse_quote! {(a - b).abs() <= EPSILON};
+        let e = ExprFloatEps::try_from(expr).unwrap();
+        assert!(e.inclusive);
+        assert!(!e.tolerance_left);
+    }
+    #[test]
+    fn detect_tolerance_left() {
+        let expr: Expr = parse_quote! {f64::EPSILON > (x - 1.0).abs()};
+        let e = ExprFloatEps::try_from(expr).unwrap();
+        assert!(!e.inclusive);
+        assert!(e.tolerance_left);
+    }
+    #[test]
+    fn ignore_abs_without_difference() {
+        let expr: Expr = parse_quote! {x.abs() < EPSILON};
+        assert!(ExprFloatEps::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_difference_greater_than_tolerance() {
+        let expr: Expr = parse_quote! {(a - b).abs() > EPSILON};
+        assert!(ExprFloatEps::try_from(expr).is_err());
+    }
+}
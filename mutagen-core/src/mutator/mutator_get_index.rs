@@ -0,0 +1,186 @@
+//! Mutator for the index of checked accessors, like `v.get(i)` or `v.get_mut(i)`.
+//!
+//! The index is incremented and decremented, such that the accessor may return a neighboring element or `None`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the index, which is perturbed if one of the mutations is active
+pub fn run<I>(
+    mutator_id: usize,
+    index: I,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> I {
+    runtime.covered(mutator_id);
+    let mutations = MutationGetIndex::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(m) => index.may_perturb(*m),
+        None => index,
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprGetIndex::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the index is described as written in the original code, before other mutators have been applied to it
+    let original_index = match context.original_expr.clone().map(ExprGetIndex::try_from) {
+        Some(Ok(original)) => original.call.args[0].to_token_stream().to_string(),
+        _ => e.call.args[0].to_token_stream().to_string(),
+    };
+    let method = e.call.method.to_string();
+
+    // maps are also accessed with `get`, where the key is not an index. These mutants are skipped.
+    let mutator_id =
+        transform_info.add_mutations(MutationGetIndex::possible_mutations().iter().map(|m| {
+            Mutation::new_spanned(
+                context,
+                "get_index".to_owned(),
+                format!("{}({})", method, original_index),
+                m.describe(&method, &original_index),
+                e.span,
+            )
+            .with_optimistic_policy(OptimisticPolicy::Skip)
+        }));
+
+    let index = &e.call.args[0];
+    e.call.args[0] = syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_get_index::run(
+                #mutator_id,
+                #index,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    Expr::MethodCall(e.call)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum MutationGetIndex {
+    Increment,
+    Decrement,
+}
+
+impl MutationGetIndex {
+    pub(crate) fn possible_mutations() -> Vec<Self> {
+        vec![MutationGetIndex::Increment, MutationGetIndex::Decrement]
+    }
+
+    pub(crate) fn describe(self, method: &str, index: &str) -> String {
+        match self {
+            MutationGetIndex::Increment => format!("{}({} + 1)", method, index),
+            MutationGetIndex::Decrement => format!("{}({} - 1)", method, index),
+        }
+    }
+}
+
+/// trait that is used to optimistically perturb an index.
+///
+/// Only indices of type `usize` can be perturbed, otherwise the optimistic assumption fails.
+trait IndexMutable {
+    fn may_perturb(self, m: MutationGetIndex) -> Self;
+}
+
+impl<T> IndexMutable for T {
+    default fn may_perturb(self, _m: MutationGetIndex) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl IndexMutable for usize {
+    /// The index wraps around, such that decrementing `0` gives an index that is out of bounds.
+    fn may_perturb(self, m: MutationGetIndex) -> Self {
+        match m {
+            MutationGetIndex::Increment => self.wrapping_add(1),
+            MutationGetIndex::Decrement => self.wrapping_sub(1),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprGetIndex {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprGetIndex {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            // references are keys of maps and ranges give sub-slices, both are no indices
+            Expr::MethodCall(call)
+                if (call.method == "get" || call.method == "get_mut")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none()
+                    && !matches!(call.args[0], Expr::Reference(_) | Expr::Range(_)) =>
+            {
+                Ok(ExprGetIndex {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn get_index_inactive() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 3);
+    }
+    #[test]
+    fn get_index_active1() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn get_index_active2() {
+        let result = run(1, 0usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, usize::MAX);
+    }
+    #[test]
+    #[should_panic]
+    fn get_index_non_usize_active() {
+        run(1, "key", &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_get() {
+        let expr: Expr = parse_quote! {v.get(i)};
+        assert!(ExprGetIndex::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_get_with_reference() {
+        let expr: Expr = parse_quote! {map.get(&key)};
+        assert!(ExprGetIndex::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_get_with_range() {
+        let expr: Expr = parse_quote! {v.get(1..3)};
+        assert!(ExprGetIndex::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+//! Mutator for unchecked accessors in `unsafe` blocks, like `unsafe { v.get_unchecked(i) }`.
+//!
+//! The accessor is swapped with its checked counterpart and the index is perturbed.
+//! Perturbing the index of an unchecked accessor can cause undefined behavior.
+//! Therefore, this mutator is only used if it is listed explicitly with `mutators = only(get_unchecked)`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, Ident};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Returns whether the checked accessor is used instead, together with the index.
+///
+/// The index is perturbed if one of the corresponding mutations is active.
+pub fn run<I>(
+    mutator_id: usize,
+    index: I,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> (bool, I) {
+    runtime.covered(mutator_id);
+    let mutations = MutationGetUnchecked::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(MutationGetUnchecked::Checked) => (true, index),
+        Some(m) => (false, index.may_perturb(*m)),
+        None => (false, index),
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprGetUnchecked::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the index is described as written in the original code, before other mutators have been applied to it
+    let original_index = match context
+        .original_expr
+        .clone()
+        .map(ExprGetUnchecked::try_from)
+    {
+        Some(Ok(original)) => original.call.args[0].to_token_stream().to_string(),
+        _ => e.call.args[0].to_token_stream().to_string(),
+    };
+    let method = e.call.method.to_string();
+    let checked = if method == "get_unchecked" {
+        "get"
+    } else {
+        "get_mut"
+    };
+
+    let mutator_id =
+        transform_info.add_mutations(MutationGetUnchecked::possible_mutations().iter().map(|m| {
+            Mutation::new_spanned(
+                context,
+                "get_unchecked".to_owned(),
+                format!("{}({})", method, original_index),
+                m.describe(&method, checked, &original_index),
+                e.span,
+            )
+            .with_optimistic_policy(OptimisticPolicy::Skip)
+        }));
+
+    let span = e.span;
+    let receiver = &e.call.receiver;
+    let index = &e.call.args[0];
+    let method = &e.call.method;
+    let checked = Ident::new(checked, span);
+    syn::parse2(quote_spanned! {span=>
+        {
+            let (__mutagen_checked, __mutagen_index) = ::mutagen::mutator::mutator_get_unchecked::run(
+                #mutator_id,
+                #index,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            );
+            if __mutagen_checked {
+                ::core::option::Option::unwrap(#receiver.#checked(__mutagen_index))
+            } else {
+                #receiver.#method(__mutagen_index)
+            }
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationGetUnchecked {
+    Checked,
+    Increment,
+    Decrement,
+}
+
+impl MutationGetUnchecked {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationGetUnchecked::Checked,
+            MutationGetUnchecked::Increment,
+            MutationGetUnchecked::Decrement,
+        ]
+    }
+
+    fn describe(self, method: &str, checked: &str, index: &str) -> String {
+        match self {
+            MutationGetUnchecked::Checked => format!("{}({}).unwrap()", checked, index),
+            MutationGetUnchecked::Increment => format!("{}({} + 1)", method, index),
+            MutationGetUnchecked::Decrement => format!("{}({} - 1)", method, index),
+        }
+    }
+}
+
+/// trait that is used to optimistically perturb an index.
+///
+/// Only indices of type `usize` can be perturbed, otherwise the optimistic assumption fails.
+trait IndexMutable {
+    fn may_perturb(self, m: MutationGetUnchecked) -> Self;
+}
+
+impl<T> IndexMutable for T {
+    default fn may_perturb(self, _m: MutationGetUnchecked) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl IndexMutable for usize {
+    fn may_perturb(self, m: MutationGetUnchecked) -> Self {
+        match m {
+            MutationGetUnchecked::Decrement => self.wrapping_sub(1),
+            _ => self.wrapping_add(1),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprGetUnchecked {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprGetUnchecked {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (call.method == "get_unchecked" || call.method == "get_unchecked_mut")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none()
+                    && !matches!(call.args[0], Expr::Range(_)) =>
+            {
+                Ok(ExprGetUnchecked {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn get_unchecked_inactive() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, (false, 3));
+    }
+    #[test]
+    fn get_unchecked_active1() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, (true, 3));
+    }
+    #[test]
+    fn get_unchecked_active2() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, (false, 4));
+    }
+    #[test]
+    fn get_unchecked_active3() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(3));
+        assert_eq!(result, (false, 2));
+    }
+
+    #[test]
+    fn detect_get_unchecked_mut() {
+        let expr: Expr = parse_quote! {v.get_unchecked_mut(i)};
+        assert!(ExprGetUnchecked::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_get_unchecked_with_range() {
+        let expr: Expr = parse_quote! {s.get_unchecked(1..3)};
+        assert!(ExprGetUnchecked::try_from(expr).is_err());
+    }
+}
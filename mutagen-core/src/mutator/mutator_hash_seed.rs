@@ -0,0 +1,264 @@
+//! Mutator for the construction of hash states and hashers, like `RandomState::new()` or `RandomState::with_seed(s)`.
+//!
+//! The seed of the hash function is changed, such that the iteration order of hash maps and sets changes.
+//! A killed mutant reveals that the tests depend on this order.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprCall};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// constructors of hashers and hash states that take a seed as first argument
+const SEEDED_CONSTRUCTORS: &[&str] = &["with_seed", "with_seeds", "with_keys", "new_with_keys"];
+
+/// Returns the hash state created by the constructor.
+///
+/// If the mutation is active, the constructor is called twice.
+/// Random states derive the seed from a counter or a random source, such that the second state has a different seed.
+pub fn run_new<T>(
+    mutator_id: usize,
+    new: impl Fn() -> T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        new();
+    }
+    new()
+}
+
+/// returns the seed, which is incremented if the mutation is active
+pub fn run_seed<S>(
+    mutator_id: usize,
+    seed: S,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> S {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        seed.may_perturb()
+    } else {
+        seed
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprHashSeed::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original = match context.original_expr.clone().map(ExprHashSeed::try_from) {
+        Some(Ok(original)) => original.call,
+        _ => e.call.clone(),
+    };
+    let original_code = original.to_token_stream().to_string();
+    let func = original.func.to_token_stream().to_string();
+
+    let span = e.span;
+    let mut call = e.call;
+    if call.args.is_empty() {
+        let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+            context,
+            "hash_seed".to_owned(),
+            original_code.clone(),
+            format!("{{ {}; {} }}", original_code, original_code),
+            span,
+        ));
+        let func = &call.func;
+        syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_hash_seed::run_new(
+                    #mutator_id,
+                    #func,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid")
+    } else {
+        let args = original
+            .args
+            .iter()
+            .map(|arg| arg.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        let mutated_args = std::iter::once(format!("{} + 1", args[0]))
+            .chain(args[1..].iter().cloned())
+            .collect::<Vec<_>>();
+
+        // seeds that are not integers cannot be perturbed. These mutants are skipped.
+        let mutator_id = transform_info.add_mutation(
+            Mutation::new_spanned(
+                context,
+                "hash_seed".to_owned(),
+                original_code,
+                format!("{}({})", func, mutated_args.join(", ")),
+                span,
+            )
+            .with_optimistic_policy(OptimisticPolicy::Skip),
+        );
+        let seed = &call.args[0];
+        call.args[0] = syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_hash_seed::run_seed(
+                    #mutator_id,
+                    #seed,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid");
+        Expr::Call(call)
+    }
+}
+
+/// trait that is used to optimistically perturb a seed.
+///
+/// Only seeds of integer types can be perturbed, otherwise the optimistic assumption fails.
+trait SeedMutable {
+    fn may_perturb(self) -> Self;
+}
+
+impl<T> SeedMutable for T {
+    default fn may_perturb(self) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+macro_rules! seed_mutable_int {
+    ($($t:ty),*) => {
+        $(
+            impl SeedMutable for $t {
+                fn may_perturb(self) -> Self {
+                    self.wrapping_add(1)
+                }
+            }
+        )*
+    };
+}
+
+seed_mutable_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Checks if the call constructs a hasher or hash state with a seed.
+///
+/// Calls to `new` are only mutated for hash states, since hashers like `DefaultHasher::new()` have fixed keys.
+fn is_hash_constructor(call: &ExprCall) -> bool {
+    let path = match &*call.func {
+        Expr::Path(path) if path.qself.is_none() => &path.path,
+        _ => return false,
+    };
+    let segments = path.segments.iter().collect::<Vec<_>>();
+    let (ty, f) = match &*segments {
+        [.., ty, f] if f.arguments.is_empty() => (ty.ident.to_string(), &f.ident),
+        _ => return false,
+    };
+    if call.args.is_empty() {
+        f == "new" && ty.ends_with("State")
+    } else {
+        SEEDED_CONSTRUCTORS.iter().any(|name| f == name)
+            && (ty.contains("Hash") || ty.ends_with("State"))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprHashSeed {
+    call: ExprCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprHashSeed {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Call(call) if is_hash_constructor(&call) => Ok(ExprHashSeed {
+                span: call.paren_token.span,
+                call,
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::Cell;
+    use syn::parse_quote;
+
+    #[test]
+    fn new_inactive() {
+        let calls = Cell::new(0);
+        run_new(
+            1,
+            || calls.set(calls.get() + 1),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(calls.get(), 1);
+    }
+    #[test]
+    fn new_active() {
+        let calls = Cell::new(0);
+        run_new(
+            1,
+            || calls.set(calls.get() + 1),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(calls.get(), 2);
+    }
+    #[test]
+    fn seed_inactive() {
+        let seed = run_seed(1, 7u64, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(seed, 7);
+    }
+    #[test]
+    fn seed_active() {
+        let seed = run_seed(1, u64::MAX, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(seed, 0);
+    }
+    #[test]
+    #[should_panic]
+    fn seed_non_int_active() {
+        run_seed(1, "seed", &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_random_state_new() {
+        let expr: Expr = parse_quote! {std::collections::hash_map::RandomState::new()};
+        assert!(ExprHashSeed::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_with_seeds() {
+        let expr: Expr = parse_quote! {ahash::RandomState::with_seeds(1, 2, 3, 4)};
+        assert!(ExprHashSeed::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_new_with_keys() {
+        let expr: Expr = parse_quote! {SipHasher::new_with_keys(k0, k1)};
+        assert!(ExprHashSeed::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_other_types() {
+        let expr: Expr = parse_quote! {StdRng::with_seed(s)};
+        assert!(ExprHashSeed::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_default_hasher_new() {
+        let expr: Expr = parse_quote! {DefaultHasher::new()};
+        assert!(ExprHashSeed::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_new_with_args() {
+        let expr: Expr = parse_quote! {RandomState::new(s)};
+        assert!(ExprHashSeed::try_from(expr).is_err());
+    }
+}
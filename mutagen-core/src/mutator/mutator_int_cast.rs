@@ -0,0 +1,382 @@
+//! Mutator for conversions between integer types, like `u8::try_from(x)`, `x.try_into().unwrap()` or `x as u8`.
+//!
+//! Fallible conversions are replaced with a lossy `as`-cast, which never fails.
+//! Casts are replaced with a checked conversion, which panics if the value does not fit.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprCall, ExprCast, ExprMethodCall, Type};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the primitive integer types, which are the targets of mutated conversions
+const INT_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// returns whether the conversion is replaced
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+/// converts the value with an `as`-cast, which may truncate the value
+pub fn lossy_cast<S, T>(value: S) -> T {
+    value.may_lossy_cast()
+}
+
+/// converts the value with `TryFrom` and panics if the value does not fit into the target type
+pub fn checked_cast<S, T>(value: S) -> T {
+    value.may_checked_cast()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprIntCast::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the conversion is described as written in the original code, before other mutators have been applied to it
+    let original = match context.original_expr.clone().map(ExprIntCast::try_from) {
+        Some(Ok(original)) => original,
+        _ => e.clone(),
+    };
+    let original_value = original.value().to_token_stream().to_string();
+    let (mutated_code, policy) = match &original {
+        ExprIntCast::TryFrom { ty, .. } => (
+            format!("Ok({} as {})", original_value, ty),
+            OptimisticPolicy::Killed,
+        ),
+        // the target type is unknown and may be no integer, like an array converted from a slice
+        ExprIntCast::TryInto { .. } => (format!("{} as _", original_value), OptimisticPolicy::Skip),
+        // the value may be no integer, like a float or an enum
+        ExprIntCast::Cast { cast, .. } => (
+            format!(
+                "{}::try_from({}).unwrap()",
+                cast.ty.to_token_stream(),
+                original_value
+            ),
+            OptimisticPolicy::Skip,
+        ),
+    };
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "int_cast".to_owned(),
+            original.to_token_stream().to_string(),
+            mutated_code,
+            e.span(),
+        )
+        .with_optimistic_policy(policy),
+    );
+
+    // the value is bound first, such that it is evaluated exactly once
+    let span = e.span();
+    let value = e.value();
+    let value_ident = syn::Ident::new("__mutagen_value", span);
+    let (mutated, unmutated) = match &e {
+        ExprIntCast::TryFrom { call, ty, .. } => {
+            let func = &call.func;
+            (
+                quote_spanned! {span=>
+                    ::core::result::Result::Ok(
+                        ::mutagen::mutator::mutator_int_cast::lossy_cast::<_, #ty>(#value_ident)
+                    )
+                },
+                quote_spanned! {span=> #func(#value_ident)},
+            )
+        }
+        ExprIntCast::TryInto { call, .. } => {
+            let mut call = call.clone();
+            if let Expr::MethodCall(try_into) = &mut *call.receiver {
+                *try_into.receiver = syn::parse_quote! {#value_ident};
+            }
+            (
+                quote_spanned! {span=>
+                    ::mutagen::mutator::mutator_int_cast::lossy_cast(#value_ident)
+                },
+                call.into_token_stream(),
+            )
+        }
+        ExprIntCast::Cast { cast, .. } => {
+            let ty = &cast.ty;
+            (
+                quote_spanned! {span=>
+                    ::mutagen::mutator::mutator_int_cast::checked_cast::<_, #ty>(#value_ident)
+                },
+                quote_spanned! {span=> #value_ident as #ty},
+            )
+        }
+    };
+    syn::parse2(quote_spanned! {span=>
+        {
+            let #value_ident = #value;
+            if ::mutagen::mutator::mutator_int_cast::run(
+                    #mutator_id,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+            {
+                #mutated
+            } else {
+                #unmutated
+            }
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// trait that is used to optimistically cast a value with `as`.
+///
+/// Only values of primitive integer types can be cast to integers, otherwise the optimistic assumption fails.
+trait LossyCast<T> {
+    fn may_lossy_cast(self) -> T;
+}
+
+impl<S, T> LossyCast<T> for S {
+    default fn may_lossy_cast(self) -> T {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+macro_rules! lossy_cast_from {
+    { $from:ident => $($to:ident,)* } => {
+        $(
+            impl LossyCast<$to> for $from {
+                fn may_lossy_cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! lossy_cast_ints {
+    { $($from:ident,)* } => {
+        $(
+            lossy_cast_from! {
+                $from => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+            }
+        )*
+    }
+}
+
+lossy_cast_ints! {
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+}
+
+/// trait that is used to optimistically convert a value with `TryFrom`.
+///
+/// Only values that can be converted with `TryFrom` are supported, otherwise the optimistic assumption fails.
+trait CheckedCast<T> {
+    fn may_checked_cast(self) -> T;
+}
+
+impl<S, T> CheckedCast<T> for S {
+    default fn may_checked_cast(self) -> T {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<S, T: TryFrom<S>> CheckedCast<T> for S {
+    fn may_checked_cast(self) -> T {
+        match T::try_from(self) {
+            Ok(value) => value,
+            Err(_) => panic!("value does not fit into the target type"),
+        }
+    }
+}
+
+/// checks if the type is a primitive integer type
+fn is_int_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => INT_TYPES.iter().any(|t| path.path.is_ident(t)),
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ExprIntCast {
+    TryFrom {
+        call: ExprCall,
+        ty: syn::Ident,
+        span: Span,
+    },
+    TryInto {
+        call: ExprMethodCall,
+        span: Span,
+    },
+    Cast {
+        cast: ExprCast,
+        span: Span,
+    },
+}
+
+impl ExprIntCast {
+    /// the value that is converted
+    fn value(&self) -> &Expr {
+        match self {
+            ExprIntCast::TryFrom { call, .. } => &call.args[0],
+            ExprIntCast::TryInto { call, .. } => match &*call.receiver {
+                Expr::MethodCall(try_into) => &try_into.receiver,
+                _ => unreachable!(),
+            },
+            ExprIntCast::Cast { cast, .. } => &cast.expr,
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            ExprIntCast::TryFrom { span, .. } => *span,
+            ExprIntCast::TryInto { span, .. } => *span,
+            ExprIntCast::Cast { span, .. } => *span,
+        }
+    }
+}
+
+impl ToTokens for ExprIntCast {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            ExprIntCast::TryFrom { call, .. } => call.to_tokens(tokens),
+            ExprIntCast::TryInto { call, .. } => call.to_tokens(tokens),
+            ExprIntCast::Cast { cast, .. } => cast.to_tokens(tokens),
+        }
+    }
+}
+
+impl TryFrom<Expr> for ExprIntCast {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            // `u8::try_from(x)`
+            Expr::Call(call) if call.args.len() == 1 => {
+                let ty = match &*call.func {
+                    Expr::Path(path) if path.qself.is_none() && path.path.segments.len() == 2 => {
+                        let segments = &path.path.segments;
+                        if segments[1].ident == "try_from"
+                            && segments.iter().all(|s| s.arguments.is_empty())
+                            && INT_TYPES.iter().any(|t| segments[0].ident == t)
+                        {
+                            Some(segments[0].ident.clone())
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                };
+                match ty {
+                    Some(ty) => Ok(ExprIntCast::TryFrom {
+                        span: call.span(),
+                        call,
+                        ty,
+                    }),
+                    None => Err(Expr::Call(call)),
+                }
+            }
+            // `x.try_into().unwrap()` or `x.try_into().expect(msg)`
+            Expr::MethodCall(call)
+                if (call.method == "unwrap" && call.args.is_empty()
+                    || call.method == "expect" && call.args.len() == 1)
+                    && matches!(&*call.receiver, Expr::MethodCall(try_into)
+                        if try_into.method == "try_into"
+                            && try_into.args.is_empty()
+                            && try_into.turbofish.is_none()) =>
+            {
+                Ok(ExprIntCast::TryInto {
+                    span: call.span(),
+                    call,
+                })
+            }
+            // `x as u8`, casts of literals are constant
+            Expr::Cast(cast) if is_int_type(&cast.ty) && !matches!(&*cast.expr, Expr::Lit(_)) => {
+                Ok(ExprIntCast::Cast {
+                    span: cast.as_token.span,
+                    cast,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn int_cast_inactive() {
+        let result = run(1, &MutagenRuntimeConfig::without_mutation());
+        assert!(!result);
+    }
+    #[test]
+    fn int_cast_active() {
+        let result = run(1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(result);
+    }
+
+    #[test]
+    fn lossy_cast_truncates() {
+        let result: u8 = lossy_cast(300u32);
+        assert_eq!(result, 44);
+    }
+    #[test]
+    #[should_panic]
+    fn lossy_cast_non_int() {
+        let _: u8 = lossy_cast("300");
+    }
+    #[test]
+    fn checked_cast_fits() {
+        let result: u8 = checked_cast(200u32);
+        assert_eq!(result, 200);
+    }
+    #[test]
+    #[should_panic]
+    fn checked_cast_does_not_fit() {
+        let _: u8 = checked_cast(300u32);
+    }
+
+    #[test]
+    fn detect_try_from() {
+        let expr: Expr = parse_quote! {u8::try_from(x)};
+        assert!(ExprIntCast::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_try_into_unwrap() {
+        let expr: Expr = parse_quote! {x.try_into().unwrap()};
+        assert!(ExprIntCast::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_cast() {
+        let expr: Expr = parse_quote! {x as u8};
+        assert!(ExprIntCast::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_try_from_of_other_type() {
+        let expr: Expr = parse_quote! {Ipv4Addr::try_from(x)};
+        assert!(ExprIntCast::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_cast_to_float() {
+        let expr: Expr = parse_quote! {x as f64};
+        assert!(ExprIntCast::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_cast_of_literal() {
+        let expr: Expr = parse_quote! {1 as u8};
+        assert!(ExprIntCast::try_from(expr).is_err());
+    }
+}
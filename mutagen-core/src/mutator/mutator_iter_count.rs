@@ -0,0 +1,383 @@
+//! Mutator for the counts of iterator adapters `step_by(n)`, `skip(n)` and `take(n)`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprLit, ExprMethodCall, Lit};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::mutator::mutator_lit_int::IntMutable;
+use crate::MutagenRuntimeConfig;
+
+pub fn run<T>(
+    mutator_id: usize,
+    count: T,
+    adapter: IterAdapter,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    let mutations = MutationIterCount::possible_mutations(adapter);
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        count.may_perturb(*m, adapter)
+    } else {
+        count
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprIterCount::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the count is described as written in the original code, before other mutators have been applied to it
+    let original_count = match context.original_expr.clone().map(ExprIterCount::try_from) {
+        Some(Ok(original)) => original.count,
+        _ => e.count.clone(),
+    };
+
+    let mutator_id = transform_info.add_mutations(
+        MutationIterCount::registered_mutations(e.adapter, &original_count)
+            .iter()
+            .map(|m| m.to_mutation(&e, &original_count.to_token_stream().to_string(), context)),
+    );
+
+    let receiver = &e.receiver;
+    let method = &e.method;
+    let count = &e.count;
+    let adapter = e.adapter_tokens();
+
+    syn::parse2(quote_spanned! {e.span=>
+        #receiver.#method(
+            ::mutagen::mutator::mutator_iter_count::run(
+                    #mutator_id,
+                    #count,
+                    #adapter,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        )
+    })
+    .expect("transformed code invalid")
+}
+
+/// the value of the count if it is an integer literal
+fn lit_count(e: &Expr) -> Option<u128> {
+    match e {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<u128>().ok(),
+        _ => None,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationIterCount {
+    Relative(i128),
+    Zero,
+}
+
+impl MutationIterCount {
+    fn possible_mutations(adapter: IterAdapter) -> Vec<Self> {
+        let mut mutations = vec![
+            MutationIterCount::Relative(1),
+            MutationIterCount::Relative(-1),
+        ];
+        // `step_by(0)` panics
+        if adapter != IterAdapter::StepBy {
+            mutations.push(MutationIterCount::Zero);
+        }
+        mutations
+    }
+
+    /// The mutations that are registered for the given count.
+    ///
+    /// For literal counts, mutations that cannot change the count or are equivalent to another mutation are left out.
+    /// The result is always a prefix of `possible_mutations`, such that the mutation ids match at runtime.
+    fn registered_mutations(adapter: IterAdapter, count: &Expr) -> Vec<Self> {
+        let mut mutations = Self::possible_mutations(adapter);
+        match lit_count(count) {
+            // decrementing `0` or the step `1` does not change the count
+            Some(0) => mutations.truncate(1),
+            Some(1) if adapter == IterAdapter::StepBy => mutations.truncate(1),
+            // decrementing `1` is equivalent to replacing it with `0`
+            Some(1) => mutations.truncate(2),
+            _ => {}
+        }
+        mutations
+    }
+
+    fn mutate<T: IntMutable>(self, count: T, adapter: IterAdapter) -> T {
+        let count = count.as_u128();
+        let mutated = match self {
+            Self::Relative(r) if r < 0 => count.saturating_sub(r.wrapping_neg() as u128),
+            Self::Relative(r) => count.saturating_add(r as u128),
+            Self::Zero => 0,
+        };
+        // `step_by(0)` panics, the step is at least 1
+        if adapter == IterAdapter::StepBy && mutated == 0 {
+            T::from_u128(count)
+        } else {
+            T::from_u128(mutated)
+        }
+    }
+
+    fn to_mutation(
+        self,
+        original: &ExprIterCount,
+        original_count: &str,
+        context: &TransformContext,
+    ) -> Mutation {
+        let mutated_count = match self {
+            Self::Relative(r) if r < 0 => format!("{} - {}", original_count, -r),
+            Self::Relative(r) => format!("{} + {}", original_count, r),
+            Self::Zero => "0".to_owned(),
+        };
+        Mutation::new_spanned(
+            context,
+            "iter_count".to_owned(),
+            format!("{}({})", original.adapter, original_count),
+            format!("{}({})", original.adapter, mutated_count),
+            original.span,
+        )
+    }
+}
+
+/// trait that is used to optimistically perturb the count of an iterator adapter.
+///
+/// Methods named like the iterator adapters can be defined on any type and take any argument.
+/// The count can only be perturbed if it is an integer, otherwise the optimistic assumption fails.
+trait CountMutable: Sized {
+    fn may_perturb(self, m: MutationIterCount, adapter: IterAdapter) -> Self;
+}
+
+impl<T> CountMutable for T {
+    default fn may_perturb(self, _m: MutationIterCount, _adapter: IterAdapter) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: IntMutable> CountMutable for T {
+    fn may_perturb(self, m: MutationIterCount, adapter: IterAdapter) -> Self {
+        m.mutate(self, adapter)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprIterCount {
+    receiver: Expr,
+    method: syn::Ident,
+    count: Expr,
+    adapter: IterAdapter,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprIterCount {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call) => {
+                let adapter = match &*call.method.to_string() {
+                    "step_by" => IterAdapter::StepBy,
+                    "skip" => IterAdapter::Skip,
+                    "take" => IterAdapter::Take,
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                if call.args.len() != 1 || call.turbofish.is_some() {
+                    return Err(Expr::MethodCall(call));
+                }
+                let ExprMethodCall {
+                    receiver,
+                    method,
+                    args,
+                    ..
+                } = call;
+                Ok(ExprIterCount {
+                    receiver: *receiver,
+                    span: method.span(),
+                    method,
+                    count: args.into_iter().next().unwrap(),
+                    adapter,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+impl ExprIterCount {
+    fn adapter_tokens(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        tokens.extend(quote_spanned!(self.span=>
+            ::mutagen::mutator::mutator_iter_count::IterAdapter::));
+        tokens.extend(match self.adapter {
+            IterAdapter::StepBy => quote_spanned!(self.span=> StepBy),
+            IterAdapter::Skip => quote_spanned!(self.span=> Skip),
+            IterAdapter::Take => quote_spanned!(self.span=> Take),
+        });
+        tokens
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum IterAdapter {
+    StepBy,
+    Skip,
+    Take,
+}
+
+use std::fmt;
+
+impl fmt::Display for IterAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IterAdapter::StepBy => write!(f, "step_by"),
+            IterAdapter::Skip => write!(f, "skip"),
+            IterAdapter::Take => write!(f, "take"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn possible_mutations_take() {
+        assert_eq!(
+            MutationIterCount::possible_mutations(IterAdapter::Take),
+            vec![
+                MutationIterCount::Relative(1),
+                MutationIterCount::Relative(-1),
+                MutationIterCount::Zero
+            ]
+        );
+    }
+    #[test]
+    fn possible_mutations_step_by() {
+        assert_eq!(
+            MutationIterCount::possible_mutations(IterAdapter::StepBy),
+            vec![
+                MutationIterCount::Relative(1),
+                MutationIterCount::Relative(-1),
+            ]
+        );
+    }
+
+    #[test]
+    fn registered_mutations_non_literal() {
+        assert_eq!(
+            MutationIterCount::registered_mutations(IterAdapter::Take, &parse_quote! {n}),
+            MutationIterCount::possible_mutations(IterAdapter::Take)
+        );
+    }
+    #[test]
+    fn registered_mutations_skip_zero() {
+        assert_eq!(
+            MutationIterCount::registered_mutations(IterAdapter::Skip, &parse_quote! {0}),
+            vec![MutationIterCount::Relative(1)]
+        );
+    }
+    #[test]
+    fn registered_mutations_take_zero() {
+        assert_eq!(
+            MutationIterCount::registered_mutations(IterAdapter::Take, &parse_quote! {0usize}),
+            vec![MutationIterCount::Relative(1)]
+        );
+    }
+    #[test]
+    fn registered_mutations_take_one() {
+        assert_eq!(
+            MutationIterCount::registered_mutations(IterAdapter::Take, &parse_quote! {1}),
+            vec![
+                MutationIterCount::Relative(1),
+                MutationIterCount::Relative(-1),
+            ]
+        );
+    }
+    #[test]
+    fn registered_mutations_step_by_one() {
+        assert_eq!(
+            MutationIterCount::registered_mutations(IterAdapter::StepBy, &parse_quote! {1}),
+            vec![MutationIterCount::Relative(1)]
+        );
+    }
+
+    #[test]
+    fn take_inactive() {
+        let result = run(
+            1,
+            2usize,
+            IterAdapter::Take,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, 2);
+    }
+    #[test]
+    fn take_active1() {
+        let result = run(
+            1,
+            2usize,
+            IterAdapter::Take,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, 3);
+    }
+    #[test]
+    fn take_active2() {
+        let result = run(
+            1,
+            2usize,
+            IterAdapter::Take,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, 1);
+    }
+    #[test]
+    fn take_active3() {
+        let result = run(
+            1,
+            2usize,
+            IterAdapter::Take,
+            &MutagenRuntimeConfig::with_mutation_id(3),
+        );
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn skip_zero_decrement_saturates() {
+        assert_eq!(
+            MutationIterCount::Relative(-1).mutate(0usize, IterAdapter::Skip),
+            0
+        );
+    }
+    #[test]
+    fn step_by_one_decrement_keeps_step() {
+        assert_eq!(
+            MutationIterCount::Relative(-1).mutate(1usize, IterAdapter::StepBy),
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn take_non_int_active() {
+        run(
+            1,
+            "x",
+            IterAdapter::Take,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Mutator for reversed iterators `iter.rev()`.
+//!
+//! The mutation removes the reversal, such that the elements are iterated in their original order.
+
+use std::ops::Deref;
+
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::Expr;
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<I: DoubleEndedIterator>(
+    mutator_id: usize,
+    iter: I,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> MaybeRev<I> {
+    runtime.covered(mutator_id);
+    MaybeRev {
+        iter,
+        reversed: !runtime.is_mutation_active(mutator_id),
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let (receiver, span) = match e {
+        Expr::MethodCall(call)
+            if call.method == "rev" && call.args.is_empty() && call.turbofish.is_none() =>
+        {
+            (call.receiver, call.method.span())
+        }
+        _ => return e,
+    };
+
+    // the reversed iterator is described as written in the original code, before other mutators have been applied to it
+    let original_receiver = match &context.original_expr {
+        Some(Expr::MethodCall(call)) if call.method == "rev" => &call.receiver,
+        _ => &receiver,
+    };
+    let original_receiver = original_receiver.to_token_stream().to_string();
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "iter_rev".to_owned(),
+        format!("{}.rev()", original_receiver),
+        original_receiver,
+        span,
+    ));
+
+    syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_iter_rev::run(
+                #mutator_id,
+                #receiver,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// iterator that is reversed unless the mutation is active.
+#[derive(Clone, Debug)]
+pub struct MaybeRev<I> {
+    iter: I,
+    reversed: bool,
+}
+
+impl<I: DoubleEndedIterator> Iterator for MaybeRev<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.reversed {
+            self.iter.next_back()
+        } else {
+            self.iter.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for MaybeRev<I> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        if self.reversed {
+            self.iter.next()
+        } else {
+            self.iter.next_back()
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> ExactSizeIterator for MaybeRev<I> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn iter_rev_inactive() {
+        let result = run(1, 1..4, &MutagenRuntimeConfig::without_mutation()).collect::<Vec<_>>();
+        assert_eq!(result, vec![3, 2, 1]);
+    }
+    #[test]
+    fn iter_rev_active() {
+        let result = run(1, 1..4, &MutagenRuntimeConfig::with_mutation_id(1)).collect::<Vec<_>>();
+        assert_eq!(result, vec![1, 2, 3]);
+    }
+    #[test]
+    fn iter_rev_next_back() {
+        let mut iter = run(1, 1..4, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.len(), 2);
+    }
+}
@@ -0,0 +1,234 @@
+//! Mutator for iterators created by `iter::once(x)` and `iter::repeat(x).take(n)`.
+//!
+//! The iterator of `once` is emptied and the repeated element is yielded only once.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprCall, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the iterator created by `once`, which is emptied if the mutation is active
+pub fn run_once<I: Iterator>(
+    mutator_id: usize,
+    mut iter: I,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> I {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        iter.next();
+    }
+    iter
+}
+
+/// returns the number of repetitions, which is `1` if the mutation is active
+pub fn run_repeat(
+    mutator_id: usize,
+    count: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> usize {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        1
+    } else {
+        count
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprIterSource::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the expression is described as written in the original code, before other mutators have been applied to it
+    let original = match context.original_expr.clone().map(ExprIterSource::try_from) {
+        Some(Ok(original)) => original,
+        _ => e.clone(),
+    };
+    let original_code = original.to_token_stream().to_string();
+
+    match e {
+        ExprIterSource::Once { call, span } => {
+            let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+                context,
+                "iter_source".to_owned(),
+                original_code,
+                "std::iter::empty()".to_owned(),
+                span,
+            ));
+            syn::parse2(quote_spanned! {span=>
+                ::mutagen::mutator::mutator_iter_source::run_once(
+                        #mutator_id,
+                        #call,
+                        ::mutagen::MutagenRuntimeConfig::get_default()
+                    )
+            })
+            .expect("transformed code invalid")
+        }
+        ExprIterSource::RepeatTake { mut call, span } => {
+            let repeat = match original {
+                ExprIterSource::RepeatTake { call, .. } => call.receiver,
+                ExprIterSource::Once { .. } => call.receiver.clone(),
+            };
+            let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+                context,
+                "iter_source".to_owned(),
+                original_code,
+                format!("{}.take(1)", repeat.to_token_stream()),
+                span,
+            ));
+            let count = &call.args[0];
+            call.args[0] = syn::parse2(quote_spanned! {span=>
+                ::mutagen::mutator::mutator_iter_source::run_repeat(
+                        #mutator_id,
+                        #count,
+                        ::mutagen::MutagenRuntimeConfig::get_default()
+                    )
+            })
+            .expect("transformed code invalid");
+            Expr::MethodCall(call)
+        }
+    }
+}
+
+/// checks if the function `iter::<name>` is called with one argument, like `std::iter::once(x)`
+fn is_iter_fn(call: &ExprCall, name: &str) -> bool {
+    if call.args.len() != 1 {
+        return false;
+    }
+    let path = match &*call.func {
+        Expr::Path(path) if path.qself.is_none() => &path.path,
+        _ => return false,
+    };
+    let segments = path.segments.iter().collect::<Vec<_>>();
+    match &*segments {
+        [.., iter, f] => iter.ident == "iter" && f.ident == name && f.arguments.is_empty(),
+        _ => false,
+    }
+}
+
+/// checks if the expression is the literal `1`
+fn is_lit_one(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse::<u128>().ok() == Some(1),
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ExprIterSource {
+    Once { call: ExprCall, span: Span },
+    RepeatTake { call: ExprMethodCall, span: Span },
+}
+
+impl ToTokens for ExprIterSource {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            ExprIterSource::Once { call, .. } => call.to_tokens(tokens),
+            ExprIterSource::RepeatTake { call, .. } => call.to_tokens(tokens),
+        }
+    }
+}
+
+impl TryFrom<Expr> for ExprIterSource {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Call(call) if is_iter_fn(&call, "once") => Ok(ExprIterSource::Once {
+                span: call.paren_token.span,
+                call,
+            }),
+            Expr::MethodCall(call)
+                if call.method == "take"
+                    && call.args.len() == 1
+                    && call.turbofish.is_none()
+                    && !is_lit_one(&call.args[0])
+                    && matches!(&*call.receiver, Expr::Call(repeat) if is_iter_fn(repeat, "repeat")) =>
+            {
+                Ok(ExprIterSource::RepeatTake {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn once_inactive() {
+        let iter = run_once(
+            1,
+            std::iter::once(5),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(iter.count(), 1);
+    }
+    #[test]
+    fn once_active() {
+        let iter = run_once(
+            1,
+            std::iter::once(5),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(iter.count(), 0);
+    }
+    #[test]
+    fn repeat_inactive() {
+        let count = run_repeat(1, 3, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(count, 3);
+    }
+    #[test]
+    fn repeat_active() {
+        let count = run_repeat(1, 3, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn detect_once() {
+        let expr: Expr = parse_quote! {std::iter::once(x)};
+        assert!(ExprIterSource::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_repeat_take() {
+        let expr: Expr = parse_quote! {iter::repeat(0).take(n)};
+        assert!(ExprIterSource::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_unqualified_once() {
+        let expr: Expr = parse_quote! {once(x)};
+        assert!(ExprIterSource::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_repeat_take_one() {
+        let expr: Expr = parse_quote! {iter::repeat(0).take(1)};
+        assert!(ExprIterSource::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_empty() {
+        let expr: Expr = parse_quote! {std::iter::empty()};
+        assert!(ExprIterSource::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Mutator for char literals.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, ExprLit, Lit, LitChar};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run(
+    mutator_id: usize,
+    original_lit: char,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> char {
+    runtime.covered(mutator_id);
+    let mutations = MutationLitChar::possible_mutations(original_lit);
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        m.mutate(original_lit)
+    } else {
+        original_lit
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprLitChar::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutator_id = transform_info.add_mutations(
+        MutationLitChar::possible_mutations(e.value)
+            .into_iter()
+            .map(|m| m.to_mutation(&e, context)),
+    );
+
+    let original_lit = e.lit;
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_lit_char::run(
+                #mutator_id,
+                #original_lit,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationLitChar {
+    Relative(i32),
+    Null,
+}
+
+impl MutationLitChar {
+    /// The mutations of the given char.
+    ///
+    /// Shifts that would give an invalid char, like a surrogate, are left out.
+    /// The null char is left out if it is the char itself or its predecessor.
+    fn possible_mutations(val: char) -> Vec<Self> {
+        let mut mutations = [MutationLitChar::Relative(1), MutationLitChar::Relative(-1)]
+            .iter()
+            .copied()
+            .filter(|m| m.try_mutate(val).is_some())
+            .collect::<Vec<_>>();
+        if (val as u32) > 1 {
+            mutations.push(MutationLitChar::Null);
+        }
+        mutations
+    }
+
+    fn try_mutate(self, val: char) -> Option<char> {
+        match self {
+            // shifts past `'\0'` wrap around to a value that is no char
+            Self::Relative(r) => char::from_u32((val as u32).wrapping_add(r as u32)),
+            Self::Null => Some('\0'),
+        }
+    }
+
+    fn mutate(self, val: char) -> char {
+        self.try_mutate(val).unwrap_or(val)
+    }
+
+    fn to_mutation(self, original_lit: &ExprLitChar, context: &TransformContext) -> Mutation {
+        Mutation::new_spanned(
+            context,
+            "lit_char".to_owned(),
+            format!("{:?}", original_lit.value),
+            format!("{:?}", self.mutate(original_lit.value)),
+            original_lit.span,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExprLitChar {
+    pub value: char,
+    pub lit: LitChar,
+    pub span: Span,
+}
+
+impl TryFrom<Expr> for ExprLitChar {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Char(lit),
+                ..
+            }) => Ok(ExprLitChar {
+                value: lit.value(),
+                span: lit.span(),
+                lit,
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn lit_char_inactive() {
+        let result = run(1, 'a', &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 'a')
+    }
+    #[test]
+    fn lit_char_active1() {
+        let result = run(1, 'a', &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 'b')
+    }
+    #[test]
+    fn lit_char_active2() {
+        let result = run(1, 'a', &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, '`')
+    }
+    #[test]
+    fn lit_char_active3() {
+        let result = run(1, 'a', &MutagenRuntimeConfig::with_mutation_id(3));
+        assert_eq!(result, '\0')
+    }
+
+    #[test]
+    fn possible_mutations_with_null() {
+        assert_eq!(
+            MutationLitChar::possible_mutations('\0'),
+            vec![MutationLitChar::Relative(1)]
+        );
+    }
+    #[test]
+    fn possible_mutations_before_surrogates() {
+        assert_eq!(
+            MutationLitChar::possible_mutations('\u{D7FF}'),
+            vec![MutationLitChar::Relative(-1), MutationLitChar::Null]
+        );
+    }
+    #[test]
+    fn possible_mutations_after_surrogates() {
+        assert_eq!(
+            MutationLitChar::possible_mutations('\u{E000}'),
+            vec![MutationLitChar::Relative(1), MutationLitChar::Null]
+        );
+    }
+    #[test]
+    fn possible_mutations_with_max_value() {
+        assert_eq!(
+            MutationLitChar::possible_mutations(char::MAX),
+            vec![MutationLitChar::Relative(-1), MutationLitChar::Null]
+        );
+    }
+}
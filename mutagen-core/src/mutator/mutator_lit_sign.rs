@@ -0,0 +1,181 @@
+//! Mutator for the sign of numeric literals like `5` or `1.5`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, ExprLit, Lit};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<T>(mutator_id: usize, val: T, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        val.may_flip_sign()
+    } else {
+        val
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the literal is inspected before other mutators have been applied to it
+    let lit = match context.original_expr.clone().map(ExprLitSign::try_from) {
+        Some(Ok(lit)) => lit,
+        _ => return e,
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "lit_sign".to_owned(),
+        lit.lit.clone(),
+        format!("-{}", lit.lit),
+        lit.span,
+    ));
+
+    syn::parse2(quote_spanned! {lit.span=>
+        ::mutagen::mutator::mutator_lit_sign::run(
+                #mutator_id,
+                #e,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Clone, Debug)]
+struct ExprLitSign {
+    lit: String,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprLitSign {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            // unsigned literals cannot be negative
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) if !lit.suffix().starts_with('u') && lit.base10_parse::<u128>().ok() != Some(0) => {
+                Ok(ExprLitSign {
+                    lit: lit.to_string(),
+                    span: lit.span(),
+                })
+            }
+            Expr::Lit(ExprLit {
+                lit: Lit::Float(lit),
+                ..
+            }) if lit.base10_parse::<f64>().is_ok_and(|f| f != 0.0) => Ok(ExprLitSign {
+                lit: lit.to_string(),
+                span: lit.span(),
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+/// trait that is used to optimistically flip the sign of a number.
+///
+/// The type of unsuffixed literals is not known at compile-time.
+/// If the literal has an unsigned type, the optimistic assumption fails.
+pub trait SignFlippable {
+    fn may_flip_sign(self) -> Self;
+}
+
+impl<T> SignFlippable for T {
+    default fn may_flip_sign(self) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+macro_rules! sign_flippable_ints {
+    { $($ty:ident,)* } => {
+        $(
+            impl SignFlippable for $ty {
+                fn may_flip_sign(self) -> Self {
+                    self.wrapping_neg()
+                }
+            }
+        )*
+    }
+}
+
+sign_flippable_ints! {
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+}
+
+impl SignFlippable for f32 {
+    fn may_flip_sign(self) -> Self {
+        -self
+    }
+}
+
+impl SignFlippable for f64 {
+    fn may_flip_sign(self) -> Self {
+        -self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn lit_int_inactive() {
+        let result = run(1, 5, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 5);
+    }
+    #[test]
+    fn lit_int_active() {
+        let result = run(1, 5, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, -5);
+    }
+
+    #[test]
+    fn lit_float_active() {
+        let result = run(1, 1.5, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, -1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lit_unsigned_active() {
+        run(1, 5u8, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_lit_signed_suffix() {
+        let expr: Expr = parse_quote! {5i64};
+        assert!(ExprLitSign::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_lit_unsigned_suffix() {
+        let expr: Expr = parse_quote! {5usize};
+        assert!(ExprLitSign::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_lit_zero() {
+        let expr: Expr = parse_quote! {0.0};
+        assert!(ExprLitSign::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_lit_str() {
+        let expr: Expr = parse_quote! {"5"};
+        assert!(ExprLitSign::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+//! Mutator for the strictness of loop bounds, like `while i < n` or `for i in 0..n`.
+
+use std::convert::TryFrom;
+use std::ops::{Deref, Range, RangeInclusive};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprRange, RangeLimits};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::mutator::mutator_binop_cmp::BinopCmp;
+use crate::MutagenRuntimeConfig;
+
+/// evaluates the comparison of a `while`-condition, with flipped strictness if the mutation is active
+pub fn run_cmp<L: PartialOrd<R>, R>(
+    mutator_id: usize,
+    left: L,
+    right: R,
+    original_op: BinopCmp,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        flip_strictness(original_op).cmp(left, right)
+    } else {
+        original_op.cmp(left, right)
+    }
+}
+
+/// creates the range of a `for`-loop, with flipped inclusiveness if the mutation is active
+pub fn run_range<T>(
+    mutator_id: usize,
+    start: T,
+    end: T,
+    inclusive: bool,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> LoopRange<T> {
+    runtime.covered(mutator_id);
+    if inclusive != runtime.is_mutation_active(mutator_id) {
+        LoopRange::Inclusive(start..=end)
+    } else {
+        LoopRange::Exclusive(start..end)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // only the loop condition itself and operands of `&&` in it are mutated
+    let is_loop_condition = match (&context.original_expr, &context.loop_condition) {
+        (Some(original), Some(condition)) => is_part_of_condition(original, condition),
+        _ => false,
+    };
+    if !is_loop_condition {
+        return e;
+    }
+
+    let e = match ExprLoopBound::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "loop_bound".to_owned(),
+        e.bound.original_op(),
+        e.bound.mutated_op(),
+        e.span,
+    ));
+
+    let left = &e.left;
+    let right = &e.right;
+    let span = e.span;
+    syn::parse2(match e.bound {
+        LoopBound::Cmp(op) => {
+            let op = op_tokens(op, span);
+            quote_spanned! {span=>
+                ::mutagen::mutator::mutator_loop_bound::run_cmp(
+                        #mutator_id,
+                        &(#left),
+                        &(#right),
+                        #op,
+                        ::mutagen::MutagenRuntimeConfig::get_default()
+                    )
+            }
+        }
+        LoopBound::Range(limits) => {
+            let inclusive = limits == Limits::Inclusive;
+            quote_spanned! {span=>
+                ::mutagen::mutator::mutator_loop_bound::run_range(
+                        #mutator_id,
+                        #left,
+                        #right,
+                        #inclusive,
+                        ::mutagen::MutagenRuntimeConfig::get_default()
+                    )
+            }
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// checks if the expression is the loop condition or an operand of `&&` in it
+fn is_part_of_condition(e: &Expr, condition: &Expr) -> bool {
+    if e == condition {
+        return true;
+    }
+    match condition {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::And(_)) => {
+            is_part_of_condition(e, &binary.left) || is_part_of_condition(e, &binary.right)
+        }
+        Expr::Paren(paren) => is_part_of_condition(e, &paren.expr),
+        _ => false,
+    }
+}
+
+/// the comparison with flipped strictness, `<` is replaced with `<=` and vice versa
+fn flip_strictness(op: BinopCmp) -> BinopCmp {
+    match op {
+        BinopCmp::Lt => BinopCmp::Le,
+        BinopCmp::Le => BinopCmp::Lt,
+        BinopCmp::Ge => BinopCmp::Gt,
+        BinopCmp::Gt => BinopCmp::Ge,
+    }
+}
+
+fn op_tokens(op: BinopCmp, span: Span) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    tokens.extend(quote_spanned!(span=>
+        ::mutagen::mutator::mutator_binop_cmp::BinopCmp::));
+    tokens.extend(match op {
+        BinopCmp::Lt => quote_spanned!(span=> Lt),
+        BinopCmp::Le => quote_spanned!(span=> Le),
+        BinopCmp::Ge => quote_spanned!(span=> Ge),
+        BinopCmp::Gt => quote_spanned!(span=> Gt),
+    });
+    tokens
+}
+
+/// the range of a `for`-loop, either exclusive or inclusive.
+#[derive(Clone, Debug)]
+pub enum LoopRange<T> {
+    Exclusive(Range<T>),
+    Inclusive(RangeInclusive<T>),
+}
+
+impl<T> Iterator for LoopRange<T>
+where
+    Range<T>: Iterator<Item = T>,
+    RangeInclusive<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self {
+            LoopRange::Exclusive(range) => range.next(),
+            LoopRange::Inclusive(range) => range.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            LoopRange::Exclusive(range) => range.size_hint(),
+            LoopRange::Inclusive(range) => range.size_hint(),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for LoopRange<T>
+where
+    Range<T>: DoubleEndedIterator<Item = T>,
+    RangeInclusive<T>: DoubleEndedIterator<Item = T>,
+{
+    fn next_back(&mut self) -> Option<T> {
+        match self {
+            LoopRange::Exclusive(range) => range.next_back(),
+            LoopRange::Inclusive(range) => range.next_back(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Limits {
+    Exclusive,
+    Inclusive,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LoopBound {
+    /// a comparison in the condition of a `while`-loop
+    Cmp(BinopCmp),
+    /// the range iterated by a `for`-loop
+    Range(Limits),
+}
+
+impl LoopBound {
+    fn original_op(self) -> String {
+        match self {
+            LoopBound::Cmp(op) => op.to_string(),
+            LoopBound::Range(Limits::Exclusive) => "..".to_owned(),
+            LoopBound::Range(Limits::Inclusive) => "..=".to_owned(),
+        }
+    }
+
+    fn mutated_op(self) -> String {
+        match self {
+            LoopBound::Cmp(op) => flip_strictness(op).to_string(),
+            LoopBound::Range(Limits::Exclusive) => "..=".to_owned(),
+            LoopBound::Range(Limits::Inclusive) => "..".to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprLoopBound {
+    bound: LoopBound,
+    left: Expr,
+    right: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprLoopBound {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Binary(expr) => {
+                let op = match expr.op {
+                    BinOp::Lt(_) => BinopCmp::Lt,
+                    BinOp::Le(_) => BinopCmp::Le,
+                    BinOp::Ge(_) => BinopCmp::Ge,
+                    BinOp::Gt(_) => BinopCmp::Gt,
+                    _ => return Err(Expr::Binary(expr)),
+                };
+                Ok(ExprLoopBound {
+                    bound: LoopBound::Cmp(op),
+                    span: expr.op.span(),
+                    left: *expr.left,
+                    right: *expr.right,
+                })
+            }
+            Expr::Range(ExprRange {
+                from: Some(from),
+                to: Some(to),
+                limits,
+                ..
+            }) => {
+                let (limits, span) = match limits {
+                    RangeLimits::HalfOpen(t) => (Limits::Exclusive, t.span()),
+                    RangeLimits::Closed(t) => (Limits::Inclusive, t.span()),
+                };
+                Ok(ExprLoopBound {
+                    bound: LoopBound::Range(limits),
+                    left: *from,
+                    right: *to,
+                    span,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn cmp_inactive() {
+        let result = run_cmp(
+            1,
+            3,
+            3,
+            BinopCmp::Lt,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert!(!result);
+    }
+    #[test]
+    fn cmp_active() {
+        let result = run_cmp(
+            1,
+            3,
+            3,
+            BinopCmp::Lt,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert!(result);
+    }
+    #[test]
+    fn range_exclusive_active() {
+        let range = run_range(1, 0, 3, false, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(range.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+    #[test]
+    fn range_inclusive_active() {
+        let range = run_range(1, 0, 3, true, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(range.collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn condition_operand_of_and() {
+        let condition: Expr = parse_quote! {i < n && (v[i] > 0)};
+        let e: Expr = parse_quote! {v[i] > 0};
+        assert!(is_part_of_condition(&e, &condition));
+    }
+    #[test]
+    fn condition_not_nested_in_call() {
+        let condition: Expr = parse_quote! {f(i < n)};
+        let e: Expr = parse_quote! {i < n};
+        assert!(!is_part_of_condition(&e, &condition));
+    }
+    #[test]
+    fn condition_not_operand_of_or() {
+        let condition: Expr = parse_quote! {i < n || done};
+        let e: Expr = parse_quote! {i < n};
+        assert!(!is_part_of_condition(&e, &condition));
+    }
+}
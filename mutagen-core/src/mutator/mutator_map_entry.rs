@@ -0,0 +1,280 @@
+//! Mutator for values inserted via the entry-api of maps, like `map.entry(k).or_insert(v)`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// runner for the value given to `or_insert`
+pub fn run<V>(mutator_id: usize, val: V, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> V {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        val.may_perturb()
+    } else {
+        val
+    }
+}
+
+/// runner for the function given to `or_insert_with`
+pub fn run_with<V, F: FnOnce() -> V>(
+    mutator_id: usize,
+    f: F,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> impl FnOnce() -> V {
+    runtime.covered(mutator_id);
+    let active = runtime.is_mutation_active(mutator_id);
+    move || {
+        if active {
+            f().may_perturb()
+        } else {
+            f()
+        }
+    }
+}
+
+/// runner for `or_default`, which is replaced by a call to `or_insert_with`
+pub fn run_default<V: Default>(
+    mutator_id: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> impl FnOnce() -> V {
+    run_with(mutator_id, V::default, runtime)
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprMapEntry::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "map_entry".to_owned(),
+        format!("{}", e.insert),
+        format!("{} with perturbed value", e.insert),
+        e.span,
+    ));
+
+    let entry = &e.entry;
+
+    syn::parse2(match &e.insert {
+        EntryInsert::OrInsert(val) => quote_spanned! {e.span=>
+            #entry.or_insert(
+                ::mutagen::mutator::mutator_map_entry::run(
+                    #mutator_id,
+                    #val,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+            )
+        },
+        EntryInsert::OrInsertWith(f) => quote_spanned! {e.span=>
+            #entry.or_insert_with(
+                ::mutagen::mutator::mutator_map_entry::run_with(
+                    #mutator_id,
+                    #f,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+            )
+        },
+        EntryInsert::OrDefault => quote_spanned! {e.span=>
+            #entry.or_insert_with(
+                ::mutagen::mutator::mutator_map_entry::run_default(
+                    #mutator_id,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+            )
+        },
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Clone, Debug)]
+struct ExprMapEntry {
+    entry: Expr,
+    insert: EntryInsert,
+    span: Span,
+}
+
+#[derive(Clone, Debug)]
+// the variants are named like the methods of `Entry`
+#[allow(clippy::enum_variant_names)]
+enum EntryInsert {
+    OrInsert(Expr),
+    OrInsertWith(Expr),
+    OrDefault,
+}
+
+impl TryFrom<Expr> for ExprMapEntry {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call) if is_entry_call(&call.receiver) => {
+                let ExprMethodCall {
+                    receiver,
+                    method,
+                    turbofish,
+                    args,
+                    ..
+                } = call;
+                let mut args_iter = args.iter().cloned();
+                let insert = match (&*method.to_string(), args.len()) {
+                    ("or_insert", 1) => EntryInsert::OrInsert(args_iter.next().unwrap()),
+                    ("or_insert_with", 1) => EntryInsert::OrInsertWith(args_iter.next().unwrap()),
+                    ("or_default", 0) => EntryInsert::OrDefault,
+                    _ => {
+                        return Err(Expr::MethodCall(ExprMethodCall {
+                            attrs: vec![],
+                            receiver,
+                            dot_token: Default::default(),
+                            method,
+                            turbofish,
+                            paren_token: Default::default(),
+                            args,
+                        }))
+                    }
+                };
+                Ok(ExprMapEntry {
+                    entry: *receiver,
+                    insert,
+                    span: method.span(),
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+/// check if the expression is a call `x.entry(key)`
+fn is_entry_call(e: &Expr) -> bool {
+    match e {
+        Expr::MethodCall(call) => call.method == "entry" && call.args.len() == 1,
+        _ => false,
+    }
+}
+
+use std::fmt;
+
+impl fmt::Display for EntryInsert {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EntryInsert::OrInsert(_) => write!(f, "or_insert"),
+            EntryInsert::OrInsertWith(_) => write!(f, "or_insert_with"),
+            EntryInsert::OrDefault => write!(f, "or_default"),
+        }
+    }
+}
+
+/// trait that is used to optimistically perturb a value.
+///
+/// Numbers are increased by one and booleans are negated.
+/// For all other types, the optimistic assumption fails.
+pub trait PerturbValue {
+    fn may_perturb(self) -> Self;
+}
+
+impl<T> PerturbValue for T {
+    default fn may_perturb(self) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl PerturbValue for bool {
+    fn may_perturb(self) -> Self {
+        !self
+    }
+}
+
+macro_rules! perturb_value_nums {
+    { $($ty:ident, $one:expr,)* } => {
+        $(
+            impl PerturbValue for $ty {
+                fn may_perturb(self) -> Self {
+                    self.wrapping_add($one)
+                }
+            }
+        )*
+    }
+}
+
+perturb_value_nums! {
+    i8, 1,
+    i16, 1,
+    i32, 1,
+    i64, 1,
+    i128, 1,
+    isize, 1,
+    u8, 1,
+    u16, 1,
+    u32, 1,
+    u64, 1,
+    u128, 1,
+    usize, 1,
+}
+
+impl PerturbValue for f32 {
+    fn may_perturb(self) -> Self {
+        self + 1.0
+    }
+}
+
+impl PerturbValue for f64 {
+    fn may_perturb(self) -> Self {
+        self + 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn or_insert_inactive() {
+        let result = run(1, 5, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 5);
+    }
+    #[test]
+    fn or_insert_active() {
+        let result = run(1, 5, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn or_insert_with_active() {
+        let result = run_with(1, || false, &MutagenRuntimeConfig::with_mutation_id(1))();
+        assert!(result);
+    }
+
+    #[test]
+    fn or_default_inactive() {
+        let result: u8 = run_default(1, &MutagenRuntimeConfig::without_mutation())();
+        assert_eq!(result, 0);
+    }
+    #[test]
+    fn or_default_active() {
+        let result: u8 = run_default(1, &MutagenRuntimeConfig::with_mutation_id(1))();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn or_default_vec_active() {
+        let _: Vec<u8> = run_default(1, &MutagenRuntimeConfig::with_mutation_id(1))();
+    }
+
+    #[test]
+    fn perturb_max_wraps() {
+        assert_eq!(u8::MAX.may_perturb(), 0);
+    }
+}
@@ -0,0 +1,213 @@
+//! Mutator for closures given to `map`, like `v.into_iter().map(|x| x * 2)` or `opt.map(|x| x + 1)`.
+//!
+//! The closure is replaced with the identity `|x| x`, such that the elements are not transformed.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprClosure, ExprMethodCall, Ident, Pat};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::ast_inspect::contains_return;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns `true` if the closure is replaced with the identity
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+/// returns the argument of the closure as its result
+pub fn identity<I, O>(input: I) -> O {
+    MayIdentity::<O>::may_identity(input)
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprMapIdentity::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the closure is described as written in the original code, before other mutators have been applied to it
+    let original_closure = match context.original_expr.clone().map(ExprMapIdentity::try_from) {
+        Some(Ok(original)) => original.closure,
+        _ => e.closure.clone(),
+    };
+    let inputs = original_closure.inputs.to_token_stream();
+    let body = original_closure.body.to_token_stream();
+    let span = e.span;
+
+    // a closure that changes the type of the elements is no weakness of the tests
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "map_identity".to_owned(),
+            format!("map(|{}| {})", inputs, body),
+            format!("map(|{}| {})", inputs, e.input),
+            span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip),
+    );
+
+    let input = &e.input;
+    let body = &e.closure.body;
+    *e.closure.body = syn::parse2(quote_spanned! {span=>
+        {
+            if ::mutagen::mutator::mutator_map_identity::run(
+                    #mutator_id,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+            {
+                ::mutagen::mutator::mutator_map_identity::identity(#input)
+            } else {
+                #body
+            }
+        }
+    })
+    .expect("transformed code invalid");
+    e.call.args[0] = Expr::Closure(e.closure);
+    Expr::MethodCall(e.call)
+}
+
+/// trait that is used to optimistically return the argument of a closure as its result.
+///
+/// The closure can only be replaced with the identity if it returns the type of its argument.
+/// Otherwise, the optimistic assumption fails.
+trait MayIdentity<O> {
+    fn may_identity(self) -> O;
+}
+
+impl<I, O> MayIdentity<O> for I {
+    default fn may_identity(self) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T> MayIdentity<T> for T {
+    fn may_identity(self) -> T {
+        self
+    }
+}
+
+/// returns the identifier bound by the pattern of a closure argument, like `x` in `|x|` or `|mut x: u32|`
+fn bound_ident(pat: &Pat) -> Option<&Ident> {
+    match pat {
+        Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => Some(&pat.ident),
+        Pat::Type(pat) => bound_ident(&pat.pat),
+        _ => None,
+    }
+}
+
+/// checks if the expression is the given identifier, such that the closure is already the identity
+fn is_ident(e: &Expr, ident: &Ident) -> bool {
+    match e {
+        Expr::Path(path) => path.qself.is_none() && path.path.is_ident(ident),
+        Expr::Paren(paren) => is_ident(&paren.expr, ident),
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprMapIdentity {
+    call: ExprMethodCall,
+    closure: ExprClosure,
+    input: Ident,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprMapIdentity {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if call.method == "map" && call.args.len() == 1 && call.turbofish.is_none() =>
+            {
+                let closure = match &call.args[0] {
+                    Expr::Closure(closure)
+                        if closure.asyncness.is_none()
+                            && closure.inputs.len() == 1
+                            // a `return` in the body would skip the identity
+                            && !contains_return(closure.body.to_token_stream()) =>
+                    {
+                        closure.clone()
+                    }
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                let input = match bound_ident(&closure.inputs[0]) {
+                    Some(input) if !is_ident(&closure.body, input) => input.clone(),
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                Ok(ExprMapIdentity {
+                    span: call.method.span(),
+                    call,
+                    closure,
+                    input,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn map_identity_inactive() {
+        assert!(!run(1, &MutagenRuntimeConfig::without_mutation()));
+    }
+    #[test]
+    fn map_identity_active() {
+        assert!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)));
+    }
+
+    #[test]
+    fn identity_same_type() {
+        let result: u32 = identity(5u32);
+        assert_eq!(result, 5);
+    }
+    #[test]
+    #[should_panic]
+    fn identity_other_type() {
+        let _: String = identity(5u32);
+    }
+
+    #[test]
+    fn detect_map() {
+        let expr: Expr = parse_quote! {v.into_iter().map(|x| x * 2)};
+        assert!(ExprMapIdentity::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_map_typed_input() {
+        let expr: Expr = parse_quote! {opt.map(|mut x: u32| { x += 1; x })};
+        assert!(ExprMapIdentity::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_map_identity() {
+        let expr: Expr = parse_quote! {v.into_iter().map(|x| x)};
+        assert!(ExprMapIdentity::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_map_with_tuple_pattern() {
+        let expr: Expr = parse_quote! {v.into_iter().map(|(a, b)| a + b)};
+        assert!(ExprMapIdentity::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_map_with_function() {
+        let expr: Expr = parse_quote! {v.into_iter().map(double)};
+        assert!(ExprMapIdentity::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,274 @@
+//! Mutator for `match`-expressions on a `Result` or an `Option`, like `match r { Ok(_) => a, Err(_) => b }`.
+//!
+//! The mutation swaps the bodies of the two arms, which tests whether both outcomes are handled individually.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprMatch, Pat};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns `true` if the bodies of the arms are swapped
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprMatchResult::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the arms are described as written in the original code, before other mutators have been applied to them
+    let original_arms = match context.original_expr.clone().map(ExprMatchResult::try_from) {
+        Some(Ok(original)) => original.expr.arms,
+        _ => e.expr.arms.clone(),
+    };
+    let describe = |first: &Expr, second: &Expr| {
+        format!(
+            "{} => {}, {} => {}",
+            original_arms[0].pat.to_token_stream(),
+            first.to_token_stream(),
+            original_arms[1].pat.to_token_stream(),
+            second.to_token_stream(),
+        )
+    };
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "match_result".to_owned(),
+        describe(&original_arms[0].body, &original_arms[1].body),
+        describe(&original_arms[1].body, &original_arms[0].body),
+        e.span,
+    ));
+
+    let span = e.span;
+    let bodies = [e.expr.arms[0].body.clone(), e.expr.arms[1].body.clone()];
+    for (i, arm) in e.expr.arms.iter_mut().enumerate() {
+        let own = &bodies[i];
+        let other = &bodies[1 - i];
+        *arm.body = syn::parse2(quote_spanned! {span=>
+            if ::mutagen::mutator::mutator_match_result::run(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+                #other
+            } else {
+                #own
+            }
+        })
+        .expect("transformed code invalid");
+        arm.comma.get_or_insert_with(Default::default);
+    }
+    Expr::Match(e.expr)
+}
+
+/// the variant of `Result` or `Option` matched by a pattern
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Variant {
+    Ok,
+    Err,
+    Some,
+    None,
+}
+
+impl Variant {
+    fn of_pat(pat: &Pat) -> Option<Self> {
+        let (path, has_fields) = match pat {
+            Pat::TupleStruct(pat) if pat.pat.elems.len() == 1 => (&pat.path, true),
+            Pat::Path(pat) if pat.qself.is_none() => (&pat.path, false),
+            Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => {
+                return if pat.ident == "None" {
+                    Some(Variant::None)
+                } else {
+                    None
+                };
+            }
+            _ => return None,
+        };
+        let ident = &path.segments.last()?.ident;
+        match (&*ident.to_string(), has_fields) {
+            ("Ok", true) => Some(Variant::Ok),
+            ("Err", true) => Some(Variant::Err),
+            ("Some", true) => Some(Variant::Some),
+            ("None", false) => Some(Variant::None),
+            _ => None,
+        }
+    }
+
+    fn counterpart(self) -> Self {
+        match self {
+            Variant::Ok => Variant::Err,
+            Variant::Err => Variant::Ok,
+            Variant::Some => Variant::None,
+            Variant::None => Variant::Some,
+        }
+    }
+}
+
+/// collects the names of the variables bound by a pattern.
+///
+/// All lowercase identifiers are collected, which may include paths to constants.
+fn bound_names(tokens: TokenStream, names: &mut Vec<String>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => {
+                let name = ident.to_string();
+                let is_lowercase = name.starts_with(char::is_lowercase);
+                if is_lowercase && name != "ref" && name != "mut" {
+                    names.push(name);
+                }
+            }
+            TokenTree::Group(group) => bound_names(group.stream(), names),
+            _ => {}
+        }
+    }
+}
+
+/// checks if any of the given names occurs as identifier in the tokens
+fn mentions_any(tokens: TokenStream, names: &[String]) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => names.iter().any(|name| ident == name),
+        TokenTree::Group(group) => mentions_any(group.stream(), names),
+        _ => false,
+    })
+}
+
+#[derive(Clone, Debug)]
+struct ExprMatchResult {
+    expr: ExprMatch,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprMatchResult {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        let expr = match expr {
+            Expr::Match(expr) if expr.arms.len() == 2 => expr,
+            _ => return Err(expr),
+        };
+        let variants = expr
+            .arms
+            .iter()
+            .map(|arm| match arm.guard {
+                None => Variant::of_pat(&arm.pat),
+                Some(_) => None,
+            })
+            .collect::<Vec<_>>();
+        match (variants[0], variants[1]) {
+            (Some(first), Some(second)) if first.counterpart() == second => {}
+            _ => return Err(Expr::Match(expr)),
+        }
+
+        // each body is moved to the other arm, where the variables of the own pattern are not bound
+        // and the variables of the other pattern could shadow variables used in the body.
+        let mut names = Vec::new();
+        for arm in &expr.arms {
+            bound_names(arm.pat.to_token_stream(), &mut names);
+        }
+        if expr
+            .arms
+            .iter()
+            .any(|arm| mentions_any(arm.body.to_token_stream(), &names))
+        {
+            return Err(Expr::Match(expr));
+        }
+
+        Ok(ExprMatchResult {
+            span: expr.match_token.span(),
+            expr,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn match_result_inactive() {
+        let result = run(1, &MutagenRuntimeConfig::without_mutation());
+        assert!(!result);
+    }
+    #[test]
+    fn match_result_active() {
+        let result = run(1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(result);
+    }
+
+    #[test]
+    fn detect_ok_err() {
+        let expr: Expr = parse_quote! {
+            match r {
+                Ok(_) => 1,
+                Err(_) => 2,
+            }
+        };
+        assert!(ExprMatchResult::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_none_some() {
+        let expr: Expr = parse_quote! {
+            match o {
+                None => 1,
+                Some(_) => 2,
+            }
+        };
+        assert!(ExprMatchResult::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_body_using_binding() {
+        let expr: Expr = parse_quote! {
+            match r {
+                Ok(x) => x,
+                Err(_) => 2,
+            }
+        };
+        assert!(ExprMatchResult::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_unused_binding_shadowing_variable() {
+        let expr: Expr = parse_quote! {
+            match r {
+                Ok(x) => 1,
+                Err(_) => x,
+            }
+        };
+        assert!(ExprMatchResult::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_guard() {
+        let expr: Expr = parse_quote! {
+            match r {
+                Ok(_) if c => 1,
+                Err(_) => 2,
+            }
+        };
+        assert!(ExprMatchResult::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_same_variant() {
+        let expr: Expr = parse_quote! {
+            match r {
+                Ok(1) => 1,
+                Ok(_) => 2,
+            }
+        };
+        assert!(ExprMatchResult::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+//! Mutator for the selection of extrema by a comparator or key, like `iter.min_by(cmp)` or `iter.max_by_key(f)`.
+//!
+//! The minimum is swapped with the maximum and the comparator or key is inverted.
+
+use std::cmp::{Ordering, Reverse};
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// runs `iter.min_by(compare)` or `iter.max_by(compare)`, which is changed if one of the mutations is active
+pub fn run_by<I: Iterator, F: FnMut(&I::Item, &I::Item) -> Ordering>(
+    mutator_id: usize,
+    max: bool,
+    iter: I,
+    mut compare: F,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<I::Item> {
+    runtime.covered(mutator_id);
+    let mutations = MutationMinMax::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(MutationMinMax::Swap) if max => iter.min_by(compare),
+        Some(MutationMinMax::Swap) => iter.max_by(compare),
+        Some(MutationMinMax::Invert) if max => iter.max_by(|a, b| compare(a, b).reverse()),
+        Some(MutationMinMax::Invert) => iter.min_by(|a, b| compare(a, b).reverse()),
+        None if max => iter.max_by(compare),
+        None => iter.min_by(compare),
+    }
+}
+
+/// runs `iter.min_by_key(f)` or `iter.max_by_key(f)`, which is changed if one of the mutations is active
+pub fn run_by_key<I: Iterator, K: Ord, F: FnMut(&I::Item) -> K>(
+    mutator_id: usize,
+    max: bool,
+    iter: I,
+    mut f: F,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<I::Item> {
+    runtime.covered(mutator_id);
+    let mutations = MutationMinMax::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(MutationMinMax::Swap) if max => iter.min_by_key(f),
+        Some(MutationMinMax::Swap) => iter.max_by_key(f),
+        Some(MutationMinMax::Invert) if max => iter.max_by_key(|x| Reverse(f(x))),
+        Some(MutationMinMax::Invert) => iter.min_by_key(|x| Reverse(f(x))),
+        None if max => iter.max_by_key(f),
+        None => iter.min_by_key(f),
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprMinMax::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original = match context.original_expr.clone().map(ExprMinMax::try_from) {
+        Some(Ok(original)) => original.call,
+        _ => e.call.clone(),
+    };
+    let method = original.method.to_string();
+    let arg = original.args[0].to_token_stream().to_string();
+
+    let mutator_id =
+        transform_info.add_mutations(MutationMinMax::possible_mutations().iter().map(|m| {
+            Mutation::new_spanned(
+                context,
+                "min_max".to_owned(),
+                format!("{}({})", method, arg),
+                m.describe(&method, &arg),
+                e.span,
+            )
+        }));
+
+    let span = e.span;
+    let max = method.starts_with("max");
+    let receiver = &e.call.receiver;
+    let arg = &e.call.args[0];
+    let run = if method.ends_with("_key") {
+        quote_spanned! {span=> run_by_key}
+    } else {
+        quote_spanned! {span=> run_by}
+    };
+    syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_min_max::#run(
+                #mutator_id,
+                #max,
+                #receiver,
+                #arg,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationMinMax {
+    Swap,
+    Invert,
+}
+
+impl MutationMinMax {
+    fn possible_mutations() -> Vec<Self> {
+        vec![MutationMinMax::Swap, MutationMinMax::Invert]
+    }
+
+    fn describe(self, method: &str, arg: &str) -> String {
+        match self {
+            MutationMinMax::Swap => {
+                let swapped = if method.starts_with("min") {
+                    method.replacen("min", "max", 1)
+                } else {
+                    method.replacen("max", "min", 1)
+                };
+                format!("{}({})", swapped, arg)
+            }
+            MutationMinMax::Invert if method.ends_with("_key") => {
+                format!("{}(|x| std::cmp::Reverse(({})(x)))", method, arg)
+            }
+            MutationMinMax::Invert => format!("{}(|a, b| ({})(a, b).reverse())", method, arg),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprMinMax {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprMinMax {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if ["min_by", "max_by", "min_by_key", "max_by_key"]
+                    .iter()
+                    .any(|m| call.method == m)
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprMinMax {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn min_by_inactive() {
+        let v = [3, 1, 2];
+        let result = run_by(
+            1,
+            false,
+            v.iter(),
+            |a, b| a.cmp(b),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, Some(&1));
+    }
+    #[test]
+    fn min_by_active1() {
+        let v = [3, 1, 2];
+        let result = run_by(
+            1,
+            false,
+            v.iter(),
+            |a, b| a.cmp(b),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, Some(&3));
+    }
+    #[test]
+    fn min_by_active2() {
+        let v = [3, 1, 2];
+        let result = run_by(
+            1,
+            false,
+            v.iter(),
+            |a, b| a.cmp(b),
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, Some(&3));
+    }
+    #[test]
+    fn max_by_key_inactive() {
+        let v = [(1, 'a'), (3, 'b'), (3, 'c')];
+        let result = run_by_key(
+            1,
+            true,
+            v.iter(),
+            |x| x.0,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, Some(&(3, 'c')));
+    }
+    #[test]
+    fn max_by_key_active1() {
+        let v = [(1, 'a'), (3, 'b'), (3, 'c')];
+        let result = run_by_key(
+            1,
+            true,
+            v.iter(),
+            |x| x.0,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, Some(&(1, 'a')));
+    }
+    #[test]
+    fn max_by_key_active2() {
+        let v = [(1, 'a'), (1, 'b'), (3, 'c')];
+        let result = run_by_key(
+            1,
+            true,
+            v.iter(),
+            |x| x.0,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, Some(&(1, 'b')));
+    }
+
+    #[test]
+    fn detect_min_by_key() {
+        let expr: Expr = parse_quote! {v.iter().min_by_key(|x| x.len())};
+        assert!(ExprMinMax::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_min() {
+        let expr: Expr = parse_quote! {v.iter().min()};
+        assert!(ExprMinMax::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_ord_max() {
+        let expr: Expr = parse_quote! {a.max(b)};
+        assert!(ExprMinMax::try_from(expr).is_err());
+    }
+}
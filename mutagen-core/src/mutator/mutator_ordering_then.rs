@@ -0,0 +1,202 @@
+//! Mutator for orderings composed with `then` or `then_with`, like `a.cmp(&b).then(c.cmp(&d))`.
+//!
+//! The mutation drops the secondary ordering, which tests whether the tie-break matters.
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// runs `ordering.then(other)`, or returns `ordering` if the mutation is active
+pub fn run_then(
+    mutator_id: usize,
+    ordering: Ordering,
+    other: Ordering,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Ordering {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        ordering
+    } else {
+        ordering.then(other)
+    }
+}
+
+/// runs `ordering.then_with(f)`, or returns `ordering` if the mutation is active
+pub fn run_then_with<F: FnOnce() -> Ordering>(
+    mutator_id: usize,
+    ordering: Ordering,
+    f: F,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Ordering {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        ordering
+    } else {
+        ordering.then_with(f)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the receiver is inspected as written in the original code, since nested chains have already been transformed
+    let original = match context
+        .original_expr
+        .clone()
+        .map(ExprOrderingThen::try_from)
+    {
+        Some(Ok(original)) if is_ordering(&original.call.receiver) => original,
+        _ => return e,
+    };
+    let e = match ExprOrderingThen::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    let original_receiver = original.call.receiver.to_token_stream().to_string();
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "ordering_then".to_owned(),
+        format!(
+            "{}.{}({})",
+            original_receiver,
+            original.call.method,
+            original.call.args[0].to_token_stream()
+        ),
+        original_receiver,
+        e.span,
+    ));
+
+    let receiver = &e.call.receiver;
+    let arg = &e.call.args[0];
+    let run_fn = if e.call.method == "then" {
+        quote_spanned! {e.span=> run_then}
+    } else {
+        quote_spanned! {e.span=> run_then_with}
+    };
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_ordering_then::#run_fn(
+                #mutator_id,
+                #receiver,
+                #arg,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// checks if the expression is known to be an `Ordering`.
+///
+/// The method `then` exists on `bool` as well. Calls are only mutated if the receiver is a comparison or an ordering.
+fn is_ordering(e: &Expr) -> bool {
+    match e {
+        Expr::MethodCall(call) => match &*call.method.to_string() {
+            "cmp" => call.args.len() == 1,
+            "then" | "then_with" => call.args.len() == 1 && is_ordering(&call.receiver),
+            "reverse" => call.args.is_empty() && is_ordering(&call.receiver),
+            _ => false,
+        },
+        Expr::Path(path) => path
+            .path
+            .segments
+            .iter()
+            .rev()
+            .nth(1)
+            .map(|segment| segment.ident == "Ordering")
+            .unwrap_or(false),
+        Expr::Paren(paren) => is_ordering(&paren.expr),
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprOrderingThen {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprOrderingThen {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (call.method == "then" || call.method == "then_with")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprOrderingThen {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn then_inactive() {
+        let result = run_then(
+            1,
+            Ordering::Equal,
+            Ordering::Less,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, Ordering::Less);
+    }
+    #[test]
+    fn then_active() {
+        let result = run_then(
+            1,
+            Ordering::Equal,
+            Ordering::Less,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, Ordering::Equal);
+    }
+    #[test]
+    fn then_with_active() {
+        let result = run_then_with(
+            1,
+            Ordering::Equal,
+            || panic!("secondary ordering evaluated"),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, Ordering::Equal);
+    }
+
+    #[test]
+    fn ordering_nested_then() {
+        let expr: Expr = parse_quote! {a.cmp(&b).then(c.cmp(&d)).then_with(|| e.cmp(&f))};
+        assert!(is_ordering(&expr));
+    }
+    #[test]
+    fn ordering_path() {
+        let expr: Expr = parse_quote! {std::cmp::Ordering::Equal};
+        assert!(is_ordering(&expr));
+    }
+    #[test]
+    fn bool_not_ordering() {
+        let expr: Expr = parse_quote! {(x > 0)};
+        assert!(!is_ordering(&expr));
+    }
+}
@@ -0,0 +1,332 @@
+//! Mutator for the overflow policy of additions and subtractions, like `deadline.checked_sub(elapsed)` or `a.saturating_add(b)`.
+//!
+//! Checked operations are replaced with plain and saturating operations, whose result is wrapped in `Some`.
+//! Saturating operations are replaced with plain operations, which panic on overflow.
+//! This tests the handling of overflows, e.g. for arithmetic on `Duration`.
+
+use std::convert::TryFrom;
+use std::ops::{Add, Deref, Sub};
+use std::time::Duration;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, Ident};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Returns the index of the overflow policy that is used instead of the original one, if any.
+///
+/// The index `0` refers to the plain operation and `1` to the saturating operation.
+pub fn run(
+    mutator_id: usize,
+    num_mutations: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<usize> {
+    runtime.covered(mutator_id);
+    runtime
+        .get_mutation_for_mutator(mutator_id, &[0, 1][..num_mutations])
+        .copied()
+}
+
+/// performs the addition `left + right`, which panics on overflow
+pub fn plain_add<L, R, O>(left: L, right: R) -> O {
+    left.may_add(right)
+}
+
+/// performs the subtraction `left - right`, which panics on overflow
+pub fn plain_sub<L, R, O>(left: L, right: R) -> O {
+    left.may_sub(right)
+}
+
+/// performs the addition `left.saturating_add(right)`
+pub fn saturating_add<L, R, O>(left: L, right: R) -> O {
+    left.may_saturating_add(right)
+}
+
+/// performs the subtraction `left.saturating_sub(right)`
+pub fn saturating_sub<L, R, O>(left: L, right: R) -> O {
+    left.may_saturating_sub(right)
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the operation is detected in the original code, since it may have been replaced by the mutator `checked_none`
+    let original = match context
+        .original_expr
+        .clone()
+        .map(ExprOverflowPolicy::try_from)
+    {
+        Some(Ok(original)) => original,
+        _ => return e,
+    };
+    // checked operations that are unwrapped immediately are mutated by `checked_unwrap`
+    if original.checked && context.is_unwrapped() {
+        return e;
+    }
+
+    let op = original.op();
+    let receiver = &original.call.receiver;
+    let arg = &original.call.args[0];
+    let receiver_code = receiver.to_token_stream().to_string();
+    let arg_code = arg.to_token_stream().to_string();
+    let mut mutated_code = vec![format!("{} {} {}", receiver_code, op.symbol(), arg_code)];
+    if original.checked {
+        mutated_code.push(format!(
+            "{}.saturating_{}({})",
+            receiver_code,
+            op.name(),
+            arg_code
+        ));
+    }
+    let num_mutations = mutated_code.len();
+
+    // methods with these names can be defined on any type. These mutants are skipped.
+    let mutator_id = transform_info.add_mutations(mutated_code.into_iter().map(|mutated| {
+        let mutated = if original.checked {
+            format!("Some({})", mutated)
+        } else {
+            mutated
+        };
+        Mutation::new_spanned(
+            context,
+            "overflow_policy".to_owned(),
+            format!("{}.{}({})", receiver_code, original.call.method, arg_code),
+            mutated,
+            original.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip)
+    }));
+
+    // the replacements are computed from the original operands. They are only evaluated if one of the mutations is active
+    let span = original.span;
+    let plain = Ident::new(&format!("plain_{}", op.name()), span);
+    let saturating = Ident::new(&format!("saturating_{}", op.name()), span);
+    let mutated_arms = if original.checked {
+        quote_spanned! {span=>
+            ::core::option::Option::Some(0) => ::core::option::Option::Some(
+                ::mutagen::mutator::mutator_overflow_policy::#plain(#receiver, #arg)
+            ),
+            ::core::option::Option::Some(_) => ::core::option::Option::Some(
+                ::mutagen::mutator::mutator_overflow_policy::#saturating(#receiver, #arg)
+            ),
+        }
+    } else {
+        quote_spanned! {span=>
+            ::core::option::Option::Some(_) =>
+                ::mutagen::mutator::mutator_overflow_policy::#plain(#receiver, #arg),
+        }
+    };
+    syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_overflow_policy::run(
+                #mutator_id,
+                #num_mutations,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+            #mutated_arms
+            ::core::option::Option::None => #e,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum OverflowOp {
+    Add,
+    Sub,
+}
+
+impl OverflowOp {
+    fn name(self) -> &'static str {
+        match self {
+            OverflowOp::Add => "add",
+            OverflowOp::Sub => "sub",
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            OverflowOp::Add => "+",
+            OverflowOp::Sub => "-",
+        }
+    }
+}
+
+/// trait that is used to optimistically perform a plain addition or subtraction.
+///
+/// The operands are required to implement `Add` or `Sub` with the result type of the original operation.
+/// Otherwise, the optimistic assumption fails.
+trait MayPlain<R, O> {
+    fn may_add(self, right: R) -> O;
+    fn may_sub(self, right: R) -> O;
+}
+
+impl<L, R, O> MayPlain<R, O> for L {
+    default fn may_add(self, _right: R) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+    default fn may_sub(self, _right: R) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<L, R, O> MayPlain<R, O> for L
+where
+    L: Add<R, Output = O> + Sub<R, Output = O>,
+{
+    fn may_add(self, right: R) -> O {
+        self + right
+    }
+    fn may_sub(self, right: R) -> O {
+        self - right
+    }
+}
+
+/// trait that is used to optimistically perform a saturating addition or subtraction.
+///
+/// Only integers and durations can be added and subtracted with saturation.
+/// Otherwise, the optimistic assumption fails.
+trait MaySaturating<R, O> {
+    fn may_saturating_add(self, right: R) -> O;
+    fn may_saturating_sub(self, right: R) -> O;
+}
+
+impl<L, R, O> MaySaturating<R, O> for L {
+    default fn may_saturating_add(self, _right: R) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+    default fn may_saturating_sub(self, _right: R) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+macro_rules! saturating_impl {
+    ($($t:ty),*) => {
+        $(
+            impl MaySaturating<$t, $t> for $t {
+                fn may_saturating_add(self, right: $t) -> $t {
+                    self.saturating_add(right)
+                }
+                fn may_saturating_sub(self, right: $t) -> $t {
+                    self.saturating_sub(right)
+                }
+            }
+        )*
+    };
+}
+
+saturating_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, Duration);
+
+#[derive(Clone, Debug)]
+struct ExprOverflowPolicy {
+    call: ExprMethodCall,
+    /// whether the original operation is checked or saturating
+    checked: bool,
+    span: Span,
+}
+
+impl ExprOverflowPolicy {
+    fn op(&self) -> OverflowOp {
+        if self.call.method.to_string().ends_with("add") {
+            OverflowOp::Add
+        } else {
+            OverflowOp::Sub
+        }
+    }
+}
+
+impl TryFrom<Expr> for ExprOverflowPolicy {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if [
+                    "checked_add",
+                    "checked_sub",
+                    "saturating_add",
+                    "saturating_sub",
+                ]
+                .iter()
+                .any(|m| call.method == m)
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprOverflowPolicy {
+                    checked: call.method.to_string().starts_with("checked"),
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn overflow_policy_inactive() {
+        let result = run(1, 2, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, None);
+    }
+    #[test]
+    fn overflow_policy_active2() {
+        let result = run(1, 2, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn plain_sub_duration() {
+        let result: Duration = plain_sub(Duration::from_secs(3), Duration::from_secs(1));
+        assert_eq!(result, Duration::from_secs(2));
+    }
+    #[test]
+    #[should_panic]
+    fn plain_sub_duration_underflow() {
+        let _: Duration = plain_sub(Duration::from_secs(1), Duration::from_secs(3));
+    }
+    #[test]
+    fn saturating_sub_duration() {
+        let result: Duration = saturating_sub(Duration::from_secs(1), Duration::from_secs(3));
+        assert_eq!(result, Duration::from_secs(0));
+    }
+    #[test]
+    fn saturating_add_int() {
+        let result: u8 = saturating_add(250u8, 10u8);
+        assert_eq!(result, 255);
+    }
+    #[test]
+    #[should_panic]
+    fn saturating_add_other_type() {
+        let _: String = saturating_add(String::new(), "a");
+    }
+
+    #[test]
+    fn detect_checked_sub() {
+        let expr: Expr = parse_quote! {deadline.checked_sub(elapsed)};
+        assert!(ExprOverflowPolicy::try_from(expr).unwrap().checked);
+    }
+    #[test]
+    fn detect_saturating_add() {
+        let expr: Expr = parse_quote! {a.saturating_add(b)};
+        let expr = ExprOverflowPolicy::try_from(expr).unwrap();
+        assert!(!expr.checked);
+        assert_eq!(expr.op(), OverflowOp::Add);
+    }
+    #[test]
+    fn ignore_checked_mul() {
+        let expr: Expr = parse_quote! {a.checked_mul(b)};
+        assert!(ExprOverflowPolicy::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,445 @@
+//! Mutator for the exponents of `pow(n)`, `powi(n)` and `powf(x)`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, Lit};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<B, T>(
+    mutator_id: usize,
+    base: &B,
+    exponent: T,
+    mutations: &[MutationPow],
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, mutations) {
+        let exponent = exponent.may_mutate(*m);
+        // a mutant that overflows is skipped instead of being killed by the panic of the overflow
+        if base.pow_overflows(&exponent) {
+            runtime.optimistic_assmuption_failed();
+        }
+        exponent
+    } else {
+        exponent
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprPow::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the exponent is read before other mutators have been applied to it
+    let original_exponent =
+        original_exponent(context).unwrap_or_else(|| e.exponent.to_token_stream().to_string());
+    let literal_exponent = original_exponent.parse::<f64>().ok();
+
+    let mutations = MutationPow::possible_mutations(literal_exponent, &e.method.to_string());
+    if mutations.is_empty() {
+        return e.into_expr();
+    }
+
+    let mutator_id = transform_info.add_mutations(
+        mutations
+            .iter()
+            .map(|m| m.to_mutation(&e, &original_exponent, literal_exponent, context)),
+    );
+
+    let receiver = &e.receiver;
+    let method = &e.method;
+    let exponent = &e.exponent;
+    let mutations = mutations.iter().map(|m| m.to_tokens(e.span));
+
+    // the base is bound by reference to check the mutated exponent for overflows without moving the receiver
+    syn::parse2(quote_spanned! {e.span=>
+        match #receiver {
+            ref __mutagen_base => __mutagen_base.#method(
+                ::mutagen::mutator::mutator_pow::run(
+                        #mutator_id,
+                        __mutagen_base,
+                        #exponent,
+                        &[#(#mutations),*],
+                        ::mutagen::MutagenRuntimeConfig::get_default()
+                    )
+            )
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// the exponent as written in the original code, before other mutators have been applied to it.
+fn original_exponent(context: &TransformContext) -> Option<String> {
+    match &context.original_expr {
+        Some(Expr::MethodCall(call)) => call.args.first().map(|arg| match arg {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit.base10_digits().to_owned(),
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Float(lit),
+                ..
+            }) => lit.base10_digits().to_owned(),
+            _ => arg.to_token_stream().to_string(),
+        }),
+        _ => None,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MutationPow {
+    Relative(i8),
+    Zero,
+    One,
+}
+
+impl MutationPow {
+    /// the mutations of an exponent.
+    ///
+    /// If the exponent is a literal, mutations that do not change the exponent or duplicate other mutations are left out.
+    fn possible_mutations(literal_exponent: Option<f64>, method: &str) -> Vec<Self> {
+        let candidates = vec![
+            MutationPow::Relative(1),
+            MutationPow::Relative(-1),
+            MutationPow::Zero,
+            MutationPow::One,
+        ];
+        let exponent = match literal_exponent {
+            Some(exponent) => exponent,
+            None => return candidates,
+        };
+        let mut mutations = vec![];
+        let mut mutated_exponents = vec![exponent];
+        for m in candidates {
+            let mut mutated = m.mutate_f64(exponent);
+            // the exponent of `pow` is unsigned
+            if method == "pow" && mutated < 0.0 {
+                mutated = 0.0;
+            }
+            if !mutated_exponents.contains(&mutated) {
+                mutated_exponents.push(mutated);
+                mutations.push(m);
+            }
+        }
+        mutations
+    }
+
+    fn mutate_f64(self, exponent: f64) -> f64 {
+        match self {
+            MutationPow::Relative(r) => exponent + f64::from(r),
+            MutationPow::Zero => 0.0,
+            MutationPow::One => 1.0,
+        }
+    }
+
+    fn to_mutation(
+        self,
+        original: &ExprPow,
+        original_exponent: &str,
+        literal_exponent: Option<f64>,
+        context: &TransformContext,
+    ) -> Mutation {
+        let mutated_exponent = match (self, literal_exponent) {
+            (MutationPow::Relative(_), Some(exponent)) if original.method == "powf" => {
+                format!("{:?}", self.mutate_f64(exponent))
+            }
+            (MutationPow::Relative(_), Some(exponent)) => format!("{}", self.mutate_f64(exponent)),
+            (MutationPow::Relative(r), None) if r < 0 => {
+                format!("{} - {}", original_exponent, -r)
+            }
+            (MutationPow::Relative(r), None) => format!("{} + {}", original_exponent, r),
+            (MutationPow::Zero, _) => "0".to_owned(),
+            (MutationPow::One, _) => "1".to_owned(),
+        };
+        let mutation = Mutation::new_spanned(
+            context,
+            "pow".to_owned(),
+            format!("{}({})", original.method, original_exponent),
+            format!("{}({})", original.method, mutated_exponent),
+            original.span,
+        );
+        // only an increased exponent can overflow the result
+        if self == MutationPow::Relative(1) {
+            mutation.with_optimistic_policy(OptimisticPolicy::Skip)
+        } else {
+            mutation
+        }
+    }
+
+    fn to_tokens(self, span: Span) -> TokenStream {
+        match self {
+            MutationPow::Relative(r) => quote_spanned! {span=>
+                ::mutagen::mutator::mutator_pow::MutationPow::Relative(#r)
+            },
+            MutationPow::Zero => quote_spanned! {span=>
+                ::mutagen::mutator::mutator_pow::MutationPow::Zero
+            },
+            MutationPow::One => quote_spanned! {span=>
+                ::mutagen::mutator::mutator_pow::MutationPow::One
+            },
+        }
+    }
+}
+
+/// trait that is used to optimistically mutate an exponent.
+///
+/// Methods named `pow`, `powi` and `powf` can be defined on any type and take any argument.
+/// The exponent can only be mutated if it is a number, otherwise the optimistic assumption fails.
+pub trait ExponentMutable {
+    fn may_mutate(self, m: MutationPow) -> Self;
+}
+
+impl<T> ExponentMutable for T {
+    default fn may_mutate(self, _m: MutationPow) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+macro_rules! exponent_mutable_ints {
+    { $($ty:ident,)* } => {
+        $(
+            impl ExponentMutable for $ty {
+                fn may_mutate(self, m: MutationPow) -> Self {
+                    // the exponent saturates to avoid overflows
+                    match m {
+                        MutationPow::Relative(r) if r < 0 => self.saturating_sub(-r as $ty),
+                        MutationPow::Relative(r) => self.saturating_add(r as $ty),
+                        MutationPow::Zero => 0,
+                        MutationPow::One => 1,
+                    }
+                }
+            }
+        )*
+    }
+}
+
+exponent_mutable_ints! {
+    u32,
+    i32,
+}
+
+macro_rules! exponent_mutable_floats {
+    { $($ty:ident,)* } => {
+        $(
+            impl ExponentMutable for $ty {
+                fn may_mutate(self, m: MutationPow) -> Self {
+                    match m {
+                        MutationPow::Relative(r) => self + $ty::from(r),
+                        MutationPow::Zero => 0.0,
+                        MutationPow::One => 1.0,
+                    }
+                }
+            }
+        )*
+    }
+}
+
+exponent_mutable_floats! {
+    f32,
+    f64,
+}
+
+/// trait that is used to check if a mutated exponent overflows the result of `pow`.
+///
+/// Only integer bases with exponents of type `u32` can overflow, other results are never checked.
+pub trait PowOverflow<T> {
+    fn pow_overflows(&self, exponent: &T) -> bool;
+}
+
+impl<B, T> PowOverflow<T> for B {
+    default fn pow_overflows(&self, _exponent: &T) -> bool {
+        false
+    }
+}
+
+macro_rules! pow_overflow_ints {
+    { $($ty:ident,)* } => {
+        $(
+            impl PowOverflow<u32> for $ty {
+                fn pow_overflows(&self, exponent: &u32) -> bool {
+                    self.checked_pow(*exponent).is_none()
+                }
+            }
+        )*
+    }
+}
+
+pow_overflow_ints! {
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+}
+
+#[derive(Clone, Debug)]
+struct ExprPow {
+    receiver: Expr,
+    method: syn::Ident,
+    exponent: Expr,
+    span: Span,
+}
+
+impl ExprPow {
+    fn into_expr(self) -> Expr {
+        let receiver = &self.receiver;
+        let method = &self.method;
+        let exponent = &self.exponent;
+        syn::parse2(quote_spanned! {self.span=> #receiver.#method(#exponent)})
+            .expect("transformed code invalid")
+    }
+}
+
+impl TryFrom<Expr> for ExprPow {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (call.method == "pow" || call.method == "powi" || call.method == "powf")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                let ExprMethodCall {
+                    receiver,
+                    method,
+                    args,
+                    ..
+                } = call;
+                Ok(ExprPow {
+                    receiver: *receiver,
+                    span: method.span(),
+                    method,
+                    exponent: args.into_iter().next().unwrap(),
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn possible_mutations_literal() {
+        assert_eq!(
+            MutationPow::possible_mutations(Some(3.0), "pow"),
+            vec![
+                MutationPow::Relative(1),
+                MutationPow::Relative(-1),
+                MutationPow::Zero,
+                MutationPow::One
+            ]
+        );
+    }
+    #[test]
+    fn possible_mutations_literal_one() {
+        assert_eq!(
+            MutationPow::possible_mutations(Some(1.0), "pow"),
+            vec![MutationPow::Relative(1), MutationPow::Relative(-1)]
+        );
+    }
+    #[test]
+    fn possible_mutations_literal_zero_unsigned() {
+        assert_eq!(
+            MutationPow::possible_mutations(Some(0.0), "pow"),
+            vec![MutationPow::Relative(1)]
+        );
+    }
+    #[test]
+    fn possible_mutations_literal_zero_signed() {
+        assert_eq!(
+            MutationPow::possible_mutations(Some(0.0), "powi"),
+            vec![MutationPow::Relative(1), MutationPow::Relative(-1)]
+        );
+    }
+
+    #[test]
+    fn pow_inactive() {
+        let mutations = MutationPow::possible_mutations(None, "pow");
+        let exponent = run(
+            1,
+            &2u32,
+            3u32,
+            &mutations,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(exponent, 3);
+    }
+    #[test]
+    fn pow_active() {
+        let mutations = MutationPow::possible_mutations(None, "pow");
+        let exponents = (1..=4)
+            .map(|id| {
+                run(
+                    1,
+                    &2u32,
+                    3u32,
+                    &mutations,
+                    &MutagenRuntimeConfig::with_mutation_id(id),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(exponents, vec![4, 2, 0, 1]);
+    }
+    #[test]
+    fn pow_zero_decrement_saturates() {
+        assert_eq!(0u32.may_mutate(MutationPow::Relative(-1)), 0);
+    }
+    #[test]
+    fn pow_overflow_detected() {
+        assert!(2u8.pow_overflows(&8u32));
+        assert!(!2u8.pow_overflows(&7u32));
+        assert!(!2.0f64.pow_overflows(&2000i32));
+    }
+    #[test]
+    #[should_panic]
+    fn pow_overflow_active() {
+        run(
+            1,
+            &2u8,
+            7u32,
+            &[MutationPow::Relative(1)],
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+    #[test]
+    fn powf_active() {
+        let exponent = 0.5f64.may_mutate(MutationPow::Relative(-1));
+        assert_eq!(exponent, -0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pow_non_number_active() {
+        run(
+            1,
+            &2u32,
+            "x",
+            &[MutationPow::Zero],
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+}
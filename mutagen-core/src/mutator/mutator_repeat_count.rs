@@ -0,0 +1,250 @@
+//! Mutator for repeat counts, like `vec![x; n]` or `s.repeat(n)`.
+//!
+//! The count is increased by 1, decreased by 1 or replaced with `0`.
+//! This tests assumptions about the length of the repeated sequence.
+//! Counts of `iter::repeat(x).take(n)` are mutated by the mutator `iter_count`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::parse::ParseStream;
+use syn::{Expr, ExprMacro, ExprMethodCall, Token};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::mutator::mutator_lit_int::IntMutable;
+use crate::MutagenRuntimeConfig;
+
+pub fn run<T>(
+    mutator_id: usize,
+    count: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, &MutationRepeatCount::ALL) {
+        count.may_perturb(*m)
+    } else {
+        count
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprRepeatCount::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the count is described as written in the original code, before other mutators have been applied to it.
+    // The content of `vec!` is not transformed by other mutators
+    let original_count = match context.original_expr.clone().map(ExprRepeatCount::try_from) {
+        Some(Ok(original)) => original.count,
+        _ => e.count.clone(),
+    };
+    let original_count = original_count.to_token_stream().to_string();
+
+    let mutator_id = transform_info.add_mutations(
+        MutationRepeatCount::ALL
+            .iter()
+            .map(|m| m.to_mutation(&e, &original_count, context)),
+    );
+
+    let span = e.span;
+    let count = &e.count;
+    let mutated_count = quote_spanned! {span=>
+        ::mutagen::mutator::mutator_repeat_count::run(
+                #mutator_id,
+                #count,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    };
+    match e.kind {
+        RepeatKind::Method(mut call) => {
+            call.args[0] = syn::parse2(mutated_count).expect("transformed code invalid");
+            Expr::MethodCall(call)
+        }
+        RepeatKind::Vec(mut mac, elem) => {
+            mac.mac.tokens = quote_spanned! {span=> #elem; #mutated_count};
+            Expr::Macro(mac)
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationRepeatCount {
+    Increment,
+    Decrement,
+    Zero,
+}
+
+impl MutationRepeatCount {
+    const ALL: [Self; 3] = [
+        MutationRepeatCount::Increment,
+        MutationRepeatCount::Decrement,
+        MutationRepeatCount::Zero,
+    ];
+
+    fn mutate<T: IntMutable>(self, count: T) -> T {
+        let count = count.as_u128();
+        T::from_u128(match self {
+            Self::Increment => count.saturating_add(1),
+            Self::Decrement => count.saturating_sub(1),
+            Self::Zero => 0,
+        })
+    }
+
+    fn to_mutation(
+        self,
+        original: &ExprRepeatCount,
+        original_count: &str,
+        context: &TransformContext,
+    ) -> Mutation {
+        let mutated_count = match self {
+            Self::Increment => format!("{} + 1", original_count),
+            Self::Decrement => format!("{} - 1", original_count),
+            Self::Zero => "0".to_owned(),
+        };
+        let describe = |count: &str| match &original.kind {
+            RepeatKind::Method(_) => format!("repeat({})", count),
+            RepeatKind::Vec(_, elem) => format!("vec![{}; {}]", elem.to_token_stream(), count),
+        };
+        Mutation::new_spanned(
+            context,
+            "repeat_count".to_owned(),
+            describe(original_count),
+            describe(&mutated_count),
+            original.span,
+        )
+    }
+}
+
+/// trait that is used to optimistically perturb a repeat count.
+///
+/// Methods named `repeat` can be defined on any type and take any argument.
+/// The count can only be perturbed if it is an integer, otherwise the optimistic assumption fails.
+trait CountMutable: Sized {
+    fn may_perturb(self, m: MutationRepeatCount) -> Self;
+}
+
+impl<T> CountMutable for T {
+    default fn may_perturb(self, _m: MutationRepeatCount) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: IntMutable> CountMutable for T {
+    fn may_perturb(self, m: MutationRepeatCount) -> Self {
+        m.mutate(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum RepeatKind {
+    /// a method call like `s.repeat(n)`
+    Method(ExprMethodCall),
+    /// a macro call `vec![x; n]` with its repeated element
+    Vec(ExprMacro, Box<Expr>),
+}
+
+#[derive(Clone, Debug)]
+struct ExprRepeatCount {
+    kind: RepeatKind,
+    count: Expr,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprRepeatCount {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if call.method == "repeat" && call.args.len() == 1 && call.turbofish.is_none() =>
+            {
+                Ok(ExprRepeatCount {
+                    count: call.args[0].clone(),
+                    span: call.method.span(),
+                    kind: RepeatKind::Method(call),
+                })
+            }
+            Expr::Macro(mac) if mac.mac.path.is_ident("vec") => {
+                let parsed = mac.mac.parse_body_with(|input: ParseStream| {
+                    let elem: Expr = input.parse()?;
+                    input.parse::<Token![;]>()?;
+                    let count: Expr = input.parse()?;
+                    Ok((elem, count))
+                });
+                match parsed {
+                    Ok((elem, count)) => Ok(ExprRepeatCount {
+                        count,
+                        span: mac.mac.path.segments[0].ident.span(),
+                        kind: RepeatKind::Vec(mac, Box::new(elem)),
+                    }),
+                    Err(_) => Err(Expr::Macro(mac)),
+                }
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn repeat_count_inactive() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 3);
+    }
+    #[test]
+    fn repeat_count_active1() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn repeat_count_active2() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 2);
+    }
+    #[test]
+    fn repeat_count_active3() {
+        let result = run(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(3));
+        assert_eq!(result, 0);
+    }
+    #[test]
+    fn decrement_zero_saturates() {
+        assert_eq!(MutationRepeatCount::Decrement.mutate(0usize), 0);
+    }
+    #[test]
+    #[should_panic]
+    fn repeat_count_non_int_active() {
+        run(1, "x", &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_str_repeat() {
+        let expr: Expr = parse_quote! {"ab".repeat(n)};
+        assert!(ExprRepeatCount::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_vec_repeat() {
+        let expr: Expr = parse_quote! {vec![0u8; len + 1]};
+        let expr = ExprRepeatCount::try_from(expr).unwrap();
+        assert_eq!(expr.count, parse_quote! {len + 1});
+    }
+    #[test]
+    fn ignore_vec_list() {
+        let expr: Expr = parse_quote! {vec![1, 2, 3]};
+        assert!(ExprRepeatCount::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,344 @@
+//! Mutator for the arguments of `resize(n, val)`, `resize_with(n, f)` and `fill(val)`.
+//!
+//! The size `n` is perturbed and a value `val` is replaced with its default.
+//! Literal values are left to the literal mutators, like `lit_int` or `lit_bool`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, UnOp};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the size, perturbed if one of the mutations is active
+pub fn run_size<T>(
+    mutator_id: usize,
+    size: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    let mutations = MutationSize::possible_mutations();
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        size.may_perturb(*m)
+    } else {
+        size
+    }
+}
+
+/// returns the value, or its default if the mutation is active
+pub fn run_value<T>(
+    mutator_id: usize,
+    value: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        T::may_default()
+    } else {
+        value
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprResizeFill::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the arguments are described as written in the original code, before other mutators have been applied to them
+    let original_args = match context.original_expr.clone().map(ExprResizeFill::try_from) {
+        Some(Ok(original)) => original.call.args,
+        _ => e.call.args.clone(),
+    };
+    let method = e.call.method.to_string();
+    let span = e.span;
+
+    if let Some(size_index) = e.size_index() {
+        let original_size = original_args[size_index].to_token_stream().to_string();
+        let mutations = MutationSize::possible_mutations();
+        // a size of `0` is only incremented
+        let mutations = if is_lit_zero(&e.call.args[size_index]) {
+            &mutations[..1]
+        } else {
+            &mutations[..]
+        };
+        let mutator_id = transform_info.add_mutations(mutations.iter().map(|m| {
+            Mutation::new_spanned(
+                context,
+                "resize_fill".to_owned(),
+                format!("{}({}, _)", method, original_size),
+                format!("{}({}, _)", method, m.describe(&original_size)),
+                span,
+            )
+        }));
+        let size = &mut e.call.args[size_index];
+        *size = syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_resize_fill::run_size(
+                    #mutator_id,
+                    #size,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid");
+    }
+
+    if let Some(value_index) = e.value_index() {
+        if !is_literal(&original_args[value_index]) {
+            let original_value = original_args[value_index].to_token_stream().to_string();
+            let describe = |value: &str| match value_index {
+                0 => format!("{}({})", method, value),
+                _ => format!("{}(_, {})", method, value),
+            };
+            let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+                context,
+                "resize_fill".to_owned(),
+                describe(&original_value),
+                describe("Default::default()"),
+                span,
+            ));
+            let value = &mut e.call.args[value_index];
+            *value = syn::parse2(quote_spanned! {span=>
+                ::mutagen::mutator::mutator_resize_fill::run_value(
+                        #mutator_id,
+                        #value,
+                        ::mutagen::MutagenRuntimeConfig::get_default()
+                    )
+            })
+            .expect("transformed code invalid");
+        }
+    }
+
+    Expr::MethodCall(e.call)
+}
+
+/// checks if the expression is a literal, possibly negated
+fn is_literal(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(_) => true,
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => is_literal(&unary.expr),
+        Expr::Paren(paren) => is_literal(&paren.expr),
+        _ => false,
+    }
+}
+
+/// checks if the expression is the literal `0`
+fn is_lit_zero(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse::<u128>().ok() == Some(0),
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationSize {
+    Increment,
+    Decrement,
+    Zero,
+}
+
+impl MutationSize {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationSize::Increment,
+            MutationSize::Decrement,
+            MutationSize::Zero,
+        ]
+    }
+
+    /// Changes the size.
+    ///
+    /// Decrementing `0` gives `0`, such that no huge buffer is allocated.
+    fn mutate(self, size: usize) -> usize {
+        match self {
+            MutationSize::Increment => size.saturating_add(1),
+            MutationSize::Decrement => size.saturating_sub(1),
+            MutationSize::Zero => 0,
+        }
+    }
+
+    fn describe(self, size: &str) -> String {
+        match self {
+            MutationSize::Increment => format!("{} + 1", size),
+            MutationSize::Decrement => format!("{} - 1", size),
+            MutationSize::Zero => "0".to_owned(),
+        }
+    }
+}
+
+/// trait that is used to optimistically perturb a size.
+///
+/// Methods like `resize` can be defined on any type and take any argument.
+/// The size can only be perturbed if it is a `usize`, otherwise the optimistic assumption fails.
+trait SizeMutable {
+    fn may_perturb(self, m: MutationSize) -> Self;
+}
+
+impl<T> SizeMutable for T {
+    default fn may_perturb(self, _m: MutationSize) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl SizeMutable for usize {
+    fn may_perturb(self, m: MutationSize) -> Self {
+        m.mutate(self)
+    }
+}
+
+/// trait that is used to optimistically replace a value with its default.
+///
+/// If the type of the value does not implement `Default`, the optimistic assumption fails.
+trait MayDefault {
+    fn may_default() -> Self;
+}
+
+impl<T> MayDefault for T {
+    default fn may_default() -> T {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Default> MayDefault for T {
+    fn may_default() -> T {
+        T::default()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprResizeFill {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl ExprResizeFill {
+    /// the position of the argument that is the new size
+    fn size_index(&self) -> Option<usize> {
+        if self.call.method == "fill" {
+            None
+        } else {
+            Some(0)
+        }
+    }
+
+    /// the position of the argument that is the fill value
+    fn value_index(&self) -> Option<usize> {
+        if self.call.method == "fill" {
+            Some(0)
+        } else if self.call.method == "resize" {
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
+
+impl TryFrom<Expr> for ExprResizeFill {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call) if call.turbofish.is_none() => {
+                let num_args = match &*call.method.to_string() {
+                    "resize" | "resize_with" => 2,
+                    "fill" => 1,
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                if call.args.len() != num_args {
+                    return Err(Expr::MethodCall(call));
+                }
+                Ok(ExprResizeFill {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn size_inactive() {
+        let result = run_size(1, 3usize, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 3);
+    }
+    #[test]
+    fn size_active1() {
+        let result = run_size(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn size_active2() {
+        let result = run_size(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 2);
+    }
+    #[test]
+    fn size_active3() {
+        let result = run_size(1, 3usize, &MutagenRuntimeConfig::with_mutation_id(3));
+        assert_eq!(result, 0);
+    }
+    #[test]
+    fn size_zero_decrement() {
+        let result = run_size(1, 0usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 0);
+    }
+    #[test]
+    fn value_active() {
+        let result = run_value(
+            1,
+            "x".to_owned(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, "");
+    }
+    #[test]
+    #[should_panic]
+    fn value_without_default_active() {
+        run_value(1, &1, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn resize_arguments() {
+        let expr: Expr = parse_quote! {v.resize(n, x)};
+        let e = ExprResizeFill::try_from(expr).unwrap();
+        assert_eq!(e.size_index(), Some(0));
+        assert_eq!(e.value_index(), Some(1));
+    }
+    #[test]
+    fn resize_with_arguments() {
+        let expr: Expr = parse_quote! {v.resize_with(n, Vec::new)};
+        let e = ExprResizeFill::try_from(expr).unwrap();
+        assert_eq!(e.size_index(), Some(0));
+        assert_eq!(e.value_index(), None);
+    }
+    #[test]
+    fn fill_arguments() {
+        let expr: Expr = parse_quote! {v.fill(x)};
+        let e = ExprResizeFill::try_from(expr).unwrap();
+        assert_eq!(e.size_index(), None);
+        assert_eq!(e.value_index(), Some(0));
+    }
+    #[test]
+    fn literal_negated() {
+        assert!(is_literal(&parse_quote! {-1}));
+        assert!(!is_literal(&parse_quote! {-x}));
+    }
+}
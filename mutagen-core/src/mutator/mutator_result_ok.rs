@@ -0,0 +1,177 @@
+//! Mutator for conversions between `Result` and `Option`, like `r.ok()` or `opt.ok_or(e)`.
+//!
+//! The conversion discards the value: `r.ok()` is replaced with `None` and `opt.ok_or(e)` is replaced with `Err(e)`.
+//! This tests the logic that depends on the converted value.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the given option, which is replaced with `None` if the mutation is active
+pub fn run<T>(
+    mutator_id: usize,
+    value: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        <T as MayNone>::may_none()
+    } else {
+        value
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprResultOk::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original_call = match context.original_expr.clone().map(ExprResultOk::try_from) {
+        Some(Ok(original)) => original.call,
+        _ => e.call.clone(),
+    };
+    let args = original_call.args.to_token_stream().to_string();
+    let mutated = match &*e.call.method.to_string() {
+        "ok" => "None".to_owned(),
+        "ok_or" => format!("Err({})", args),
+        _ => format!("Err(({})())", args),
+    };
+
+    // methods with these names can be defined on any type. These mutants are skipped.
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "result_ok".to_owned(),
+            format!("{}({})", original_call.method, args),
+            mutated,
+            e.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip),
+    );
+
+    let span = e.span;
+    if e.call.method == "ok" {
+        // the option returned by `ok()` is discarded
+        let call = &e.call;
+        syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_result_ok::run(
+                    #mutator_id,
+                    #call,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid")
+    } else {
+        // the option converted by `ok_or` is discarded, such that the error is returned
+        let receiver = &e.call.receiver;
+        *e.call.receiver = syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_result_ok::run(
+                    #mutator_id,
+                    #receiver,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid");
+        Expr::MethodCall(e.call)
+    }
+}
+
+/// trait that is used to optimistically replace an option with `None`.
+///
+/// Only values of type `Option` can be replaced, otherwise the optimistic assumption fails.
+trait MayNone {
+    fn may_none() -> Self;
+}
+
+impl<T> MayNone for T {
+    default fn may_none() -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T> MayNone for Option<T> {
+    fn may_none() -> Self {
+        None
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprResultOk {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprResultOk {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if ((call.method == "ok" && call.args.is_empty())
+                    || ((call.method == "ok_or" || call.method == "ok_or_else")
+                        && call.args.len() == 1))
+                    && call.turbofish.is_none() =>
+            {
+                Ok(ExprResultOk {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn result_ok_inactive() {
+        let result = run(1, Some(5), &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, Some(5));
+    }
+    #[test]
+    fn result_ok_active() {
+        let result = run(1, Some(5), &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, None);
+    }
+    #[test]
+    #[should_panic]
+    fn result_ok_active_other_type() {
+        run(1, 5, &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_ok() {
+        let expr: Expr = parse_quote! {s.parse::<u32>().ok()};
+        assert!(ExprResultOk::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_ok_or_else() {
+        let expr: Expr = parse_quote! {opt.ok_or_else(|| Error::Missing)};
+        assert!(ExprResultOk::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_ok_constructor() {
+        let expr: Expr = parse_quote! {Ok(5)};
+        assert!(ExprResultOk::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+//! Mutator for the predicate of `retain`, like `v.retain(|x| *x > 0)`.
+//!
+//! The mutation inverts the predicate, such that the elements that should be dropped are kept and vice versa.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprClosure, ExprMethodCall};
+
+use crate::comm::Mutation;
+use crate::transformer::ast_inspect::contains_return;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns the result of the predicate, inverted if the mutation is active
+pub fn run(
+    mutator_id: usize,
+    keep: bool,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        !keep
+    } else {
+        keep
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprRetain::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the predicate is described as written in the original code, before other mutators have been applied to it
+    let original_predicate = match context.original_expr.clone().map(ExprRetain::try_from) {
+        Some(Ok(original)) => original.closure,
+        _ => e.closure.clone(),
+    };
+    let inputs = original_predicate.inputs.to_token_stream();
+    let body = original_predicate.body.to_token_stream();
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "retain".to_owned(),
+        format!("retain(|{}| {})", inputs, body),
+        format!("retain(|{}| !({}))", inputs, body),
+        e.span,
+    ));
+
+    let span = e.span;
+    let body = &e.closure.body;
+    *e.closure.body = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_retain::run(
+                #mutator_id,
+                #body,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    e.call.args[0] = Expr::Closure(e.closure);
+    Expr::MethodCall(e.call)
+}
+
+#[derive(Clone, Debug)]
+struct ExprRetain {
+    call: ExprMethodCall,
+    closure: ExprClosure,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprRetain {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if call.method == "retain" && call.args.len() == 1 && call.turbofish.is_none() =>
+            {
+                let closure = match &call.args[0] {
+                    // a `return` in the body would skip the inversion of the result
+                    Expr::Closure(closure) if !contains_return(closure.body.to_token_stream()) => {
+                        closure.clone()
+                    }
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                Ok(ExprRetain {
+                    span: call.method.span(),
+                    call,
+                    closure,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn retain_inactive() {
+        let result = run(1, true, &MutagenRuntimeConfig::without_mutation());
+        assert!(result);
+    }
+    #[test]
+    fn retain_active() {
+        let result = run(1, true, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert!(!result);
+    }
+
+    #[test]
+    fn detect_retain_closure() {
+        let expr: Expr = parse_quote! {v.retain(|x| *x > 0)};
+        assert!(ExprRetain::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_retain_two_arguments() {
+        let expr: Expr = parse_quote! {map.retain(|_, v| *v > 0)};
+        assert!(ExprRetain::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_retain_function() {
+        let expr: Expr = parse_quote! {v.retain(is_positive)};
+        assert!(ExprRetain::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_retain_with_return() {
+        let expr: Expr = parse_quote! {v.retain(|x| { if *x == 0 { return false; } true })};
+        assert!(ExprRetain::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,386 @@
+//! Mutator for inverted orders with `Reverse`, like `v.sort_by_key(|x| Reverse(x.score))` or min-heaps of type `BinaryHeap<Reverse<T>>`.
+//!
+//! The wrapper `Reverse` is removed from sort keys or added to them, such that the order of the keys is flipped.
+//! Elements that are popped from a heap and matched with `Reverse`, like in `while let Some(Reverse(x)) = heap.pop()`, are popped as if `Reverse` had been removed from the elements.
+//! This detects confusion between ascending and descending orders, like between max- and min-heaps.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprClosure, ExprLet, ExprMethodCall, Pat};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::ast_inspect::{contains_return, path_ends_with};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the methods that take a closure computing a key, which can be compared with or without `Reverse`
+///
+/// The keys of `min_by_key` and `max_by_key` are already inverted by `min_max`.
+const KEY_METHODS: &[&str] = &["sort_by_key", "sort_unstable_by_key", "sort_by_cached_key"];
+
+/// returns `true` if the order of the key is flipped
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+/// Pops an element of a heap.
+///
+/// If the mutation is active, the smallest element is popped instead of the largest one.
+/// For a heap of type `BinaryHeap<Reverse<T>>`, this pops the largest value, as if the elements were not wrapped in `Reverse`.
+pub fn run_pop<H, O>(
+    mutator_id: usize,
+    heap: &mut H,
+    pop: impl FnOnce(&mut H) -> O,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> O {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        heap.may_pop_reversed()
+    } else {
+        pop(heap)
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprReverseKey::try_from(e) {
+        Ok(e) => return transform_key(e, transform_info, context),
+        Err(e) => e,
+    };
+    match ExprReversePop::try_from(e) {
+        Ok(e) => transform_pop(e, transform_info, context),
+        Err(e) => e,
+    }
+}
+
+fn transform_key(
+    e: ExprReverseKey,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the key is described as written in the original code, before other mutators have been applied to it
+    let original_key = match context.original_expr.clone().map(ExprReverseKey::try_from) {
+        Some(Ok(original)) => original.key,
+        _ => e.key.clone(),
+    };
+    let key = original_key.to_token_stream().to_string();
+    let reversed_key = format!("Reverse({})", key);
+    let (original_code, mutated_code) = if e.reversed {
+        (reversed_key, key)
+    } else {
+        (key, reversed_key)
+    };
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "reverse".to_owned(),
+        original_code,
+        mutated_code,
+        e.span,
+    ));
+
+    // the key of the closure has a different type with or without `Reverse`, so the whole call is duplicated
+    let span = e.span;
+    let original = &e.call;
+    let key = &e.key;
+    let mut flipped_closure = e.closure.clone();
+    *flipped_closure.body = if e.reversed {
+        key.clone()
+    } else {
+        syn::parse2(quote_spanned! {span=> ::std::cmp::Reverse(#key)})
+            .expect("transformed code invalid")
+    };
+    let mut flipped = e.call.clone();
+    flipped.args[0] = Expr::Closure(flipped_closure);
+    syn::parse2(quote_spanned! {span=>
+        if ::mutagen::mutator::mutator_reverse::run(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+        {
+            #flipped
+        } else {
+            #original
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+fn transform_pop(
+    mut e: ExprReversePop,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    // the heap is described as written in the original code, before other mutators have been applied to it
+    let heap_code = match context.original_expr.clone().map(ExprReversePop::try_from) {
+        Some(Ok(original)) => original.pop.receiver.to_token_stream().to_string(),
+        _ => e.pop.receiver.to_token_stream().to_string(),
+    };
+
+    // other collections that contain `Reverse` are no weakness of the tests
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "reverse".to_owned(),
+            format!("{}.pop()", heap_code),
+            format!("{}.pop() without Reverse", heap_code),
+            e.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip),
+    );
+
+    let span = e.span;
+    let heap = &e.pop.receiver;
+    *e.expr.expr = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_reverse::run_pop(
+                #mutator_id,
+                &mut #heap,
+                |__mutagen_heap| __mutagen_heap.pop(),
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+    Expr::Let(e.expr)
+}
+
+/// trait that is used to optimistically pop the element of a heap with `Reverse` elements in the opposite order.
+///
+/// Only heaps of type `BinaryHeap<Reverse<T>>` can be popped in the opposite order.
+/// Otherwise, the optimistic assumption fails.
+trait MayPopReversed<O> {
+    fn may_pop_reversed(&mut self) -> O;
+}
+
+impl<H, O> MayPopReversed<O> for H {
+    default fn may_pop_reversed(&mut self) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Ord> MayPopReversed<Option<Reverse<T>>> for BinaryHeap<Reverse<T>> {
+    fn may_pop_reversed(&mut self) -> Option<Reverse<T>> {
+        let mut elements = std::mem::take(self).into_vec();
+        let smallest = elements
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(i, _)| i);
+        let popped = smallest.map(|i| elements.swap_remove(i));
+        *self = BinaryHeap::from(elements);
+        popped
+    }
+}
+
+/// returns the wrapped value if the expression is a construction of `Reverse`, like `Reverse(x)` or `cmp::Reverse(x)`
+fn reversed_value(e: &Expr) -> Option<&Expr> {
+    match e {
+        Expr::Call(call) if call.args.len() == 1 && path_ends_with(&call.func, &["Reverse"]) => {
+            Some(&call.args[0])
+        }
+        Expr::Paren(paren) => reversed_value(&paren.expr),
+        _ => None,
+    }
+}
+
+/// checks if the pattern matches a popped element wrapped in `Reverse`, like `Some(Reverse(x))`
+fn is_reversed_pattern(pat: &Pat) -> bool {
+    let is_tuple_struct = |pat: &Pat, name: &str| match pat {
+        Pat::TupleStruct(pat) => {
+            pat.path.segments.last().is_some_and(|s| s.ident == name) && pat.pat.elems.len() == 1
+        }
+        _ => false,
+    };
+    match pat {
+        Pat::TupleStruct(some) if is_tuple_struct(pat, "Some") => {
+            is_tuple_struct(&some.pat.elems[0], "Reverse")
+        }
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprReverseKey {
+    call: ExprMethodCall,
+    closure: ExprClosure,
+    /// the key without `Reverse`
+    key: Expr,
+    /// whether the key is wrapped in `Reverse` in the original code
+    reversed: bool,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprReverseKey {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if KEY_METHODS.iter().any(|m| call.method == m)
+                    && call.args.len() == 1
+                    && call.turbofish.is_none() =>
+            {
+                let closure = match &call.args[0] {
+                    // a `return` in the body could return a key with another order
+                    Expr::Closure(closure) if !contains_return(closure.body.to_token_stream()) => {
+                        closure.clone()
+                    }
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                let (key, reversed) = match reversed_value(&closure.body) {
+                    Some(key) => (key.clone(), true),
+                    None => ((*closure.body).clone(), false),
+                };
+                Ok(ExprReverseKey {
+                    span: call.method.span(),
+                    call,
+                    closure,
+                    key,
+                    reversed,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprReversePop {
+    expr: ExprLet,
+    pop: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprReversePop {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Let(expr) if is_reversed_pattern(&expr.pat) => {
+                let pop = match &*expr.expr {
+                    Expr::MethodCall(call)
+                        if call.method == "pop"
+                            && call.args.is_empty()
+                            && call.turbofish.is_none() =>
+                    {
+                        call.clone()
+                    }
+                    _ => return Err(Expr::Let(expr)),
+                };
+                Ok(ExprReversePop {
+                    span: pop.method.span(),
+                    expr,
+                    pop,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn reverse_inactive() {
+        assert!(!run(1, &MutagenRuntimeConfig::without_mutation()));
+    }
+    #[test]
+    fn reverse_active() {
+        assert!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)));
+    }
+
+    #[test]
+    fn pop_inactive() {
+        let mut heap = vec![Reverse(2), Reverse(5), Reverse(1)]
+            .into_iter()
+            .collect::<BinaryHeap<_>>();
+        let popped = run_pop(
+            1,
+            &mut heap,
+            |h| h.pop(),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(popped, Some(Reverse(1)));
+    }
+    #[test]
+    fn pop_active() {
+        let mut heap = vec![Reverse(2), Reverse(5), Reverse(1)]
+            .into_iter()
+            .collect::<BinaryHeap<_>>();
+        let popped = run_pop(
+            1,
+            &mut heap,
+            |h| h.pop(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(popped, Some(Reverse(5)));
+        assert_eq!(heap.into_sorted_vec(), vec![Reverse(2), Reverse(1)]);
+    }
+    #[test]
+    #[should_panic]
+    fn pop_active_vec() {
+        let mut v = vec![Reverse(2)];
+        run_pop(
+            1,
+            &mut v,
+            |v| v.pop(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+
+    #[test]
+    fn reversed_value_with_path() {
+        let expr: Expr = parse_quote! {std::cmp::Reverse(x.score)};
+        let key: Expr = parse_quote! {x.score};
+        assert_eq!(reversed_value(&expr), Some(&key));
+    }
+    #[test]
+    fn reversed_value_other_call() {
+        let expr: Expr = parse_quote! {Some(x)};
+        assert_eq!(reversed_value(&expr), None);
+    }
+
+    #[test]
+    fn detect_sort_by_key() {
+        let expr: Expr = parse_quote! {v.sort_by_key(|x| Reverse(*x))};
+        assert!(ExprReverseKey::try_from(expr).unwrap().reversed);
+    }
+    #[test]
+    fn detect_key_without_reverse() {
+        let expr: Expr = parse_quote! {v.sort_by_key(|x| *x)};
+        assert!(!ExprReverseKey::try_from(expr).unwrap().reversed);
+    }
+    #[test]
+    fn ignore_other_method() {
+        let expr: Expr = parse_quote! {v.iter().max_by_key(|x| Reverse(*x))};
+        assert!(ExprReverseKey::try_from(expr).is_err());
+    }
+
+    #[test]
+    fn detect_while_let_pop() {
+        let expr: Expr = parse_quote! {let Some(Reverse((dist, node))) = heap.pop()};
+        assert!(ExprReversePop::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_pop_without_reverse() {
+        let expr: Expr = parse_quote! {let Some(x) = heap.pop()};
+        assert!(ExprReversePop::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_peek() {
+        let expr: Expr = parse_quote! {let Some(Reverse(x)) = heap.peek()};
+        assert!(ExprReversePop::try_from(expr).is_err());
+    }
+}
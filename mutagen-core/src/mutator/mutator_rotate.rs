@@ -0,0 +1,358 @@
+//! Mutator for rotations of integers and slices, like `x.rotate_left(n)` or `v.rotate_right(n)`.
+//!
+//! The rotation amount is perturbed and the direction of the rotation is swapped.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, Ident, UnOp};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Performs the rotation with the given closure.
+///
+/// The closure gets whether the direction is swapped and the rotation amount, which are changed if one of the mutations is active.
+/// The length `len` of a rotated slice is used to keep a perturbed amount in bounds.
+pub fn run<A, R, F: FnOnce(bool, A) -> R>(
+    mutator_id: usize,
+    amount: A,
+    len: Option<usize>,
+    rotate: F,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> R {
+    runtime.covered(mutator_id);
+    let mutations = MutationRotate::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(MutationRotate::SwapDirection) => rotate(true, amount),
+        Some(m) => rotate(false, amount.may_perturb(*m, len)),
+        None => rotate(false, amount),
+    }
+}
+
+/// Returns the length of the rotated value if it is a slice or a collection that can be rotated.
+///
+/// Returns `None` for integers.
+pub fn rotation_len<T: ?Sized>(receiver: &T) -> Option<usize> {
+    receiver.rotation_len()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprRotate::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the amount is described as written in the original code, before other mutators have been applied to it
+    let original_amount = match context.original_expr.clone().map(ExprRotate::try_from) {
+        Some(Ok(original)) => original.call.args[0].to_token_stream().to_string(),
+        _ => e.call.args[0].to_token_stream().to_string(),
+    };
+    let method = e.call.method.to_string();
+
+    let mutations = MutationRotate::possible_mutations();
+    // an amount of `0` is not replaced with `0`
+    let mutations = if is_lit_zero(&e.call.args[0]) {
+        &mutations[..3]
+    } else {
+        &mutations[..]
+    };
+    let mutator_id = transform_info.add_mutations(mutations.iter().map(|m| {
+        Mutation::new_spanned(
+            context,
+            "rotate".to_owned(),
+            format!("{}({})", method, original_amount),
+            m.describe(&method, &original_amount),
+            e.span,
+        )
+    }));
+
+    let span = e.span;
+    let receiver = &e.call.receiver;
+    let amount = &e.call.args[0];
+    let (method, swapped) = if method == "rotate_left" {
+        (
+            Ident::new("rotate_left", span),
+            Ident::new("rotate_right", span),
+        )
+    } else {
+        (
+            Ident::new("rotate_right", span),
+            Ident::new("rotate_left", span),
+        )
+    };
+    syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_rotate::run(
+                #mutator_id,
+                #amount,
+                ::mutagen::mutator::mutator_rotate::rotation_len(&#receiver),
+                |__mutagen_swapped, __mutagen_amount| if __mutagen_swapped {
+                    #receiver.#swapped(__mutagen_amount)
+                } else {
+                    #receiver.#method(__mutagen_amount)
+                },
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// Checks if the expression can be evaluated several times without side effects.
+///
+/// The receiver of the rotation is evaluated twice, once to get its length and once for the rotation.
+fn is_pure_place(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(_) | Expr::Path(_) => true,
+        Expr::Field(e) => is_pure_place(&e.base),
+        Expr::Index(e) => is_pure_place(&e.expr) && is_pure_place(&e.index),
+        Expr::Range(e) => {
+            e.from.iter().all(|e| is_pure_place(e)) && e.to.iter().all(|e| is_pure_place(e))
+        }
+        Expr::Paren(e) => is_pure_place(&e.expr),
+        Expr::Reference(e) => is_pure_place(&e.expr),
+        Expr::Unary(e) if matches!(e.op, UnOp::Deref(_)) => is_pure_place(&e.expr),
+        _ => false,
+    }
+}
+
+/// checks if the expression is the literal `0`
+fn is_lit_zero(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse::<u128>().ok() == Some(0),
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationRotate {
+    Increment,
+    Decrement,
+    SwapDirection,
+    Zero,
+}
+
+impl MutationRotate {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationRotate::Increment,
+            MutationRotate::Decrement,
+            MutationRotate::SwapDirection,
+            MutationRotate::Zero,
+        ]
+    }
+
+    fn describe(self, method: &str, amount: &str) -> String {
+        match self {
+            MutationRotate::Increment => format!("{}({} + 1)", method, amount),
+            MutationRotate::Decrement => format!("{}({} - 1)", method, amount),
+            MutationRotate::Zero => format!("{}(0)", method),
+            MutationRotate::SwapDirection if method == "rotate_left" => {
+                format!("rotate_right({})", amount)
+            }
+            MutationRotate::SwapDirection => format!("rotate_left({})", amount),
+        }
+    }
+}
+
+/// trait that is used to optimistically perturb a rotation amount.
+///
+/// Integers are rotated by a `u32`, which is masked by the bit width of the integer.
+/// Slices are rotated by a `usize`, which panics if it exceeds the length of the slice.
+trait AmountMutable {
+    fn may_perturb(self, m: MutationRotate, len: Option<usize>) -> Self;
+}
+
+impl<T> AmountMutable for T {
+    default fn may_perturb(self, _m: MutationRotate, _len: Option<usize>) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl AmountMutable for u32 {
+    fn may_perturb(self, m: MutationRotate, _len: Option<usize>) -> Self {
+        match m {
+            MutationRotate::Increment => self.wrapping_add(1),
+            MutationRotate::Decrement => self.wrapping_sub(1),
+            MutationRotate::SwapDirection => self,
+            MutationRotate::Zero => 0,
+        }
+    }
+}
+
+impl AmountMutable for usize {
+    /// Rotating a slice by its length is the same as not rotating it.
+    /// The perturbed amount is taken modulo the length, such that it stays in bounds.
+    fn may_perturb(self, m: MutationRotate, len: Option<usize>) -> Self {
+        match (m, len) {
+            (MutationRotate::Increment, Some(len)) if len > 0 => (self % len + 1) % len,
+            (MutationRotate::Increment, None) => self.saturating_add(1),
+            (MutationRotate::Decrement, Some(len)) if len > 0 => (self % len + len - 1) % len,
+            (MutationRotate::Decrement, None) => self.saturating_sub(1),
+            (MutationRotate::SwapDirection, _) => self,
+            _ => 0,
+        }
+    }
+}
+
+/// trait that is used to get the length of rotated slices and collections
+trait RotationLen {
+    fn rotation_len(&self) -> Option<usize>;
+}
+
+impl<T: ?Sized> RotationLen for T {
+    default fn rotation_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<T> RotationLen for [T] {
+    fn rotation_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T, const N: usize> RotationLen for [T; N] {
+    fn rotation_len(&self) -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<T> RotationLen for Vec<T> {
+    fn rotation_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T> RotationLen for VecDeque<T> {
+    fn rotation_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: ?Sized> RotationLen for &T {
+    fn rotation_len(&self) -> Option<usize> {
+        (**self).rotation_len()
+    }
+}
+
+impl<T: ?Sized> RotationLen for &mut T {
+    fn rotation_len(&self) -> Option<usize> {
+        (**self).rotation_len()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprRotate {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprRotate {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if (call.method == "rotate_left" || call.method == "rotate_right")
+                    && call.args.len() == 1
+                    && call.turbofish.is_none()
+                    && is_pure_place(&call.receiver) =>
+            {
+                Ok(ExprRotate {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    fn rotate_u8(x: u8, amount: u32, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> u8 {
+        run(
+            1,
+            amount,
+            rotation_len(&x),
+            |swapped, amount| {
+                if swapped {
+                    x.rotate_right(amount)
+                } else {
+                    x.rotate_left(amount)
+                }
+            },
+            runtime,
+        )
+    }
+
+    #[test]
+    fn rotate_inactive() {
+        let result = rotate_u8(0b0000_0011, 1, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 0b0000_0110);
+    }
+    #[test]
+    fn rotate_active1() {
+        let result = rotate_u8(0b0000_0011, 1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 0b0000_1100);
+    }
+    #[test]
+    fn rotate_active2() {
+        let result = rotate_u8(0b0000_0011, 0, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 0b1000_0001);
+    }
+    #[test]
+    fn rotate_active3() {
+        let result = rotate_u8(0b0000_0011, 1, &MutagenRuntimeConfig::with_mutation_id(3));
+        assert_eq!(result, 0b1000_0001);
+    }
+    #[test]
+    fn rotate_active4() {
+        let result = rotate_u8(0b0000_0011, 1, &MutagenRuntimeConfig::with_mutation_id(4));
+        assert_eq!(result, 0b0000_0011);
+    }
+
+    #[test]
+    fn slice_amount_stays_in_bounds() {
+        assert_eq!(3usize.may_perturb(MutationRotate::Increment, Some(3)), 1);
+        assert_eq!(0usize.may_perturb(MutationRotate::Decrement, Some(3)), 2);
+        assert_eq!(0usize.may_perturb(MutationRotate::Increment, Some(0)), 0);
+    }
+    #[test]
+    fn rotation_len_of_slices() {
+        let mut v = vec![1, 2, 3];
+        assert_eq!(rotation_len(&v), Some(3));
+        assert_eq!(rotation_len(&v[1..]), Some(2));
+        assert_eq!(rotation_len(&&mut v), Some(3));
+        assert_eq!(rotation_len(&[1, 2]), Some(2));
+        assert_eq!(rotation_len(&1u8), None);
+    }
+
+    #[test]
+    fn ignore_impure_receiver() {
+        let expr: Expr = parse_quote! {f().rotate_left(1)};
+        assert!(ExprRotate::try_from(expr).is_err());
+    }
+    #[test]
+    fn detect_sub_slice() {
+        let expr: Expr = parse_quote! {self.v[1..n].rotate_right(k)};
+        assert!(ExprRotate::try_from(expr).is_ok());
+    }
+}
@@ -0,0 +1,220 @@
+//! Mutator for the sizes of windows and chunks of slices, like `v.windows(n)` or `v.chunks(n)`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprLit, ExprMethodCall, Lit};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<T>(mutator_id: usize, size: T, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> T {
+    runtime.covered(mutator_id);
+    let mutations = MutationSliceWindow::possible_mutations();
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        size.may_perturb(*m)
+    } else {
+        size
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprSliceWindow::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the size is described as written in the original code, before other mutators have been applied to it
+    let original_size = match context.original_expr.clone().map(ExprSliceWindow::try_from) {
+        Some(Ok(original)) => original.call.args[0].to_token_stream().to_string(),
+        _ => e.call.args[0].to_token_stream().to_string(),
+    };
+
+    let mutations = MutationSliceWindow::possible_mutations();
+    // a window size of `1` is not decremented since a size of `0` always panics
+    let mutations = if is_lit_one(&e.call.args[0]) {
+        &mutations[..1]
+    } else {
+        &mutations[..]
+    };
+    let mutator_id = transform_info.add_mutations(
+        mutations
+            .iter()
+            .map(|m| m.to_mutation(&e, &original_size, context)),
+    );
+
+    let span = e.span;
+    let size = &mut e.call.args[0];
+    *size = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_slice_window::run(
+                #mutator_id,
+                #size,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+
+    Expr::MethodCall(e.call)
+}
+
+/// checks if the expression is the literal `1`
+fn is_lit_one(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<u128>().ok() == Some(1),
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct MutationSliceWindow {
+    delta: i8,
+}
+
+impl MutationSliceWindow {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationSliceWindow { delta: 1 },
+            MutationSliceWindow { delta: -1 },
+        ]
+    }
+
+    /// Changes the size by the delta.
+    ///
+    /// The size is never decremented below `1`, since windows and chunks of size `0` panic.
+    fn mutate(self, size: usize) -> usize {
+        if self.delta < 0 {
+            size.saturating_sub(-self.delta as usize).max(1)
+        } else {
+            size.saturating_add(self.delta as usize)
+        }
+    }
+
+    fn to_mutation(
+        self,
+        original: &ExprSliceWindow,
+        original_size: &str,
+        context: &TransformContext,
+    ) -> Mutation {
+        let mutated_size = if self.delta < 0 {
+            format!("{} - {}", original_size, -self.delta)
+        } else {
+            format!("{} + {}", original_size, self.delta)
+        };
+        Mutation::new_spanned(
+            context,
+            "slice_window".to_owned(),
+            format!("{}({})", original.call.method, original_size),
+            format!("{}({})", original.call.method, mutated_size),
+            original.span,
+        )
+    }
+}
+
+/// trait that is used to optimistically perturb the size of windows or chunks.
+///
+/// Methods like `chunks` can be defined on any type and take any argument.
+/// The size can only be perturbed if it is a `usize`, otherwise the optimistic assumption fails.
+trait SizeMutable {
+    fn may_perturb(self, m: MutationSliceWindow) -> Self;
+}
+
+impl<T> SizeMutable for T {
+    default fn may_perturb(self, _m: MutationSliceWindow) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl SizeMutable for usize {
+    fn may_perturb(self, m: MutationSliceWindow) -> Self {
+        m.mutate(self)
+    }
+}
+
+/// methods of slices that take the size of windows or chunks
+const WINDOW_METHODS: &[&str] = &["windows", "chunks", "chunks_exact"];
+
+#[derive(Clone, Debug)]
+struct ExprSliceWindow {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprSliceWindow {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if call.args.len() == 1
+                    && call.turbofish.is_none()
+                    && WINDOW_METHODS.iter().any(|m| call.method == m) =>
+            {
+                Ok(ExprSliceWindow {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn size_inactive() {
+        let result = run(1, 2usize, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 2);
+    }
+    #[test]
+    fn size_active1() {
+        let result = run(1, 2usize, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 3);
+    }
+    #[test]
+    fn size_active2() {
+        let result = run(1, 2usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 1);
+    }
+    #[test]
+    fn size_one_not_decremented_to_zero() {
+        let result = run(1, 1usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 1);
+    }
+    #[test]
+    #[should_panic]
+    fn size_not_usize_active() {
+        run(1, "size", &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_chunks_exact() {
+        let expr: Expr = parse_quote! {v.chunks_exact(n)};
+        assert!(ExprSliceWindow::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_split() {
+        let expr: Expr = parse_quote! {v.split_at(n)};
+        assert!(ExprSliceWindow::try_from(expr).is_err());
+    }
+    #[test]
+    fn lit_one() {
+        assert!(is_lit_one(&parse_quote! {1usize}));
+        assert!(!is_lit_one(&parse_quote! {2}));
+    }
+}
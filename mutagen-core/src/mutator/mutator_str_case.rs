@@ -0,0 +1,302 @@
+//! Mutator for case conversions, like `s.to_uppercase()` or `c.to_ascii_lowercase()`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, Ident};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<R: ?Sized, O, S>(
+    mutator_id: usize,
+    receiver: &R,
+    convert: impl FnOnce(&R) -> O,
+    swapped: impl FnOnce(&R) -> S,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> O {
+    runtime.covered(mutator_id);
+    let mutations = MutationStrCase::possible_mutations();
+    match runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        Some(MutationStrCase::Swap) => SwappedCase::<O>::may_convert(swapped(receiver)),
+        Some(MutationStrCase::Remove) => KeepCase::<O>::may_keep_case(receiver),
+        None => convert(receiver),
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprStrCase::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the receiver is described as written in the original code, before other mutators have been applied to it
+    let receiver_code = match context.original_expr.clone().map(ExprStrCase::try_from) {
+        Some(Ok(original)) => original.receiver.to_token_stream().to_string(),
+        _ => e.receiver.to_token_stream().to_string(),
+    };
+
+    let mutator_id = transform_info.add_mutations(
+        MutationStrCase::possible_mutations()
+            .iter()
+            .map(|m| m.to_mutation(&e, &receiver_code, context)),
+    );
+
+    let receiver = &e.receiver;
+    let method = &e.method;
+    let swapped_method = Ident::new(&e.swapped_method(), e.span);
+
+    syn::parse2(quote_spanned! {e.span=>
+        ::mutagen::mutator::mutator_str_case::run(
+                #mutator_id,
+                &(#receiver),
+                |__mutagen_receiver| __mutagen_receiver.#method(),
+                |__mutagen_receiver| __mutagen_receiver.#swapped_method(),
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationStrCase {
+    /// converting to the opposite case
+    Swap,
+    /// removing the conversion
+    Remove,
+}
+
+impl MutationStrCase {
+    fn possible_mutations() -> Vec<Self> {
+        vec![MutationStrCase::Swap, MutationStrCase::Remove]
+    }
+
+    fn to_mutation(
+        self,
+        original: &ExprStrCase,
+        receiver_code: &str,
+        context: &TransformContext,
+    ) -> Mutation {
+        let mutated_code = match self {
+            MutationStrCase::Swap => format!("{}.{}()", receiver_code, original.swapped_method()),
+            MutationStrCase::Remove => receiver_code.to_owned(),
+        };
+        Mutation::new_spanned(
+            context,
+            "str_case".to_owned(),
+            format!("{}.{}()", receiver_code, original.method),
+            mutated_code,
+            original.span,
+        )
+    }
+}
+
+/// pairs of case conversions that are swapped
+const CASE_METHODS: &[(&str, &str)] = &[
+    ("to_uppercase", "to_lowercase"),
+    ("to_ascii_uppercase", "to_ascii_lowercase"),
+];
+
+#[derive(Clone, Debug)]
+struct ExprStrCase {
+    receiver: Expr,
+    method: Ident,
+    span: Span,
+}
+
+impl ExprStrCase {
+    /// the conversion to the opposite case
+    fn swapped_method(&self) -> String {
+        CASE_METHODS
+            .iter()
+            .find_map(|(upper, lower)| {
+                if self.method == upper {
+                    Some(*lower)
+                } else if self.method == lower {
+                    Some(*upper)
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+            .to_owned()
+    }
+}
+
+impl TryFrom<Expr> for ExprStrCase {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if call.args.is_empty()
+                    && call.turbofish.is_none()
+                    && CASE_METHODS
+                        .iter()
+                        .any(|(upper, lower)| call.method == upper || call.method == lower) =>
+            {
+                Ok(ExprStrCase {
+                    receiver: *call.receiver,
+                    span: call.method.span(),
+                    method: call.method,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+/// trait that is used to optimistically use the result of the opposite case conversion.
+///
+/// For strings, both conversions give a `String`.
+/// For `char`, `to_uppercase` and `to_lowercase` return different iterator types and the optimistic assumption fails.
+trait SwappedCase<O> {
+    fn may_convert(self) -> O;
+}
+
+impl<S, O> SwappedCase<O> for S {
+    default fn may_convert(self) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<O> SwappedCase<O> for O {
+    fn may_convert(self) -> O {
+        self
+    }
+}
+
+/// trait that is used to optimistically use the receiver of a case conversion instead of its result.
+///
+/// The receiver is used as is for conversions that return the type of the receiver, like `char::to_ascii_uppercase`.
+/// Strings are copied into a new `String`.
+/// Otherwise, the optimistic assumption fails.
+trait KeepCase<O> {
+    fn may_keep_case(&self) -> O;
+}
+
+impl<R: ?Sized, O> KeepCase<O> for R {
+    default fn may_keep_case(&self) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<R: Clone> KeepCase<R> for R {
+    fn may_keep_case(&self) -> R {
+        self.clone()
+    }
+}
+
+impl KeepCase<String> for str {
+    fn may_keep_case(&self) -> String {
+        self.to_owned()
+    }
+}
+
+impl KeepCase<String> for &str {
+    fn may_keep_case(&self) -> String {
+        (*self).to_owned()
+    }
+}
+
+impl KeepCase<String> for &String {
+    fn may_keep_case(&self) -> String {
+        (*self).clone()
+    }
+}
+
+impl KeepCase<Vec<u8>> for &[u8] {
+    fn may_keep_case(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn str_case_inactive() {
+        let result = run(
+            1,
+            &"Hello",
+            |s| s.to_uppercase(),
+            |s| s.to_lowercase(),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, "HELLO");
+    }
+    #[test]
+    fn str_case_active1() {
+        let result = run(
+            1,
+            &"Hello",
+            |s| s.to_uppercase(),
+            |s| s.to_lowercase(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, "hello");
+    }
+    #[test]
+    fn str_case_active2() {
+        let result = run(
+            1,
+            &"Hello",
+            |s| s.to_uppercase(),
+            |s| s.to_lowercase(),
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, "Hello");
+    }
+    #[test]
+    fn string_case_active2() {
+        let result = run(
+            1,
+            &"Hello".to_owned(),
+            |s| s.to_lowercase(),
+            |s| s.to_uppercase(),
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, "Hello");
+    }
+    #[test]
+    fn ascii_char_case_active2() {
+        let result = run(
+            1,
+            &'a',
+            |c| c.to_ascii_uppercase(),
+            |c| c.to_ascii_lowercase(),
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, 'a');
+    }
+    #[test]
+    #[should_panic]
+    fn char_case_active1() {
+        run(
+            1,
+            &'a',
+            |c| c.to_uppercase(),
+            |c| c.to_lowercase(),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+    }
+
+    #[test]
+    fn swapped_method_ascii() {
+        let expr: Expr = parse_quote! {s.to_ascii_lowercase()};
+        let e = ExprStrCase::try_from(expr).unwrap();
+        assert_eq!(e.swapped_method(), "to_ascii_uppercase");
+    }
+}
@@ -0,0 +1,245 @@
+//! Mutator for splitting strings, like `s.split(',')` or `s.splitn(2, ',')`.
+//!
+//! The delimiter is replaced with a sentinel that does not occur in usual text, and the count of `splitn` and `rsplitn` is incremented or decremented by 1.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprLit, ExprMethodCall, Lit};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the sentinel that replaces delimiters, as char
+pub const SENTINEL_CHAR: char = '\0';
+
+/// the sentinel that replaces delimiters, as string
+pub const SENTINEL_STR: &str = "\0";
+
+/// returns the delimiter, which is replaced with the sentinel if the mutation is active
+pub fn run_delimiter<P>(
+    mutator_id: usize,
+    delimiter: P,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> P {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        <P as MaySentinel>::sentinel()
+    } else {
+        delimiter
+    }
+}
+
+/// returns the count of `splitn`, which is incremented or decremented by 1 if one of the mutations is active
+pub fn run_count(
+    mutator_id: usize,
+    count: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> usize {
+    runtime.covered(mutator_id);
+    match runtime.get_mutation_for_mutator(mutator_id, &[1, -1]) {
+        Some(1) => count.wrapping_add(1),
+        Some(_) => count.wrapping_sub(1),
+        None => count,
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprStrSplit::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the arguments are described as written in the original code, before other mutators have been applied to them
+    let original_args = match context.original_expr.clone().map(ExprStrSplit::try_from) {
+        Some(Ok(original)) => original.call.args,
+        _ => e.call.args.clone(),
+    };
+    let original_args = original_args
+        .iter()
+        .map(|arg| arg.to_token_stream().to_string())
+        .collect::<Vec<_>>();
+    let method = e.call.method.to_string();
+    let span = e.span;
+    let describe = |args: &[String]| format!("{}({})", method, args.join(", "));
+
+    if original_args.len() == 2 {
+        let mutator_id = transform_info.add_mutations(["+ 1", "- 1"].iter().map(|op| {
+            let mutated_args = [
+                format!("{} {}", original_args[0], op),
+                original_args[1].clone(),
+            ];
+            Mutation::new_spanned(
+                context,
+                "str_split".to_owned(),
+                describe(&original_args),
+                describe(&mutated_args),
+                span,
+            )
+        }));
+        let count = &e.call.args[0];
+        e.call.args[0] = syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_str_split::run_count(
+                    #mutator_id,
+                    #count,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid");
+    }
+
+    // only char and string delimiters can be replaced with the sentinel. Mutants of other delimiters are skipped.
+    let delimiter = e.call.args.last_mut().unwrap();
+    let (sentinel, policy) = match delimiter {
+        Expr::Lit(ExprLit {
+            lit: Lit::Char(_), ..
+        }) => ("'\\0'", OptimisticPolicy::Killed),
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(_), ..
+        }) => ("\"\\0\"", OptimisticPolicy::Killed),
+        _ => ("'\\0'", OptimisticPolicy::Skip),
+    };
+    let mut mutated_args = original_args.clone();
+    *mutated_args.last_mut().unwrap() = sentinel.to_owned();
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "str_split".to_owned(),
+            describe(&original_args),
+            describe(&mutated_args),
+            span,
+        )
+        .with_optimistic_policy(policy),
+    );
+    *delimiter = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_str_split::run_delimiter(
+                #mutator_id,
+                #delimiter,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+
+    Expr::MethodCall(e.call)
+}
+
+/// trait that is used to optimistically replace a delimiter with the sentinel.
+///
+/// Only chars and strings can be replaced, otherwise the optimistic assumption fails.
+trait MaySentinel {
+    fn sentinel() -> Self;
+}
+
+impl<P> MaySentinel for P {
+    default fn sentinel() -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl MaySentinel for char {
+    fn sentinel() -> Self {
+        SENTINEL_CHAR
+    }
+}
+
+impl MaySentinel for &str {
+    fn sentinel() -> Self {
+        SENTINEL_STR
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprStrSplit {
+    call: ExprMethodCall,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprStrSplit {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call)
+                if call.turbofish.is_none()
+                    && ((call.method == "split" && call.args.len() == 1)
+                        || ((call.method == "splitn" || call.method == "rsplitn")
+                            && call.args.len() == 2)) =>
+            {
+                Ok(ExprStrSplit {
+                    span: call.method.span(),
+                    call,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn delimiter_inactive() {
+        let result = run_delimiter(1, ',', &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, ',');
+    }
+    #[test]
+    fn delimiter_active_char() {
+        let result = run_delimiter(1, ',', &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, SENTINEL_CHAR);
+    }
+    #[test]
+    fn delimiter_active_str() {
+        let result = run_delimiter(1, ", ", &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, SENTINEL_STR);
+    }
+    #[test]
+    #[should_panic]
+    fn delimiter_active_char_array() {
+        run_delimiter(1, [',', ';'], &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn count_inactive() {
+        let result = run_count(1, 2, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 2);
+    }
+    #[test]
+    fn count_active1() {
+        let result = run_count(1, 2, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 3);
+    }
+    #[test]
+    fn count_active2() {
+        let result = run_count(1, 2, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn detect_splitn() {
+        let expr: Expr = parse_quote! {s.splitn(2, ',')};
+        assert!(ExprStrSplit::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_split_at() {
+        let expr: Expr = parse_quote! {s.split_at(2)};
+        assert!(ExprStrSplit::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_split_without_args() {
+        let expr: Expr = parse_quote! {s.split()};
+        assert!(ExprStrSplit::try_from(expr).is_err());
+    }
+}
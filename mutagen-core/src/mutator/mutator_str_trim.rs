@@ -0,0 +1,145 @@
+//! Mutator for trimming whitespace of strings, like `s.trim()` or `line.trim_end()`.
+//!
+//! The trimming is removed, such that surrounding whitespace is kept.
+//! This tests whether the handling of whitespace is relied upon.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, Ident};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// the trimming methods and the methods that trim with a given pattern on the same side
+const TRIM_METHODS: &[(&str, &str)] = &[
+    ("trim", "trim_matches"),
+    ("trim_start", "trim_start_matches"),
+    ("trim_end", "trim_end_matches"),
+];
+
+/// Returns the pattern of chars that are trimmed.
+///
+/// The pattern matches whitespace like the original trimming methods, or no char at all if the mutation is active.
+pub fn run(
+    mutator_id: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> fn(char) -> bool {
+    runtime.covered(mutator_id);
+    if runtime.is_mutation_active(mutator_id) {
+        no_char
+    } else {
+        char::is_whitespace
+    }
+}
+
+/// the pattern that matches no char, such that nothing is trimmed
+fn no_char(_c: char) -> bool {
+    false
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprStrTrim::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the receiver is described as written in the original code, before other mutators have been applied to it
+    let receiver_code = match context.original_expr.clone().map(ExprStrTrim::try_from) {
+        Some(Ok(original)) => original.call.receiver.to_token_stream().to_string(),
+        _ => e.call.receiver.to_token_stream().to_string(),
+    };
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "str_trim".to_owned(),
+        format!("{}.{}()", receiver_code, e.call.method),
+        receiver_code,
+        e.span,
+    ));
+
+    // the trimming with a pattern has the same type as the original trimming
+    let span = e.span;
+    e.call.method = Ident::new(e.pattern_method, span);
+    e.call.args.push(
+        syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_str_trim::run(
+                    #mutator_id,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                )
+        })
+        .expect("transformed code invalid"),
+    );
+    Expr::MethodCall(e.call)
+}
+
+#[derive(Clone, Debug)]
+struct ExprStrTrim {
+    call: ExprMethodCall,
+    pattern_method: &'static str,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprStrTrim {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call) if call.args.is_empty() && call.turbofish.is_none() => {
+                match TRIM_METHODS.iter().find(|(m, _)| call.method == m) {
+                    Some(&(_, pattern_method)) => Ok(ExprStrTrim {
+                        span: call.method.span(),
+                        call,
+                        pattern_method,
+                    }),
+                    None => Err(Expr::MethodCall(call)),
+                }
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn str_trim_inactive() {
+        let pattern = run(1, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(" a ".trim_matches(pattern), "a");
+    }
+    #[test]
+    fn str_trim_active() {
+        let pattern = run(1, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(" a ".trim_matches(pattern), " a ");
+    }
+
+    #[test]
+    fn detect_trim() {
+        let expr: Expr = parse_quote! {s.trim()};
+        let e = ExprStrTrim::try_from(expr).unwrap();
+        assert_eq!(e.pattern_method, "trim_matches");
+    }
+    #[test]
+    fn detect_trim_end() {
+        let expr: Expr = parse_quote! {line.trim_end()};
+        let e = ExprStrTrim::try_from(expr).unwrap();
+        assert_eq!(e.pattern_method, "trim_end_matches");
+    }
+    #[test]
+    fn ignore_trim_matches() {
+        let expr: Expr = parse_quote! {s.trim_matches('x')};
+        assert!(ExprStrTrim::try_from(expr).is_err());
+    }
+}
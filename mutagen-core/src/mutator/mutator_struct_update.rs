@@ -0,0 +1,240 @@
+//! Mutator for struct expressions with functional update syntax, like `Foo { a: 1, ..base }`.
+//!
+//! Each explicitly given field is removed, such that the value of the base is used instead.
+//! The base is replaced with `Default::default()`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprStruct};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Returns the index of the active mutation, if any.
+///
+/// The indices below the number of fields remove the field with the same index.
+/// The index equal to the number of fields replaces the base.
+pub fn run(
+    mutator_id: usize,
+    num_mutations: usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<usize> {
+    runtime.covered(mutator_id);
+    let mutations = (0..num_mutations).collect::<Vec<_>>();
+    runtime
+        .get_mutation_for_mutator(mutator_id, &mutations)
+        .copied()
+}
+
+/// returns the base of the struct expression, or the default value of its type if `replace` is set
+pub fn base<T>(base: T, replace: bool) -> T {
+    if replace {
+        T::may_default()
+    } else {
+        base
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprStructUpdate::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the expression is described as written in the original code, before other mutators have been applied to it
+    let original = match context
+        .original_expr
+        .clone()
+        .map(ExprStructUpdate::try_from)
+    {
+        Some(Ok(original)) => original.expr,
+        _ => e.expr.clone(),
+    };
+    let replace_base = !is_default_call(original.rest.as_ref().unwrap());
+
+    let num_fields = e.expr.fields.len();
+    let mut mutations = (0..num_fields)
+        .map(|i| without_field(&original, i).to_token_stream().to_string())
+        .collect::<Vec<_>>();
+    if replace_base {
+        let mut mutated = original.clone();
+        mutated.rest = Some(Box::new(syn::parse_quote! {Default::default()}));
+        mutations.push(mutated.to_token_stream().to_string());
+    }
+    let num_mutations = mutations.len();
+    let original_code = original.to_token_stream().to_string();
+    let mutator_id =
+        transform_info.add_mutations(mutations.into_iter().enumerate().map(|(i, mutated_code)| {
+            let mutation = Mutation::new_spanned(
+                context,
+                "struct_update".to_owned(),
+                original_code.clone(),
+                mutated_code,
+                e.span,
+            );
+            // a type without `Default` is no weakness of the tests
+            if i == num_fields {
+                mutation.with_optimistic_policy(OptimisticPolicy::Skip)
+            } else {
+                mutation
+            }
+        }));
+
+    // each removed field is a separate struct expression, such that the type of the base is inferred from the struct
+    let span = e.span;
+    let removed_fields = (0..num_fields)
+        .map(|i| without_field(&e.expr, i))
+        .collect::<Vec<_>>();
+    let indices = 0..num_fields;
+    let rest = e.expr.rest.take().unwrap();
+    e.expr.rest = Some(Box::new(
+        syn::parse2(quote_spanned! {span=>
+            ::mutagen::mutator::mutator_struct_update::base(
+                #rest,
+                __mutagen_mutation == ::core::option::Option::Some(#num_fields)
+            )
+        })
+        .expect("transformed code invalid"),
+    ));
+    let expr = &e.expr;
+    syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_struct_update::run(
+            #mutator_id,
+            #num_mutations,
+            ::mutagen::MutagenRuntimeConfig::get_default()
+        ) {
+            #(::core::option::Option::Some(#indices) => #removed_fields,)*
+            __mutagen_mutation => #expr,
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// returns the struct expression without the field with index `i`
+fn without_field(expr: &ExprStruct, i: usize) -> ExprStruct {
+    let mut removed = expr.clone();
+    removed.fields = expr
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| *j != i)
+        .map(|(_, field)| field.clone())
+        .collect();
+    removed
+}
+
+/// checks if the expression is a call to `Default::default()`, which is not replaced
+fn is_default_call(e: &Expr) -> bool {
+    match e {
+        Expr::Call(call) if call.args.is_empty() => match &*call.func {
+            Expr::Path(path) => {
+                matches!(path.path.segments.last(), Some(segment) if segment.ident == "default")
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// trait that is used to optimistically create the default value of a type.
+///
+/// The base can only be replaced if its type implements `Default`, otherwise the optimistic assumption fails.
+trait MayDefault {
+    fn may_default() -> Self;
+}
+
+impl<T> MayDefault for T {
+    default fn may_default() -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Default> MayDefault for T {
+    fn may_default() -> Self {
+        T::default()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprStructUpdate {
+    expr: ExprStruct,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprStructUpdate {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::Struct(expr) if expr.rest.is_some() => Ok(ExprStructUpdate {
+                span: expr.dot2_token.span(),
+                expr,
+            }),
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn struct_update_inactive() {
+        let result = run(1, 2, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, None);
+    }
+    #[test]
+    fn struct_update_active1() {
+        let result = run(1, 2, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, Some(0));
+    }
+    #[test]
+    fn struct_update_active2() {
+        let result = run(1, 2, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn base_replaced_with_default() {
+        assert_eq!(base(5, false), 5);
+        assert_eq!(base(5, true), 0);
+    }
+    #[test]
+    #[should_panic]
+    fn base_without_default() {
+        struct NoDefault;
+        base(NoDefault, true);
+    }
+
+    #[test]
+    fn detect_struct_update() {
+        let expr: Expr = parse_quote! {Foo { a: 1, ..base }};
+        assert!(ExprStructUpdate::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_struct_without_base() {
+        let expr: Expr = parse_quote! {Foo { a: 1, b: 2 }};
+        assert!(ExprStructUpdate::try_from(expr).is_err());
+    }
+    #[test]
+    fn default_call() {
+        assert!(is_default_call(&parse_quote! {Default::default()}));
+        assert!(is_default_call(&parse_quote! {Foo::default()}));
+        assert!(!is_default_call(&parse_quote! {Foo::new()}));
+        assert!(!is_default_call(&parse_quote! {base}));
+    }
+}
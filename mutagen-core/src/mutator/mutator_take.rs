@@ -0,0 +1,216 @@
+//! Mutator for taking values out of a place, like `opt.take()`, `mem::take(&mut x)` or `mem::replace(&mut x, y)`.
+//!
+//! The value is not taken. Instead, a clone of the value is returned and the place is left unchanged.
+//! This tests that the side effect of taking or replacing the value is checked.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprCall, ExprMethodCall};
+
+use crate::comm::{Mutation, OptimisticPolicy};
+use crate::transformer::ast_inspect::path_ends_with;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// returns `true` if the value is left in place instead of taking it
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+/// returns a clone of the value in the given place, which has the same type as the taken value
+pub fn peek<S, O>(place: &S) -> O {
+    place.may_peek()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let e = match ExprTake::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the call is described as written in the original code, before other mutators have been applied to it
+    let original = match context.original_expr.clone().map(ExprTake::try_from) {
+        Some(Ok(original)) => original,
+        _ => e.clone(),
+    };
+    let original_code = original.expr.to_token_stream().to_string();
+    let mutated_code = match &original.kind {
+        TakeKind::Method(call) => format!("{}.clone()", call.receiver.to_token_stream()),
+        TakeKind::Mem(call) => format!("(*{}).clone()", call.args[0].to_token_stream()),
+    };
+
+    // values that cannot be cloned are no weakness of the tests
+    let mutator_id = transform_info.add_mutation(
+        Mutation::new_spanned(
+            context,
+            "take".to_owned(),
+            original_code,
+            mutated_code,
+            e.span,
+        )
+        .with_optimistic_policy(OptimisticPolicy::Skip),
+    );
+
+    // the place is evaluated in one of the branches only
+    let span = e.span;
+    let peeked = match &e.kind {
+        TakeKind::Method(call) => {
+            let receiver = &call.receiver;
+            quote_spanned! {span=> ::mutagen::mutator::mutator_take::peek(&#receiver)}
+        }
+        TakeKind::Mem(call) => {
+            let place = &call.args[0];
+            quote_spanned! {span=> ::mutagen::mutator::mutator_take::peek(&*#place)}
+        }
+    };
+    let original = &e.expr;
+    syn::parse2(quote_spanned! {span=>
+        if ::mutagen::mutator::mutator_take::run(
+                #mutator_id,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+        {
+            #peeked
+        } else {
+            #original
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// trait that is used to optimistically clone the value of a place.
+///
+/// The place is required to have the type of the taken value or to be a mutable reference to it, and the value is required to implement `Clone`.
+/// Otherwise, the optimistic assumption fails.
+trait MayPeek<O> {
+    fn may_peek(&self) -> O;
+}
+
+impl<S: ?Sized, O> MayPeek<O> for S {
+    default fn may_peek(&self) -> O {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<T: Clone> MayPeek<T> for T {
+    fn may_peek(&self) -> T {
+        self.clone()
+    }
+}
+
+impl<T: Clone> MayPeek<T> for &mut T {
+    fn may_peek(&self) -> T {
+        (**self).clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+enum TakeKind {
+    /// a method like `opt.take()`
+    Method(ExprMethodCall),
+    /// a function like `mem::take(&mut x)` or `mem::replace(&mut x, y)`
+    Mem(ExprCall),
+}
+
+#[derive(Clone, Debug)]
+struct ExprTake {
+    expr: Expr,
+    kind: TakeKind,
+    span: Span,
+}
+
+impl TryFrom<Expr> for ExprTake {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        let kind = match &expr {
+            Expr::MethodCall(call)
+                if call.method == "take" && call.args.is_empty() && call.turbofish.is_none() =>
+            {
+                TakeKind::Method(call.clone())
+            }
+            Expr::Call(call)
+                if (path_ends_with(&call.func, &["mem", "take"]) && call.args.len() == 1)
+                    || (path_ends_with(&call.func, &["mem", "replace"])
+                        && call.args.len() == 2) =>
+            {
+                TakeKind::Mem(call.clone())
+            }
+            _ => return Err(expr),
+        };
+        let span = match &kind {
+            TakeKind::Method(call) => call.method.span(),
+            TakeKind::Mem(call) => call.func.span(),
+        };
+        Ok(ExprTake { expr, kind, span })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::Cell;
+    use syn::parse_quote;
+
+    #[test]
+    fn take_inactive() {
+        assert!(!run(1, &MutagenRuntimeConfig::without_mutation()));
+    }
+    #[test]
+    fn take_active() {
+        assert!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)));
+    }
+
+    #[test]
+    fn peek_option() {
+        let opt = Some(5);
+        let peeked: Option<i32> = peek(&opt);
+        assert_eq!(peeked, Some(5));
+    }
+    #[test]
+    fn peek_mut_ref() {
+        let mut v = vec![1, 2];
+        let place = &mut v;
+        let peeked: Vec<i32> = peek(&place);
+        assert_eq!(peeked, vec![1, 2]);
+    }
+    #[test]
+    #[should_panic]
+    fn peek_cell() {
+        let cell = Cell::new(5);
+        let _: i32 = peek(&cell);
+    }
+
+    #[test]
+    fn detect_option_take() {
+        let expr: Expr = parse_quote! {self.pending.take()};
+        assert!(ExprTake::try_from(expr).is_ok());
+    }
+    #[test]
+    fn detect_mem_replace() {
+        let expr: Expr = parse_quote! {std::mem::replace(&mut self.state, State::Idle)};
+        assert!(ExprTake::try_from(expr).is_ok());
+    }
+    #[test]
+    fn ignore_iter_take() {
+        let expr: Expr = parse_quote! {iter.take(3)};
+        assert!(ExprTake::try_from(expr).is_err());
+    }
+    #[test]
+    fn ignore_other_fn() {
+        let expr: Expr = parse_quote! {take(&mut x)};
+        assert!(ExprTake::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Mutator for the `?`-operator, like `x?`.
+//!
+//! The mutation forces an early return, even if `?` would continue with a value.
+//! The returned value depends on the return type of the enclosing function: `None` for functions returning `Option` and `Err(_)` for functions returning `Result`.
+
+use std::ops::Deref;
+
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Expr;
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::{ReturnShape, TransformContext};
+
+use crate::MutagenRuntimeConfig;
+
+/// returns `true` if the early return is forced
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+/// the error returned by a forced early return
+pub fn forced_error<E>() -> E {
+    E::may_default()
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let (e, shape) = match (e, context.return_shape) {
+        (Expr::Try(e), Some(shape)) => (e, shape),
+        (e, _) => return e,
+    };
+    let span = e.question_token.span();
+
+    let forced_return = match shape {
+        ReturnShape::Option => quote_spanned! {span=>
+            ::core::option::Option::None
+        },
+        ReturnShape::Result => quote_spanned! {span=>
+            ::core::result::Result::Err(::mutagen::mutator::mutator_try::forced_error())
+        },
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "try".to_owned(),
+        "?".to_owned(),
+        match shape {
+            ReturnShape::Option => "return None".to_owned(),
+            ReturnShape::Result => "return Err(_)".to_owned(),
+        },
+        span,
+    ));
+
+    let expr = &e.expr;
+
+    // the operand is evaluated before the early return
+    syn::parse2(quote_spanned! {span=>
+        match #expr {
+            __mutagen_tried => {
+                if ::mutagen::mutator::mutator_try::run(
+                    #mutator_id,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                ) {
+                    return #forced_return;
+                }
+                __mutagen_tried?
+            }
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+/// trait that is used to optimistically create an error value for a forced early return.
+///
+/// The error can only be created if the error type implements `Default`, otherwise the optimistic assumption fails.
+trait MayDefault {
+    fn may_default() -> Self;
+}
+
+impl<E> MayDefault for E {
+    default fn may_default() -> E {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<E: Default> MayDefault for E {
+    fn may_default() -> E {
+        E::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn try_inactive() {
+        assert!(!run(1, &MutagenRuntimeConfig::without_mutation()));
+    }
+    #[test]
+    fn try_active() {
+        assert!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)));
+    }
+
+    #[test]
+    fn forced_error_default() {
+        assert_eq!(forced_error::<String>(), "");
+    }
+    #[test]
+    #[should_panic]
+    fn forced_error_without_default() {
+        forced_error::<std::io::Error>();
+    }
+}
@@ -0,0 +1,112 @@
+//! Mutator for the conversion of errors by the `?`-operator, like `x?` in a function returning `Result`.
+//!
+//! The mutation bypasses the conversion with `From::from` and returns the original error.
+//! This only type-checks if the error already has the type returned by the function.
+//! Otherwise, the optimistic assumption fails, which reveals that the conversion is exercised by the tests.
+
+use std::ops::Deref;
+
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Expr;
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::{ReturnShape, TransformContext};
+
+use crate::MutagenRuntimeConfig;
+
+/// returns `true` if the conversion of the error is bypassed
+pub fn run(mutator_id: usize, runtime: impl Deref<Target = MutagenRuntimeConfig>) -> bool {
+    runtime.covered(mutator_id);
+    runtime.is_mutation_active(mutator_id)
+}
+
+/// the original error, as error of the function
+pub fn bypassed_error<E, F>(e: E) -> F {
+    MayBypass::<F>::may_bypass(e)
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match (e, context.return_shape) {
+        (Expr::Try(e), Some(ReturnShape::Result)) => e,
+        (e, _) => return e,
+    };
+    let span = e.question_token.span();
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "try_bypass".to_owned(),
+        "?".to_owned(),
+        "Err(e) => return Err(e)".to_owned(),
+        span,
+    ));
+
+    // the mutator is only covered if `?` returns an error. The `?` remains, such that `try` can mutate it as well.
+    let expr = &e.expr;
+    e.expr = syn::parse2(quote_spanned! {span=>
+        (match #expr {
+            ::core::result::Result::Err(__mutagen_err)
+                if ::mutagen::mutator::mutator_try_bypass::run(
+                    #mutator_id,
+                    ::mutagen::MutagenRuntimeConfig::get_default()
+                ) =>
+            {
+                return ::core::result::Result::Err(
+                    ::mutagen::mutator::mutator_try_bypass::bypassed_error(__mutagen_err)
+                );
+            }
+            __mutagen_result => __mutagen_result,
+        })
+    })
+    .expect("transformed code invalid");
+    Expr::Try(e)
+}
+
+/// trait that is used to optimistically pass an error to the caller without conversion.
+///
+/// The error can only be passed if it has the error type of the function, otherwise the optimistic assumption fails.
+trait MayBypass<F> {
+    fn may_bypass(self) -> F;
+}
+
+impl<E, F> MayBypass<F> for E {
+    default fn may_bypass(self) -> F {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<E> MayBypass<E> for E {
+    fn may_bypass(self) -> E {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn try_bypass_inactive() {
+        assert!(!run(1, &MutagenRuntimeConfig::without_mutation()));
+    }
+    #[test]
+    fn try_bypass_active() {
+        assert!(run(1, &MutagenRuntimeConfig::with_mutation_id(1)));
+    }
+
+    #[test]
+    fn bypassed_error_same_type() {
+        assert_eq!(bypassed_error::<_, String>("e".to_owned()), "e");
+    }
+    #[test]
+    #[should_panic]
+    fn bypassed_error_different_type() {
+        bypassed_error::<_, String>(1u8);
+    }
+}
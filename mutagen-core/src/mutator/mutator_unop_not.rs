@@ -6,6 +6,7 @@ use std::ops::Not;
 
 use proc_macro2::Span;
 use quote::quote_spanned;
+use quote::ToTokens;
 use syn::spanned::Spanned;
 use syn::{Expr, UnOp};
 
@@ -51,11 +52,14 @@ pub fn transform(
         Err(e) => return e,
     };
 
+    // the operand is described as written in the original code, before other mutators have been applied to it
+    let operand = original_operand(context).unwrap_or_else(|| e.expr.to_token_stream().to_string());
+
     let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
         &context,
         "unop_not".to_owned(),
-        "!".to_owned(),
-        "".to_owned(),
+        format!("!{}", operand),
+        operand,
         e.span,
     ));
 
@@ -78,6 +82,14 @@ pub fn transform(
     .expect("transformed code invalid")
 }
 
+/// the negated operand as written in the original code
+fn original_operand(context: &TransformContext) -> Option<String> {
+    match context.original_expr.clone().map(ExprUnopNot::try_from) {
+        Some(Ok(e)) => Some(e.expr.to_token_stream().to_string()),
+        _ => None,
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ExprUnopNot {
     pub expr: Expr,
@@ -156,6 +168,7 @@ mod tests {
 
     use super::optimistc_types::*;
     use super::*;
+    use syn::parse_quote;
 
     #[test]
     fn boolnot_inactive() {
@@ -192,4 +205,13 @@ mod tests {
             &MutagenRuntimeConfig::with_mutation_id(1),
         );
     }
+
+    #[test]
+    fn original_operand_unfolded() {
+        let context = TransformContext {
+            original_expr: Some(parse_quote! {!done}),
+            ..TransformContext::default()
+        };
+        assert_eq!(original_operand(&context), Some("done".to_owned()));
+    }
 }
@@ -0,0 +1,276 @@
+//! Mutator for indices and lengths of removing methods `remove(i)`, `swap_remove(i)`, `truncate(n)` and `drain(range)`.
+
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::{Expr, ExprMethodCall, ExprRange};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<T>(
+    mutator_id: usize,
+    index: T,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> T {
+    runtime.covered(mutator_id);
+    let mutations = MutationVecRemove::possible_mutations();
+    if let Some(m) = runtime.get_mutation_for_mutator(mutator_id, &mutations) {
+        index.may_perturb(*m)
+    } else {
+        index
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match ExprVecRemove::try_from(e) {
+        Ok(e) => e,
+        Err(e) => return e,
+    };
+
+    // the index is described as written in the original code, before other mutators have been applied to it
+    let original_index =
+        original_index(context).unwrap_or_else(|| e.index_mut().to_token_stream().to_string());
+
+    let mutator_id = transform_info.add_mutations(
+        MutationVecRemove::possible_mutations()
+            .iter()
+            .map(|m| m.to_mutation(&e, &original_index, context)),
+    );
+
+    let span = e.span;
+    let index = e.index_mut();
+    *index = syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_vec_remove::run(
+                #mutator_id,
+                #index,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid");
+
+    Expr::MethodCall(e.call)
+}
+
+/// the mutated index as written in the original code
+fn original_index(context: &TransformContext) -> Option<String> {
+    match context.original_expr.clone().map(ExprVecRemove::try_from) {
+        Some(Ok(mut e)) => Some(e.index_mut().to_token_stream().to_string()),
+        _ => None,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct MutationVecRemove {
+    delta: i8,
+}
+
+impl MutationVecRemove {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationVecRemove { delta: 1 },
+            MutationVecRemove { delta: -1 },
+        ]
+    }
+
+    /// Changes the index by the delta.
+    ///
+    /// Decrementing `0` wraps around, such that the index is out of bounds.
+    /// Removing at an index that is out of bounds panics, which kills the mutant.
+    fn mutate(self, index: usize) -> usize {
+        if self.delta < 0 {
+            index.wrapping_sub(-self.delta as usize)
+        } else {
+            index.wrapping_add(self.delta as usize)
+        }
+    }
+
+    fn to_mutation(
+        self,
+        original: &ExprVecRemove,
+        original_index: &str,
+        context: &TransformContext,
+    ) -> Mutation {
+        let mutated_index = if self.delta < 0 {
+            format!("{} - {}", original_index, -self.delta)
+        } else {
+            format!("{} + {}", original_index, self.delta)
+        };
+        Mutation::new_spanned(
+            context,
+            "vec_remove".to_owned(),
+            format!(
+                "{}({})",
+                original.call.method,
+                original.describe(original_index)
+            ),
+            format!(
+                "{}({})",
+                original.call.method,
+                original.describe(&mutated_index)
+            ),
+            original.span,
+        )
+    }
+}
+
+/// trait that is used to optimistically perturb an index.
+///
+/// Methods like `remove` can be defined on any type and take any argument, e.g. `HashMap::remove` takes a key.
+/// The index can only be perturbed if it is a `usize`, otherwise the optimistic assumption fails.
+trait IndexMutable {
+    fn may_perturb(self, m: MutationVecRemove) -> Self;
+}
+
+impl<T> IndexMutable for T {
+    default fn may_perturb(self, _m: MutationVecRemove) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl IndexMutable for usize {
+    fn may_perturb(self, m: MutationVecRemove) -> Self {
+        m.mutate(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ExprVecRemove {
+    call: ExprMethodCall,
+    bound: RangeBound,
+    span: Span,
+}
+
+/// the mutated part of the argument
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RangeBound {
+    /// the argument is the index itself
+    Index,
+    /// the end of a range, like `drain(..n)`
+    End,
+    /// the start of a range without end, like `drain(n..)`
+    Start,
+}
+
+impl ExprVecRemove {
+    /// the expression of the index that is mutated
+    fn index_mut(&mut self) -> &mut Expr {
+        let arg = self.call.args.first_mut().unwrap();
+        match (self.bound, arg) {
+            (RangeBound::End, Expr::Range(ExprRange { to: Some(to), .. })) => to,
+            (
+                RangeBound::Start,
+                Expr::Range(ExprRange {
+                    from: Some(from), ..
+                }),
+            ) => from,
+            (_, arg) => arg,
+        }
+    }
+
+    /// describes the argument of the call with the given index
+    fn describe(&self, index: &str) -> String {
+        match (self.bound, self.call.args.first()) {
+            (RangeBound::End, Some(Expr::Range(range))) => format!(
+                "{}{}{}",
+                range
+                    .from
+                    .as_ref()
+                    .map(|from| from.to_token_stream().to_string())
+                    .unwrap_or_default(),
+                range.limits.to_token_stream(),
+                index
+            ),
+            (RangeBound::Start, Some(Expr::Range(range))) => {
+                format!("{}{}", index, range.limits.to_token_stream())
+            }
+            _ => index.to_owned(),
+        }
+    }
+}
+
+impl TryFrom<Expr> for ExprVecRemove {
+    type Error = Expr;
+    fn try_from(expr: Expr) -> Result<Self, Expr> {
+        match expr {
+            Expr::MethodCall(call) if call.args.len() == 1 && call.turbofish.is_none() => {
+                let bound = match (&*call.method.to_string(), &call.args[0]) {
+                    ("drain", Expr::Range(ExprRange { to: Some(_), .. })) => RangeBound::End,
+                    ("drain", Expr::Range(ExprRange { from: Some(_), .. })) => RangeBound::Start,
+                    ("remove", _) | ("swap_remove", _) | ("truncate", _) => RangeBound::Index,
+                    _ => return Err(Expr::MethodCall(call)),
+                };
+                Ok(ExprVecRemove {
+                    span: call.method.span(),
+                    call,
+                    bound,
+                })
+            }
+            _ => Err(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn index_inactive() {
+        let result = run(1, 1usize, &MutagenRuntimeConfig::without_mutation());
+        assert_eq!(result, 1);
+    }
+    #[test]
+    fn index_active1() {
+        let result = run(1, 1usize, &MutagenRuntimeConfig::with_mutation_id(1));
+        assert_eq!(result, 2);
+    }
+    #[test]
+    fn index_active2() {
+        let result = run(1, 1usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, 0);
+    }
+    #[test]
+    fn index_zero_decrement_out_of_bounds() {
+        let result = run(1, 0usize, &MutagenRuntimeConfig::with_mutation_id(2));
+        assert_eq!(result, usize::MAX);
+    }
+    #[test]
+    #[should_panic]
+    fn key_active() {
+        run(1, "key", &MutagenRuntimeConfig::with_mutation_id(1));
+    }
+
+    #[test]
+    fn detect_drain_range_end() {
+        let expr: Expr = parse_quote! {v.drain(1..n)};
+        let mut e = ExprVecRemove::try_from(expr).unwrap();
+        assert_eq!(e.bound, RangeBound::End);
+        assert_eq!(e.index_mut(), &parse_quote! {n});
+        assert_eq!(e.describe("n + 1"), "1..n + 1");
+    }
+    #[test]
+    fn detect_drain_range_start() {
+        let expr: Expr = parse_quote! {v.drain(n..)};
+        let mut e = ExprVecRemove::try_from(expr).unwrap();
+        assert_eq!(e.bound, RangeBound::Start);
+        assert_eq!(e.index_mut(), &parse_quote! {n});
+    }
+    #[test]
+    fn ignore_drain_full_range() {
+        let expr: Expr = parse_quote! {v.drain(..)};
+        assert!(ExprVecRemove::try_from(expr).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+//! Mutator for the number of iterations of `while`-loops.
+//!
+//! The mutations force the loop to run exactly zero or exactly one iteration, regardless of its condition.
+
+use std::ops::Deref;
+
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Expr;
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+/// Counts the checks of the loop condition.
+///
+/// Returns the forced result of the condition if one of the mutations is active.
+/// Otherwise, `None` is returned and the original condition is evaluated.
+pub fn run(
+    mutator_id: usize,
+    iterations: &mut usize,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> Option<bool> {
+    runtime.covered(mutator_id);
+    let mutations = MutationWhileIterations::possible_mutations();
+    let m = runtime.get_mutation_for_mutator(mutator_id, &mutations)?;
+    *iterations += 1;
+    Some(*iterations <= m.iterations())
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let mut e = match e {
+        // the condition of `while let` binds variables and cannot be skipped
+        Expr::While(e) if !matches!(*e.cond, Expr::Let(_)) => e,
+        e => return e,
+    };
+    let span = e.while_token.span();
+
+    let mutator_id = transform_info.add_mutations(
+        MutationWhileIterations::possible_mutations()
+            .iter()
+            .map(|m| m.to_mutation(span, context)),
+    );
+
+    let cond = &e.cond;
+    e.cond = syn::parse2(quote_spanned! {span=>
+        match ::mutagen::mutator::mutator_while_iterations::run(
+                #mutator_id,
+                &mut __mutagen_iterations,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            ) {
+            ::core::option::Option::Some(__mutagen_forced) => __mutagen_forced,
+            ::core::option::Option::None => #cond,
+        }
+    })
+    .expect("transformed code invalid");
+
+    syn::parse2(quote_spanned! {span=>
+        {
+            let mut __mutagen_iterations: usize = 0;
+            #e
+        }
+    })
+    .expect("transformed code invalid")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MutationWhileIterations {
+    ZeroIterations,
+    OneIteration,
+}
+
+impl MutationWhileIterations {
+    fn possible_mutations() -> Vec<Self> {
+        vec![
+            MutationWhileIterations::ZeroIterations,
+            MutationWhileIterations::OneIteration,
+        ]
+    }
+
+    /// the number of iterations that are forced
+    fn iterations(self) -> usize {
+        match self {
+            MutationWhileIterations::ZeroIterations => 0,
+            MutationWhileIterations::OneIteration => 1,
+        }
+    }
+
+    fn to_mutation(self, span: proc_macro2::Span, context: &TransformContext) -> Mutation {
+        let mutated_code = match self {
+            MutationWhileIterations::ZeroIterations => "run loop zero times",
+            MutationWhileIterations::OneIteration => "run loop exactly once",
+        };
+        Mutation::new_spanned(
+            context,
+            "while_iterations".to_owned(),
+            "while".to_owned(),
+            mutated_code.to_owned(),
+            span,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn while_iterations_inactive() {
+        let mut iterations = 0;
+        let result = run(
+            1,
+            &mut iterations,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, None);
+    }
+    #[test]
+    fn while_iterations_active1() {
+        let mut iterations = 0;
+        let result = run(
+            1,
+            &mut iterations,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, Some(false));
+    }
+    #[test]
+    fn while_iterations_active2() {
+        let mut iterations = 0;
+        let config = MutagenRuntimeConfig::with_mutation_id(2);
+        assert_eq!(run(1, &mut iterations, &config), Some(true));
+        assert_eq!(run(1, &mut iterations, &config), Some(false));
+        assert_eq!(iterations, 2);
+    }
+}
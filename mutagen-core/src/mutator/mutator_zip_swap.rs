@@ -0,0 +1,213 @@
+//! Mutator for the pairing of zipped iterators `a.iter().zip(b)`.
+
+use std::ops::Deref;
+
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::Expr;
+
+use crate::comm::Mutation;
+use crate::transformer::transform_info::SharedTransformInfo;
+use crate::transformer::TransformContext;
+
+use crate::MutagenRuntimeConfig;
+
+pub fn run<Z>(
+    mutator_id: usize,
+    zip: Z,
+    runtime: impl Deref<Target = MutagenRuntimeConfig>,
+) -> ZipSwap<Z> {
+    runtime.covered(mutator_id);
+    ZipSwap {
+        zip,
+        swap: runtime.is_mutation_active(mutator_id),
+    }
+}
+
+pub fn transform(
+    e: Expr,
+    transform_info: &SharedTransformInfo,
+    context: &TransformContext,
+) -> Expr {
+    let span = match &e {
+        Expr::MethodCall(call)
+            if call.method == "zip"
+                && call.args.len() == 1
+                && call.turbofish.is_none()
+                && is_iterator(&call.receiver) =>
+        {
+            call.method.span()
+        }
+        _ => return e,
+    };
+
+    // the zipped iterator is described as written in the original code, before other mutators have been applied to it
+    let zipped = match &context.original_expr {
+        Some(Expr::MethodCall(call)) if call.method == "zip" && call.args.len() == 1 => {
+            call.args[0].to_token_stream().to_string()
+        }
+        _ => "b".to_owned(),
+    };
+
+    let mutator_id = transform_info.add_mutation(Mutation::new_spanned(
+        context,
+        "zip_swap".to_owned(),
+        format!("zip({})", zipped),
+        format!("zip({}) with swapped pairs", zipped),
+        span,
+    ));
+
+    syn::parse2(quote_spanned! {span=>
+        ::mutagen::mutator::mutator_zip_swap::run(
+                #mutator_id,
+                #e,
+                ::mutagen::MutagenRuntimeConfig::get_default()
+            )
+    })
+    .expect("transformed code invalid")
+}
+
+/// methods that create iterators.
+///
+/// Methods that also exist on `Option`, like `map` or `take`, are left out since `Option` has a method `zip` as well.
+const ITERATOR_METHODS: &[&str] = &[
+    "iter",
+    "iter_mut",
+    "into_iter",
+    "chars",
+    "char_indices",
+    "bytes",
+    "lines",
+    "split_whitespace",
+    "keys",
+    "values",
+    "values_mut",
+    "windows",
+    "chunks",
+    "enumerate",
+    "rev",
+    "skip",
+    "step_by",
+    "chain",
+    "peekable",
+    "skip_while",
+    "take_while",
+    "filter_map",
+    "flat_map",
+];
+
+/// checks if the receiver of `zip` is known to be an iterator.
+///
+/// The mutated code changes the type of the zipped iterator, which only compiles for iterators.
+fn is_iterator(e: &Expr) -> bool {
+    match e {
+        Expr::MethodCall(call) => ITERATOR_METHODS.iter().any(|m| call.method == m),
+        Expr::Range(_) => true,
+        Expr::Paren(paren) => is_iterator(&paren.expr),
+        _ => false,
+    }
+}
+
+/// iterator that swaps the elements of zipped pairs if the mutation is active.
+#[derive(Clone, Debug)]
+pub struct ZipSwap<Z> {
+    zip: Z,
+    swap: bool,
+}
+
+impl<Z: Iterator<Item = (A, B)>, A, B> Iterator for ZipSwap<Z> {
+    type Item = (A, B);
+
+    fn next(&mut self) -> Option<(A, B)> {
+        let pair = self.zip.next()?;
+        Some(if self.swap { pair.may_swap() } else { pair })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.zip.size_hint()
+    }
+}
+
+impl<Z: DoubleEndedIterator<Item = (A, B)>, A, B> DoubleEndedIterator for ZipSwap<Z> {
+    fn next_back(&mut self) -> Option<(A, B)> {
+        let pair = self.zip.next_back()?;
+        Some(if self.swap { pair.may_swap() } else { pair })
+    }
+}
+
+impl<Z: ExactSizeIterator<Item = (A, B)>, A, B> ExactSizeIterator for ZipSwap<Z> {}
+
+/// trait that is used to optimistically swap the elements of a pair.
+///
+/// The elements can only be swapped if they have the same type, otherwise the optimistic assumption fails.
+trait PairSwappable {
+    fn may_swap(self) -> Self;
+}
+
+impl<A, B> PairSwappable for (A, B) {
+    default fn may_swap(self) -> Self {
+        MutagenRuntimeConfig::get_default().optimistic_assmuption_failed();
+    }
+}
+
+impl<A> PairSwappable for (A, A) {
+    fn may_swap(self) -> Self {
+        (self.1, self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn zip_inactive() {
+        let a = [1, 2];
+        let b = [3, 4];
+        let zip = run(
+            1,
+            a.iter().zip(b.iter()),
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(zip.collect::<Vec<_>>(), vec![(&1, &3), (&2, &4)]);
+    }
+    #[test]
+    fn zip_active() {
+        let a = [1, 2];
+        let b = [3, 4];
+        let zip = run(
+            1,
+            a.iter().zip(b.iter()),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(zip.collect::<Vec<_>>(), vec![(&3, &1), (&4, &2)]);
+    }
+    #[test]
+    #[should_panic]
+    fn zip_different_types_active() {
+        let zip = run(
+            1,
+            (0..2).zip("ab".chars()),
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        zip.for_each(drop);
+    }
+
+    #[test]
+    fn iterator_iter() {
+        let e: Expr = parse_quote! {a.iter()};
+        assert!(is_iterator(&e));
+    }
+    #[test]
+    fn iterator_range() {
+        let e: Expr = parse_quote! {(0..10)};
+        assert!(is_iterator(&e));
+    }
+    #[test]
+    fn iterator_not_option() {
+        let e: Expr = parse_quote! {a.map(f)};
+        assert!(!is_iterator(&e));
+    }
+}
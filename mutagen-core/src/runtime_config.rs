@@ -8,21 +8,57 @@
 //!
 //! The main method to configure the global runtime configuration is via environment variables.
 //! The variable `MUTATION_ID` activates a single mutation
+//! If the variable `MUTAGEN_ID_VAR` is set, the active mutation is read from the variable with the given name instead, e.g. `MUTAGEN_ID_VAR=MY_MUTANT MY_MUTANT=1`.
 //! The variable `MUTAGEN_MODE` is used to specify other configurations.
 //!
 //! * `MUTAGEN_MODE=mutation`: activate a single mutation (default)
 //! * `MUTAGEN_MODE=coverage`: perform coverage analysis
 //!
 //! In the mode `coverage`, it is required to add the environment variable `MUTAGEN_NUM_MUTATIONS=N` where `N` are the total number of mutations
+//!
+//! When `mutagen-core` is compiled with the feature `no_coverage`, recording coverage is compiled away and only checking for the active mutation remains at runtime.
+//! In the mode `coverage`, all mutations are reported as covered instead.
+//!
+//! The variable `MUTAGEN_ONLY_KIND` contains a comma-separated list of mutators.
+//! If it is set, the mutation given by `MUTATION_ID` is only activated if it has been generated by one of these mutators.
+//! This allows running subsets of the mutations of a single test binary.
+//!
+//! The variable `MUTAGEN_EXPECTED_EQUIVALENT` contains a comma-separated list of mutators that are expected to produce equivalent mutants.
+//! Surviving mutants of these mutators are reported as informational instead of survived.
+//!
+//! The variable `MUTAGEN_OPTIMISTIC_POLICY` is set by `cargo mutagen` to the policy the mutator registered for the active mutation.
+//! With the policies `skip` and `fatal`, a failed optimistic assumption exits the test suite with a special exit code instead of panicking.
+//!
+//! If the variable `MUTAGEN_FAIL_FAST` is set, `cargo mutagen` stops at the first surviving mutant instead of running all mutations.
+//!
+//! For debugging a single mutant, the function `replay` activates a mutation listed in the mutations file written by `cargo mutagen`, together with its policy for failed optimistic assumptions.
+//!
+//! Every item transformed by `#[mutate]` with a global configuration registers its mutations when the binary is loaded.
+//! Drivers that run mutations of several binaries can query the ids of the mutations compiled into a binary with `MutagenRuntimeConfig::mutation_range`.
+//!
+//! Harnesses that change the global runtime config can save and restore it with `MutagenRuntimeConfig::snapshot` and `MutagenRuntimeConfig::restore`.
+//! The function `MutagenRuntimeConfig::scoped` sets a config until the returned guard is dropped, which also happens when unwinding from a panic.
+//!
+//! Coverage and failed optimistic assumptions are reported to a `ResultSink`.
+//! By default, coverage is written to the coverage file read by `cargo mutagen`.
+//! Test harnesses can install another sink with `mutagen::set_result_sink` before the first mutated code is executed.
+//!
+//! Mutated functions that call themselves and contain mutations record their recursion depth for each thread.
+//! If the variable `MUTAGEN_RECURSION_LIMIT` is set, a mutant that exceeds this depth exits the test suite with a special exit code and is reported as killed by the recursion limit.
 
+use failure::{format_err, Fallible};
 use lazy_static::lazy_static;
+use std::cell::Cell;
 use std::fs::File;
-use std::io::{BufWriter, Write};
 use std::ops::Deref;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use crate::comm;
+use crate::comm::{
+    BakedMutation, CoverageFileSink, Mutation, MutationInfo, OptimisticPolicy, ResultSink,
+};
 
 lazy_static! {
     static ref RUNTIME_CONFIG: RwLock<MutagenRuntimeConfig> =
@@ -36,18 +72,46 @@ lazy_static! {
             let config = MutagenRuntimeConfig::without_mutation();
             RwLock::new(config)
         };
+    /// the policy for failed optimistic assumptions of the active mutation, which is part of the global runtime config
+    static ref OPTIMISTIC_POLICY: RwLock<OptimisticPolicy> = RwLock::new(
+        std::env::var("MUTAGEN_OPTIMISTIC_POLICY")
+            .ok()
+            .and_then(|s| OptimisticPolicy::from_name(&s))
+            .unwrap_or_default()
+    );
+    /// the sink installed by `set_result_sink`, which replaces the default sinks
+    static ref RESULT_SINK: RwLock<Option<Arc<dyn ResultSink>>> = RwLock::new(None);
+    /// the mutations registered by the items transformed with a global configuration
+    static ref MUTATION_REGISTRY: RwLock<Vec<&'static [MutationInfo]>> = RwLock::new(vec![]);
+    static ref RECURSION_LIMIT: Option<usize> =
+        {
+            // during tests, the limit is fixed such that mutants recursing forever panic before the stack overflows
+            #[cfg(not(any(test, feature = "self_test")))]
+            let limit = std::env::var("MUTAGEN_RECURSION_LIMIT")
+                .ok()
+                .and_then(|s| s.parse().ok());
+            #[cfg(any(test, feature = "self_test"))]
+            let limit = Some(1000);
+            limit
+        };
+}
+
+thread_local! {
+    /// the current and the maximum depth of recursive calls of mutated functions on this thread
+    static RECURSION_DEPTH: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
 }
 
+#[derive(Clone)]
 pub enum MutagenRuntimeConfig {
     Pass,
     Mutation(usize),
-    Coverage(CoverageRecorder),
+    Coverage(Arc<CoverageRecorder>),
 }
 
 /// Counts how many times each mutator has been covered and reports when a mutator is covered the first time.
 pub struct CoverageRecorder {
     coverage: CoverageHitCollector,
-    coverage_file: File,
+    sink: Arc<dyn ResultSink>,
 }
 
 impl MutagenRuntimeConfig {
@@ -59,6 +123,54 @@ impl MutagenRuntimeConfig {
         RUNTIME_CONFIG.read().unwrap()
     }
 
+    /// Captures the complete global runtime config, including the policy for failed optimistic assumptions.
+    ///
+    /// The config can be reinstated later with `restore`.
+    /// A coverage recorder is shared between the snapshot and the global config.
+    pub fn snapshot() -> ConfigSnapshot {
+        let config = RUNTIME_CONFIG.read().unwrap();
+        ConfigSnapshot {
+            config: config.clone(),
+            policy: *OPTIMISTIC_POLICY.read().unwrap(),
+        }
+    }
+
+    /// Reinstates the global runtime config captured by `snapshot`.
+    pub fn restore(snapshot: ConfigSnapshot) {
+        let mut config = RUNTIME_CONFIG.write().unwrap();
+        *OPTIMISTIC_POLICY.write().unwrap() = snapshot.policy;
+        *config = snapshot.config;
+    }
+
+    /// Sets the global runtime config until the returned guard is dropped.
+    ///
+    /// The policy for failed optimistic assumptions is kept.
+    /// The previous config is captured and replaced under a single lock.
+    /// Guards can be nested and restore the configs in reverse order, also when a panic unwinds the stack.
+    pub fn scoped(config: Self) -> ConfigGuard {
+        let policy = *OPTIMISTIC_POLICY.read().unwrap();
+        Self::scoped_with_policy(config, policy)
+    }
+
+    /// Sets the global runtime config and the policy for failed optimistic assumptions until the returned guard is dropped.
+    pub fn scoped_with_policy(config: Self, policy: OptimisticPolicy) -> ConfigGuard {
+        let mut current = RUNTIME_CONFIG.write().unwrap();
+        let previous = ConfigSnapshot {
+            config: std::mem::replace(&mut *current, config),
+            policy: std::mem::replace(&mut *OPTIMISTIC_POLICY.write().unwrap(), policy),
+        };
+        ConfigGuard {
+            previous: Some(previous),
+        }
+    }
+
+    /// The policy for failed optimistic assumptions of the active mutation.
+    ///
+    /// This is read from the environment variable `MUTAGEN_OPTIMISTIC_POLICY`, unless another policy is set by `scoped_with_policy` or `replay`.
+    pub fn optimistic_policy() -> OptimisticPolicy {
+        *OPTIMISTIC_POLICY.read().unwrap()
+    }
+
     /// Creates a runtime config from environment variables.
     ///
     /// See the module documentation for configuration options
@@ -72,15 +184,17 @@ impl MutagenRuntimeConfig {
                     .ok()
                     .and_then(|s| s.parse().ok())
                     .expect("environemnt variable `MUTAGEN_NUM_MUTATIONS` missing");
-                Self::Coverage(CoverageRecorder::new(num_mutations))
+                Self::Coverage(Arc::new(CoverageRecorder::new(num_mutations)))
             }
             "" | "mutation" => {
-                let mutation_id = std::env::var("MUTATION_ID")
-                    .ok()
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(0);
+                let mutation_id = read_mutation_id(&Self::mutation_id_var());
                 if mutation_id == 0 {
                     Self::Pass
+                } else if let Ok(kinds) = std::env::var("MUTAGEN_ONLY_KIND") {
+                    let mutations_file = comm::get_mutations_file().unwrap();
+                    let mutations = comm::read_items::<BakedMutation>(&mutations_file)
+                        .unwrap_or_else(|_| panic!("unable to read file {:?}", mutations_file));
+                    Self::with_kind_filter(mutation_id, &mutations, &parse_mutator_list(&kinds))
                 } else {
                     Self::Mutation(mutation_id)
                 }
@@ -89,21 +203,106 @@ impl MutagenRuntimeConfig {
         }
     }
 
+    /// Activates the given mutation only if it has been generated by one of the given mutators.
+    ///
+    /// The mutator of the mutation is looked up once when creating the config.
+    /// This keeps checking for active mutations cheap.
+    #[cfg_attr(feature = "self_test", allow(dead_code))]
+    fn with_kind_filter(mutation_id: usize, mutations: &[BakedMutation], kinds: &[String]) -> Self {
+        let kind_matches = mutations
+            .iter()
+            .filter(|m| m.id() == mutation_id)
+            .any(|m| kinds.iter().any(|k| k == m.mutator_name()));
+        if kind_matches {
+            Self::Mutation(mutation_id)
+        } else {
+            Self::Pass
+        }
+    }
+
+    /// The name of the environment variable that activates a mutation.
+    ///
+    /// This is `MUTATION_ID`, unless another name is given by the environment variable `MUTAGEN_ID_VAR`.
+    pub fn mutation_id_var() -> String {
+        mutation_id_var_name(std::env::var("MUTAGEN_ID_VAR").ok())
+    }
+
+    /// Reads the list of mutators that are expected to produce equivalent mutants from the environment variable `MUTAGEN_EXPECTED_EQUIVALENT`.
+    pub fn expected_equivalent_mutators() -> Vec<String> {
+        std::env::var("MUTAGEN_EXPECTED_EQUIVALENT")
+            .map(|s| parse_mutator_list(&s))
+            .unwrap_or_default()
+    }
+
+    /// Checks if the environment variable `MUTAGEN_FAIL_FAST` is set.
+    ///
+    /// In this case, the run of all mutations stops at the first surviving mutant.
+    pub fn fail_fast() -> bool {
+        std::env::var_os("MUTAGEN_FAIL_FAST").is_some()
+    }
+
+    /// Registers the mutations of an item that has been transformed by `#[mutate]` with a global configuration.
+    ///
+    /// This is called by the generated code when the binary is loaded.
+    #[doc(hidden)]
+    pub fn register_mutations(mutations: &'static [MutationInfo]) {
+        MUTATION_REGISTRY.write().unwrap().push(mutations);
+    }
+
+    /// Returns the descriptions of all mutations compiled into this binary, ordered by their id.
+    pub fn mutation_table() -> Vec<MutationInfo> {
+        let mut table = MUTATION_REGISTRY
+            .read()
+            .unwrap()
+            .iter()
+            .flat_map(|mutations| mutations.iter().copied())
+            .collect::<Vec<_>>();
+        table.sort_by_key(|m| m.id);
+        table
+    }
+
+    /// Returns the smallest and the largest id of the mutations compiled into this binary.
+    ///
+    /// The range contains the mutations of all items with a global configuration that are linked into the binary, independent of the order in which they have been transformed.
+    /// Mutations with ids outside of this range behave like the original program.
+    /// Returns `None` if the binary contains no mutations.
+    pub fn mutation_range() -> Option<(usize, usize)> {
+        let registry = MUTATION_REGISTRY.read().unwrap();
+        range_of(registry.iter().flat_map(|mutations| mutations.iter()))
+    }
+
     /// Records that mutator with the given id is covered.
     ///
     /// This does nothing if coverage is not enabled.
+    #[cfg(not(feature = "no_coverage"))]
+    #[inline]
     pub fn covered(&self, mutator_id: usize) {
         if let Self::Coverage(coverage) = &self {
             coverage.covered(mutator_id)
         }
     }
 
+    /// Does nothing since coverage tracking is disabled by the feature `no_coverage`.
+    ///
+    /// In coverage mode, all mutators are reported as covered when the config is created.
+    #[cfg(feature = "no_coverage")]
+    #[inline(always)]
+    pub fn covered(&self, _mutator_id: usize) {}
+
     /// Function to abort the computation in case a optimistic mutation fails.
     ///
-    /// In the future, this will be configurable
+    /// By default, the test panics and the mutant is killed.
+    /// If the runner passes another policy of the mutation via `MUTAGEN_OPTIMISTIC_POLICY`, the test suite exits with the exit code of that policy.
     pub fn optimistic_assmuption_failed(&self) -> ! {
         match self {
             Self::Mutation(m_id) => {
+                if let Some(sink) = installed_result_sink() {
+                    sink.on_optimistic_failed(*m_id);
+                }
+                if let Some(code) = Self::optimistic_policy().exit_code() {
+                    eprintln!("optimistic assumption failed for mutation {}", m_id);
+                    std::process::exit(code);
+                }
                 panic!("optimistic assumption failed for mutation {}", m_id);
             }
             _ => panic!("optimistic assumption failed without mutation"),
@@ -119,6 +318,7 @@ impl MutagenRuntimeConfig {
     }
 
     /// Checks if the given mutation is activated.
+    #[inline]
     pub fn is_mutation_active(&self, mutation_id: usize) -> bool {
         self.mutation_id() == Some(mutation_id)
     }
@@ -130,36 +330,223 @@ impl MutagenRuntimeConfig {
         mutations: &'a [T],
     ) -> Option<&'a T> {
         let m_id = self.mutation_id()?;
-        if m_id < mutator_id {
+        // mutators without registered mutations are never activated
+        if mutator_id == 0 || m_id < mutator_id {
             return None;
         }
         let index = m_id - mutator_id;
         mutations.get(index)
     }
+
+    /// Records the entry into a mutated function that calls itself.
+    ///
+    /// The depth is decreased again when the returned guard is dropped.
+    /// Exceeding the limit given by `MUTAGEN_RECURSION_LIMIT` aborts the computation.
+    pub fn enter_recursion() -> RecursionGuard {
+        let depth = RECURSION_DEPTH.with(|d| {
+            let (depth, max_depth) = d.get();
+            d.set((depth + 1, max_depth.max(depth + 1)));
+            depth + 1
+        });
+        let guard = RecursionGuard(());
+        if let Some(limit) = *RECURSION_LIMIT {
+            if depth > limit {
+                Self::recursion_limit_exceeded(limit);
+            }
+        }
+        guard
+    }
+
+    /// The maximum recursion depth of mutated functions that has been reached on the current thread.
+    pub fn max_recursion_depth() -> usize {
+        RECURSION_DEPTH.with(|d| d.get().1)
+    }
+
+    /// Function to abort the computation in case a mutated function recurses too deep.
+    ///
+    /// With an active mutation, the test suite exits with a special exit code, such that the mutant is reported as killed.
+    /// During tests of mutagen itself, and if no mutation is active, the test panics.
+    fn recursion_limit_exceeded(limit: usize) -> ! {
+        let mutation_id = Self::get_default().mutation_id();
+        match mutation_id {
+            #[cfg(not(any(test, feature = "self_test")))]
+            Some(m_id) => {
+                eprintln!("recursion limit {} exceeded for mutation {}", limit, m_id);
+                std::process::exit(comm::RECURSION_LIMIT_EXIT_CODE);
+            }
+            #[cfg(any(test, feature = "self_test"))]
+            Some(m_id) => panic!("recursion limit {} exceeded for mutation {}", limit, m_id),
+            None => panic!("recursion limit {} exceeded without mutation", limit),
+        }
+    }
+}
+
+/// Marks the entry into a mutated function that calls itself, created by `MutagenRuntimeConfig::enter_recursion`.
+///
+/// The recursion depth of the current thread is decreased when the guard is dropped, which also happens when unwinding from a panic.
+pub struct RecursionGuard(());
+
+impl Drop for RecursionGuard {
+    fn drop(&mut self) {
+        RECURSION_DEPTH.with(|d| {
+            let (depth, max_depth) = d.get();
+            d.set((depth.saturating_sub(1), max_depth));
+        });
+    }
+}
+
+/// Activates the mutation with the given id from a mutations file to reproduce a mutant.
+///
+/// The mutations file is the file `target/mutagen/mutations.json` written by `cargo mutagen`.
+/// The mutation stays active until the returned guard is dropped.
+/// The policy for failed optimistic assumptions is set to the policy registered for the mutation.
+/// Running tests while the guard is alive executes them exactly as they were run for this mutant.
+pub fn replay(mutations_file: impl AsRef<Path>, mutation_id: usize) -> Fallible<ReplayGuard> {
+    let mutations_file = mutations_file.as_ref();
+    let mutation = comm::read_mutations_catalog(mutations_file)?
+        .into_iter()
+        .find(|(id, _)| *id == mutation_id)
+        .map(|(_, mutation)| mutation)
+        .ok_or_else(|| format_err!("mutation {} not found in {:?}", mutation_id, mutations_file))?;
+
+    let config = MutagenRuntimeConfig::scoped_with_policy(
+        MutagenRuntimeConfig::Mutation(mutation_id),
+        mutation.optimistic_policy,
+    );
+    Ok(ReplayGuard {
+        mutation,
+        _config: config,
+    })
+}
+
+/// A saved global runtime config, created by `MutagenRuntimeConfig::snapshot`.
+#[derive(Clone)]
+pub struct ConfigSnapshot {
+    config: MutagenRuntimeConfig,
+    policy: OptimisticPolicy,
+}
+
+impl ConfigSnapshot {
+    /// The mutation that is active in the saved config.
+    pub fn mutation_id(&self) -> Option<usize> {
+        self.config.mutation_id()
+    }
+
+    /// The policy for failed optimistic assumptions in the saved config.
+    pub fn optimistic_policy(&self) -> OptimisticPolicy {
+        self.policy
+    }
+}
+
+/// Keeps a runtime config set by `MutagenRuntimeConfig::scoped` active.
+///
+/// The previous runtime config is restored when the guard is dropped.
+pub struct ConfigGuard {
+    previous: Option<ConfigSnapshot>,
+}
+
+impl Drop for ConfigGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            MutagenRuntimeConfig::restore(previous);
+        }
+    }
+}
+
+/// Keeps a replayed mutation active.
+///
+/// The previous runtime config is restored when the guard is dropped.
+pub struct ReplayGuard {
+    mutation: Mutation,
+    _config: ConfigGuard,
+}
+
+impl ReplayGuard {
+    /// The mutation that is replayed.
+    pub fn mutation(&self) -> &Mutation {
+        &self.mutation
+    }
+}
+
+/// Installs the sink that receives the events reported by mutated code.
+///
+/// This has to be called before the first mutated code is executed, since the coverage recorder keeps the sink it has been created with.
+pub fn set_result_sink(sink: Arc<dyn ResultSink>) {
+    *RESULT_SINK.write().unwrap() = Some(sink);
+}
+
+/// returns the sink installed by `set_result_sink`, if any
+fn installed_result_sink() -> Option<Arc<dyn ResultSink>> {
+    RESULT_SINK.read().unwrap().clone()
+}
+
+/// reads the id of the active mutation from the given environment variable, which is `0` if no mutation is active
+#[cfg_attr(any(test, feature = "self_test"), allow(dead_code))]
+fn read_mutation_id(var: &str) -> usize {
+    parse_mutation_id(std::env::var(var).ok().as_deref())
+}
+
+/// parses the value of the variable with the id of the active mutation, which is `0` if it is missing or invalid
+fn parse_mutation_id(value: Option<&str>) -> usize {
+    value.and_then(|s| s.parse().ok()).unwrap_or(0)
+}
+
+/// the name of the variable with the id of the active mutation, given the value of `MUTAGEN_ID_VAR`
+fn mutation_id_var_name(id_var: Option<String>) -> String {
+    id_var
+        .filter(|var| !var.is_empty())
+        .unwrap_or_else(|| "MUTATION_ID".to_owned())
+}
+
+/// returns the smallest and the largest id of the given mutations
+fn range_of<'a>(
+    mutations: impl Iterator<Item = &'a MutationInfo> + Clone,
+) -> Option<(usize, usize)> {
+    let min = mutations.clone().map(|m| m.id).min()?;
+    let max = mutations.map(|m| m.id).max()?;
+    Some((min, max))
+}
+
+/// parses a comma-separated list of mutator names
+fn parse_mutator_list(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
 }
 
 impl CoverageRecorder {
+    /// Creates a recorder that reports to the installed sink, or to the coverage file if no sink has been installed.
+    #[cfg_attr(any(test, feature = "self_test"), allow(dead_code))]
     fn new(num_mutations: usize) -> Self {
-        let coverage = CoverageHitCollector::new(num_mutations);
-        let coverage_filepath = comm::get_coverage_file().unwrap();
-        let coverage_file = File::create(&coverage_filepath)
-            .unwrap_or_else(|_| panic!("unable to open file {:?}", &coverage_filepath));
-
-        Self {
-            coverage,
-            coverage_file,
+        let sink = installed_result_sink().unwrap_or_else(|| {
+            let coverage_filepath = comm::get_coverage_file().unwrap();
+            let coverage_file = File::create(&coverage_filepath)
+                .unwrap_or_else(|_| panic!("unable to open file {:?}", &coverage_filepath));
+            Arc::new(CoverageFileSink::new(coverage_file))
+        });
+        Self::with_sink(num_mutations, sink)
+    }
+
+    fn with_sink(num_mutations: usize, sink: Arc<dyn ResultSink>) -> Self {
+        let recorder = Self {
+            coverage: CoverageHitCollector::new(num_mutations),
+            sink,
+        };
+        // without coverage tracking, every mutator is assumed to be covered
+        if cfg!(feature = "no_coverage") {
+            for mutator_id in 1..=num_mutations {
+                recorder.covered(mutator_id);
+            }
         }
+        recorder
     }
 
     fn covered(&self, mutator_id: usize) {
         // report first coverage
         if self.coverage.hit(mutator_id) {
-            let coverage_hit = comm::CoverageHit { mutator_id };
-
-            let mut w = BufWriter::new(&self.coverage_file);
-            serde_json::to_writer(&mut w, &coverage_hit).expect("unable to write to coverage file");
-            // write newline
-            writeln!(&mut w).expect("unable to write to coverage file");
+            self.sink.on_covered(mutator_id);
         }
     }
 }
@@ -230,6 +617,8 @@ mod test_tools {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::comm::{MemoryResultSink, Mutation, ResultEvent};
+    use std::collections::HashMap;
 
     #[test]
     fn config_mutation_active() {
@@ -244,12 +633,281 @@ mod tests {
         assert!(!config.is_mutation_active(2));
     }
     #[test]
+    fn config_mutation_unregistered_mutator() {
+        let config = MutagenRuntimeConfig::with_mutation_id(1);
+
+        assert_eq!(config.get_mutation_for_mutator(0, &[1, 2]), None);
+    }
+    #[test]
     fn config_mutation_no_mutation() {
         let config = MutagenRuntimeConfig::without_mutation();
 
         assert!(!config.is_mutation_active(1));
     }
 
+    fn mutations_of_kinds() -> Vec<BakedMutation> {
+        vec![
+            Mutation {
+                mutator: "binop_num".to_owned(),
+                ..Mutation::new_stub()
+            }
+            .with_id(1, 1),
+            Mutation {
+                mutator: "lit_int".to_owned(),
+                ..Mutation::new_stub()
+            }
+            .with_id(2, 2),
+        ]
+    }
+
+    #[test]
+    fn kind_filter_matching_kind() {
+        let config = MutagenRuntimeConfig::with_kind_filter(
+            1,
+            &mutations_of_kinds(),
+            &["binop_num".to_owned()],
+        );
+
+        assert!(config.is_mutation_active(1));
+    }
+    #[test]
+    fn kind_filter_other_kind() {
+        let config = MutagenRuntimeConfig::with_kind_filter(
+            2,
+            &mutations_of_kinds(),
+            &["binop_num".to_owned()],
+        );
+
+        assert!(!config.is_mutation_active(2));
+        assert_eq!(config.mutation_id(), None);
+    }
+    #[test]
+    fn kind_filter_multiple_kinds() {
+        let config = MutagenRuntimeConfig::with_kind_filter(
+            2,
+            &mutations_of_kinds(),
+            &["binop_num".to_owned(), "lit_int".to_owned()],
+        );
+
+        assert!(config.is_mutation_active(2));
+    }
+    #[test]
+    fn kind_filter_unknown_mutation() {
+        let config = MutagenRuntimeConfig::with_kind_filter(
+            3,
+            &mutations_of_kinds(),
+            &["binop_num".to_owned()],
+        );
+
+        assert!(!config.is_mutation_active(3));
+    }
+
+    fn mutation_info(id: usize) -> MutationInfo {
+        MutationInfo {
+            id,
+            mutator_id: id,
+            mutator: "",
+            impl_name: None,
+            fn_name: None,
+            original_code: "",
+            mutated_code: "",
+            source_file: "",
+            location_in_file: "",
+        }
+    }
+
+    #[test]
+    fn range_of_registered_mutations() {
+        let mutations = (1..=5).map(mutation_info).collect::<Vec<_>>();
+
+        assert_eq!(range_of(mutations.iter()), Some((1, mutations.len())));
+    }
+    #[test]
+    fn range_of_unordered_mutations() {
+        let mutations = [mutation_info(7), mutation_info(4), mutation_info(5)];
+
+        assert_eq!(range_of(mutations.iter()), Some((4, 7)));
+    }
+    #[test]
+    fn range_of_no_mutations() {
+        assert_eq!(range_of([].iter()), None);
+    }
+
+    #[test]
+    fn mutator_list_empty() {
+        assert_eq!(parse_mutator_list(""), Vec::<String>::new());
+    }
+    #[test]
+    fn mutator_list_multiple() {
+        assert_eq!(
+            parse_mutator_list("box_new, iter_count,"),
+            vec!["box_new".to_owned(), "iter_count".to_owned()]
+        );
+    }
+
+    #[test]
+    fn mutation_id_from_value() {
+        assert_eq!(parse_mutation_id(Some("3")), 3);
+    }
+    #[test]
+    fn mutation_id_from_invalid_value() {
+        assert_eq!(parse_mutation_id(Some("x")), 0);
+    }
+    #[test]
+    fn mutation_id_from_missing_var() {
+        assert_eq!(read_mutation_id("MUTAGEN_TEST_MISSING_ID"), 0);
+    }
+    #[test]
+    fn mutation_id_var_default_name() {
+        assert_eq!(mutation_id_var_name(None), "MUTATION_ID");
+        assert_eq!(mutation_id_var_name(Some(String::new())), "MUTATION_ID");
+    }
+    #[test]
+    fn mutation_id_var_custom_name() {
+        assert_eq!(
+            mutation_id_var_name(Some("MUTAGEN_TEST_ID".to_owned())),
+            "MUTAGEN_TEST_ID"
+        );
+    }
+
+    fn mutations_file(name: &str, mutations: &HashMap<usize, Mutation>) -> std::path::PathBuf {
+        let file = std::env::temp_dir().join(format!("mutagen-test-{}.json", name));
+        std::fs::write(&file, serde_json::to_string(mutations).unwrap()).unwrap();
+        file
+    }
+
+    #[test]
+    fn replay_activates_mutation() {
+        let mut mutations = HashMap::new();
+        mutations.insert(
+            2,
+            Mutation {
+                mutator: "binop_num".to_owned(),
+                ..Mutation::new_stub()
+            },
+        );
+        let file = mutations_file("replay", &mutations);
+
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let guard = replay(&file, 2).unwrap();
+            assert_eq!(guard.mutation().mutator, "binop_num");
+            assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), Some(2));
+
+            drop(guard);
+            assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), None);
+        });
+    }
+    #[test]
+    fn replay_sets_policy() {
+        let mut mutations = HashMap::new();
+        mutations.insert(
+            3,
+            Mutation::new_stub().with_optimistic_policy(OptimisticPolicy::Skip),
+        );
+        let file = mutations_file("replay-policy", &mutations);
+
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let guard = replay(&file, 3).unwrap();
+            assert_eq!(
+                MutagenRuntimeConfig::optimistic_policy(),
+                OptimisticPolicy::Skip
+            );
+
+            drop(guard);
+            assert_eq!(
+                MutagenRuntimeConfig::optimistic_policy(),
+                OptimisticPolicy::Killed
+            );
+        });
+    }
+    #[test]
+    fn replay_unknown_mutation() {
+        let file = mutations_file("replay-unknown", &HashMap::new());
+
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(replay(&file, 1).is_err());
+            assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), None);
+        });
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let snapshot = MutagenRuntimeConfig::snapshot();
+            assert_eq!(snapshot.mutation_id(), Some(1));
+
+            *RUNTIME_CONFIG.write().unwrap() = MutagenRuntimeConfig::with_mutation_id(2);
+            MutagenRuntimeConfig::restore(snapshot);
+            assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), Some(1));
+        });
+    }
+    #[test]
+    fn scoped_nested() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let outer = MutagenRuntimeConfig::scoped(MutagenRuntimeConfig::with_mutation_id(1));
+            {
+                let _inner =
+                    MutagenRuntimeConfig::scoped(MutagenRuntimeConfig::with_mutation_id(2));
+                assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), Some(2));
+            }
+            assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), Some(1));
+
+            drop(outer);
+            assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), None);
+        });
+    }
+    #[test]
+    fn scoped_nested_restored_on_panic() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let result = std::panic::catch_unwind(|| {
+                let _outer =
+                    MutagenRuntimeConfig::scoped(MutagenRuntimeConfig::with_mutation_id(1));
+                let _inner =
+                    MutagenRuntimeConfig::scoped(MutagenRuntimeConfig::with_mutation_id(2));
+                panic!("test case failed");
+            });
+            assert!(result.is_err());
+            assert_eq!(MutagenRuntimeConfig::get_default().mutation_id(), None);
+        });
+    }
+
+    #[test]
+    fn coverage_recorder_reports_first_hit() {
+        let sink = Arc::new(MemoryResultSink::new());
+        let recorder = CoverageRecorder::with_sink(2, sink.clone());
+        recorder.covered(2);
+        recorder.covered(1);
+        recorder.covered(2);
+
+        assert_eq!(
+            sink.events(),
+            vec![ResultEvent::Covered(2), ResultEvent::Covered(1)]
+        );
+    }
+    #[test]
+    fn coverage_config_reports_to_sink() {
+        let sink = Arc::new(MemoryResultSink::new());
+        let config =
+            MutagenRuntimeConfig::Coverage(Arc::new(CoverageRecorder::with_sink(1, sink.clone())));
+        config.covered(1);
+
+        assert_eq!(sink.events(), vec![ResultEvent::Covered(1)]);
+    }
+    #[test]
+    fn optimistic_failure_reported_to_installed_sink() {
+        let sink = Arc::new(MemoryResultSink::new());
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            set_result_sink(sink.clone());
+            let result = std::panic::catch_unwind(|| {
+                MutagenRuntimeConfig::get_default().optimistic_assmuption_failed()
+            });
+            *RESULT_SINK.write().unwrap() = None;
+            assert!(result.is_err());
+        });
+
+        assert_eq!(sink.events(), vec![ResultEvent::OptimisticFailed(3)]);
+    }
+
     #[test]
     fn coverage_hit_collector_hit() {
         let collector = CoverageHitCollector::new(1);
@@ -4,12 +4,18 @@ use syn::fold::Fold;
 
 mod arg_ast;
 pub(crate) mod ast_inspect;
+mod changed_lines;
+pub(crate) mod crate_conf;
+mod derive_expand;
 mod mutate_args;
+mod mutation_table;
+mod skip_comments;
 pub mod transform_context;
 pub mod transform_info;
-pub use transform_context::TransformContext;
+pub use transform_context::{ReturnShape, TransformContext};
 
 use crate::mutator::*;
+use crate_conf::CrateConf;
 use transform_info::SharedTransformInfo;
 
 pub fn do_transform_item(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -23,6 +29,10 @@ pub fn do_transform_item(args: TokenStream, input: TokenStream) -> TokenStream {
 pub enum MutagenTransformer {
     Expr(Box<MutagenExprTransformer>),
     Stmt(Box<MutagenStmtTransformer>),
+    Const(Box<MutagenConstTransformer>),
+    FnBody(Box<MutagenFnBodyTransformer>),
+    /// expression-transformer that is only applied inside of `unsafe` blocks
+    UnsafeExpr(Box<MutagenExprTransformer>),
 }
 
 pub struct MutagenTransformerBundle {
@@ -30,6 +40,12 @@ pub struct MutagenTransformerBundle {
     transform_context: TransformContext,
     expr_transformers: Vec<Box<MutagenExprTransformer>>,
     stmt_transformers: Vec<Box<MutagenStmtTransformer>>,
+    const_transformers: Vec<Box<MutagenConstTransformer>>,
+    fn_body_transformers: Vec<Box<MutagenFnBodyTransformer>>,
+    unsafe_expr_transformers: Vec<Box<MutagenExprTransformer>>,
+    expand_derives: Vec<String>,
+    /// whether a token generated by a macro has been folded since the start of the innermost expression or statement
+    macro_generated_seen: bool,
 }
 
 /// function-type that describes expression-transformers.
@@ -44,17 +60,37 @@ type MutagenExprTransformer =
 type MutagenStmtTransformer =
     dyn FnMut(syn::Stmt, &SharedTransformInfo, &TransformContext) -> syn::Stmt;
 
+/// function-type that describes transformers of initializers of `const` and `static` items.
+///
+// the initializer is given together with the type of the item
+type MutagenConstTransformer =
+    dyn FnMut(syn::Expr, &syn::Type, &SharedTransformInfo, &TransformContext) -> syn::Expr;
+
+/// function-type that describes transformers of the bodies of functions and methods.
+///
+// the body is given together with the declared return type, after the content of the body has been transformed
+type MutagenFnBodyTransformer =
+    dyn FnMut(syn::Block, &syn::ReturnType, &SharedTransformInfo, &TransformContext) -> syn::Block;
+
 impl Fold for MutagenTransformerBundle {
     fn fold_expr(&mut self, e: syn::Expr) -> syn::Expr {
         // save the original expr into the context
         let old_expr = self.transform_context.original_expr.replace(e.clone());
 
         // transform content of the expression first
+        let outer_macro_generated_seen = std::mem::replace(&mut self.macro_generated_seen, false);
         let mut result = syn::fold::fold_expr(self, e);
 
+        // expressions generated by macros are not mutated, but their content may be.
+        // The spans of all tokens of the expression have been inspected while folding its content
+        let is_macro_generated = self.macro_generated_seen;
+        self.macro_generated_seen |= outer_macro_generated_seen;
+
         // call all transformers on this expression
-        for transformer in &mut self.expr_transformers {
-            result = transformer(result, &self.transform_info, &self.transform_context);
+        if !is_macro_generated {
+            for transformer in &mut self.expr_transformers {
+                result = transformer(result, &self.transform_info, &self.transform_context);
+            }
         }
 
         // reset original_stmt to original state
@@ -67,11 +103,18 @@ impl Fold for MutagenTransformerBundle {
         let old_stmt = self.transform_context.original_stmt.replace(s.clone());
 
         // transform content of the statement first
+        let outer_macro_generated_seen = std::mem::replace(&mut self.macro_generated_seen, false);
         let mut result = syn::fold::fold_stmt(self, s);
 
+        // statements generated by macros are not mutated, but their content may be
+        let is_macro_generated = self.macro_generated_seen;
+        self.macro_generated_seen |= outer_macro_generated_seen;
+
         // call all transformers on this statement
-        for transformer in &mut self.stmt_transformers {
-            result = transformer(result, &self.transform_info, &self.transform_context);
+        if !is_macro_generated {
+            for transformer in &mut self.stmt_transformers {
+                result = transformer(result, &self.transform_info, &self.transform_context);
+            }
         }
 
         // reset original_stmt to original state
@@ -80,6 +123,10 @@ impl Fold for MutagenTransformerBundle {
     }
 
     fn fold_item_fn(&mut self, i: syn::ItemFn) -> syn::ItemFn {
+        // do not mutate functions marked with `#[mutagen::skip]`
+        if ast_inspect::has_skip_attr(&i.attrs) {
+            return i;
+        }
         // do not mutate const functions
         if i.sig.constness.is_some() {
             return i;
@@ -94,17 +141,72 @@ impl Fold for MutagenTransformerBundle {
             .transform_context
             .fn_name
             .replace(i.sig.ident.to_string());
+        let old_return_shape = std::mem::replace(
+            &mut self.transform_context.return_shape,
+            ReturnShape::of(&i.sig.output),
+        );
 
         // do transformations
-        let result = syn::fold::fold_item_fn(self, i);
+        let original_block = i.block.clone();
+        let first_mutation = self.transform_info.get_num_mutations();
+        let mut result = syn::fold::fold_item_fn(self, i);
+        result.block = Box::new(self.transform_fn_body(
+            *original_block,
+            *result.block,
+            &result.sig,
+            first_mutation,
+        ));
 
         // restore old context
         self.transform_context.fn_name = old_fn_name;
+        self.transform_context.return_shape = old_return_shape;
 
         result
     }
 
     fn fold_impl_item_method(&mut self, i: syn::ImplItemMethod) -> syn::ImplItemMethod {
+        // do not mutate methods marked with `#[mutagen::skip]`
+        if ast_inspect::has_skip_attr(&i.attrs) {
+            return i;
+        }
+        // do not mutate const functions
+        if i.sig.constness.is_some() {
+            return i;
+        }
+        // do not mutate unsafe functions
+        if i.sig.unsafety.is_some() {
+            return i;
+        }
+
+        // insert the new functionname into context
+        let old_fn_name = self
+            .transform_context
+            .fn_name
+            .replace(i.sig.ident.to_string());
+        let old_return_shape = std::mem::replace(
+            &mut self.transform_context.return_shape,
+            ReturnShape::of(&i.sig.output),
+        );
+
+        // do transformations
+        let original_block = i.block.clone();
+        let first_mutation = self.transform_info.get_num_mutations();
+        let mut result = syn::fold::fold_impl_item_method(self, i);
+        result.block =
+            self.transform_fn_body(original_block, result.block, &result.sig, first_mutation);
+
+        // restore old context
+        self.transform_context.fn_name = old_fn_name;
+        self.transform_context.return_shape = old_return_shape;
+
+        result
+    }
+
+    fn fold_trait_item_method(&mut self, i: syn::TraitItemMethod) -> syn::TraitItemMethod {
+        // do not mutate methods marked with `#[mutagen::skip]`
+        if ast_inspect::has_skip_attr(&i.attrs) {
+            return i;
+        }
         // do not mutate const functions
         if i.sig.constness.is_some() {
             return i;
@@ -119,17 +221,32 @@ impl Fold for MutagenTransformerBundle {
             .transform_context
             .fn_name
             .replace(i.sig.ident.to_string());
+        let old_return_shape = std::mem::replace(
+            &mut self.transform_context.return_shape,
+            ReturnShape::of(&i.sig.output),
+        );
 
         // do transformations
-        let result = syn::fold::fold_impl_item_method(self, i);
+        let original_block = i.default.clone();
+        let first_mutation = self.transform_info.get_num_mutations();
+        let mut result = syn::fold::fold_trait_item_method(self, i);
+        if let (Some(original_block), Some(block)) = (original_block, result.default.take()) {
+            result.default =
+                Some(self.transform_fn_body(original_block, block, &result.sig, first_mutation));
+        }
 
         // restore old context
         self.transform_context.fn_name = old_fn_name;
+        self.transform_context.return_shape = old_return_shape;
 
         result
     }
 
     fn fold_item_impl(&mut self, i: syn::ItemImpl) -> syn::ItemImpl {
+        // do not mutate impls marked with `#[mutagen::skip]`
+        if ast_inspect::has_skip_attr(&i.attrs) {
+            return i;
+        }
         // insert the new item name into context
         let old_fn_name = self
             .transform_context
@@ -145,6 +262,30 @@ impl Fold for MutagenTransformerBundle {
         result
     }
 
+    fn fold_item_trait(&mut self, i: syn::ItemTrait) -> syn::ItemTrait {
+        // do not mutate traits marked with `#[mutagen::skip]`
+        if ast_inspect::has_skip_attr(&i.attrs) {
+            return i;
+        }
+        syn::fold::fold_item_trait(self, i)
+    }
+
+    fn fold_item_mod(&mut self, i: syn::ItemMod) -> syn::ItemMod {
+        // do not mutate modules marked with `#[mutagen::skip]`
+        if ast_inspect::has_skip_attr(&i.attrs) {
+            return i;
+        }
+        // the content of inline modules and their nested modules is mutated
+        let mut i = i;
+        if let Some((brace, items)) = i.content {
+            i.content = Some((
+                brace,
+                derive_expand::expand_derives(items, &self.expand_derives),
+            ));
+        }
+        syn::fold::fold_item_mod(self, i)
+    }
+
     fn fold_expr_repeat(&mut self, e: syn::ExprRepeat) -> syn::ExprRepeat {
         let syn::ExprRepeat {
             attrs,
@@ -195,31 +336,216 @@ impl Fold for MutagenTransformerBundle {
         i
     }
 
-    fn fold_item_const(&mut self, i: syn::ItemConst) -> syn::ItemConst {
-        // do not mutate const-items
+    fn fold_item_const(&mut self, mut i: syn::ItemConst) -> syn::ItemConst {
+        // only const-transformers are applied to const-items
+        i.expr = Box::new(self.transform_const_expr(*i.expr, &i.ty));
         i
     }
 
-    fn fold_item_static(&mut self, i: syn::ItemStatic) -> syn::ItemStatic {
-        // do not mutate static items
+    fn fold_item_static(&mut self, mut i: syn::ItemStatic) -> syn::ItemStatic {
+        // only const-transformers are applied to static items
+        i.expr = Box::new(self.transform_const_expr(*i.expr, &i.ty));
         i
     }
 
+    fn fold_impl_item_const(&mut self, mut i: syn::ImplItemConst) -> syn::ImplItemConst {
+        // only const-transformers are applied to associated consts
+        i.expr = self.transform_const_expr(i.expr, &i.ty);
+        i
+    }
+
+    fn fold_expr_closure(&mut self, e: syn::ExprClosure) -> syn::ExprClosure {
+        // `?` in closures returns from the closure
+        let old_return_shape = std::mem::replace(
+            &mut self.transform_context.return_shape,
+            ReturnShape::of(&e.output),
+        );
+        let result = syn::fold::fold_expr_closure(self, e);
+        self.transform_context.return_shape = old_return_shape;
+        result
+    }
+
+    fn fold_expr_async(&mut self, e: syn::ExprAsync) -> syn::ExprAsync {
+        // `?` in async blocks returns from the block
+        let old_return_shape = self.transform_context.return_shape.take();
+        let result = syn::fold::fold_expr_async(self, e);
+        self.transform_context.return_shape = old_return_shape;
+        result
+    }
+
+    fn fold_expr_while(&mut self, mut e: syn::ExprWhile) -> syn::ExprWhile {
+        // insert the condition into the context while it is transformed
+        let old_condition = self
+            .transform_context
+            .loop_condition
+            .replace((*e.cond).clone());
+        e.cond = Box::new(self.fold_expr(*e.cond));
+        self.transform_context.loop_condition = old_condition;
+
+        e.body = self.fold_loop_body(e.body);
+        e
+    }
+
+    fn fold_expr_loop(&mut self, mut e: syn::ExprLoop) -> syn::ExprLoop {
+        e.body = self.fold_loop_body(e.body);
+        e
+    }
+
+    fn fold_expr_method_call(&mut self, mut e: syn::ExprMethodCall) -> syn::ExprMethodCall {
+        if e.method != "unwrap" || !e.args.is_empty() {
+            return syn::fold::fold_expr_method_call(self, e);
+        }
+        // insert the receiver of `unwrap()` into the context while it is transformed
+        let old_receiver = self
+            .transform_context
+            .unwrapped_receiver
+            .replace((*e.receiver).clone());
+        e.receiver = Box::new(self.fold_expr(*e.receiver));
+        self.transform_context.unwrapped_receiver = old_receiver;
+        e
+    }
+
+    fn fold_expr_for_loop(&mut self, mut e: syn::ExprForLoop) -> syn::ExprForLoop {
+        // insert the iterated expression into the context while it is transformed
+        let old_condition = self
+            .transform_context
+            .loop_condition
+            .replace((*e.expr).clone());
+        e.expr = Box::new(self.fold_expr(*e.expr));
+        self.transform_context.loop_condition = old_condition;
+
+        e.body = self.fold_loop_body(e.body);
+        e
+    }
+
     fn fold_type(&mut self, t: syn::Type) -> syn::Type {
         // do not mutate types
         t
     }
 
+    fn fold_span(&mut self, span: proc_macro2::Span) -> proc_macro2::Span {
+        // each span is inspected once, while it is folded
+        if !self.macro_generated_seen && ast_inspect::is_span_macro_generated(span) {
+            self.macro_generated_seen = true;
+        }
+        span
+    }
+
+    fn fold_macro(&mut self, m: syn::Macro) -> syn::Macro {
+        // the arguments of a macro call are not folded
+        if !self.macro_generated_seen && ast_inspect::is_macro_generated(m.tokens.clone()) {
+            self.macro_generated_seen = true;
+        }
+        syn::fold::fold_macro(self, m)
+    }
+
     fn fold_expr_unsafe(&mut self, e: syn::ExprUnsafe) -> syn::ExprUnsafe {
-        // do not mutate unsafe blocks
-        e
+        // do not mutate unsafe blocks, except with transformers that are meant for unsafe code
+        if self.unsafe_expr_transformers.is_empty() {
+            return e;
+        }
+        std::mem::swap(
+            &mut self.expr_transformers,
+            &mut self.unsafe_expr_transformers,
+        );
+        let stmt_transformers = std::mem::take(&mut self.stmt_transformers);
+        let result = syn::fold::fold_expr_unsafe(self, e);
+        std::mem::swap(
+            &mut self.expr_transformers,
+            &mut self.unsafe_expr_transformers,
+        );
+        self.stmt_transformers = stmt_transformers;
+        result
     }
 }
 
 impl MutagenTransformerBundle {
+    /// applies the const-transformers to the initializer of a `const` or `static` item
+    fn transform_const_expr(&mut self, e: syn::Expr, ty: &syn::Type) -> syn::Expr {
+        if ast_inspect::is_macro_generated(e.to_token_stream()) {
+            return e;
+        }
+        let old_expr = self.transform_context.original_expr.replace(e.clone());
+
+        let mut result = e;
+        for transformer in &mut self.const_transformers {
+            result = transformer(result, ty, &self.transform_info, &self.transform_context);
+        }
+
+        self.transform_context.original_expr = old_expr;
+        result
+    }
+
+    /// transforms the body of a loop, which is inserted into the context
+    fn fold_loop_body(&mut self, body: syn::Block) -> syn::Block {
+        let old_in_loop_body = std::mem::replace(&mut self.transform_context.in_loop_body, true);
+        let result = self.fold_block(body);
+        self.transform_context.in_loop_body = old_in_loop_body;
+        result
+    }
+
+    /// applies the fn-body-transformers to the transformed body of a function.
+    ///
+    /// The tail expression of the original body is inserted into the context as original expression.
+    /// The recursion depth is tracked for functions that call themselves and contain mutations registered after the given number of mutations.
+    fn transform_fn_body(
+        &mut self,
+        original: syn::Block,
+        block: syn::Block,
+        sig: &syn::Signature,
+        first_mutation: usize,
+    ) -> syn::Block {
+        let original_tail = match original.stmts.last() {
+            Some(syn::Stmt::Expr(e)) => Some(e.clone()),
+            _ => None,
+        };
+        let old_expr = std::mem::replace(&mut self.transform_context.original_expr, original_tail);
+
+        let mut result = block;
+        for transformer in &mut self.fn_body_transformers {
+            result = transformer(
+                result,
+                &sig.output,
+                &self.transform_info,
+                &self.transform_context,
+            );
+        }
+
+        self.transform_context.original_expr = old_expr;
+
+        // the guard of async functions would be held across `.await` and dropped on other threads
+        let is_recursive = sig.asyncness.is_none()
+            && ast_inspect::calls_itself(
+                original.into_token_stream(),
+                &sig.ident.to_string(),
+                sig.receiver().is_some(),
+            );
+        // functions without mutations cannot turn into unbounded recursion
+        let has_mutations = self.transform_info.get_num_mutations() > first_mutation;
+        if is_recursive && has_mutations {
+            result = guard_recursion(result);
+        }
+        result
+    }
+
     pub fn mutagen_process_item(&mut self, target: syn::Item) -> TokenStream {
-        let stream = self.fold_item(target).into_token_stream();
+        let first_mutation = self.transform_info.get_num_mutations();
+        let items = derive_expand::expand_derives(vec![target], &self.expand_derives);
+        let mut items = items
+            .into_iter()
+            .map(|item| self.fold_item(item))
+            .collect::<Vec<_>>();
         self.transform_info.check_mutations();
+        if let Some(registration) = self.transform_info.global_registration(first_mutation) {
+            insert_item(&mut items, registration);
+        }
+        if let Some(conf_include) = CrateConf::track_conf_file() {
+            insert_item(&mut items, conf_include);
+        }
+        let mut stream = TokenStream::new();
+        for item in items {
+            stream.extend(item.into_token_stream());
+        }
         stream
     }
 
@@ -229,14 +555,98 @@ impl MutagenTransformerBundle {
     ) -> MutagenTransformer {
         match transformer_name {
             "lit_int" => MutagenTransformer::Expr(Box::new(mutator_lit_int::transform)),
+            "lit_sign" => MutagenTransformer::Expr(Box::new(mutator_lit_sign::transform)),
             "lit_bool" => MutagenTransformer::Expr(Box::new(mutator_lit_bool::transform)),
+            "lit_char" => MutagenTransformer::Expr(Box::new(mutator_lit_char::transform)),
             "unop_not" => MutagenTransformer::Expr(Box::new(mutator_unop_not::transform)),
             "binop_bit" => MutagenTransformer::Expr(Box::new(mutator_binop_bit::transform)),
+            "bit_assign" => MutagenTransformer::Expr(Box::new(mutator_bit_assign::transform)),
             "binop_num" => MutagenTransformer::Expr(Box::new(mutator_binop_num::transform)),
+            "ceil_div" => MutagenTransformer::Expr(Box::new(mutator_ceil_div::transform)),
+            "binop_shift" => MutagenTransformer::Expr(Box::new(mutator_binop_shift::transform)),
+            "checked_unwrap" => {
+                MutagenTransformer::Expr(Box::new(mutator_checked_unwrap::transform))
+            }
+            "checked_none" => MutagenTransformer::Expr(Box::new(mutator_checked_none::transform)),
+            "overflow_policy" => {
+                MutagenTransformer::Expr(Box::new(mutator_overflow_policy::transform))
+            }
+            "cmp_cloned" => MutagenTransformer::Expr(Box::new(mutator_cmp_cloned::transform)),
             "binop_eq" => MutagenTransformer::Expr(Box::new(mutator_binop_eq::transform)),
+            "float_eps" => MutagenTransformer::Expr(Box::new(mutator_float_eps::transform)),
+            "duration_threshold" => {
+                MutagenTransformer::Expr(Box::new(mutator_duration_threshold::transform))
+            }
             "binop_cmp" => MutagenTransformer::Expr(Box::new(mutator_binop_cmp::transform)),
+            "loop_bound" => MutagenTransformer::Expr(Box::new(mutator_loop_bound::transform)),
+            "counter_step" => MutagenTransformer::Expr(Box::new(mutator_counter_step::transform)),
+            "cmp_chain" => MutagenTransformer::Expr(Box::new(mutator_cmp_chain::transform)),
+            "bound_inclusive" => {
+                MutagenTransformer::Expr(Box::new(mutator_bound_inclusive::transform))
+            }
+            "ordering_then" => MutagenTransformer::Expr(Box::new(mutator_ordering_then::transform)),
             "binop_bool" => MutagenTransformer::Expr(Box::new(mutator_binop_bool::transform)),
+            "bool_query" => MutagenTransformer::Expr(Box::new(mutator_bool_query::transform)),
+            "flag_ops" => MutagenTransformer::Expr(Box::new(mutator_flag_ops::transform)),
+            "bool_then" => MutagenTransformer::Expr(Box::new(mutator_bool_then::transform)),
+            "borrow" => MutagenTransformer::Expr(Box::new(mutator_borrow::transform)),
+            "box_new" => MutagenTransformer::Expr(Box::new(mutator_box_new::transform)),
+            "iter_count" => MutagenTransformer::Expr(Box::new(mutator_iter_count::transform)),
+            "repeat_count" => MutagenTransformer::Expr(Box::new(mutator_repeat_count::transform)),
+            "iter_rev" => MutagenTransformer::Expr(Box::new(mutator_iter_rev::transform)),
+            "iter_source" => MutagenTransformer::Expr(Box::new(mutator_iter_source::transform)),
+            "copied_cloned" => MutagenTransformer::Expr(Box::new(mutator_copied_cloned::transform)),
+            "zip_swap" => MutagenTransformer::Expr(Box::new(mutator_zip_swap::transform)),
+            "flatten" => MutagenTransformer::Expr(Box::new(mutator_flatten::transform)),
+            "combinator_order" => {
+                MutagenTransformer::Expr(Box::new(mutator_combinator_order::transform))
+            }
+            "min_max" => MutagenTransformer::Expr(Box::new(mutator_min_max::transform)),
+            "reverse" => MutagenTransformer::Expr(Box::new(mutator_reverse::transform)),
+            "str_case" => MutagenTransformer::Expr(Box::new(mutator_str_case::transform)),
+            "str_split" => MutagenTransformer::Expr(Box::new(mutator_str_split::transform)),
+            "str_trim" => MutagenTransformer::Expr(Box::new(mutator_str_trim::transform)),
+            "collect_target" => {
+                MutagenTransformer::Expr(Box::new(mutator_collect_target::transform))
+            }
+            "slice_window" => MutagenTransformer::Expr(Box::new(mutator_slice_window::transform)),
+            "constructor" => MutagenTransformer::Expr(Box::new(mutator_constructor::transform)),
+            "hash_seed" => MutagenTransformer::Expr(Box::new(mutator_hash_seed::transform)),
+            "struct_update" => MutagenTransformer::Expr(Box::new(mutator_struct_update::transform)),
+            "resize_fill" => MutagenTransformer::Expr(Box::new(mutator_resize_fill::transform)),
+            "rotate" => MutagenTransformer::Expr(Box::new(mutator_rotate::transform)),
+            "retain" => MutagenTransformer::Expr(Box::new(mutator_retain::transform)),
+            "find" => MutagenTransformer::Expr(Box::new(mutator_find::transform)),
+            "map_identity" => MutagenTransformer::Expr(Box::new(mutator_map_identity::transform)),
+            "first" => MutagenTransformer::Expr(Box::new(mutator_first::transform)),
+            "first_last" => MutagenTransformer::Expr(Box::new(mutator_first_last::transform)),
+            "while_iterations" => {
+                MutagenTransformer::Expr(Box::new(mutator_while_iterations::transform))
+            }
+            "pow" => MutagenTransformer::Expr(Box::new(mutator_pow::transform)),
+            "abs_signum" => MutagenTransformer::Expr(Box::new(mutator_abs_signum::transform)),
+            "int_cast" => MutagenTransformer::Expr(Box::new(mutator_int_cast::transform)),
+            "vec_remove" => MutagenTransformer::Expr(Box::new(mutator_vec_remove::transform)),
+            "try_bypass" => MutagenTransformer::Expr(Box::new(mutator_try_bypass::transform)),
+            "try" => MutagenTransformer::Expr(Box::new(mutator_try::transform)),
+            "early_return" => MutagenTransformer::Expr(Box::new(mutator_early_return::transform)),
+            "match_result" => MutagenTransformer::Expr(Box::new(mutator_match_result::transform)),
+            "result_ok" => MutagenTransformer::Expr(Box::new(mutator_result_ok::transform)),
+            "take" => MutagenTransformer::Expr(Box::new(mutator_take::transform)),
+            "map_entry" => MutagenTransformer::Expr(Box::new(mutator_map_entry::transform)),
+            "extend" => MutagenTransformer::Stmt(Box::new(mutator_extend::transform)),
+            "dedup" => MutagenTransformer::Stmt(Box::new(mutator_dedup::transform)),
+            "debug_assert" => MutagenTransformer::Stmt(Box::new(mutator_debug_assert::transform)),
             "stmt_call" => MutagenTransformer::Stmt(Box::new(mutator_stmt_call::transform)),
+            "array_len" => MutagenTransformer::Expr(Box::new(mutator_array_len::transform)),
+            "const_init" => MutagenTransformer::Const(Box::new(mutator_const_init::transform)),
+            "get_index" => MutagenTransformer::Expr(Box::new(mutator_get_index::transform)),
+            "get_unchecked" => {
+                MutagenTransformer::UnsafeExpr(Box::new(mutator_get_unchecked::transform))
+            }
+            "bool_return_negate" => {
+                MutagenTransformer::FnBody(Box::new(mutator_bool_return_negate::transform))
+            }
             _ => panic!("unknown transformer {}", transformer_name),
         }
     }
@@ -245,14 +655,80 @@ impl MutagenTransformerBundle {
     pub fn all_transformers() -> Vec<String> {
         [
             "lit_int",
+            "lit_sign",
             "lit_bool",
+            "lit_char",
             "unop_not",
             "binop_bit",
+            "bit_assign",
             "binop_num",
+            "ceil_div",
+            "binop_shift",
+            "checked_unwrap",
+            "checked_none",
+            "overflow_policy",
+            "cmp_cloned",
             "binop_eq",
+            "float_eps",
+            "duration_threshold",
             "binop_cmp",
+            "loop_bound",
+            "counter_step",
+            "cmp_chain",
+            "bound_inclusive",
+            "ordering_then",
             "binop_bool",
+            "bool_query",
+            "flag_ops",
+            "bool_then",
+            "borrow",
+            "box_new",
+            "iter_count",
+            "repeat_count",
+            "iter_rev",
+            "iter_source",
+            "copied_cloned",
+            "zip_swap",
+            "flatten",
+            "combinator_order",
+            "min_max",
+            "reverse",
+            "str_case",
+            "str_split",
+            "str_trim",
+            "collect_target",
+            "slice_window",
+            "constructor",
+            "hash_seed",
+            "struct_update",
+            "resize_fill",
+            "rotate",
+            "retain",
+            "find",
+            "map_identity",
+            "first",
+            "first_last",
+            "get_index",
+            "while_iterations",
+            "pow",
+            "abs_signum",
+            "int_cast",
+            "vec_remove",
+            "try_bypass",
+            "try",
+            "early_return",
+            "match_result",
+            "result_ok",
+            "take",
+            "map_entry",
+            "extend",
+            "dedup",
+            "debug_assert",
             "stmt_call",
+            "bool_return_negate",
+            "get_unchecked",
+            "array_len",
+            "const_init",
         ]
         .iter()
         .copied()
@@ -260,28 +736,27 @@ impl MutagenTransformerBundle {
         .collect()
     }
 
-    /// parse the arguments of the `#[mutate]` attribute
-    fn setup_from_attr(args: TokenStream) -> Self {
-        use self::mutate_args::*;
-
-        let options = ArgOptions::parse(args).expect("invalid options");
+    /// all transformers except the opt-in transformers, in order they are executed
+    fn default_transformers() -> Vec<String> {
+        Self::all_transformers()
+            .into_iter()
+            .filter(|t| !OPT_IN_TRANSFORMERS.contains(&&**t))
+            .collect()
+    }
 
-        // create transform_info
-        let transform_info: SharedTransformInfo = match options.conf {
-            Conf::Global => SharedTransformInfo::global_info(),
-            Conf::Local(local_conf) => SharedTransformInfo::local_info(local_conf),
-        };
+    /// the names of the selected transformers, in order they are executed
+    fn transformer_names(transformers: mutate_args::Transformers) -> Vec<String> {
+        use self::mutate_args::Transformers;
 
-        // create transformers
-        let transformers = match options.transformers {
-            Transformers::All => Self::all_transformers(),
+        match transformers {
+            Transformers::All => Self::default_transformers(),
             Transformers::Only(list) => {
                 let mut transformers = list.transformers;
                 transformers.sort_by_key(|t| TRANSFORMER_ORDER[t]);
                 transformers
             }
             Transformers::Not(list) => {
-                let mut transformers = Self::all_transformers();
+                let mut transformers = Self::default_transformers();
                 for l in &list.transformers {
                     // transformers.remove_item(l)
                     if let Some(pos) = transformers.iter().position(|x| *x == *l) {
@@ -290,14 +765,40 @@ impl MutagenTransformerBundle {
                 }
                 transformers
             }
+        }
+    }
+
+    /// parse the arguments of the `#[mutate]` attribute
+    fn setup_from_attr(args: TokenStream) -> Self {
+        use self::mutate_args::*;
+
+        let options = ArgOptions::parse(args).expect("invalid options");
+
+        // create transform_info
+        let transform_info: SharedTransformInfo = match options.conf {
+            Conf::Global => SharedTransformInfo::global_info(),
+            Conf::Local(local_conf) => SharedTransformInfo::local_info(local_conf),
+        };
+
+        // create transformers, the crate config provides the defaults
+        let transformers = match options.transformers {
+            Transformers::All => CrateConf::get().default_transformers(),
+            transformers => transformers,
         };
+        let transformers = Self::transformer_names(transformers);
         let mut expr_transformers = Vec::new();
         let mut stmt_transformers = Vec::new();
+        let mut const_transformers = Vec::new();
+        let mut fn_body_transformers = Vec::new();
+        let mut unsafe_expr_transformers = Vec::new();
         for t in &transformers {
             let t = Self::mk_transformer(t, &[]);
             match t {
                 MutagenTransformer::Expr(t) => expr_transformers.push(t),
                 MutagenTransformer::Stmt(t) => stmt_transformers.push(t),
+                MutagenTransformer::Const(t) => const_transformers.push(t),
+                MutagenTransformer::FnBody(t) => fn_body_transformers.push(t),
+                MutagenTransformer::UnsafeExpr(t) => unsafe_expr_transformers.push(t),
             }
         }
 
@@ -308,13 +809,58 @@ impl MutagenTransformerBundle {
             transform_info,
             expr_transformers,
             stmt_transformers,
+            const_transformers,
+            fn_body_transformers,
+            unsafe_expr_transformers,
+            expand_derives: options.expand_derives,
+            macro_generated_seen: false,
         }
     }
 }
 
+/// inserts a generated item, like the registration of mutations, next to the transformed items.
+///
+/// A function may be a method of an impl block, where no other items can be placed next to it.
+/// Therefore, the generated item is placed into the body of a transformed function.
+fn insert_item(items: &mut Vec<syn::Item>, item: TokenStream) {
+    let item: syn::Item = syn::parse2(item).expect("transformed code invalid");
+    match items.first_mut() {
+        Some(syn::Item::Fn(f)) => f.block.stmts.insert(0, syn::Stmt::Item(item)),
+        _ => items.push(item),
+    }
+}
+
+/// inserts a guard at the start of the body of a recursive function that tracks the recursion depth
+fn guard_recursion(block: syn::Block) -> syn::Block {
+    let span = block.brace_token.span;
+    let stmts = &block.stmts;
+    syn::parse2(quote::quote_spanned! {span=>
+        {
+            let __mutagen_recursion = ::mutagen::MutagenRuntimeConfig::enter_recursion();
+            #(#stmts)*
+        }
+    })
+    .expect("transformed code invalid")
+}
+
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+/// transformers that are only used if they are listed explicitly with `mutators = only(...)`.
+///
+/// The mutations of `array_len` and `const_init` are selected at compile time and cannot be activated by `cargo mutagen`.
+/// The mutations of `debug_assert` only change the behavior of builds without debug assertions, which excludes `cargo test`.
+/// The mutations of `get_unchecked` can cause undefined behavior.
+/// The methods mutated by `flag_ops` share their names with collections like `HashSet`, where the swapped calls do not compile.
+const OPT_IN_TRANSFORMERS: &[&str] = &[
+    "array_len",
+    "box_new",
+    "const_init",
+    "debug_assert",
+    "flag_ops",
+    "get_unchecked",
+];
+
 lazy_static! {
     static ref TRANSFORMER_ORDER: HashMap<String, usize> = {
         MutagenTransformerBundle::all_transformers()
@@ -324,3 +870,61 @@ lazy_static! {
             .collect()
     };
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use mutate_args::{TransformerList, Transformers};
+
+    #[test]
+    fn transformer_names_only_sorted() {
+        let names =
+            MutagenTransformerBundle::transformer_names(Transformers::Only(TransformerList {
+                transformers: vec!["stmt_call".to_owned(), "lit_int".to_owned()],
+            }));
+        assert_eq!(names, vec!["lit_int".to_owned(), "stmt_call".to_owned()]);
+    }
+
+    #[test]
+    fn transformer_names_from_crate_conf() {
+        let conf_file = std::env::temp_dir().join("mutagen-test-conf-transformers.json");
+        std::fs::write(&conf_file, r#"{"mutators": {"not": ["lit_int"]}}"#).unwrap();
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        let names = MutagenTransformerBundle::transformer_names(conf.default_transformers());
+
+        assert!(!names.contains(&"lit_int".to_owned()));
+        assert_eq!(
+            names.len() + 1,
+            MutagenTransformerBundle::default_transformers().len()
+        );
+    }
+
+    #[test]
+    fn transformer_names_all_without_opt_in() {
+        let names = MutagenTransformerBundle::transformer_names(Transformers::All);
+        assert!(!names.contains(&"const_init".to_owned()));
+    }
+
+    #[test]
+    fn transformer_names_all_without_box_new() {
+        let names = MutagenTransformerBundle::transformer_names(Transformers::All);
+        assert!(!names.contains(&"box_new".to_owned()));
+    }
+
+    #[test]
+    fn transformer_names_all_without_unsafe() {
+        let names = MutagenTransformerBundle::transformer_names(Transformers::All);
+        assert!(!names.contains(&"get_unchecked".to_owned()));
+    }
+
+    #[test]
+    fn transformer_names_only_opt_in() {
+        let names =
+            MutagenTransformerBundle::transformer_names(Transformers::Only(TransformerList {
+                transformers: vec!["const_init".to_owned()],
+            }));
+        assert_eq!(names, vec!["const_init".to_owned()]);
+    }
+}
@@ -36,10 +36,126 @@ pub fn is_num_expr(e: &syn::Expr) -> bool {
     return false;
 }
 
+/// get the identifiers of a path-expression, without generic arguments.
+///
+/// Returns `None` if the expression is not a plain path, e.g. `<T as X>::f` or `x.f`.
+pub fn path_idents(e: &syn::Expr) -> Option<Vec<String>> {
+    match e {
+        syn::Expr::Path(syn::ExprPath {
+            qself: None, path, ..
+        }) => Some(path.segments.iter().map(|s| s.ident.to_string()).collect()),
+        _ => None,
+    }
+}
+
+/// check if a path-expression ends with the given identifiers.
+///
+/// `Box::new` and `std::boxed::Box::new` both end with `["Box", "new"]`.
+pub fn path_ends_with(e: &syn::Expr, suffix: &[&str]) -> bool {
+    path_idents(e)
+        .map(|idents| {
+            idents.len() >= suffix.len()
+                && idents[idents.len() - suffix.len()..]
+                    .iter()
+                    .zip(suffix)
+                    .all(|(i, s)| i == s)
+        })
+        .unwrap_or(false)
+}
+
+/// check if the tokens contain the keyword `return`, including nested groups.
+pub fn contains_return(tokens: proc_macro2::TokenStream) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(ident) => ident == "return",
+        proc_macro2::TokenTree::Group(group) => contains_return(group.stream()),
+        _ => false,
+    })
+}
+
+/// check if the tokens contain a recursive call of the function or method with the given name, including nested groups.
+///
+/// Methods with a receiver call themselves like `self.f(x)` or `Self::f(self, x)`. Other functions call themselves like `f(x)` or `Self::f(x)`.
+/// Calls of functions with the same name on other receivers or paths, like `self.inner.f(x)` or `other::f(x)`, are not recursive.
+pub fn calls_itself(tokens: proc_macro2::TokenStream, name: &str, has_receiver: bool) -> bool {
+    use proc_macro2::TokenTree;
+
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let is_punct =
+        |i: usize, c: char| matches!(tokens.get(i), Some(TokenTree::Punct(p)) if p.as_char() == c);
+    let is_ident =
+        |i: usize, s: &str| matches!(tokens.get(i), Some(TokenTree::Ident(ident)) if ident == s);
+    // `i` is the index of a path or receiver that starts a call chain, i.e. is not preceded by `.` or `::`
+    let starts_chain = |i: usize| i == 0 || !(is_punct(i - 1, '.') || is_punct(i - 1, ':'));
+
+    for (i, tt) in tokens.iter().enumerate() {
+        match tt {
+            TokenTree::Ident(ident) if ident == name => {
+                let is_call = matches!(tokens.get(i + 1), Some(TokenTree::Group(g)) if g.delimiter() == proc_macro2::Delimiter::Parenthesis);
+                if !is_call {
+                    continue;
+                }
+                let is_recursive = if i >= 2 && is_punct(i - 1, '.') {
+                    has_receiver && is_ident(i - 2, "self") && starts_chain(i - 2)
+                } else if i >= 3 && is_punct(i - 1, ':') && is_punct(i - 2, ':') {
+                    is_ident(i - 3, "Self") && starts_chain(i - 3)
+                } else {
+                    !has_receiver && (i == 0 || !is_ident(i - 1, "fn"))
+                };
+                if is_recursive {
+                    return true;
+                }
+            }
+            TokenTree::Group(group) if calls_itself(group.stream(), name, has_receiver) => {
+                return true
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// check if any of the tokens has been generated by a macro expansion.
+///
+/// Tokens from the body of a `macro_rules!`-definition have a span with a parent, which is the span of the macro invocation.
+/// Tokens that are written in the source file have no parent, even if they are passed as argument to a macro.
+/// Mutations of generated tokens cannot be mapped back to source code that can be edited.
+pub fn is_macro_generated(tokens: proc_macro2::TokenStream) -> bool {
+    tokens.into_iter().any(|tt| {
+        is_span_macro_generated(tt.span())
+            || match tt {
+                proc_macro2::TokenTree::Group(group) => is_macro_generated(group.stream()),
+                _ => false,
+            }
+    })
+}
+
+/// check if a single span has been generated by a macro expansion.
+pub fn is_span_macro_generated(span: proc_macro2::Span) -> bool {
+    // spans can only be inspected during the expansion of a procedural macro
+    proc_macro::is_available() && span.unwrap().parent().is_some()
+}
+
+/// check if the attributes contain `#[mutagen::skip]`.
+///
+/// The attribute can be written as `#[mutagen::skip]` or `#[::mutagen::skip]`.
+/// A bare `#[skip]` is not accepted, since it is not known if it refers to the attribute of mutagen.
+pub fn has_skip_attr(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let idents = attr
+            .path
+            .segments
+            .iter()
+            .map(|s| s.ident.to_string())
+            .collect::<Vec<_>>();
+        idents == ["mutagen", "skip"]
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use quote::ToTokens;
     use syn::parse_quote;
 
     #[test]
@@ -167,4 +283,129 @@ mod tests {
 
         assert!(is_num_expr(&tt), format!("{:#?}", tt));
     }
+
+    #[test]
+    fn path_ends_with_full_path() {
+        let tt = parse_quote! {std::boxed::Box::new};
+
+        assert!(path_ends_with(&tt, &["Box", "new"]));
+    }
+
+    #[test]
+    fn path_ends_with_short_path() {
+        let tt = parse_quote! {new};
+
+        assert!(!path_ends_with(&tt, &["Box", "new"]));
+    }
+
+    #[test]
+    fn path_ends_with_method_call() {
+        let tt = parse_quote! {x.new()};
+
+        assert!(!path_ends_with(&tt, &["new"]));
+    }
+
+    #[test]
+    fn skip_attr_full_path() {
+        let item: syn::ItemFn = parse_quote! {#[::mutagen::skip] fn f() {}};
+
+        assert!(has_skip_attr(&item.attrs));
+    }
+
+    #[test]
+    fn macro_generated_outside_of_proc_macro() {
+        let e: syn::Expr = parse_quote! {a + b};
+
+        assert!(!is_macro_generated(e.into_token_stream()));
+    }
+
+    fn calls_itself_in_body(item: syn::ItemFn) -> bool {
+        let has_receiver = item.sig.receiver().is_some();
+        calls_itself(
+            item.block.into_token_stream(),
+            &item.sig.ident.to_string(),
+            has_receiver,
+        )
+    }
+
+    #[test]
+    fn calls_itself_recursive_fn() {
+        let item: syn::ItemFn = parse_quote! {fn f(n: u32) -> u32 {if n == 0 {0} else {f(n - 1)}}};
+        assert!(calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_other_fn() {
+        let item: syn::ItemFn = parse_quote! {fn f(n: u32) -> u32 {g(n)}};
+        assert!(!calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_in_macro() {
+        let item: syn::ItemFn = parse_quote! {fn f(n: u32) -> u32 {assert!(f(n) > 0); n}};
+        assert!(calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_other_path() {
+        let item: syn::ItemFn = parse_quote! {fn f(n: u32) -> u32 {other::f(n)}};
+        assert!(!calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_associated_fn() {
+        let item: syn::ItemFn = parse_quote! {fn f(n: u32) -> u32 {Self::f(n)}};
+        assert!(calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_nested_fn_definition() {
+        let item: syn::ItemFn = parse_quote! {fn f(n: u32) -> u32 {fn f(n: u32) -> u32 {n} n}};
+        assert!(!calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_method_on_self() {
+        let item: syn::ItemFn = parse_quote! {fn len(&self) -> usize {self.len() + 1}};
+        assert!(calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_method_on_field() {
+        let item: syn::ItemFn = parse_quote! {fn len(&self) -> usize {self.items.len()}};
+        assert!(!calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_method_on_other_receiver() {
+        let item: syn::ItemFn = parse_quote! {fn len(&self) -> usize {other.len()}};
+        assert!(!calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn calls_itself_method_free_fn() {
+        let item: syn::ItemFn = parse_quote! {fn len(&self) -> usize {len(&self.items)}};
+        assert!(!calls_itself_in_body(item));
+    }
+
+    #[test]
+    fn skip_attr_short_path() {
+        let item: syn::ItemFn = parse_quote! {#[mutagen::skip] fn f() {}};
+
+        assert!(has_skip_attr(&item.attrs));
+    }
+
+    #[test]
+    fn skip_attr_bare_skip() {
+        let item: syn::ItemFn = parse_quote! {#[skip] fn f() {}};
+
+        assert!(!has_skip_attr(&item.attrs));
+    }
+
+    #[test]
+    fn skip_attr_other_attr() {
+        let item: syn::ItemFn = parse_quote! {#[inline] fn f() {}};
+
+        assert!(!has_skip_attr(&item.attrs));
+    }
 }
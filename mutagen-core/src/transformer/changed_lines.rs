@@ -0,0 +1,152 @@
+//! restrict mutations to lines that have been changed, e.g. in the current git diff.
+//!
+//! The changed lines are read from the environment variable `MUTAGEN_DIFF_LINES`.
+//! It contains either a comma-separated list of changed line ranges or the path of a file that contains one range per line.
+//! Each range has the format `path:start-end` or `path:line`, where the lines are 1-based and inclusive.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::comm::Mutation;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ChangedLines(Vec<ChangedRange>);
+
+#[derive(Debug, PartialEq, Eq)]
+struct ChangedRange {
+    file: PathBuf,
+    start: usize,
+    end: usize,
+}
+
+impl ChangedLines {
+    /// Reads the changed lines from the environment variable `MUTAGEN_DIFF_LINES`.
+    ///
+    /// Returns `None` if the variable is not set, which means that all lines can be mutated.
+    pub fn from_env() -> Option<Self> {
+        let diff_lines = env::var("MUTAGEN_DIFF_LINES").ok()?;
+        let diff_lines = if Path::new(&diff_lines).is_file() {
+            fs::read_to_string(&diff_lines)
+                .unwrap_or_else(|_| panic!("unable to read file {:?}", diff_lines))
+        } else {
+            diff_lines
+        };
+        Some(Self::parse(&diff_lines).expect("invalid format of `MUTAGEN_DIFF_LINES`"))
+    }
+
+    pub fn parse(s: &str) -> Result<Self, ()> {
+        s.split(&[',', '\n'][..])
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .map(ChangedRange::parse)
+            .collect::<Result<Vec<_>, ()>>()
+            .map(ChangedLines)
+    }
+
+    /// Checks if the location of the given mutation overlaps with a changed line.
+    pub fn contains(&self, mutation: &Mutation) -> bool {
+        let (start, end) = match mutation_lines(&mutation.location_in_file) {
+            Some(lines) => lines,
+            None => return false,
+        };
+        self.0
+            .iter()
+            .any(|r| same_file(&r.file, &mutation.source_file) && r.start <= end && start <= r.end)
+    }
+}
+
+impl ChangedRange {
+    fn parse(s: &str) -> Result<Self, ()> {
+        let sep = s.rfind(':').ok_or(())?;
+        let file = PathBuf::from(&s[..sep]);
+        let lines = &s[sep + 1..];
+        let (start, end) = match lines.find('-') {
+            Some(dash) => (&lines[..dash], &lines[dash + 1..]),
+            None => (lines, lines),
+        };
+        Ok(ChangedRange {
+            file,
+            start: start.parse().map_err(|_| ())?,
+            end: end.parse().map_err(|_| ())?,
+        })
+    }
+}
+
+/// the first and last line of a location of the form `line:column-line:column`
+pub(super) fn mutation_lines(location_in_file: &str) -> Option<(usize, usize)> {
+    let mut parts = location_in_file.split('-');
+    let start = parts.next()?.split(':').next()?.parse().ok()?;
+    let end = parts.next()?.split(':').next()?.parse().ok()?;
+    Some((start, end))
+}
+
+/// compares file paths that may be relative to different directories, e.g. the repository or the workspace root
+fn same_file(changed_file: &Path, source_file: &Path) -> bool {
+    !changed_file.as_os_str().is_empty()
+        && !source_file.as_os_str().is_empty()
+        && (source_file.ends_with(changed_file) || changed_file.ends_with(source_file))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn mutation_at(file: &str, location: &str) -> Mutation {
+        let mut m = Mutation::new_stub();
+        m.source_file = PathBuf::from(file);
+        m.location_in_file = location.to_owned();
+        m
+    }
+
+    #[test]
+    fn parse_ranges() {
+        let changed = ChangedLines::parse("src/lib.rs:3-5, src/main.rs:7\n").unwrap();
+        assert_eq!(
+            changed,
+            ChangedLines(vec![
+                ChangedRange {
+                    file: PathBuf::from("src/lib.rs"),
+                    start: 3,
+                    end: 5
+                },
+                ChangedRange {
+                    file: PathBuf::from("src/main.rs"),
+                    start: 7,
+                    end: 7
+                },
+            ])
+        );
+    }
+    #[test]
+    fn parse_invalid_range() {
+        assert!(ChangedLines::parse("src/lib.rs").is_err());
+    }
+
+    #[test]
+    fn contains_mutation_in_range() {
+        let changed = ChangedLines::parse("src/lib.rs:3-5").unwrap();
+        assert!(changed.contains(&mutation_at("src/lib.rs", "5:8-5:9")));
+    }
+    #[test]
+    fn contains_mutation_overlapping_range() {
+        let changed = ChangedLines::parse("src/lib.rs:3-5").unwrap();
+        assert!(changed.contains(&mutation_at("src/lib.rs", "1:8-3:2")));
+    }
+    #[test]
+    fn contains_mutation_relative_to_workspace() {
+        let changed = ChangedLines::parse("src/lib.rs:3").unwrap();
+        assert!(changed.contains(&mutation_at("my-crate/src/lib.rs", "3:8-3:9")));
+    }
+    #[test]
+    fn contains_not_mutation_outside_range() {
+        let changed = ChangedLines::parse("src/lib.rs:3-5").unwrap();
+        assert!(!changed.contains(&mutation_at("src/lib.rs", "6:8-6:9")));
+    }
+    #[test]
+    fn contains_not_mutation_in_other_file() {
+        let changed = ChangedLines::parse("src/lib.rs:3-5").unwrap();
+        assert!(!changed.contains(&mutation_at("src/main.rs", "4:8-4:9")));
+    }
+}
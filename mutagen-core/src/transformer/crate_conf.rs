@@ -0,0 +1,275 @@
+//! crate-wide defaults for the `#[mutate]` attribute, read from the file `mutagen.json` at the crate root.
+//!
+//! The file is loaded once per crate. Arguments given to `#[mutate]` take precedence over the defaults in the file.
+//! The file is included into the crate under test, such that changes to the file trigger a recompilation.
+//!
+//! ```json
+//! {
+//!     "mutators": { "not": ["lit_int"] }
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use lazy_static::lazy_static;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Deserialize;
+
+use super::mutate_args::{TransformerList, Transformers};
+
+const CRATE_CONF_FILENAME: &str = "mutagen.json";
+
+lazy_static! {
+    static ref CRATE_CONF: CrateConf = {
+        let conf_file = crate_conf_file();
+        CrateConf::load(&conf_file).unwrap_or_else(|e| panic!("{}", e))
+    };
+}
+
+/// set once the config file has been included into the crate under test
+static CRATE_CONF_TRACKED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Deserialize, PartialEq, Eq, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct CrateConf {
+    /// the mutators used for items whose attribute does not list mutators
+    #[serde(default)]
+    mutators: Option<MutatorsConf>,
+    /// methods that are mutated by `bool_query` in addition to the default methods
+    #[serde(default)]
+    bool_query_methods: Vec<String>,
+    /// whether `bool_then` forces `Some` for `then`, which calls the closure regardless of the condition
+    #[serde(default)]
+    bool_then_force_some: bool,
+    /// the methods of flag sets mutated by `flag_ops`, instead of its default methods
+    #[serde(default)]
+    flag_methods: Option<Vec<String>>,
+    /// the maximal number of mutations registered for the whole crate
+    #[serde(default)]
+    max_total_mutations: Option<usize>,
+    /// the maximal number of mutations registered for the whole crate by each of the given mutators
+    #[serde(default)]
+    max_mutations_per_mutator: HashMap<String, usize>,
+}
+
+#[derive(Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+enum MutatorsConf {
+    Only(Vec<String>),
+    Not(Vec<String>),
+}
+
+impl CrateConf {
+    /// the configuration of the crate that is currently compiled
+    pub fn get() -> &'static Self {
+        &CRATE_CONF
+    }
+
+    /// Reads the configuration from the given file.
+    ///
+    /// Returns the default configuration if the file does not exist.
+    pub fn load(conf_file: &Path) -> Result<Self, String> {
+        if !conf_file.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(conf_file)
+            .map_err(|e| format!("unable to read file {:?}: {}", conf_file, e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("invalid mutagen config {:?}: {}", conf_file, e))
+    }
+
+    /// the mutators to use if the attribute does not list mutators.
+    pub fn default_transformers(&self) -> Transformers {
+        match &self.mutators {
+            None => Transformers::All,
+            Some(MutatorsConf::Only(transformers)) => Transformers::Only(TransformerList {
+                transformers: transformers.clone(),
+            }),
+            Some(MutatorsConf::Not(transformers)) => Transformers::Not(TransformerList {
+                transformers: transformers.clone(),
+            }),
+        }
+    }
+
+    /// the methods returning `bool` that are negated by `bool_query`, in addition to the default methods
+    pub fn bool_query_methods(&self) -> &[String] {
+        &self.bool_query_methods
+    }
+
+    /// whether `bool_then` forces the result of `then` to `Some`
+    pub fn bool_then_force_some(&self) -> bool {
+        self.bool_then_force_some
+    }
+
+    /// the methods of flag sets that are mutated by `flag_ops`, if they differ from the default methods
+    pub fn flag_methods(&self) -> Option<&[String]> {
+        self.flag_methods.as_deref()
+    }
+
+    /// the maximal number of mutations of the crate, further mutations are not registered
+    pub fn max_total_mutations(&self) -> Option<usize> {
+        self.max_total_mutations
+    }
+
+    /// the maximal number of mutations of the crate for each mutator, further mutations of this mutator are not registered
+    pub fn max_mutations_per_mutator(&self) -> &HashMap<String, usize> {
+        &self.max_mutations_per_mutator
+    }
+
+    /// Generates an item that includes the config file into the crate under test.
+    ///
+    /// This registers the file as input of the crate, such that cargo recompiles the crate when the file changes.
+    /// The item is generated only on the first call for each crate and only if the file exists.
+    pub fn track_conf_file() -> Option<TokenStream> {
+        let conf_file = crate_conf_file();
+        if !conf_file.is_file() || CRATE_CONF_TRACKED.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        include_conf_file(&conf_file)
+    }
+}
+
+/// an item that includes the given file, the path has to be absolute since it is resolved relative to the source file otherwise.
+fn include_conf_file(conf_file: &Path) -> Option<TokenStream> {
+    let conf_file = conf_file.to_str()?;
+    Some(quote! {
+        const _: &[u8] = include_bytes!(#conf_file);
+    })
+}
+
+/// the config file is located at the root of the crate under test
+fn crate_conf_file() -> PathBuf {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(CRATE_CONF_FILENAME)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn conf_file(name: &str, contents: &str) -> PathBuf {
+        let conf_file = env::temp_dir().join(format!("mutagen-test-{}.json", name));
+        fs::write(&conf_file, contents).unwrap();
+        conf_file
+    }
+
+    #[test]
+    fn crate_conf_missing_file() {
+        let conf_file = env::temp_dir().join("mutagen-test-missing-conf.json");
+        let _ = fs::remove_file(&conf_file);
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert_eq!(conf, CrateConf::default());
+        assert_eq!(conf.default_transformers(), Transformers::All);
+    }
+
+    #[test]
+    fn crate_conf_only() {
+        let conf_file = conf_file("conf-only", r#"{"mutators": {"only": ["lit_int"]}}"#);
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert_eq!(
+            conf.default_transformers(),
+            Transformers::Only(TransformerList {
+                transformers: vec!["lit_int".to_owned()]
+            })
+        );
+    }
+
+    #[test]
+    fn crate_conf_not() {
+        let conf_file = conf_file("conf-not", r#"{"mutators": {"not": ["lit_int"]}}"#);
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert_eq!(
+            conf.default_transformers(),
+            Transformers::Not(TransformerList {
+                transformers: vec!["lit_int".to_owned()]
+            })
+        );
+    }
+
+    #[test]
+    fn crate_conf_bool_query_methods() {
+        let conf_file = conf_file("conf-bool-query", r#"{"bool_query_methods": ["is_valid"]}"#);
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert_eq!(conf.bool_query_methods(), &["is_valid".to_owned()]);
+        assert_eq!(conf.default_transformers(), Transformers::All);
+    }
+
+    #[test]
+    fn crate_conf_bool_then_force_some() {
+        let conf_file = conf_file("conf-bool-then", r#"{"bool_then_force_some": true}"#);
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert!(conf.bool_then_force_some());
+        assert!(!CrateConf::default().bool_then_force_some());
+    }
+
+    #[test]
+    fn crate_conf_flag_methods() {
+        let conf_file = conf_file("conf-flag-methods", r#"{"flag_methods": ["toggle"]}"#);
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert_eq!(conf.flag_methods(), Some(&["toggle".to_owned()][..]));
+        assert_eq!(CrateConf::default().flag_methods(), None);
+    }
+
+    #[test]
+    fn crate_conf_max_total_mutations() {
+        let conf_file = conf_file("conf-max-total", r#"{"max_total_mutations": 1000}"#);
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert_eq!(conf.max_total_mutations(), Some(1000));
+    }
+
+    #[test]
+    fn crate_conf_max_mutations_per_mutator() {
+        let conf_file = conf_file(
+            "conf-max-per-mutator",
+            r#"{"max_mutations_per_mutator": {"lit_int": 10}}"#,
+        );
+
+        let conf = CrateConf::load(&conf_file).unwrap();
+
+        assert_eq!(conf.max_mutations_per_mutator().get("lit_int"), Some(&10));
+        assert_eq!(conf.max_mutations_per_mutator().get("lit_bool"), None);
+    }
+
+    #[test]
+    fn crate_conf_include_file() {
+        let conf_file = conf_file("conf-include", "{}");
+
+        let item = include_conf_file(&conf_file).unwrap();
+
+        let expected = format!(
+            "const _: &[u8] = include_bytes!({:?});",
+            conf_file.to_str().unwrap()
+        );
+        let expected: TokenStream = expected.parse().unwrap();
+        assert_eq!(item.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn crate_conf_unknown_setting() {
+        let conf_file = conf_file("conf-unknown", r#"{"mutator": {"only": ["lit_int"]}}"#);
+
+        assert!(CrateConf::load(&conf_file).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+//! Expansion of derived trait implementations into manual implementations.
+//!
+//! Derived implementations are generated outside of `#[mutate]` and cannot be mutated.
+//! If enabled with the argument `derives = expand(...)`, the listed derives of a struct are replaced by equivalent manual implementations, which are mutated like any other code.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::{Attribute, Fields, Ident, Index, Item, ItemStruct, Meta, NestedMeta};
+
+/// the derives that can be expanded
+pub const EXPANDABLE_DERIVES: &[&str] = &["PartialEq"];
+
+/// replaces the listed derives of structs with manual implementations.
+///
+/// The implementations are inserted directly after the struct.
+pub fn expand_derives(items: Vec<Item>, derives: &[String]) -> Vec<Item> {
+    if derives.is_empty() {
+        return items;
+    }
+    let mut result = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            Item::Struct(mut item) => {
+                let expanded = remove_derives(&mut item.attrs, derives);
+                let impls = expanded
+                    .iter()
+                    .map(|(derive, span)| expand_derive(&item, derive, *span))
+                    .collect::<Vec<_>>();
+                result.push(Item::Struct(item));
+                result.extend(impls);
+            }
+            item => result.push(item),
+        }
+    }
+    result
+}
+
+/// removes the listed derives from the `#[derive(...)]` attributes.
+///
+/// Returns the names of the removed derives together with their spans.
+fn remove_derives(attrs: &mut Vec<Attribute>, derives: &[String]) -> Vec<(String, Span)> {
+    let mut removed = Vec::new();
+    let mut new_attrs = Vec::with_capacity(attrs.len());
+    for attr in attrs.drain(..) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) if attr.path.is_ident("derive") => list,
+            _ => {
+                new_attrs.push(attr);
+                continue;
+            }
+        };
+        let (expanded, kept): (Vec<_>, Vec<_>) =
+            list.nested.into_iter().partition(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => derives
+                    .iter()
+                    .any(|derive| path.is_ident(derive) || is_std_derive_path(path, derive)),
+                _ => false,
+            });
+        for nested in &expanded {
+            if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                let name = path.segments.last().unwrap().ident.to_string();
+                removed.push((name, nested.span()));
+            }
+        }
+        if !kept.is_empty() {
+            let span = attr.span();
+            new_attrs.push(syn::parse_quote_spanned! {span=> #[derive(#(#kept),*)]});
+        }
+    }
+    *attrs = new_attrs;
+    removed
+}
+
+/// checks if the path is the full path of a derive, like `std::cmp::PartialEq`
+fn is_std_derive_path(path: &syn::Path, derive: &str) -> bool {
+    path.segments
+        .last()
+        .map(|s| s.ident == derive)
+        .unwrap_or(false)
+        && path.segments.len() == 3
+        && (path.segments[0].ident == "std" || path.segments[0].ident == "core")
+        && path.segments[1].ident == "cmp"
+}
+
+fn expand_derive(item: &ItemStruct, derive: &str, span: Span) -> Item {
+    match derive {
+        "PartialEq" => expand_partial_eq(item, span),
+        _ => panic!("derive {} cannot be expanded", derive),
+    }
+}
+
+/// creates an implementation of `PartialEq` that compares all fields, like `#[derive(PartialEq)]`.
+///
+/// All tokens get the span of the derive, since tokens with the span of the call site would count as generated by a macro.
+fn expand_partial_eq(item: &ItemStruct, span: Span) -> Item {
+    let name = &item.ident;
+    let mut generics = item.generics.clone();
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    if !type_params.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for param in type_params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote_spanned! {span=> #param: ::core::cmp::PartialEq});
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let comparisons = match &item.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let field = field.ident.as_ref().unwrap();
+                quote_spanned! {span=> self.#field == other.#field}
+            })
+            .collect::<Vec<_>>(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|i| {
+                let field = Index {
+                    index: i as u32,
+                    span,
+                };
+                quote_spanned! {span=> self.#field == other.#field}
+            })
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    // the parameter `other` is unused for structs without fields
+    let (other, body): (Ident, TokenStream) = if comparisons.is_empty() {
+        (Ident::new("_other", span), quote_spanned! {span=> true})
+    } else {
+        (
+            Ident::new("other", span),
+            quote_spanned! {span=> #(#comparisons)&&*},
+        )
+    };
+
+    syn::parse_quote_spanned! {span=>
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, #other: &Self) -> bool {
+                #body
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    fn partial_eq() -> Vec<String> {
+        vec!["PartialEq".to_owned()]
+    }
+
+    #[test]
+    fn expand_named_fields() {
+        let item: Item = parse_quote! {
+            #[derive(Debug, PartialEq)]
+            struct Point { x: i32, y: i32 }
+        };
+
+        let items = expand_derives(vec![item], &partial_eq());
+
+        let expected_struct: Item = parse_quote! {
+            #[derive(Debug)]
+            struct Point { x: i32, y: i32 }
+        };
+        let expected_impl: Item = parse_quote! {
+            impl ::core::cmp::PartialEq for Point {
+                fn eq(&self, other: &Self) -> bool {
+                    self.x == other.x && self.y == other.y
+                }
+            }
+        };
+        assert_eq!(items, vec![expected_struct, expected_impl]);
+    }
+
+    #[test]
+    fn expand_tuple_struct_with_generics() {
+        let item: Item = parse_quote! {
+            #[derive(PartialEq)]
+            struct Pair<T>(T, u8);
+        };
+
+        let items = expand_derives(vec![item], &partial_eq());
+
+        let expected_impl: Item = parse_quote! {
+            impl<T> ::core::cmp::PartialEq for Pair<T> where T: ::core::cmp::PartialEq {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0 && self.1 == other.1
+                }
+            }
+        };
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1], expected_impl);
+        match &items[0] {
+            Item::Struct(item) => assert!(item.attrs.is_empty()),
+            _ => panic!("struct expected"),
+        }
+    }
+
+    #[test]
+    fn expand_unit_struct() {
+        let item: Item = parse_quote! {
+            #[derive(PartialEq)]
+            struct Unit;
+        };
+
+        let items = expand_derives(vec![item], &partial_eq());
+
+        let expected_impl: Item = parse_quote! {
+            impl ::core::cmp::PartialEq for Unit {
+                fn eq(&self, _other: &Self) -> bool {
+                    true
+                }
+            }
+        };
+        assert_eq!(items[1], expected_impl);
+    }
+
+    #[test]
+    fn keep_other_derives_and_attributes() {
+        let item: Item = parse_quote! {
+            #[derive(Clone)]
+            #[allow(dead_code)]
+            struct S { x: i32 }
+        };
+
+        let items = expand_derives(vec![item.clone()], &partial_eq());
+
+        assert_eq!(items, vec![item]);
+    }
+
+    #[test]
+    fn expand_nothing_without_derives() {
+        let item: Item = parse_quote! {
+            #[derive(PartialEq)]
+            struct S { x: i32 }
+        };
+
+        let items = expand_derives(vec![item.clone()], &[]);
+
+        assert_eq!(items, vec![item]);
+    }
+}
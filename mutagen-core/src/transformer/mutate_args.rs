@@ -3,12 +3,15 @@
 //! Please refer to the customization documentation about the format of arguments.
 
 use super::arg_ast::{ArgAstList, ArgFn};
+use super::derive_expand::EXPANDABLE_DERIVES;
 use proc_macro2::TokenStream;
 
 #[derive(PartialEq, Eq, Debug)]
 pub struct ArgOptions {
     pub conf: Conf,
     pub transformers: Transformers,
+    /// derives that are expanded into manual implementations, given as `derives = expand(...)`
+    pub expand_derives: Vec<String>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -27,6 +30,7 @@ pub enum Conf {
 #[derive(PartialEq, Eq, Debug, Default)]
 pub struct LocalConf {
     pub expected_mutations: Option<usize>,
+    pub pin: Option<String>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -39,6 +43,7 @@ impl Default for ArgOptions {
         Self {
             conf: Conf::Global,
             transformers: Transformers::All,
+            expand_derives: Vec::new(),
         }
     }
 }
@@ -62,6 +67,19 @@ impl ArgOptions {
                 _ => return Err(()),
             }
         }
+        if let Some(derives_arg) = ast.find_named_arg("derives")? {
+            if derives_arg.name != "expand" {
+                return Err(());
+            }
+            let derives = TransformerList::parse(&derives_arg.args)?.transformers;
+            if !derives
+                .iter()
+                .all(|d| EXPANDABLE_DERIVES.contains(&d.as_str()))
+            {
+                return Err(());
+            }
+            options.expand_derives = derives;
+        }
         Ok(options)
     }
 }
@@ -75,7 +93,15 @@ impl Conf {
                     .map(|arg| arg.name.parse::<usize>())
                     .transpose()
                     .map_err(|_| ())?;
-                Ok(Conf::Local(LocalConf { expected_mutations }))
+                let pin = conf.args.find_named_arg("pin")?;
+                let pin = pin
+                    .map(|arg| syn::parse_str::<syn::LitStr>(&arg.name).map(|lit| lit.value()))
+                    .transpose()
+                    .map_err(|_| ())?;
+                Ok(Conf::Local(LocalConf {
+                    expected_mutations,
+                    pin,
+                }))
             }
             "global" => Ok(Conf::Global),
             _ => Err(()),
@@ -159,4 +185,48 @@ mod tests {
         });
         assert_eq!(parsed.transformers, expected_transformers);
     }
+
+    #[test]
+    fn config_local_pin() {
+        let input = TokenStream::from_str("conf = local(pin = \"mutations.pin\")").unwrap();
+
+        let parsed = ArgOptions::parse(input);
+
+        assert!(parsed.is_ok());
+        let parsed = parsed.unwrap();
+
+        let expected_conf_local = Conf::Local(LocalConf {
+            expected_mutations: None,
+            pin: Some("mutations.pin".to_owned()),
+        });
+        assert_eq!(parsed.conf, expected_conf_local);
+    }
+
+    #[test]
+    fn config_expand_derives() {
+        let input = TokenStream::from_str("derives = expand(PartialEq)").unwrap();
+
+        let parsed = ArgOptions::parse(input);
+
+        assert!(parsed.is_ok());
+        assert_eq!(parsed.unwrap().expand_derives, vec!["PartialEq".to_owned()]);
+    }
+
+    #[test]
+    fn config_expand_unknown_derive() {
+        let input = TokenStream::from_str("derives = expand(Hash)").unwrap();
+
+        let parsed = ArgOptions::parse(input);
+
+        assert!(parsed.is_err());
+    }
+
+    #[test]
+    fn config_local_pin_not_a_string() {
+        let input = TokenStream::from_str("conf = local(pin = mutations)").unwrap();
+
+        let parsed = ArgOptions::parse(input);
+
+        assert!(parsed.is_err());
+    }
 }
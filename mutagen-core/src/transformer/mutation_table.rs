@@ -0,0 +1,79 @@
+//! generates the descriptions of mutations that are embedded into the code under test.
+//!
+//! Each item with a global configuration registers the descriptions of its mutations when the binary is loaded.
+//! Each mutation is described by a `MutationInfo` in a table that is embedded as a `static` into the code.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::comm::BakedMutation;
+
+/// an item that registers the given mutations at runtime, when the binary is loaded.
+///
+/// The registration is a static constructor, such that the runtime knows all mutations before any mutated code is executed.
+pub fn registration(mutations: &[BakedMutation]) -> TokenStream {
+    let infos = mutations.iter().map(mutation_info);
+    quote! {
+        const _: () = {
+            #[::mutagen::ctor]
+            fn __mutagen_register_mutations() {
+                static MUTATIONS: &[::mutagen::MutationInfo] = &[#(#infos),*];
+                ::mutagen::MutagenRuntimeConfig::register_mutations(MUTATIONS);
+            }
+        };
+    }
+}
+
+fn mutation_info(m: &BakedMutation) -> TokenStream {
+    let id = m.id();
+    let mutator_id = m.mutator_id();
+    let mutator = m.mutator_name();
+    let impl_name = opt_str(m.as_ref().impl_name.as_deref());
+    let fn_name = opt_str(m.fn_name());
+    let original_code = m.original_code();
+    let mutated_code = m.mutated_code();
+    let source_file = m.source_file().to_string_lossy();
+    let location_in_file = m.location_in_file();
+    quote! {
+        ::mutagen::MutationInfo {
+            id: #id,
+            mutator_id: #mutator_id,
+            mutator: #mutator,
+            impl_name: #impl_name,
+            fn_name: #fn_name,
+            original_code: #original_code,
+            mutated_code: #mutated_code,
+            source_file: #source_file,
+            location_in_file: #location_in_file,
+        }
+    }
+}
+
+fn opt_str(s: Option<&str>) -> TokenStream {
+    match s {
+        Some(s) => quote! { ::core::option::Option::Some(#s) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::comm::Mutation;
+    use quote::ToTokens;
+
+    #[test]
+    fn registration_contains_all_mutations() {
+        let mut mutation = Mutation::new_stub();
+        mutation.fn_name = Some("f".to_owned());
+        let mutations = vec![Mutation::new_stub().with_id(1, 1), mutation.with_id(2, 1)];
+
+        let item: syn::ItemConst = syn::parse2(registration(&mutations)).unwrap();
+        let tokens = item.to_token_stream().to_string();
+
+        assert_eq!(tokens.matches("MutationInfo {").count(), 2);
+        assert!(tokens.contains(":: core :: option :: Option :: Some (\"f\")"));
+        assert!(tokens.contains("register_mutations (MUTATIONS)"));
+    }
+}
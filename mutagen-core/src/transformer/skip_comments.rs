@@ -0,0 +1,264 @@
+//! suppress mutations on lines that are annotated with the comment `// mutagen:skip`.
+//!
+//! Procedural macros do not see comments. Therefore, the source file of a mutation is read and searched for these comments.
+//! The comment suppresses mutations that start on the same line.
+//! A comment on a line of its own suppresses mutations that start on the following line.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use lazy_static::lazy_static;
+
+use super::changed_lines::mutation_lines;
+use crate::comm::Mutation;
+
+const SKIP_COMMENT: &str = "mutagen:skip";
+
+lazy_static! {
+    /// the skipped lines of each source file, such that each file is read only once
+    static ref SKIPPED_LINES: Mutex<HashMap<PathBuf, Arc<HashSet<usize>>>> = Default::default();
+}
+
+/// Checks if the given mutation starts on a line that is skipped by a comment.
+///
+/// Source files that cannot be read do not skip any lines.
+pub fn is_skipped(mutation: &Mutation) -> bool {
+    let start = match mutation_lines(&mutation.location_in_file) {
+        Some((start, _)) => start,
+        None => return false,
+    };
+    skipped_lines_of_file(&mutation.source_file).contains(&start)
+}
+
+fn skipped_lines_of_file(source_file: &Path) -> Arc<HashSet<usize>> {
+    let mut cache = SKIPPED_LINES.lock().unwrap_or_else(|e| e.into_inner());
+    let lines = cache.entry(source_file.to_owned()).or_insert_with(|| {
+        let source = fs::read_to_string(source_file).unwrap_or_default();
+        Arc::new(skipped_lines(&source))
+    });
+    Arc::clone(lines)
+}
+
+/// the 1-based numbers of the lines whose mutations are skipped
+fn skipped_lines(source: &str) -> HashSet<usize> {
+    let mut skipped = HashSet::new();
+    for comment in line_comments(source) {
+        if !comment.text.trim_start().starts_with(SKIP_COMMENT) {
+            continue;
+        }
+        if comment.own_line {
+            skipped.insert(comment.line + 1);
+        } else {
+            skipped.insert(comment.line);
+        }
+    }
+    skipped
+}
+
+/// a line comment in a source file
+#[derive(Debug, PartialEq, Eq)]
+struct LineComment<'a> {
+    /// the 1-based number of the line of the comment
+    line: usize,
+    /// the text after `//`
+    text: &'a str,
+    /// whether the comment is the only content of its line
+    own_line: bool,
+}
+
+/// Finds all line comments in the given source code.
+///
+/// `//` inside of string literals, character literals and block comments does not start a comment.
+fn line_comments(source: &str) -> Vec<LineComment<'_>> {
+    let mut comments = vec![];
+    let mut chars = source.char_indices().peekable();
+    let mut line = 1;
+    let mut line_start = 0;
+    // the previous character outside of literals and comments, to tell raw strings from identifiers ending with `r`
+    let mut prev = ' ';
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\n' => {
+                line += 1;
+                line_start = i + 1;
+            }
+            '/' if source[i..].starts_with("//") => {
+                let end = source[i..].find('\n').map_or(source.len(), |n| i + n);
+                comments.push(LineComment {
+                    line,
+                    text: &source[i + 2..end],
+                    own_line: source[line_start..i].trim().is_empty(),
+                });
+                while chars.peek().is_some_and(|&(j, _)| j < end) {
+                    chars.next();
+                }
+            }
+            '/' if source[i..].starts_with("/*") => {
+                chars.next();
+                let mut depth = 1;
+                while depth > 0 {
+                    match chars.next() {
+                        Some((j, '/')) if source[j..].starts_with("/*") => {
+                            chars.next();
+                            depth += 1;
+                        }
+                        Some((j, '*')) if source[j..].starts_with("*/") => {
+                            chars.next();
+                            depth -= 1;
+                        }
+                        Some((_, '\n')) => line += 1,
+                        Some(_) => {}
+                        None => break,
+                    }
+                }
+                if let Some(n) =
+                    source[..chars.peek().map_or(source.len(), |&(j, _)| j)].rfind('\n')
+                {
+                    line_start = n + 1;
+                }
+            }
+            '"' => {
+                skip_string(source, &mut chars, &mut line, &mut line_start, i, 0);
+            }
+            'r' if !prev.is_alphanumeric() && prev != '_' => {
+                let hashes = source[i + 1..].chars().take_while(|&h| h == '#').count();
+                if source[i + 1 + hashes..].starts_with('"') {
+                    for _ in 0..=hashes {
+                        chars.next();
+                    }
+                    skip_string(source, &mut chars, &mut line, &mut line_start, i, hashes);
+                }
+            }
+            '\'' => {
+                // a character literal, unless it is a lifetime like `'a`
+                let rest = &source[i + 1..];
+                let mut rest_chars = rest.chars();
+                let is_char = match rest_chars.next() {
+                    Some('\\') => true,
+                    Some(_) => rest_chars.next() == Some('\''),
+                    None => false,
+                };
+                if is_char {
+                    let mut escaped = false;
+                    for (_, c) in chars.by_ref() {
+                        match c {
+                            '\\' if !escaped => escaped = true,
+                            '\'' if !escaped => break,
+                            _ => escaped = false,
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        prev = c;
+    }
+    comments
+}
+
+/// Skips a string literal whose opening quote has been consumed.
+///
+/// Raw strings with the given number of `#` end at a quote followed by as many `#`, other strings at an unescaped quote.
+fn skip_string(
+    source: &str,
+    chars: &mut std::iter::Peekable<std::str::CharIndices>,
+    line: &mut usize,
+    line_start: &mut usize,
+    start: usize,
+    hashes: usize,
+) {
+    let raw = source[start..].starts_with('r');
+    let mut escaped = false;
+    while let Some((j, c)) = chars.next() {
+        match c {
+            '\n' => {
+                *line += 1;
+                *line_start = j + 1;
+                escaped = false;
+            }
+            '\\' if !raw && !escaped => escaped = true,
+            '"' if !escaped => {
+                if source[j + 1..].starts_with(&"#".repeat(hashes)) {
+                    for _ in 0..hashes {
+                        chars.next();
+                    }
+                    return;
+                }
+            }
+            _ => escaped = false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn skipped_lines_trailing_comment() {
+        let source = "let x = 1;\nlet y = 2; // mutagen:skip\n";
+        assert_eq!(skipped_lines(source), vec![2].into_iter().collect());
+    }
+
+    #[test]
+    fn skipped_lines_comment_before_line() {
+        let source = "    // mutagen:skip: the default is arbitrary\n    let x = 1;\n";
+        assert_eq!(skipped_lines(source), vec![2].into_iter().collect());
+    }
+
+    #[test]
+    fn skipped_lines_comment_after_string_with_slashes() {
+        let source = "let s = \"a // b\"; // mutagen:skip\n";
+        assert_eq!(skipped_lines(source), vec![1].into_iter().collect());
+    }
+
+    #[test]
+    fn skipped_lines_slashes_in_string() {
+        let source = "let s = \"// mutagen:skip\";\nlet t = r#\"x // mutagen:skip\"#;\n";
+        assert!(skipped_lines(source).is_empty());
+    }
+
+    #[test]
+    fn skipped_lines_slashes_in_block_comment() {
+        let source = "/* see http://example.com\n // mutagen:skip */\nlet x = 1;\n";
+        assert!(skipped_lines(source).is_empty());
+    }
+
+    #[test]
+    fn skipped_lines_after_char_and_lifetime() {
+        let source = "fn f<'a>(s: &'a str) -> bool { s.contains('\"') } // mutagen:skip\n";
+        assert_eq!(skipped_lines(source), vec![1].into_iter().collect());
+    }
+
+    #[test]
+    fn skipped_lines_other_comments() {
+        let source = "let x = 1; // mutagen is great\n// skip this\n";
+        assert!(skipped_lines(source).is_empty());
+    }
+
+    #[test]
+    fn is_skipped_reads_source_file() {
+        let source_file = env::temp_dir().join("mutagen-test-skip-comments.rs");
+        fs::write(&source_file, "let x = 1;\nlet y = 2; //mutagen:skip\n").unwrap();
+
+        let mut m = Mutation::new_stub();
+        m.source_file = source_file;
+        m.location_in_file = "2:9-2:10".to_owned();
+        assert!(is_skipped(&m));
+
+        m.location_in_file = "1:9-1:10".to_owned();
+        assert!(!is_skipped(&m));
+    }
+
+    #[test]
+    fn is_skipped_missing_file() {
+        let mut m = Mutation::new_stub();
+        m.source_file = PathBuf::from("does/not/exist.rs");
+        m.location_in_file = "1:1-1:2".to_owned();
+        assert!(!is_skipped(&m));
+    }
+}
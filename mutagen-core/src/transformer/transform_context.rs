@@ -6,6 +6,44 @@ pub struct TransformContext {
     pub fn_name: Option<String>,
     pub original_stmt: Option<syn::Stmt>,
     pub original_expr: Option<syn::Expr>,
+    /// the condition of a `while`-loop or the iterated expression of a `for`-loop, while it is transformed
+    pub loop_condition: Option<syn::Expr>,
+    /// whether the expression is in the body of a `while`-, `for`- or `loop`-loop
+    pub in_loop_body: bool,
+    /// the receiver of a call of `unwrap()`, while it is transformed
+    pub unwrapped_receiver: Option<syn::Expr>,
+    /// the shape of the return type of the innermost function or closure, which is the target of `?`
+    pub return_shape: Option<ReturnShape>,
+}
+
+/// the kind of the return type of a function, as far as `?` is concerned
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReturnShape {
+    Option,
+    Result,
+}
+
+impl ReturnShape {
+    /// Determines the shape of a declared return type by the name of the type.
+    ///
+    /// Aliases like `io::Result<T>` are recognized, since their name ends with `Result`.
+    pub fn of(output: &syn::ReturnType) -> Option<Self> {
+        let ty = match output {
+            syn::ReturnType::Type(_, ty) => ty,
+            syn::ReturnType::Default => return None,
+        };
+        let ident = match &**ty {
+            syn::Type::Path(path) if path.qself.is_none() => &path.path.segments.last()?.ident,
+            _ => return None,
+        };
+        if ident == "Option" {
+            Some(ReturnShape::Option)
+        } else if ident == "Result" {
+            Some(ReturnShape::Result)
+        } else {
+            None
+        }
+    }
 }
 
 impl TransformContext {
@@ -15,4 +53,51 @@ impl TransformContext {
             .map(|e| ast_inspect::is_num_expr(e))
             .unwrap_or(false)
     }
+
+    /// checks if the original expression is unwrapped immediately, like `x.checked_add(y)` in `x.checked_add(y).unwrap()`
+    pub fn is_unwrapped(&self) -> bool {
+        self.original_expr.is_some() && self.original_expr == self.unwrapped_receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn return_shape_option() {
+        let output: syn::ReturnType = parse_quote! {-> Option<u8>};
+        assert_eq!(ReturnShape::of(&output), Some(ReturnShape::Option));
+    }
+    #[test]
+    fn return_shape_result_alias() {
+        let output: syn::ReturnType = parse_quote! {-> io::Result<()>};
+        assert_eq!(ReturnShape::of(&output), Some(ReturnShape::Result));
+    }
+    #[test]
+    fn return_shape_unit() {
+        let output: syn::ReturnType = parse_quote! {};
+        assert_eq!(ReturnShape::of(&output), None);
+    }
+
+    #[test]
+    fn is_unwrapped_receiver() {
+        let context = TransformContext {
+            original_expr: Some(parse_quote! {x.checked_add(y)}),
+            unwrapped_receiver: Some(parse_quote! {x.checked_add(y)}),
+            ..TransformContext::default()
+        };
+        assert!(context.is_unwrapped());
+    }
+    #[test]
+    fn is_unwrapped_other_expr() {
+        let context = TransformContext {
+            original_expr: Some(parse_quote! {y}),
+            unwrapped_receiver: Some(parse_quote! {x.checked_add(y)}),
+            ..TransformContext::default()
+        };
+        assert!(!context.is_unwrapped());
+    }
 }
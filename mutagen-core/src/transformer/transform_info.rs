@@ -1,43 +1,80 @@
 use lazy_static::lazy_static;
-use std::fs::{create_dir_all, File};
+use proc_macro2::TokenStream;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs::{self, create_dir_all, File};
 use std::iter;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, MutexGuard};
 
+use super::changed_lines::ChangedLines;
+use super::crate_conf::CrateConf;
 use super::mutate_args::LocalConf;
+use super::mutation_table;
+use super::skip_comments;
 use crate::comm;
-use crate::comm::{BakedMutation, Mutation};
+use crate::comm::{BakedMutation, FileSink, Mutation, MutationSink};
 
 lazy_static! {
-    static ref GLOBAL_TRANSFORM_INFO: SharedTransformInfo = Default::default();
+    static ref GLOBAL_TRANSFORM_INFO: SharedTransformInfo =
+        SharedTransformInfo::new(MutagenTransformInfo {
+            max_mutations: CrateConf::get().max_total_mutations(),
+            max_mutations_per_mutator: CrateConf::get().max_mutations_per_mutator().clone(),
+            ..Default::default()
+        });
+    static ref CHANGED_LINES: Option<Arc<ChangedLines>> = ChangedLines::from_env().map(Arc::new);
 }
 
+/// mutator id returned for mutations that are not registered since they are outside the changed lines, skipped by a comment or exceed the maximal number of mutations.
+///
+/// No mutation has this id, such that the inserted mutator is never activated.
+const UNREGISTERED_MUTATOR_ID: usize = 0;
+
 #[derive(Default)]
 pub struct SharedTransformInfo(Arc<Mutex<MutagenTransformInfo>>);
 
 /// Contains information about all mutations inserted into the code under test
 ///
 /// This struct is used to collect the mutations during transformation. After running all transformers, this struct contains all mutators and their mutaitons inserted into the code
-#[derive(Debug)]
 pub struct MutagenTransformInfo {
     mutations: Vec<BakedMutation>,
-    mutagen_file: Option<File>,
+    sink: Option<Box<dyn MutationSink>>,
     expected_mutations: Option<usize>,
+    pin_file: Option<PathBuf>,
+    changed_lines: Option<Arc<ChangedLines>>,
+    /// the maximal number of mutations, given by the setting `max_total_mutations` for the whole crate
+    max_mutations: Option<usize>,
+    /// set once mutations have been dropped because of the maximal number of mutations
+    max_mutations_reached: bool,
+    /// the maximal number of mutations of single mutators, given by the setting `max_mutations_per_mutator` for the whole crate
+    max_mutations_per_mutator: HashMap<String, usize>,
+    /// the number of registered mutations of each mutator with a maximal number of mutations
+    mutator_counts: HashMap<String, usize>,
+    /// the mutators whose mutations have been dropped because of their maximal number of mutations
+    mutator_limits_reached: HashSet<String>,
 }
 
 impl Default for MutagenTransformInfo {
     fn default() -> Self {
         Self {
             mutations: vec![],
-            mutagen_file: None,
+            sink: None,
             expected_mutations: None,
+            pin_file: None,
+            changed_lines: CHANGED_LINES.clone(),
+            max_mutations: None,
+            max_mutations_reached: false,
+            max_mutations_per_mutator: HashMap::new(),
+            mutator_counts: HashMap::new(),
+            mutator_limits_reached: HashSet::new(),
         }
     }
 }
 
 impl MutagenTransformInfo {
     pub fn with_default_mutagen_file(&mut self) {
-        // open file only once
-        if self.mutagen_file.is_none() {
+        // open file only once and only if no other sink has been set
+        if self.sink.is_none() {
             let mutagen_filepath = comm::get_mutations_file().unwrap();
             let mutagen_dir = mutagen_filepath.parent().unwrap();
             if !mutagen_dir.exists() {
@@ -46,18 +83,18 @@ impl MutagenTransformInfo {
             let mutagen_file = File::create(&mutagen_filepath)
                 .unwrap_or_else(|_| panic!("unable to open file {:?}", &mutagen_filepath));
 
-            self.mutagen_file = Some(mutagen_file);
+            self.sink = Some(Box::new(FileSink::new(mutagen_file)));
         }
     }
 
-    /// add a mutation and return the id used for it, also writes the mutation to the global file.
+    /// add a mutation and return the id used for it, also records the mutation in the sink.
     pub fn add_mutation(&mut self, mutation: Mutation, mutator_id: usize) -> usize {
         let mut_id = 1 + self.mutations.len();
         let mutation = mutation.with_id(mut_id, mutator_id);
 
-        // write the mutation if file was configured
-        if let Some(mutagen_file) = &mut self.mutagen_file {
-            comm::append_item(mutagen_file, &mutation).expect("unable to write to mutagen file");
+        // record the mutation if a sink was configured
+        if let Some(sink) = &self.sink {
+            sink.record(&mutation);
         }
 
         // add mutation to list
@@ -71,6 +108,68 @@ impl MutagenTransformInfo {
         self.mutations.len()
     }
 
+    /// Checks if any of the given mutations is located on a changed line.
+    ///
+    /// If no changed lines are configured, all mutations are considered to be changed.
+    fn is_changed(&self, mutations: &[Mutation]) -> bool {
+        match &self.changed_lines {
+            Some(changed_lines) => mutations.iter().any(|m| changed_lines.contains(m)),
+            None => true,
+        }
+    }
+
+    /// Checks if any of the given mutations starts on a line that is annotated with `// mutagen:skip`.
+    fn is_skipped(&self, mutations: &[Mutation]) -> bool {
+        mutations.iter().any(skip_comments::is_skipped)
+    }
+
+    /// Checks if the given number of additional mutations fits below the maximal number of mutations.
+    ///
+    /// A warning is printed the first time mutations are dropped.
+    fn has_capacity_for(&mut self, num_mutations: usize) -> bool {
+        let max_mutations = match self.max_mutations {
+            Some(max_mutations) => max_mutations,
+            None => return true,
+        };
+        if self.mutations.len() + num_mutations <= max_mutations {
+            return true;
+        }
+        if !self.max_mutations_reached {
+            self.max_mutations_reached = true;
+            eprintln!(
+                "warning: reached the maximum of {} mutations set by `max_total_mutations`, further mutations are not generated",
+                max_mutations
+            );
+        }
+        false
+    }
+
+    /// Checks if the given mutations of a single mutator fit below the maximal number of mutations of this mutator.
+    ///
+    /// A warning is printed the first time mutations of a mutator are dropped.
+    fn has_mutator_capacity_for(&mut self, mutations: &[Mutation]) -> bool {
+        let mutator = match mutations.first() {
+            Some(mutation) => &mutation.mutator,
+            None => return true,
+        };
+        let max_mutations = match self.max_mutations_per_mutator.get(mutator) {
+            Some(&max_mutations) => max_mutations,
+            None => return true,
+        };
+        let count = self.mutator_counts.entry(mutator.clone()).or_insert(0);
+        if *count + mutations.len() <= max_mutations {
+            *count += mutations.len();
+            return true;
+        }
+        if self.mutator_limits_reached.insert(mutator.clone()) {
+            eprintln!(
+                "warning: reached the maximum of {} mutations of mutator `{}` set by `max_mutations_per_mutator`, further mutations of this mutator are not generated",
+                max_mutations, mutator
+            );
+        }
+        false
+    }
+
     pub fn get_next_mutation_id(&self) -> usize {
         self.mutations.len() + 1
     }
@@ -85,7 +184,53 @@ impl MutagenTransformInfo {
                 );
             }
         }
+        if let Some(pin_file) = &self.pin_file {
+            let update = env::var_os("MUTAGEN_UPDATE_PINS").is_some();
+            if let Err(msg) = check_pin(pin_file, &pin_contents(&self.mutations), update) {
+                panic!("{}", msg);
+            }
+        }
+    }
+}
+
+/// the content of a pin-file for the given mutations.
+///
+/// Each line contains the stable hash of a mutation followed by a description. The lines are sorted, such that the pin does not depend on the order of mutations.
+fn pin_contents(mutations: &[BakedMutation]) -> String {
+    let mut lines = mutations
+        .iter()
+        .map(|m| {
+            format!(
+                "{:016x} {}: {}\n",
+                m.stable_hash(),
+                m.mutator_name(),
+                m.mutation_description()
+            )
+        })
+        .collect::<Vec<_>>();
+    lines.sort();
+    lines.concat()
+}
+
+/// compares the pinned mutations with the generated ones, or writes the pin-file if `update` is set.
+fn check_pin(pin_file: &Path, contents: &str, update: bool) -> Result<(), String> {
+    if update {
+        return fs::write(pin_file, contents)
+            .map_err(|e| format!("unable to write pin file {:?}: {}", pin_file, e));
+    }
+    let pinned = fs::read_to_string(pin_file).map_err(|e| {
+        format!(
+            "unable to read pin file {:?}: {}. Set MUTAGEN_UPDATE_PINS=1 to create it",
+            pin_file, e
+        )
+    })?;
+    if pinned != contents {
+        return Err(format!(
+            "generated mutations differ from pin file {:?}. Set MUTAGEN_UPDATE_PINS=1 to regenerate it",
+            pin_file
+        ));
     }
+    Ok(())
 }
 
 impl SharedTransformInfo {
@@ -104,11 +249,24 @@ impl SharedTransformInfo {
         GLOBAL_TRANSFORM_INFO.clone_shared()
     }
 
+    /// Sets the sink that records the mutations of all items with a global configuration.
+    ///
+    /// This replaces the default sink, which writes to the file `target/mutagen/mutations`.
+    pub fn set_global_sink(sink: Box<dyn MutationSink>) {
+        GLOBAL_TRANSFORM_INFO.lock_tranform_info().sink = Some(sink);
+    }
+
     pub fn local_info(conf: LocalConf) -> Self {
         let mut transform_info = MutagenTransformInfo::default();
         if let Some(n) = conf.expected_mutations {
             transform_info.expected_mutations = Some(n);
         }
+        // pin files are given relative to the crate under test
+        transform_info.pin_file = conf.pin.map(|pin| {
+            env::var_os("CARGO_MANIFEST_DIR")
+                .map(|dir| Path::new(&dir).join(&pin))
+                .unwrap_or_else(|| PathBuf::from(pin))
+        });
         Self::new(transform_info)
     }
 
@@ -119,6 +277,16 @@ impl SharedTransformInfo {
     pub fn add_mutations(&self, mutations: impl IntoIterator<Item = Mutation>) -> usize {
         let mut transform_info = self.lock_tranform_info();
 
+        // the mutations of a mutator are registered together or not at all, since their ids are consecutive
+        let mutations = mutations.into_iter().collect::<Vec<_>>();
+        if !transform_info.is_changed(&mutations)
+            || transform_info.is_skipped(&mutations)
+            || !transform_info.has_capacity_for(mutations.len())
+            || !transform_info.has_mutator_capacity_for(&mutations)
+        {
+            return UNREGISTERED_MUTATOR_ID;
+        }
+
         let mutator_id = transform_info.get_next_mutation_id();
 
         // add all mutations within a single lock and return the first id
@@ -139,4 +307,270 @@ impl SharedTransformInfo {
     pub fn check_mutations(&self) {
         self.lock_tranform_info().check_mutations()
     }
+
+    /// Generates the item that registers the mutations added after the given number of mutations when the binary is loaded.
+    ///
+    /// Only items with a global configuration register their mutations, since the ids of local configurations are not unique.
+    /// Returns `None` if no mutations have been added.
+    pub fn global_registration(&self, first_mutation: usize) -> Option<TokenStream> {
+        if !Arc::ptr_eq(&self.0, &GLOBAL_TRANSFORM_INFO.0) {
+            return None;
+        }
+        let transform_info = self.lock_tranform_info();
+        let mutations = &transform_info.mutations[first_mutation..];
+        if mutations.is_empty() {
+            return None;
+        }
+        Some(mutation_table::registration(mutations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn pin_file(name: &str) -> PathBuf {
+        let pin_file = env::temp_dir().join(format!("mutagen-test-{}.pin", name));
+        let _ = fs::remove_file(&pin_file);
+        pin_file
+    }
+
+    fn baked_mutations(mutated_code: &[&str]) -> Vec<BakedMutation> {
+        mutated_code
+            .iter()
+            .enumerate()
+            .map(|(i, code)| {
+                let mut m = Mutation::new_stub();
+                m.mutated_code = (*code).to_owned();
+                m.with_id(i + 1, 1)
+            })
+            .collect()
+    }
+
+    #[derive(Clone, Default)]
+    struct MemorySink(Arc<Mutex<Vec<(usize, String)>>>);
+
+    impl MutationSink for MemorySink {
+        fn record(&self, mutation: &BakedMutation) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((mutation.id(), mutation.mutated_code().to_owned()));
+        }
+    }
+
+    #[test]
+    fn sink_records_mutations_in_order() {
+        let sink = MemorySink::default();
+        let transform_info = SharedTransformInfo::new(MutagenTransformInfo {
+            sink: Some(Box::new(sink.clone())),
+            ..Default::default()
+        });
+
+        let mut m1 = Mutation::new_stub();
+        m1.mutated_code = "1".to_owned();
+        let mut m2 = Mutation::new_stub();
+        m2.mutated_code = "2".to_owned();
+        let mut m3 = Mutation::new_stub();
+        m3.mutated_code = "3".to_owned();
+        transform_info.add_mutation(m1);
+        transform_info.add_mutations(vec![m2, m3]);
+
+        assert_eq!(
+            *sink.0.lock().unwrap(),
+            vec![
+                (1, "1".to_owned()),
+                (2, "2".to_owned()),
+                (3, "3".to_owned())
+            ]
+        );
+    }
+
+    fn mutation_at_line(line: usize) -> Mutation {
+        let mut m = Mutation::new_stub();
+        m.source_file = PathBuf::from("src/lib.rs");
+        m.location_in_file = format!("{}:5-{}:6", line, line);
+        m
+    }
+
+    fn transform_info_with_changed_lines(changed_lines: &str) -> SharedTransformInfo {
+        SharedTransformInfo::new(MutagenTransformInfo {
+            changed_lines: Some(Arc::new(ChangedLines::parse(changed_lines).unwrap())),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn changed_lines_register_mutation_inside() {
+        let transform_info = transform_info_with_changed_lines("src/lib.rs:3-4");
+
+        let mutator_id = transform_info.add_mutation(mutation_at_line(4));
+
+        assert_eq!(mutator_id, 1);
+        assert_eq!(transform_info.get_num_mutations(), 1);
+    }
+
+    #[test]
+    fn changed_lines_skip_mutation_outside() {
+        let transform_info = transform_info_with_changed_lines("src/lib.rs:3-4");
+
+        let mutator_id = transform_info.add_mutation(mutation_at_line(5));
+
+        assert_eq!(mutator_id, UNREGISTERED_MUTATOR_ID);
+        assert_eq!(transform_info.get_num_mutations(), 0);
+    }
+
+    #[test]
+    fn changed_lines_consecutive_ids() {
+        let transform_info = transform_info_with_changed_lines("src/lib.rs:3");
+
+        transform_info.add_mutations(vec![mutation_at_line(2), mutation_at_line(2)]);
+        let mutator_id =
+            transform_info.add_mutations(vec![mutation_at_line(3), mutation_at_line(3)]);
+
+        assert_eq!(mutator_id, 1);
+        assert_eq!(transform_info.get_num_mutations(), 2);
+    }
+
+    #[test]
+    fn max_mutations_drop_mutations_past_cap() {
+        let transform_info = SharedTransformInfo::new(MutagenTransformInfo {
+            max_mutations: Some(3),
+            ..Default::default()
+        });
+
+        assert_eq!(transform_info.add_mutation(Mutation::new_stub()), 1);
+        assert_eq!(
+            transform_info.add_mutations(vec![Mutation::new_stub(), Mutation::new_stub()]),
+            2
+        );
+        assert_eq!(
+            transform_info.add_mutation(Mutation::new_stub()),
+            UNREGISTERED_MUTATOR_ID
+        );
+        assert_eq!(transform_info.get_num_mutations(), 3);
+        assert!(transform_info.lock_tranform_info().max_mutations_reached);
+    }
+
+    #[test]
+    fn max_mutations_keep_mutator_together() {
+        let transform_info = SharedTransformInfo::new(MutagenTransformInfo {
+            max_mutations: Some(2),
+            ..Default::default()
+        });
+
+        transform_info.add_mutation(Mutation::new_stub());
+        let mutator_id =
+            transform_info.add_mutations(vec![Mutation::new_stub(), Mutation::new_stub()]);
+
+        assert_eq!(mutator_id, UNREGISTERED_MUTATOR_ID);
+        assert_eq!(transform_info.get_num_mutations(), 1);
+    }
+
+    #[test]
+    fn max_mutations_shared_between_clones() {
+        let transform_info = SharedTransformInfo::new(MutagenTransformInfo {
+            max_mutations: Some(2),
+            ..Default::default()
+        });
+        let other = transform_info.clone_shared();
+
+        transform_info.add_mutation(Mutation::new_stub());
+        other.add_mutation(Mutation::new_stub());
+        other.add_mutation(Mutation::new_stub());
+
+        assert_eq!(transform_info.get_num_mutations(), 2);
+    }
+
+    fn mutation_of(mutator: &str) -> Mutation {
+        let mut mutation = Mutation::new_stub();
+        mutation.mutator = mutator.to_owned();
+        mutation
+    }
+
+    #[test]
+    fn max_mutations_per_mutator_drop_mutations_past_cap() {
+        let transform_info = SharedTransformInfo::new(MutagenTransformInfo {
+            max_mutations_per_mutator: vec![("lit_int".to_owned(), 2)].into_iter().collect(),
+            ..Default::default()
+        });
+
+        assert_eq!(transform_info.add_mutation(mutation_of("lit_int")), 1);
+        assert_eq!(
+            transform_info.add_mutations(vec![mutation_of("lit_int"), mutation_of("lit_int")]),
+            UNREGISTERED_MUTATOR_ID
+        );
+        assert_eq!(transform_info.add_mutation(mutation_of("lit_int")), 2);
+        assert_eq!(
+            transform_info.add_mutation(mutation_of("lit_int")),
+            UNREGISTERED_MUTATOR_ID
+        );
+        assert_eq!(transform_info.get_num_mutations(), 2);
+        assert!(transform_info
+            .lock_tranform_info()
+            .mutator_limits_reached
+            .contains("lit_int"));
+    }
+
+    #[test]
+    fn max_mutations_per_mutator_other_mutators_unlimited() {
+        let transform_info = SharedTransformInfo::new(MutagenTransformInfo {
+            max_mutations_per_mutator: vec![("lit_int".to_owned(), 0)].into_iter().collect(),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            transform_info.add_mutation(mutation_of("lit_int")),
+            UNREGISTERED_MUTATOR_ID
+        );
+        assert_eq!(transform_info.add_mutation(mutation_of("lit_bool")), 1);
+        assert_eq!(transform_info.add_mutation(mutation_of("lit_bool")), 2);
+        assert_eq!(transform_info.get_num_mutations(), 2);
+    }
+
+    #[test]
+    fn pin_matching() {
+        let pin_file = pin_file("matching");
+        let contents = pin_contents(&baked_mutations(&["1", "2"]));
+        check_pin(&pin_file, &contents, true).unwrap();
+
+        assert_eq!(check_pin(&pin_file, &contents, false), Ok(()));
+    }
+
+    #[test]
+    fn pin_independent_of_order() {
+        assert_eq!(
+            pin_contents(&baked_mutations(&["1", "2"])),
+            pin_contents(&baked_mutations(&["2", "1"]))
+        );
+    }
+
+    #[test]
+    fn pin_mismatching() {
+        let pin_file = pin_file("mismatching");
+        let contents = pin_contents(&baked_mutations(&["1", "2"]));
+        check_pin(&pin_file, &contents, true).unwrap();
+
+        let changed = pin_contents(&baked_mutations(&["1", "3"]));
+        assert!(check_pin(&pin_file, &changed, false).is_err());
+    }
+
+    #[test]
+    fn pin_missing_file() {
+        let pin_file = pin_file("missing");
+        let contents = pin_contents(&baked_mutations(&["1"]));
+
+        assert!(check_pin(&pin_file, &contents, false).is_err());
+    }
+
+    #[test]
+    fn pin_regenerate() {
+        let pin_file = pin_file("regenerate");
+        check_pin(&pin_file, &pin_contents(&baked_mutations(&["1"])), true).unwrap();
+
+        let changed = pin_contents(&baked_mutations(&["2"]));
+        check_pin(&pin_file, &changed, true).unwrap();
+        assert_eq!(check_pin(&pin_file, &changed, false), Ok(()));
+    }
 }
@@ -1,6 +1,8 @@
+mod mutants;
 mod progress;
 mod progress_bar;
 mod test_bin;
 
+pub use mutants::{run_mutants, MutatorWeights};
 pub use progress::Progress;
 pub use test_bin::{TestBin, TestBinTested};
@@ -1,7 +1,5 @@
 use failure::{bail, Fallible};
-use std::collections::HashMap;
 use std::fs::File;
-use std::io::BufWriter;
 use std::path::PathBuf;
 use std::process;
 use std::process::{Command, Stdio};
@@ -10,7 +8,10 @@ use std::time::Instant;
 
 use cargo_mutagen::*;
 use mutagen_core::comm;
-use mutagen_core::comm::{BakedMutation, CoverageCollection, MutagenReport, MutantStatus};
+use mutagen_core::comm::{
+    BakedMutation, CoverageCollection, KillingTests, MutagenReport, MutantStatus,
+};
+use mutagen_core::MutagenRuntimeConfig;
 
 fn main() {
     if let Err(err) = run() {
@@ -31,6 +32,14 @@ struct Options {
     /// Activate all available features
     #[structopt(long)]
     all_features: bool,
+
+    /// Stop at the first surviving mutant, also enabled by the environment variable `MUTAGEN_FAIL_FAST`
+    #[structopt(long)]
+    fail_fast: bool,
+
+    /// JSON file with weights of mutators, the mutants of mutators with higher weights are run first
+    #[structopt(long, name = "WEIGHTS", parse(from_os_str))]
+    weights: Option<PathBuf>,
 }
 
 fn run() -> Fallible<()> {
@@ -43,7 +52,7 @@ fn run() -> Fallible<()> {
     if test_bins.is_empty() {
         bail!("no test executable(s) found");
     }
-    let mutations = read_mutations()?;
+    let mut mutations = read_mutations()?;
     let num_mutations = mutations.len();
 
     let mut progress = Progress::new(mutations.len());
@@ -68,55 +77,88 @@ fn run() -> Fallible<()> {
 
     // run the mutations on the test-suites
     progress.section_mutants()?;
-    let mutagen_report = run_mutations(&mut progress, &test_bins, mutations, &coverage)?;
+    let fail_fast = opt.fail_fast || MutagenRuntimeConfig::fail_fast();
+    if let Some(weights_file) = &opt.weights {
+        MutatorWeights::load(weights_file)?.order(&mut mutations);
+    }
+    let mutagen_report = run_mutations(&mut progress, &test_bins, mutations, &coverage, fail_fast)?;
 
     progress.section_summary()?;
 
     // final report
     mutagen_report.print_survived();
+    mutagen_report.print_mutator_scores();
     mutagen_report.summary().print();
 
     progress.finish(mutagen_start.elapsed())?;
 
+    let num_survived = mutagen_report.summary().num_survived();
+    if num_survived != 0 {
+        bail!("{} mutants survived", num_survived);
+    }
+
     Ok(())
 }
 
 /// run all mutations on all test-executables
+///
+/// All mutations are run, unless fail-fast mode stops the run at the first surviving mutant.
+/// The tests that killed each mutant are written to `target/mutagen/killing_tests.json`.
+/// The number of killed and surviving mutants of each mutator is written to `target/mutagen/mutator_scores.json`.
 fn run_mutations(
     progress: &mut Progress,
     test_bins: &[TestBinTested],
     mutations: Vec<BakedMutation>,
     coverage: &CoverageCollection,
+    fail_fast: bool,
 ) -> Fallible<MutagenReport> {
-    let mut mutagen_report = MutagenReport::new();
-
-    for m in mutations {
-        let mutant_status = if coverage.is_covered(m.id()) {
-            progress.start_mutation_covered(&m)?;
-
-            // run all test binaries
-            let mut mutant_status = MutantStatus::Survived;
-            for bin in test_bins {
-                mutant_status = bin.check_mutant(&m)?;
-                if mutant_status != MutantStatus::Survived {
-                    break;
-                }
+    let mut mutagen_report =
+        MutagenReport::with_expected_equivalent(MutagenRuntimeConfig::expected_equivalent_mutators());
+
+    let mut killing_tests = KillingTests::new();
+
+    let stopped = run_mutants(&mut mutagen_report, mutations, fail_fast, |m| {
+        if !coverage.is_covered(m.id()) {
+            progress.skip_mutation_uncovered(m)?;
+            return Ok(MutantStatus::NotCovered);
+        }
+        progress.start_mutation_covered(m)?;
+
+        // run all test binaries
+        let mut mutant_status = MutantStatus::Survived;
+        for bin in test_bins {
+            mutant_status = bin.check_mutant(m, &mut killing_tests)?;
+            if mutant_status != MutantStatus::Survived {
+                break;
             }
-            progress.finish_mutation(mutant_status)?;
-
-            mutant_status
-        } else {
-            progress.skip_mutation_uncovered(&m)?;
-            MutantStatus::NotCovered
-        };
-        if let MutantStatus::Killed(_) = mutant_status {}
-        else { panic!("Non-Failing Mutation test!"); }
-        mutagen_report.add_mutation_result(m, mutant_status);
+        }
+        progress.finish_mutation(mutant_status)?;
+
+        Ok(mutant_status)
+    })?;
+    if let Some(num_remaining) = stopped {
+        progress.stop_fail_fast(num_remaining)?;
     }
+    write_killing_tests(&killing_tests)?;
+    write_mutator_scores(&mutagen_report)?;
 
     Ok(mutagen_report)
 }
 
+/// writes the tests that killed each mutation to `target/mutagen/killing_tests.json`
+fn write_killing_tests(killing_tests: &KillingTests) -> Fallible<()> {
+    let file = File::create(comm::get_killing_tests_file()?)?;
+    serde_json::to_writer(file, killing_tests)?;
+    Ok(())
+}
+
+/// writes the number of killed and surviving mutants of each mutator to `target/mutagen/mutator_scores.json`
+fn write_mutator_scores(mutagen_report: &MutagenReport) -> Fallible<()> {
+    let file = File::create(comm::get_mutator_scores_file()?)?;
+    serde_json::to_writer(file, mutagen_report.mutator_scores())?;
+    Ok(())
+}
+
 /// build all tests and collect test-suite executables
 fn compile_tests(opt: &Options) -> Fallible<Vec<PathBuf>> {
     let mut tests: Vec<PathBuf> = Vec::new();
@@ -188,12 +230,7 @@ fn read_mutations() -> Fallible<Vec<BakedMutation>> {
     let mutations = comm::read_items::<BakedMutation>(&mutations_file)?;
 
     // write the collected mutations
-    let mutations_map = mutations
-        .iter()
-        .map(|m| (m.id(), m.as_ref()))
-        .collect::<HashMap<_, _>>();
-    let mutations_writer = BufWriter::new(File::create(comm::get_mutations_file_json()?)?);
-    serde_json::to_writer(mutations_writer, &mutations_map)?;
+    comm::write_mutations_catalog(File::create(comm::get_mutations_file_json()?)?, &mutations)?;
 
     Ok(mutations)
 }
@@ -0,0 +1,231 @@
+use failure::{format_err, Fallible};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use mutagen_core::comm;
+use mutagen_core::comm::{BakedMutation, MutagenReport, MutantStatus, MutatorScore};
+
+/// the weight of mutators that are not listed in the weights file or have no score
+const DEFAULT_WEIGHT: f64 = 1.0;
+
+/// Runs the given mutations in order and adds their results to the report.
+///
+/// The status of each mutant is determined by `check`.
+/// All mutations are run, such that the report contains full statistics.
+/// In fail-fast mode, the run stops as soon as a mutant survived and the number of mutations that have not been run is returned.
+pub fn run_mutants(
+    report: &mut MutagenReport,
+    mutations: Vec<BakedMutation>,
+    fail_fast: bool,
+    mut check: impl FnMut(&BakedMutation) -> Fallible<MutantStatus>,
+) -> Fallible<Option<usize>> {
+    let num_mutations = mutations.len();
+    for (i, m) in mutations.into_iter().enumerate() {
+        let mutant_status = check(&m)?;
+        report.add_mutation_result(m, mutant_status);
+        if fail_fast && comm::should_stop(&report.summary()) {
+            return Ok(Some(num_mutations - i - 1));
+        }
+    }
+    Ok(None)
+}
+
+/// Weights of mutators that determine the order in which their mutants are run.
+///
+/// The weights are read from a JSON file that maps the name of each mutator either to a number or to its score from a previous run, as written to `target/mutagen/mutator_scores.json`.
+/// The weight of a score is the fraction of surviving mutants, such that mutators whose mutants survived more often are run first.
+#[derive(Debug, Default, Deserialize)]
+pub struct MutatorWeights(BTreeMap<String, MutatorWeight>);
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MutatorWeight {
+    Weight(f64),
+    Score(MutatorScore),
+}
+
+impl MutatorWeights {
+    pub fn load(weights_file: &Path) -> Fallible<Self> {
+        let file = File::open(weights_file)
+            .map_err(|e| format_err!("unable to read weights file {:?}: {}", weights_file, e))?;
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|e| format_err!("invalid weights file {:?}: {}", weights_file, e))
+    }
+
+    /// the weight of the given mutator, mutators without a weight or score have the weight 1.
+    pub fn weight(&self, mutator: &str) -> f64 {
+        match self.0.get(mutator) {
+            Some(MutatorWeight::Weight(weight)) => *weight,
+            Some(MutatorWeight::Score(score)) => score.survival_rate().unwrap_or(DEFAULT_WEIGHT),
+            None => DEFAULT_WEIGHT,
+        }
+    }
+
+    /// Sorts the mutations by the weight of their mutator, starting with the highest weight.
+    ///
+    /// Mutations with equal weights keep their order.
+    pub fn order(&self, mutations: &mut [BakedMutation]) {
+        mutations.sort_by(|a, b| {
+            let weight_a = self.weight(a.mutator_name());
+            let weight_b = self.weight(b.mutator_name());
+            weight_b.total_cmp(&weight_a)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use mutagen_core::comm::Mutation;
+    use std::path::PathBuf;
+
+    fn mutations(n: usize) -> Vec<BakedMutation> {
+        mutations_of(&vec!["binop_num"; n])
+    }
+
+    /// mutations with consecutive ids, one of each of the given mutators
+    fn mutations_of(mutators: &[&str]) -> Vec<BakedMutation> {
+        mutators
+            .iter()
+            .enumerate()
+            .map(|(i, mutator)| {
+                let id = i + 1;
+                Mutation {
+                    impl_name: None,
+                    fn_name: None,
+                    mutator: (*mutator).to_owned(),
+                    original_code: "+".to_owned(),
+                    mutated_code: "-".to_owned(),
+                    source_file: PathBuf::new(),
+                    location_in_file: format!("{}:1-{}:2", id, id),
+                    optimistic_policy: Default::default(),
+                }
+                .with_id(id, id)
+            })
+            .collect()
+    }
+
+    /// the second mutant survives, all others are killed
+    fn second_survives(m: &BakedMutation) -> Fallible<MutantStatus> {
+        Ok(if m.id() == 2 {
+            MutantStatus::Survived
+        } else {
+            MutantStatus::Killed(Some(101))
+        })
+    }
+
+    #[test]
+    fn run_all_mutants_without_fail_fast() {
+        let mut report = MutagenReport::new();
+        let mut num_checked = 0;
+
+        let stopped = run_mutants(&mut report, mutations(4), false, |m| {
+            num_checked += 1;
+            second_survives(m)
+        })
+        .unwrap();
+
+        assert_eq!(stopped, None);
+        assert_eq!(num_checked, 4);
+        assert_eq!(report.summary().num_survived(), 1);
+    }
+
+    #[test]
+    fn stop_at_first_survivor_with_fail_fast() {
+        let mut report = MutagenReport::new();
+        let mut num_checked = 0;
+
+        let stopped = run_mutants(&mut report, mutations(4), true, |m| {
+            num_checked += 1;
+            second_survives(m)
+        })
+        .unwrap();
+
+        assert_eq!(stopped, Some(2));
+        assert_eq!(num_checked, 2);
+        assert_eq!(report.summary().num_survived(), 1);
+    }
+
+    #[test]
+    fn fail_fast_runs_all_killed_mutants() {
+        let mut report = MutagenReport::new();
+
+        let stopped = run_mutants(&mut report, mutations(3), true, |_| {
+            Ok(MutantStatus::Killed(None))
+        })
+        .unwrap();
+
+        assert_eq!(stopped, None);
+        assert_eq!(report.summary().num_survived(), 0);
+    }
+
+    fn ids(mutations: &[BakedMutation]) -> Vec<usize> {
+        mutations.iter().map(|m| m.id()).collect()
+    }
+
+    fn weights_file(name: &str, contents: &str) -> PathBuf {
+        let weights_file = std::env::temp_dir().join(format!("mutagen-test-{}.json", name));
+        std::fs::write(&weights_file, contents).unwrap();
+        weights_file
+    }
+
+    #[test]
+    fn weights_order_mutations() {
+        let weights_file = weights_file("weights", r#"{"lit_int": 2, "binop_num": 0.5}"#);
+        let weights = MutatorWeights::load(&weights_file).unwrap();
+        let mut mutations =
+            mutations_of(&["binop_num", "lit_bool", "lit_int", "binop_num", "lit_int"]);
+
+        weights.order(&mut mutations);
+
+        assert_eq!(ids(&mutations), vec![3, 5, 2, 1, 4]);
+    }
+
+    #[test]
+    fn weights_default_keeps_order() {
+        let mut mutations = mutations_of(&["binop_num", "lit_bool", "lit_int"]);
+
+        MutatorWeights::default().order(&mut mutations);
+
+        assert_eq!(ids(&mutations), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn weights_from_mutator_scores() {
+        let mut report = MutagenReport::new();
+        let mutations = mutations_of(&["binop_num", "binop_num", "lit_int", "lit_int", "lit_bool"]);
+        let statuses = vec![
+            MutantStatus::Killed(None),
+            MutantStatus::Killed(None),
+            MutantStatus::Survived,
+            MutantStatus::Killed(None),
+            MutantStatus::Skipped,
+        ];
+        for (m, status) in mutations.into_iter().zip(statuses) {
+            report.add_mutation_result(m, status);
+        }
+        let scores = serde_json::to_string(report.mutator_scores()).unwrap();
+        let weights_file = weights_file("weights-scores", &scores);
+        let weights = MutatorWeights::load(&weights_file).unwrap();
+
+        assert_eq!(weights.weight("binop_num"), 0.0);
+        assert_eq!(weights.weight("lit_int"), 0.5);
+        assert_eq!(weights.weight("lit_bool"), DEFAULT_WEIGHT);
+        assert_eq!(weights.weight("unop_not"), DEFAULT_WEIGHT);
+
+        let mut mutations = mutations_of(&["binop_num", "lit_int", "unop_not"]);
+        weights.order(&mut mutations);
+        assert_eq!(ids(&mutations), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn weights_invalid_file() {
+        let weights_file = weights_file("weights-invalid", r#"{"lit_int": "high"}"#);
+
+        assert!(MutatorWeights::load(&weights_file).is_err());
+    }
+}
@@ -157,6 +157,16 @@ impl Progress {
         Ok(())
     }
 
+    /// indicate that the remaining mutations are skipped since a mutant survived in fail-fast mode
+    pub fn stop_fail_fast(&mut self, num_remaining: usize) -> Fallible<()> {
+        self.bar.println("")?;
+        self.bar.println(&format!(
+            "Stopped at the first surviving mutant, {} mutations not tested",
+            num_remaining
+        ))?;
+        Ok(())
+    }
+
     /// indicate that mutation-testing is finished
     ///
     /// clears the progress-bar
@@ -1,13 +1,17 @@
-use failure::{bail, Fallible};
+use failure::{bail, format_err, Fallible};
 use std::fs;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::thread;
 use std::time::{Duration, Instant};
 
 use wait_timeout::ChildExt;
 
-use mutagen_core::comm::{self, BakedMutation, CoverageCollection, CoverageHit, MutantStatus};
+use mutagen_core::comm::{
+    self, BakedMutation, CoverageCollection, CoverageHit, KillingTests, MutantStatus,
+};
+use mutagen_core::MutagenRuntimeConfig;
 
 use super::Progress;
 
@@ -94,29 +98,51 @@ impl<'a> TestBinTested<'a> {
         self.coverage.num_covered() != 0
     }
 
-    pub fn check_mutant(&self, mutation: &BakedMutation) -> Fallible<MutantStatus> {
+    /// Runs the test suite with the given mutation and records the tests that failed.
+    pub fn check_mutant(
+        &self,
+        mutation: &BakedMutation,
+        killing_tests: &mut KillingTests,
+    ) -> Fallible<MutantStatus> {
         // run command and wait for its output
         let mut command = Command::new(self.test_bin.bin_path);
-        command.env("MUTATION_ID", mutation.id().to_string());
-        command.stdout(Stdio::null());
+        command.env(
+            MutagenRuntimeConfig::mutation_id_var(),
+            mutation.id().to_string(),
+        );
+        command.env("MUTAGEN_OPTIMISTIC_POLICY", mutation.optimistic_policy().name());
+        command.stdout(Stdio::piped());
         command.stderr(Stdio::null());
         let mut test_run = command.spawn()?;
 
+        // the output is read concurrently, such that the test suite does not block on a full pipe
+        let mut stdout = test_run.stdout.take().unwrap();
+        let output_reader = thread::spawn(move || {
+            let mut output = String::new();
+            stdout.read_to_string(&mut output).map(|_| output)
+        });
+
         let wait_time = 5 * self.exe_time + Duration::from_millis(500);
         let timeout = test_run.wait_timeout(wait_time)?;
+        if timeout.is_none() {
+            test_run.kill()?;
+        }
+
+        let output = output_reader
+            .join()
+            .map_err(|_| format_err!("reading the output of the test suite panicked"))??;
+        killing_tests.record_test_output(mutation.id(), &output);
 
         Ok(match timeout {
             Some(status) => {
                 if status.success() {
                     MutantStatus::Survived
                 } else {
-                    MutantStatus::Killed(status.code())
+                    MutantStatus::of_failed_run(status.code())
+                        .map_err(|e| format_err!("{} for mutation {}", e, mutation.id()))?
                 }
             }
-            None => {
-                test_run.kill()?;
-                MutantStatus::Timeout
-            }
+            None => MutantStatus::Timeout,
         })
     }
 }
@@ -2,4 +2,12 @@
 
 mod mutator;
 mod runtime_config;
+mod test_cfg;
+mod test_expand_derives;
+mod test_mutate_mod;
+mod test_mutation_table;
+mod test_mutation_range;
 mod test_not_mutated;
+mod test_pin;
+mod test_recursion_limit;
+mod test_skip_comment;
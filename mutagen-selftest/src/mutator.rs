@@ -1,9 +1,75 @@
+mod test_abs_signum;
+mod test_array_len;
 mod test_binop_bit;
 mod test_binop_bool;
 mod test_binop_cmp;
 mod test_binop_eq;
 mod test_binop_num;
+mod test_binop_shift;
+mod test_bit_assign;
+mod test_bool_query;
+mod test_bool_return_negate;
+mod test_bool_then;
+mod test_borrow;
+mod test_bound_inclusive;
+mod test_box_new;
+mod test_ceil_div;
+mod test_checked_none;
+mod test_checked_unwrap;
+mod test_cmp_chain;
+mod test_cmp_cloned;
+mod test_collect_target;
+mod test_combinator_order;
+mod test_const_init;
+mod test_constructor;
+mod test_copied_cloned;
+mod test_counter_step;
+mod test_debug_assert;
+mod test_dedup;
+mod test_duration_threshold;
+mod test_early_return;
+mod test_extend;
+mod test_find;
+mod test_first;
+mod test_first_last;
+mod test_flag_ops;
+mod test_flatten;
+mod test_float_eps;
+mod test_get_index;
+mod test_get_unchecked;
+mod test_hash_seed;
+mod test_int_cast;
+mod test_iter_count;
+mod test_iter_rev;
+mod test_iter_source;
 mod test_lit_bool;
+mod test_lit_char;
 mod test_lit_int;
+mod test_lit_sign;
+mod test_loop_bound;
+mod test_map_entry;
+mod test_map_identity;
+mod test_match_result;
+mod test_min_max;
+mod test_ordering_then;
+mod test_overflow_policy;
+mod test_pow;
+mod test_repeat_count;
+mod test_resize_fill;
+mod test_result_ok;
+mod test_retain;
+mod test_reverse;
+mod test_rotate;
+mod test_slice_window;
 mod test_stmt_call;
+mod test_str_case;
+mod test_str_split;
+mod test_str_trim;
+mod test_struct_update;
+mod test_take;
+mod test_try;
+mod test_try_bypass;
 mod test_unop_not;
+mod test_vec_remove;
+mod test_while_iterations;
+mod test_zip_swap;
@@ -0,0 +1,83 @@
+mod test_is_close {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // checks if the values differ by at most 2
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(abs_signum))]
+    fn is_close(a: i32, b: i32) -> bool {
+        (a - b).abs() <= 2
+    }
+    #[test]
+    fn is_close_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(is_close(5, 4));
+            assert!(!is_close(1, 5));
+        })
+    }
+    // (a - b)
+    #[test]
+    fn is_close_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(is_close(1, 5));
+        })
+    }
+}
+
+mod test_direction {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // moves the position one step towards the target
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(abs_signum))]
+    fn step_towards(pos: i64, target: i64) -> i64 {
+        pos + (target - pos).signum()
+    }
+    #[test]
+    fn step_towards_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(step_towards(3, 5), 4);
+            assert_eq!(step_towards(3, 3), 3);
+        })
+    }
+    // -(target - pos).signum()
+    #[test]
+    fn step_towards_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(step_towards(3, 5), 2);
+        })
+    }
+}
+
+mod test_squared_distance {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the squared distance between two values, `abs` and `pow` are mutated together
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(abs_signum, pow))]
+    fn squared_distance(a: i32, b: i32) -> i32 {
+        (a - b).abs().pow(2)
+    }
+    #[test]
+    fn squared_distance_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(squared_distance(1, 4), 9);
+        })
+    }
+    // (a - b), which is an equivalent mutant
+    #[test]
+    fn squared_distance_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(squared_distance(1, 4), 9);
+        })
+    }
+    // pow(3)
+    #[test]
+    fn squared_distance_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(squared_distance(1, 4), 27);
+        })
+    }
+}
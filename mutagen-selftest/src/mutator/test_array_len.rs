@@ -0,0 +1,51 @@
+// the mutations of `array_len` are selected when compiling the tests.
+// The tests in this module fail when the crate is compiled with an active mutation, e.g. with `MUTATION_ID=2 cargo test`.
+
+mod test_sum_of_buffer {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sums a buffer of constant size
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(array_len))]
+    fn sum_of_buffer() -> u32 {
+        [3u32; 4].iter().sum()
+    }
+    #[test]
+    fn sum_of_buffer_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(sum_of_buffer(), 12);
+        })
+    }
+}
+
+mod test_empty_array {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // an array of length 0 is only made longer
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(array_len))]
+    fn empty() -> Vec<u8> {
+        [1u8; 0].to_vec()
+    }
+    #[test]
+    fn empty_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(empty().is_empty());
+        })
+    }
+}
+
+mod test_array_len_not_literal {
+
+    use ::mutagen::mutate;
+
+    const LEN: usize = 4;
+
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(array_len))]
+    fn not_literal() -> Vec<u8> {
+        [0u8; LEN].to_vec()
+    }
+}
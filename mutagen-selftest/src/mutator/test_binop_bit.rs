@@ -27,3 +27,67 @@ mod test_and_i32 {
         })
     }
 }
+
+mod test_low_bits {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // keeps the lowest four bits
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(binop_bit))]
+    fn low_bits(x: u8) -> u8 {
+        x & 0x0f
+    }
+    #[test]
+    fn low_bits_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(low_bits(0xab), 0x0b);
+        })
+    }
+    // x & 0
+    #[test]
+    fn low_bits_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(low_bits(0xab), 0);
+        })
+    }
+    // x & !0
+    #[test]
+    fn low_bits_active4() {
+        MutagenRuntimeConfig::test_with_mutation_id(4, || {
+            assert_eq!(low_bits(0xab), 0xab);
+        })
+    }
+}
+
+mod test_set_flag {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sets the highest bit
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(binop_bit))]
+    fn set_flag(x: u8) -> u8 {
+        0x80 | x
+    }
+    #[test]
+    fn set_flag_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(set_flag(0x01), 0x81);
+        })
+    }
+    // 0 | x
+    #[test]
+    fn set_flag_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(set_flag(0x01), 0x01);
+        })
+    }
+    // !0 | x
+    #[test]
+    fn set_flag_active4() {
+        MutagenRuntimeConfig::test_with_mutation_id(4, || {
+            assert_eq!(set_flag(0x01), 0xff);
+        })
+    }
+}
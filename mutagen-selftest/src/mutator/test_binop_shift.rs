@@ -0,0 +1,78 @@
+mod test_double_u32 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // double a number via shift
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(binop_shift))]
+    fn double_u32(x: u32) -> u32 {
+        x << 1
+    }
+    #[test]
+    fn double_u32_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(double_u32(3), 6);
+        })
+    }
+    // x << 2
+    #[test]
+    fn double_u32_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(double_u32(3), 12);
+        })
+    }
+    // x << 0
+    #[test]
+    fn double_u32_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(double_u32(3), 3);
+        })
+    }
+}
+
+mod test_high_bit_u32 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // extract the highest bit, the shift amount is at the boundary of `u32`
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(binop_shift))]
+    fn high_bit_u32(x: u32) -> u32 {
+        x >> 31
+    }
+    #[test]
+    fn high_bit_u32_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(high_bit_u32(0x8000_0000), 1);
+            assert_eq!(high_bit_u32(0x4000_0000), 0);
+        })
+    }
+    // x >> 32 overflows
+    #[test]
+    #[should_panic]
+    fn high_bit_u32_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            high_bit_u32(0x8000_0000);
+        })
+    }
+    // x >> 30
+    #[test]
+    fn high_bit_u32_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(high_bit_u32(0x8000_0000), 2);
+            assert_eq!(high_bit_u32(0x4000_0000), 1);
+        })
+    }
+}
+
+mod test_shift_by_var {
+
+    use ::mutagen::mutate;
+
+    // shifts by non-constant amounts are not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(binop_shift))]
+    #[allow(dead_code)]
+    fn shift_by_var(x: u32, n: u32) -> u32 {
+        x << n
+    }
+}
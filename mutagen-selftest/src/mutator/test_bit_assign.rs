@@ -0,0 +1,92 @@
+mod test_clear_flag {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    const DIRTY: u8 = 0b0100;
+
+    // clears the flag `DIRTY`
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(bit_assign))]
+    fn clear_dirty(flags: &mut u8) {
+        *flags &= !DIRTY
+    }
+    #[test]
+    fn clear_dirty_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut flags = 0b0101;
+            clear_dirty(&mut flags);
+            assert_eq!(flags, 0b0001);
+        })
+    }
+    // |= !DIRTY
+    #[test]
+    fn clear_dirty_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut flags = 0b0101;
+            clear_dirty(&mut flags);
+            assert_eq!(flags, 0b1111_1111);
+        })
+    }
+    // ^= !DIRTY
+    #[test]
+    fn clear_dirty_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            let mut flags = 0b0101;
+            clear_dirty(&mut flags);
+            assert_eq!(flags, 0b1111_1110);
+        })
+    }
+    // &= !(!DIRTY)
+    #[test]
+    fn clear_dirty_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            let mut flags = 0b0101;
+            clear_dirty(&mut flags);
+            assert_eq!(flags, 0b0100);
+        })
+    }
+}
+
+mod test_set_flag {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    const VISIBLE: u8 = 0b0010;
+
+    struct Widget {
+        flags: u8,
+    }
+
+    // sets the flag `VISIBLE`
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(bit_assign))]
+    fn show(widget: &mut Widget) {
+        widget.flags |= VISIBLE;
+    }
+    #[test]
+    fn show_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut widget = Widget { flags: 0b0001 };
+            show(&mut widget);
+            assert_eq!(widget.flags, 0b0011);
+        })
+    }
+    // &= VISIBLE
+    #[test]
+    fn show_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut widget = Widget { flags: 0b0001 };
+            show(&mut widget);
+            assert_eq!(widget.flags, 0b0000);
+        })
+    }
+    // ^= VISIBLE
+    #[test]
+    fn show_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            let mut widget = Widget { flags: 0b0011 };
+            show(&mut widget);
+            assert_eq!(widget.flags, 0b0001);
+        })
+    }
+}
@@ -0,0 +1,94 @@
+mod test_is_empty {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // check if a vector has no elements
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(bool_query))]
+    fn has_no_elements(v: &[u32]) -> bool {
+        v.is_empty()
+    }
+    #[test]
+    fn has_no_elements_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(has_no_elements(&[]));
+            assert!(!has_no_elements(&[1]));
+        })
+    }
+    // !v.is_empty()
+    #[test]
+    fn has_no_elements_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!has_no_elements(&[]));
+            assert!(has_no_elements(&[1]));
+        })
+    }
+}
+
+mod test_contains {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // count the words of a list that contain a character
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(bool_query))]
+    fn count_containing(words: &[&str], c: char) -> usize {
+        words.iter().filter(|w| w.contains(c)).count()
+    }
+    #[test]
+    fn count_containing_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_containing(&["ab", "bc", "cd"], 'b'), 2);
+        })
+    }
+    // !w.contains(c)
+    #[test]
+    fn count_containing_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_containing(&["ab", "bc", "cd"], 'b'), 1);
+        })
+    }
+}
+
+mod test_is_ok {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // classify the input by whether it can be parsed as a number
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(bool_query))]
+    fn classify(input: &str) -> &'static str {
+        if input.parse::<u32>().is_ok() {
+            "number"
+        } else {
+            "text"
+        }
+    }
+    #[test]
+    fn classify_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(classify("42"), "number");
+            assert_eq!(classify("abc"), "text");
+        })
+    }
+    // input.parse::<u32>().is_err()
+    #[test]
+    fn classify_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(classify("42"), "text");
+            assert_eq!(classify("abc"), "number");
+        })
+    }
+}
+
+mod test_other_methods {
+
+    use ::mutagen::mutate;
+
+    // methods that are not queries are not mutated
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(bool_query))]
+    fn len_of(v: &[u32]) -> usize {
+        v.len()
+    }
+}
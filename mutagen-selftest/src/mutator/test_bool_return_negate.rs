@@ -0,0 +1,83 @@
+mod test_is_adult {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // check if a person of the given age is an adult
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(bool_return_negate))]
+    fn is_adult(age: u32) -> bool {
+        age >= 18
+    }
+
+    fn ticket_price(age: u32) -> u32 {
+        if is_adult(age) {
+            10
+        } else {
+            5
+        }
+    }
+
+    #[test]
+    fn is_adult_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(ticket_price(30), 10);
+            assert_eq!(ticket_price(8), 5);
+        })
+    }
+    // !(age >= 18)
+    #[test]
+    fn is_adult_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(ticket_price(30), 5);
+            assert_eq!(ticket_price(8), 10);
+        })
+    }
+}
+
+mod test_method {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    struct Range {
+        start: u32,
+        end: u32,
+    }
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(bool_return_negate))]
+    impl Range {
+        // check if the range contains no values
+        fn is_empty(&self) -> bool {
+            let len = self.end.saturating_sub(self.start);
+            len == 0
+        }
+    }
+
+    #[test]
+    fn is_empty_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(Range { start: 2, end: 2 }.is_empty());
+            assert!(!Range { start: 2, end: 4 }.is_empty());
+        })
+    }
+    // !(len == 0)
+    #[test]
+    fn is_empty_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!Range { start: 2, end: 2 }.is_empty());
+            assert!(Range { start: 2, end: 4 }.is_empty());
+        })
+    }
+}
+
+mod test_other_return_type {
+
+    use ::mutagen::mutate;
+
+    // functions that do not return `bool` are not mutated
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(bool_return_negate))]
+    fn find_adult(ages: &[u32]) -> Option<u32> {
+        ages.iter().copied().find(|&age| age >= 18)
+    }
+}
@@ -0,0 +1,89 @@
+mod test_positive {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // returns the value if it is positive
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(bool_then))]
+    fn positive(x: i32) -> Option<i32> {
+        (x > 0).then_some(x)
+    }
+    #[test]
+    fn positive_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(positive(5), Some(5));
+            assert_eq!(positive(-5), None);
+        })
+    }
+    // (!(x > 0)).then_some(x)
+    #[test]
+    fn positive_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(positive(5), None);
+            assert_eq!(positive(-5), Some(-5));
+        })
+    }
+    // false.then_some(x)
+    #[test]
+    fn positive_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(positive(5), None);
+            assert_eq!(positive(-5), None);
+        })
+    }
+    // true.then_some(x)
+    #[test]
+    fn positive_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(positive(5), Some(5));
+            assert_eq!(positive(-5), Some(-5));
+        })
+    }
+}
+
+mod test_then_closure {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the closure is only called for positive values, forcing `Some` is not generated by default
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(bool_then))]
+    fn halve_positive(x: i32) -> Option<i32> {
+        (x > 0).then(|| x / 2)
+    }
+    #[test]
+    fn halve_positive_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(halve_positive(6), Some(3));
+            assert_eq!(halve_positive(-6), None);
+        })
+    }
+    // (!(x > 0)).then(|| x / 2)
+    #[test]
+    fn halve_positive_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(halve_positive(6), None);
+            assert_eq!(halve_positive(-6), Some(-3));
+        })
+    }
+    // false.then(|| x / 2)
+    #[test]
+    fn halve_positive_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(halve_positive(6), None);
+        })
+    }
+}
+
+mod test_ordering_then {
+
+    use ::mutagen::mutate;
+    use std::cmp::Ordering;
+
+    // `Ordering::then` is not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(bool_then))]
+    #[allow(dead_code)]
+    fn cmp_pairs(a: (u32, u32), b: (u32, u32)) -> Ordering {
+        a.0.cmp(&b.0).then(a.1.cmp(&b.1))
+    }
+}
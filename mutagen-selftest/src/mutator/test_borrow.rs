@@ -0,0 +1,93 @@
+mod test_push_one {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    fn push_one(v: &mut Vec<u32>) {
+        v.push(1);
+    }
+
+    // length of a vector after pushing a single element
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(borrow))]
+    fn pushed_len() -> usize {
+        let mut v = vec![];
+        push_one(&mut v);
+        v.len()
+    }
+    #[test]
+    fn pushed_len_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(pushed_len(), 1);
+        })
+    }
+    // push_one(&mut v.clone())
+    #[test]
+    fn pushed_len_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(pushed_len(), 0);
+        })
+    }
+}
+
+mod test_generic_callee {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::ops::Deref;
+
+    // the callee accepts both `&x` and `&mut x`
+    fn sum<V: Deref<Target = Vec<u32>>>(v: V) -> u32 {
+        v.iter().sum()
+    }
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(borrow))]
+    fn sum_of_borrows(mut v: Vec<u32>) -> u32 {
+        sum(&v) + sum(&mut v)
+    }
+    #[test]
+    fn sum_of_borrows_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(sum_of_borrows(vec![1, 2]), 6);
+        })
+    }
+    // sum(&mut v.clone())
+    #[test]
+    fn sum_of_borrows_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(sum_of_borrows(vec![1, 2]), 6);
+        })
+    }
+}
+
+mod test_not_clone {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    struct Counter(u32);
+
+    fn increment(c: &mut Counter) {
+        c.0 += 1;
+    }
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(borrow))]
+    fn incremented() -> u32 {
+        let mut c = Counter(0);
+        increment(&mut c);
+        c.0
+    }
+    #[test]
+    fn incremented_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(incremented(), 1);
+        })
+    }
+    // `Counter` cannot be cloned
+    #[test]
+    #[should_panic]
+    fn incremented_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            incremented();
+        })
+    }
+}
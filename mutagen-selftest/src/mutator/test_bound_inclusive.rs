@@ -0,0 +1,49 @@
+mod test_in_range {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // checks if the value is in the half-open interval `[lo, hi)`
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(bound_inclusive))]
+    fn in_range(x: u32, lo: u32, hi: u32) -> bool {
+        x >= lo && x < hi
+    }
+    #[test]
+    fn in_range_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(in_range(2, 2, 5));
+            assert!(in_range(4, 2, 5));
+            assert!(!in_range(5, 2, 5));
+        })
+    }
+    // x > lo && x < hi
+    #[test]
+    fn in_range_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!in_range(2, 2, 5));
+            assert!(in_range(4, 2, 5));
+            assert!(!in_range(5, 2, 5));
+        })
+    }
+    // x >= lo && x <= hi
+    #[test]
+    fn in_range_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(in_range(2, 2, 5));
+            assert!(in_range(4, 2, 5));
+            assert!(in_range(5, 2, 5));
+        })
+    }
+}
+
+mod test_unrelated_comparisons {
+
+    use ::mutagen::mutate;
+
+    // comparisons of different values do not form a range check
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(bound_inclusive))]
+    #[allow(dead_code)]
+    fn both_small(a: u32, b: u32, limit: u32) -> bool {
+        a < limit && b <= 10
+    }
+}
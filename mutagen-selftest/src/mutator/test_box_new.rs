@@ -0,0 +1,88 @@
+mod test_box_new_i32 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // a function that allocates a single integer on the heap
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(box_new))]
+    fn box_new_i32() -> Box<i32> {
+        Box::new(5)
+    }
+    #[test]
+    fn box_new_i32_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(*box_new_i32(), 5);
+        })
+    }
+    // the bare `i32` cannot be returned as `Box<i32>`, the optimistic assumption fails and the mutant is skipped
+    #[test]
+    #[should_panic]
+    fn box_new_i32_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            box_new_i32();
+        })
+    }
+}
+
+mod test_rc_new_shared {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::rc::Rc;
+
+    // counts the references to a value shared by an `Rc`
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(box_new))]
+    fn rc_new_shared() -> usize {
+        let x = Rc::new(vec![1]);
+        let y = Rc::clone(&x);
+        Rc::strong_count(&y)
+    }
+    #[test]
+    fn rc_new_shared_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(rc_new_shared(), 2);
+        })
+    }
+    // the bare vector cannot be used as `Rc<Vec<i32>>`, the optimistic assumption fails and the mutant is skipped
+    #[test]
+    #[should_panic]
+    fn rc_new_shared_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            rc_new_shared();
+        })
+    }
+}
+
+mod test_unboxed {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // a box that is dereferenced immediately, whose allocation is not needed
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(box_new))]
+    fn unboxed_sum(v: Vec<u32>) -> u32 {
+        let v = *Box::new(v);
+        v.iter().sum()
+    }
+    #[test]
+    fn unboxed_sum_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(unboxed_sum(vec![1, 2]), 3);
+        })
+    }
+    // the bare vector cannot be dereferenced, the optimistic assumption fails and the mutant is skipped
+    #[test]
+    #[should_panic]
+    fn unboxed_sum_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            unboxed_sum(vec![1, 2]);
+        })
+    }
+    // `v` instead of `*Box::new(v)`, which gives the same result without allocation
+    #[test]
+    fn unboxed_sum_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(unboxed_sum(vec![1, 2]), 3);
+        })
+    }
+}
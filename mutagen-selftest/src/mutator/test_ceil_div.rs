@@ -0,0 +1,63 @@
+mod test_page_count {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the number of pages needed for the given number of items
+    #[allow(clippy::manual_div_ceil)]
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(ceil_div))]
+    fn page_count(items: usize, page_size: usize) -> usize {
+        (items + page_size - 1) / page_size
+    }
+    #[test]
+    fn page_count_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(page_count(25, 10), 3);
+            assert_eq!(page_count(20, 10), 2);
+        })
+    }
+    // (items + page_size) / page_size
+    #[test]
+    fn page_count_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(page_count(25, 10), 3);
+            assert_eq!(page_count(20, 10), 3);
+        })
+    }
+    // (items) / page_size
+    #[test]
+    fn page_count_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(page_count(25, 10), 2);
+            assert_eq!(page_count(20, 10), 2);
+        })
+    }
+}
+
+mod test_chunk_count {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    const CHUNK_SIZE: u32 = 4;
+
+    // the number of chunks needed for the given number of bytes
+    #[allow(clippy::manual_div_ceil)]
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(ceil_div))]
+    fn chunk_count(bytes: u32) -> u32 {
+        (bytes + (CHUNK_SIZE - 1)) / CHUNK_SIZE
+    }
+    #[test]
+    fn chunk_count_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(chunk_count(9), 3);
+        })
+    }
+    // (bytes) / CHUNK_SIZE
+    #[test]
+    fn chunk_count_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(chunk_count(9), 2);
+        })
+    }
+}
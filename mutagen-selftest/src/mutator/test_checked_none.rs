@@ -0,0 +1,69 @@
+mod test_saturating_sum {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sums up values, saturating at the maximum on overflow
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(checked_none))]
+    fn saturating_sum(values: &[u8]) -> u8 {
+        let mut sum = 0u8;
+        for &v in values {
+            sum = match sum.checked_add(v) {
+                Some(s) => s,
+                None => return u8::MAX,
+            };
+        }
+        sum
+    }
+    #[test]
+    fn saturating_sum_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(saturating_sum(&[1, 2, 3]), 6);
+            assert_eq!(saturating_sum(&[200, 100]), 255);
+        })
+    }
+    // replacing `checked_add(v)` with `None`
+    #[test]
+    fn saturating_sum_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(saturating_sum(&[1, 2, 3]), 255);
+        })
+    }
+}
+
+mod test_checked_unwrap_not_mutated {
+
+    use ::mutagen::mutate;
+
+    // checked operations that are unwrapped immediately are mutated by `checked_unwrap`
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(checked_none))]
+    fn double(x: u8) -> u8 {
+        x.checked_mul(2).unwrap()
+    }
+}
+
+mod test_checked_both {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // halves the sum of two values, the division is mutated by `checked_unwrap` only
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(checked_none, checked_unwrap))]
+    fn half_sum(x: u8, y: u8) -> Option<u8> {
+        Some(x.checked_add(y)?.checked_div(2).unwrap())
+    }
+    #[test]
+    fn half_sum_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(half_sum(2, 4), Some(3));
+        })
+    }
+    // replacing `checked_add(y)` with `None`
+    #[test]
+    fn half_sum_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(half_sum(2, 4), None);
+        })
+    }
+}
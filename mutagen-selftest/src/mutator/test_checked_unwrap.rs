@@ -0,0 +1,80 @@
+mod test_add_one_u8 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // increment a number, panics on overflow
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(checked_unwrap))]
+    fn add_one_u8(x: u8) -> u8 {
+        x.checked_add(1).unwrap()
+    }
+    #[test]
+    fn add_one_u8_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(add_one_u8(100), 101);
+        })
+    }
+    #[test]
+    #[should_panic]
+    fn add_one_u8_inactive_max() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            add_one_u8(u8::MAX);
+        })
+    }
+    // x + 1
+    #[test]
+    fn add_one_u8_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(add_one_u8(100), 101);
+        })
+    }
+    // x + 1 panics if overflow-checks are enabled (default for debug builds) and wraps otherwise
+    #[test]
+    fn add_one_u8_active1_max() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let result = std::panic::catch_unwind(|| add_one_u8(u8::MAX));
+            if cfg!(debug_assertions) {
+                assert!(result.is_err());
+            } else {
+                assert_eq!(result.ok(), Some(0));
+            }
+        })
+    }
+}
+
+mod test_square_u8 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // square a number, panics on overflow
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(checked_unwrap))]
+    fn square_u8(x: u8) -> u8 {
+        x.checked_pow(2).unwrap()
+    }
+    #[test]
+    fn square_u8_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(square_u8(15), 225);
+        })
+    }
+    // x.pow(2)
+    #[test]
+    fn square_u8_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(square_u8(15), 225);
+        })
+    }
+}
+
+mod test_checked_with_default {
+
+    use ::mutagen::mutate;
+
+    // checked operations with a fallback are not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(checked_unwrap))]
+    #[allow(dead_code)]
+    fn checked_with_default(x: u8) -> u8 {
+        x.checked_add(1).unwrap_or(0)
+    }
+}
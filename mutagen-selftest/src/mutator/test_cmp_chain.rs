@@ -0,0 +1,81 @@
+mod test_in_range {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // range check with inclusive bounds
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(cmp_chain))]
+    fn in_range(lo: i32, x: i32, hi: i32) -> bool {
+        lo <= x && x <= hi
+    }
+    #[test]
+    fn in_range_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(!in_range(1, 0, 3));
+            assert!(in_range(1, 1, 3));
+            assert!(in_range(1, 3, 3));
+            assert!(!in_range(1, 4, 3));
+        })
+    }
+    // x <= hi
+    #[test]
+    fn in_range_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(in_range(1, 0, 3));
+            assert!(in_range(1, 1, 3));
+            assert!(in_range(1, 3, 3));
+            assert!(!in_range(1, 4, 3));
+        })
+    }
+    // lo <= x
+    #[test]
+    fn in_range_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(!in_range(1, 0, 3));
+            assert!(in_range(1, 1, 3));
+            assert!(in_range(1, 3, 3));
+            assert!(in_range(1, 4, 3));
+        })
+    }
+    // lo <= x || x <= hi
+    #[test]
+    fn in_range_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert!(in_range(1, 0, 3));
+            assert!(in_range(1, 4, 3));
+            assert!(!in_range(3, 2, 1));
+        })
+    }
+}
+
+mod test_unrelated_comparisons {
+
+    use ::mutagen::mutate;
+
+    // the comparisons do not share an operand
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(cmp_chain))]
+    #[allow(dead_code)]
+    fn both_positive(a: i32, b: i32) -> bool {
+        0 < a && 0 < b
+    }
+}
+
+mod test_chain_with_bool {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `binop_bool` does not mutate the `&&` of a chain
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(cmp_chain, binop_bool))]
+    fn in_range_excl(lo: i32, x: i32, hi: i32) -> bool {
+        lo < x && x < hi
+    }
+    #[test]
+    fn in_range_excl_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(!in_range_excl(1, 1, 3));
+            assert!(in_range_excl(1, 2, 3));
+            assert!(!in_range_excl(1, 3, 3));
+        })
+    }
+}
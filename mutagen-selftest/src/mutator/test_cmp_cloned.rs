@@ -0,0 +1,93 @@
+mod test_first_is {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `Option<&u32>` cannot be compared to `Option<u32>`, the mutation is skipped
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(cmp_cloned))]
+    fn first_is(v: &[u32], x: u32) -> bool {
+        v.first().cloned() == Some(x)
+    }
+    #[test]
+    fn first_is_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(first_is(&[1, 2], 1));
+            assert!(!first_is(&[1, 2], 2));
+        })
+    }
+    #[test]
+    fn first_is_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(first_is(&[1, 2], 1));
+            assert!(!first_is(&[1, 2], 2));
+        })
+    }
+}
+
+mod test_compare_copied {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // a reference to a value
+    #[derive(Clone, Copy)]
+    struct Ref<'a>(&'a u32);
+
+    impl<'a> Ref<'a> {
+        fn copied(self) -> u32 {
+            *self.0
+        }
+    }
+
+    impl PartialEq<u32> for Ref<'_> {
+        fn eq(&self, other: &u32) -> bool {
+            self.0 == other
+        }
+    }
+
+    // the uncopied reference can be compared, the removal is behavior-preserving
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(cmp_cloned))]
+    fn is_answer(x: Ref) -> bool {
+        42 == x.copied()
+    }
+    #[test]
+    fn is_answer_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(is_answer(Ref(&42)));
+            assert!(!is_answer(Ref(&1)));
+        })
+    }
+    #[test]
+    fn is_answer_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(is_answer(Ref(&42)));
+            assert!(!is_answer(Ref(&1)));
+        })
+    }
+}
+
+mod test_cloned_ordering {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // ordering comparisons are supported as well
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(cmp_cloned))]
+    fn max_below(v: &[i32], limit: i32) -> bool {
+        v.iter().max().copied() < Some(limit)
+    }
+    #[test]
+    fn max_below_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(max_below(&[1, 3], 4));
+            assert!(!max_below(&[1, 3], 3));
+        })
+    }
+    #[test]
+    fn max_below_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(max_below(&[1, 3], 4));
+            assert!(!max_below(&[1, 3], 3));
+        })
+    }
+}
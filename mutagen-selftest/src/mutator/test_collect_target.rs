@@ -0,0 +1,81 @@
+mod test_collect_words {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // split a sentence into words, keeping duplicates
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(collect_target))]
+    fn words(s: &str) -> Vec<&str> {
+        s.split_whitespace().collect::<Vec<_>>()
+    }
+    #[test]
+    fn words_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(words("b a b"), vec!["b", "a", "b"]);
+        })
+    }
+    // collect::<HashSet<_>>()
+    #[test]
+    fn words_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(words("b a b").len(), 2);
+        })
+    }
+    // collect::<BTreeSet<_>>()
+    #[test]
+    fn words_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(words("b a b"), vec!["a", "b"]);
+        })
+    }
+}
+
+mod test_collect_inferred_elements {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the element type of the target is required to infer the conversion
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(collect_target))]
+    fn widen(v: &[u8]) -> usize {
+        v.iter().map(|&x| x.into()).collect::<Vec<u32>>().len()
+    }
+    #[test]
+    fn widen_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(widen(&[1, 1]), 2);
+        })
+    }
+    // collect::<HashSet<_>>()
+    #[test]
+    fn widen_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(widen(&[1, 1]), 1);
+        })
+    }
+}
+
+mod test_collect_unhashable {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(collect_target))]
+    fn halves(v: &[f64]) -> Vec<f64> {
+        v.iter().map(|x| x / 2.0).collect::<Vec<_>>()
+    }
+    #[test]
+    fn halves_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(halves(&[1.0, 1.0]), vec![0.5, 0.5]);
+        })
+    }
+    // floats cannot be collected into a `HashSet`
+    #[test]
+    #[should_panic]
+    fn halves_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            halves(&[1.0, 1.0]);
+        })
+    }
+}
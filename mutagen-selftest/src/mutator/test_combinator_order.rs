@@ -0,0 +1,85 @@
+mod test_filter_then_double {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // remove the last element and double it if it is small
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(combinator_order))]
+    fn double_last_if_small(mut v: Vec<u32>) -> Option<u32> {
+        v.pop().filter(|x| *x < 10).map(|x| x * 2)
+    }
+    #[test]
+    fn double_last_if_small_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(double_last_if_small(vec![6]), Some(12));
+        })
+    }
+    // map(|x| x * 2).filter(|x| *x < 10)
+    #[test]
+    fn double_last_if_small_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(double_last_if_small(vec![6]), None);
+        })
+    }
+}
+
+mod test_double_then_filter {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // double a number and keep it if the result is small
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(combinator_order))]
+    fn double_if_result_small(x: u32) -> Option<u32> {
+        Some(x).map(|x| x * 2).filter(|x| *x < 10)
+    }
+    #[test]
+    fn double_if_result_small_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(double_if_result_small(6), None);
+        })
+    }
+    // filter(|x| *x < 10).map(|x| x * 2)
+    #[test]
+    fn double_if_result_small_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(double_if_result_small(6), Some(12));
+        })
+    }
+}
+
+mod test_map_changes_type {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(combinator_order))]
+    fn first_positive_as_string(v: &[i32]) -> Option<String> {
+        v.first().filter(|x| **x > 0).map(|x| x.to_string())
+    }
+    #[test]
+    fn first_positive_as_string_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(first_positive_as_string(&[1]), Some("1".to_owned()));
+        })
+    }
+    // the types of the values before and after `map` differ
+    #[test]
+    #[should_panic]
+    fn first_positive_as_string_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            first_positive_as_string(&[1]);
+        })
+    }
+}
+
+mod test_iterator_not_mutated {
+
+    use ::mutagen::mutate;
+
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(combinator_order))]
+    fn doubled_small(v: &[u32]) -> Vec<u32> {
+        v.iter().filter(|x| **x < 10).map(|x| x * 2).collect()
+    }
+}
@@ -0,0 +1,53 @@
+// the mutations of `const_init` are selected when compiling the tests.
+// The tests in this module fail when the crate is compiled with an active mutation, e.g. with `MUTATION_ID=1 cargo test`.
+
+mod test_buffer_size {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // fill a buffer of constant size
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(const_init))]
+    fn buffer() -> Vec<u8> {
+        const BUFFER_SIZE: usize = 4;
+        [0xFF; BUFFER_SIZE].to_vec()
+    }
+    #[test]
+    fn buffer_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(buffer().len(), 4);
+        })
+    }
+}
+
+mod test_static_limit {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // check if a number is below a limit
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(const_init))]
+    fn below_limit(x: u8) -> bool {
+        static LIMIT: u8 = 1;
+        x < LIMIT
+    }
+    #[test]
+    fn below_limit_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(below_limit(0));
+            assert!(!below_limit(1));
+        })
+    }
+}
+
+mod test_const_not_literal {
+
+    use ::mutagen::mutate;
+
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(const_init))]
+    fn not_literal() -> usize {
+        const SIZE: usize = std::mem::size_of::<u64>();
+        SIZE
+    }
+}
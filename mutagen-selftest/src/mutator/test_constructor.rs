@@ -0,0 +1,135 @@
+mod test_count_words {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::HashMap;
+
+    // count the occurrences of each word
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(constructor))]
+    fn count_words(text: &str) -> HashMap<&str, usize> {
+        let mut counts = HashMap::new();
+        for word in text.split_whitespace() {
+            *counts.entry(word).or_insert(0) += 1;
+        }
+        counts
+    }
+    #[test]
+    fn count_words_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_words("a b a")["a"], 2);
+        })
+    }
+    // HashMap::default(), which is an equivalent mutant
+    #[test]
+    fn count_words_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_words("a b a")["a"], 2);
+        })
+    }
+}
+
+mod test_empty_default {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::HashMap;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(constructor))]
+    fn empty_default() -> HashMap<u8, u8> {
+        HashMap::default()
+    }
+    #[test]
+    fn empty_default_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(empty_default().is_empty());
+        })
+    }
+    // HashMap::new(), which is an equivalent mutant
+    #[test]
+    fn empty_default_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(empty_default().is_empty());
+        })
+    }
+}
+
+mod test_buffer {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // a buffer with reserved capacity
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(constructor))]
+    fn buffer(n: usize) -> Vec<u8> {
+        Vec::with_capacity(n)
+    }
+    #[test]
+    fn buffer_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(buffer(16).capacity() >= 16);
+        })
+    }
+    // Vec::new()
+    #[test]
+    fn buffer_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(buffer(16).capacity(), 0);
+        })
+    }
+}
+
+mod test_join_words {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // a string builder that reserves the length of the result in advance
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(constructor))]
+    fn join_words(words: &[&str]) -> String {
+        let len = words.iter().map(|w| w.len() + 1).sum();
+        let mut s = String::with_capacity(len);
+        for w in words {
+            s.push_str(w);
+            s.push(' ');
+        }
+        s
+    }
+    #[test]
+    fn join_words_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(join_words(&["a", "bc"]), "a bc ");
+        })
+    }
+    // String::new(), i.e. the capacity 0, which is an equivalent mutant
+    #[test]
+    fn join_words_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(join_words(&["a", "bc"]), "a bc ");
+        })
+    }
+}
+
+mod test_format_pair {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `format!` preallocates the string, the content does not depend on the capacity
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(constructor))]
+    fn format_pair(a: u32, b: &str) -> String {
+        format!("{}={}", a, b)
+    }
+    #[test]
+    fn format_pair_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(format_pair(1, "x"), "1=x");
+        })
+    }
+    // the capacity 0, which is an equivalent mutant
+    #[test]
+    fn format_pair_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(format_pair(1, "x"), "1=x");
+        })
+    }
+}
@@ -0,0 +1,77 @@
+mod test_sum_copied {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sums the values of a slice, the mutant with `cloned` is equivalent
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(copied_cloned))]
+    fn sum_copied(values: &[i32]) -> i32 {
+        values.iter().copied().sum()
+    }
+    #[test]
+    fn sum_copied_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(sum_copied(&[1, 2, 3]), 6);
+        })
+    }
+    // cloned()
+    #[test]
+    fn sum_copied_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(sum_copied(&[1, 2, 3]), 6);
+        })
+    }
+}
+
+mod test_max_cloned {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the largest value of a slice, the mutant with `copied` is equivalent
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(copied_cloned))]
+    fn max_cloned(values: &[i32]) -> Option<i32> {
+        values.iter().max().cloned()
+    }
+    #[test]
+    fn max_cloned_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(max_cloned(&[4, -2, 7]), Some(7));
+            assert_eq!(max_cloned(&[]), None);
+        })
+    }
+    // copied()
+    #[test]
+    fn max_cloned_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(max_cloned(&[4, -2, 7]), Some(7));
+            assert_eq!(max_cloned(&[]), None);
+        })
+    }
+}
+
+mod test_names_cloned {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // clones the names, which cannot be copied
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(copied_cloned))]
+    fn names_cloned(names: &[String]) -> Vec<String> {
+        names.iter().cloned().collect()
+    }
+    #[test]
+    fn names_cloned_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(names_cloned(&["a".to_owned()]), vec!["a".to_owned()]);
+        })
+    }
+    // copied()
+    #[test]
+    #[should_panic]
+    fn names_cloned_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            names_cloned(&["a".to_owned()]);
+        })
+    }
+}
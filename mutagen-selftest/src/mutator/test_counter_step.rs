@@ -0,0 +1,93 @@
+mod test_count_even {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // count the even numbers in a slice
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(counter_step))]
+    fn count_even(v: &[u32]) -> i32 {
+        let mut count = 0;
+        for x in v {
+            if x % 2 == 0 {
+                count += 1;
+            }
+        }
+        count
+    }
+    #[test]
+    fn count_even_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_even(&[1, 2, 4]), 2);
+        })
+    }
+    // count += 2
+    #[test]
+    fn count_even_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_even(&[1, 2, 4]), 4);
+        })
+    }
+    // count += 0
+    #[test]
+    fn count_even_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(count_even(&[1, 2, 4]), 0);
+        })
+    }
+    // count -= 1
+    #[test]
+    fn count_even_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(count_even(&[1, 2, 4]), -2);
+        })
+    }
+}
+
+mod test_countdown {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // count the iterations of a loop that counts down to zero
+    #[mutate(conf = local(expected_mutations = 6), mutators = only(counter_step))]
+    fn countdown(n: u8) -> u8 {
+        let mut remaining = n;
+        let mut iterations = 0;
+        while remaining > 0 {
+            remaining -= 1u8;
+            iterations += 1;
+        }
+        iterations
+    }
+    #[test]
+    fn countdown_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(countdown(3), 3);
+        })
+    }
+    // remaining -= 2u8
+    #[test]
+    fn countdown_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(countdown(4), 2);
+        })
+    }
+    // iterations += 2
+    #[test]
+    fn countdown_active4() {
+        MutagenRuntimeConfig::test_with_mutation_id(4, || {
+            assert_eq!(countdown(3), 6);
+        })
+    }
+}
+
+mod test_outside_of_loop {
+
+    use ::mutagen::mutate;
+
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(counter_step))]
+    fn increment(x: &mut u32) {
+        *x += 1;
+    }
+}
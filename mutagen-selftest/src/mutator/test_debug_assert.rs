@@ -0,0 +1,75 @@
+// the mutations of `debug_assert` only change the behavior of builds without debug assertions.
+// Some tests in this module are only compiled for such builds, e.g. with `cargo test --release`.
+
+mod test_percentage {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the percentage is expected to be at most 100
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(debug_assert))]
+    fn remaining(percentage: u8) -> u8 {
+        debug_assert!(percentage <= 100);
+        100u8.wrapping_sub(percentage)
+    }
+    #[test]
+    fn remaining_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(remaining(40), 60);
+        })
+    }
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn remaining_too_large_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(remaining(101), 255);
+        })
+    }
+    // replacing `debug_assert!` with `assert!`
+    #[test]
+    #[should_panic]
+    fn remaining_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            remaining(101);
+        })
+    }
+}
+
+mod test_checked_index {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the index is checked before the unchecked access
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(debug_assert))]
+    fn last_digit(digits: &[u8], len: usize) -> u8 {
+        assert_ne!(len, 0);
+        digits[len.wrapping_sub(1) % digits.len()]
+    }
+    #[test]
+    #[should_panic]
+    fn last_digit_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            last_digit(&[1, 2, 3], 0);
+        })
+    }
+    // replacing `assert_ne!` with `debug_assert_ne!`
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn last_digit_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(last_digit(&[1, 2, 3], 0), 1);
+        })
+    }
+}
+
+mod test_other_macro {
+
+    use ::mutagen::mutate;
+
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(debug_assert))]
+    fn print(x: u8) {
+        println!("{}", x);
+    }
+}
@@ -0,0 +1,56 @@
+mod test_unique_sorted {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sorts the values and removes duplicates
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(dedup))]
+    fn unique_sorted(v: &[u32]) -> Vec<u32> {
+        let mut result = v.to_vec();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+    #[test]
+    fn unique_sorted_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(unique_sorted(&[3, 1, 3, 2, 1]), vec![1, 2, 3]);
+        })
+    }
+    // skipping `result.dedup();`
+    #[test]
+    fn unique_sorted_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(unique_sorted(&[3, 1, 3, 2, 1]).len(), 5);
+        })
+    }
+}
+
+mod test_dedup_by_key {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // removes consecutive words with the same first letter
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(dedup))]
+    fn first_letters(words: &mut Vec<&str>) {
+        words.dedup_by_key(|w| w.chars().next());
+    }
+    #[test]
+    fn first_letters_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut words = vec!["apple", "avocado", "banana"];
+            first_letters(&mut words);
+            assert_eq!(words, vec!["apple", "banana"]);
+        })
+    }
+    // skipping `words.dedup_by_key(|w| w.chars().next());`
+    #[test]
+    fn first_letters_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut words = vec!["apple", "avocado", "banana"];
+            first_letters(&mut words);
+            assert_eq!(words.len(), 3);
+        })
+    }
+}
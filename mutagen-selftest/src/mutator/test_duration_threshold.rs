@@ -0,0 +1,79 @@
+mod test_is_expired {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::time::Duration;
+
+    // the elapsed time is passed in, such that the tests control it
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(duration_threshold))]
+    fn is_expired(elapsed: Duration) -> bool {
+        elapsed > Duration::from_secs(5)
+    }
+    #[test]
+    fn is_expired_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(is_expired(Duration::from_secs(6)));
+            assert!(!is_expired(Duration::from_secs(3)));
+        })
+    }
+    // elapsed > (Duration::from_secs(5)) * 2
+    #[test]
+    fn is_expired_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!is_expired(Duration::from_secs(6)));
+        })
+    }
+    // elapsed > (Duration::from_secs(5)) / 2
+    #[test]
+    fn is_expired_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(is_expired(Duration::from_secs(3)));
+        })
+    }
+    // elapsed > Duration::from_secs(0)
+    #[test]
+    fn is_expired_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert!(is_expired(Duration::from_millis(1)));
+        })
+    }
+}
+
+mod test_within_budget {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::time::Duration;
+
+    // the threshold is on the left side of the comparison
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(duration_threshold))]
+    fn within_budget(elapsed: Duration) -> bool {
+        Duration::from_millis(100) >= elapsed
+    }
+    #[test]
+    fn within_budget_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(within_budget(Duration::from_millis(80)));
+            assert!(!within_budget(Duration::from_millis(150)));
+        })
+    }
+    // (Duration::from_millis(100)) * 2 >= elapsed
+    #[test]
+    fn within_budget_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(within_budget(Duration::from_millis(150)));
+        })
+    }
+}
+
+mod test_other_comparison {
+
+    use ::mutagen::mutate;
+    use std::time::Duration;
+
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(duration_threshold))]
+    fn is_late(elapsed: Duration, timeout: Duration) -> bool {
+        elapsed > timeout
+    }
+}
@@ -0,0 +1,78 @@
+mod test_parse_percent {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // parses a percentage, which is at most 100
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(early_return))]
+    fn parse_percent(s: &str) -> Result<u8, String> {
+        let n = s.parse::<u32>().map_err(|e| e.to_string())?;
+        if n > 100 {
+            return Err(format!("{} is larger than 100", n));
+        }
+        Ok(n as u8)
+    }
+    #[test]
+    fn parse_percent_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(parse_percent("42"), Ok(42));
+            assert!(parse_percent("300").is_err());
+        })
+    }
+    // removing the check `n > 100`, such that `300` is truncated to `44`
+    #[test]
+    fn parse_percent_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(parse_percent("300"), Ok(44));
+        })
+    }
+}
+
+mod test_average {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the average of the values, if there are any
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(early_return))]
+    fn average(v: &[u32]) -> Option<u32> {
+        if v.is_empty() {
+            return None;
+        }
+        Some(v.iter().sum::<u32>() / v.len() as u32)
+    }
+    #[test]
+    fn average_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(average(&[1, 3]), Some(2));
+            assert_eq!(average(&[]), None);
+        })
+    }
+    // removing the check `v.is_empty()`, such that the sum is divided by zero
+    #[test]
+    #[should_panic]
+    fn average_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            average(&[]);
+        })
+    }
+}
+
+mod test_unchanged_guards {
+
+    use ::mutagen::mutate;
+
+    // returning a value and guards with an `else` branch are not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(early_return))]
+    #[allow(dead_code, clippy::needless_return)]
+    fn clamp(x: u32, max: u32) -> Result<u32, String> {
+        if x > max {
+            return Ok(max);
+        }
+        if x == 0 {
+            return Err("zero".to_owned());
+        } else {
+            return Ok(x);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+mod test_concat {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // concatenates two slices
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(extend))]
+    fn concat(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut result = a.to_vec();
+        result.extend(b.iter().copied());
+        result
+    }
+    #[test]
+    fn concat_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(concat(&[1, 2], &[3]), vec![1, 2, 3]);
+        })
+    }
+    // b.iter().copied();
+    #[test]
+    fn concat_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(concat(&[1, 2], &[3]), vec![1, 2]);
+        })
+    }
+}
+
+mod test_append {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // moves all elements of the second vector to the first one
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(extend))]
+    fn move_all(a: &mut Vec<u32>, b: &mut Vec<u32>) {
+        a.append(b);
+    }
+    #[test]
+    fn move_all_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut a = vec![1];
+            let mut b = vec![2, 3];
+            move_all(&mut a, &mut b);
+            assert_eq!(a.len(), 3);
+            assert!(b.is_empty());
+        })
+    }
+    // b;
+    #[test]
+    fn move_all_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut a = vec![1];
+            let mut b = vec![2, 3];
+            move_all(&mut a, &mut b);
+            assert_eq!(a, vec![1]);
+            assert_eq!(b, vec![2, 3]);
+        })
+    }
+}
@@ -0,0 +1,137 @@
+mod test_has_negative {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(find))]
+    fn has_negative(v: &[i32]) -> bool {
+        v.iter().any(|x| *x < 0)
+    }
+    #[test]
+    fn has_negative_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(!has_negative(&[1, 2]));
+            assert!(has_negative(&[1, -2]));
+        })
+    }
+    // any(|x| !(*x < 0))
+    #[test]
+    fn has_negative_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(has_negative(&[1, 2]));
+            assert!(!has_negative(&[-1, -2]));
+        })
+    }
+}
+
+mod test_first_even {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(find))]
+    fn first_even(v: &[u32]) -> Option<u32> {
+        v.iter().copied().find(|x| x & 1 == 0)
+    }
+    #[test]
+    fn first_even_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(first_even(&[1, 4, 6]), Some(4));
+        })
+    }
+    // find(|x| !(x & 1 == 0))
+    #[test]
+    fn first_even_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(first_even(&[1, 4, 6]), Some(1));
+        })
+    }
+    // find(|x| true)
+    #[test]
+    fn first_even_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(first_even(&[1, 4, 6]), Some(1));
+        })
+    }
+    // find(|x| false)
+    #[test]
+    fn first_even_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(first_even(&[1, 4, 6]), None);
+        })
+    }
+}
+
+mod test_position_of_three {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(find))]
+    fn position_of_three(v: &[u32]) -> Option<usize> {
+        v.iter().position(|&x| x == 3)
+    }
+    #[test]
+    fn position_of_three_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(position_of_three(&[1, 2, 3]), Some(2));
+        })
+    }
+    // position(|&x| !(x == 3))
+    #[test]
+    fn position_of_three_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(position_of_three(&[3, 2, 3]), Some(1));
+        })
+    }
+    // position(|&x| true)
+    #[test]
+    fn position_of_three_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(position_of_three(&[1, 2, 3]), Some(0));
+        })
+    }
+    // position(|&x| false)
+    #[test]
+    fn position_of_three_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(position_of_three(&[1, 2, 3]), None);
+        })
+    }
+}
+
+mod test_first_number {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(find))]
+    fn first_number(words: &[&str]) -> Option<u32> {
+        words.iter().find_map(|w| w.parse().ok())
+    }
+    #[test]
+    fn first_number_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(first_number(&["a", "12", "3"]), Some(12));
+        })
+    }
+    // find_map(|w| None)
+    #[test]
+    fn first_number_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(first_number(&["a", "12", "3"]), None);
+        })
+    }
+}
+
+mod test_str_find {
+
+    use ::mutagen::mutate;
+
+    // searching for a pattern is not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(find))]
+    #[allow(dead_code)]
+    fn find_colon(s: &str) -> Option<usize> {
+        s.find(':')
+    }
+}
@@ -0,0 +1,64 @@
+mod test_first_word {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // returns the first of the words, if there is any
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(first))]
+    fn first_word<'a>(words: &[&'a str]) -> Option<&'a str> {
+        words.first().copied()
+    }
+    #[test]
+    fn first_word_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(first_word(&["a", "b"]), Some("a"));
+            assert_eq!(first_word(&[]), None);
+        })
+    }
+    // get(0 + 1)
+    #[test]
+    fn first_word_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(first_word(&["a", "b"]), Some("b"));
+            assert_eq!(first_word(&["a"]), None);
+        })
+    }
+    // get(0 - 1)
+    #[test]
+    fn first_word_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(first_word(&["a", "b"]), None);
+        })
+    }
+}
+
+mod test_first_mut {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // increments the first element of the slice
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(first))]
+    fn increment_first(v: &mut [u32]) {
+        if let Some(x) = v.first_mut() {
+            *x += 1;
+        }
+    }
+    #[test]
+    fn increment_first_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut v = vec![1, 2];
+            increment_first(&mut v);
+            assert_eq!(v, vec![2, 2]);
+        })
+    }
+    // get_mut(0 + 1)
+    #[test]
+    fn increment_first_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut v = vec![1, 2];
+            increment_first(&mut v);
+            assert_eq!(v, vec![1, 3]);
+        })
+    }
+}
@@ -0,0 +1,103 @@
+mod test_lowest_score {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // returns the lowest of the sorted scores, if there is any
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(first_last))]
+    fn lowest_score(scores: &[u32]) -> Option<u32> {
+        scores.first().copied()
+    }
+    #[test]
+    fn lowest_score_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(lowest_score(&[1, 5, 9]), Some(1));
+            assert_eq!(lowest_score(&[]), None);
+        })
+    }
+    // last()
+    #[test]
+    fn lowest_score_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(lowest_score(&[1, 5, 9]), Some(9));
+        })
+    }
+}
+
+mod test_last_mut {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // increments the last element of the vector
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(first_last))]
+    fn increment_last(v: &mut [u32]) {
+        if let Some(x) = v.last_mut() {
+            *x += 1;
+        }
+    }
+    #[test]
+    fn increment_last_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut v = vec![1, 2];
+            increment_last(&mut v);
+            assert_eq!(v, vec![1, 3]);
+        })
+    }
+    // first_mut()
+    #[test]
+    fn increment_last_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut v = vec![1, 2];
+            increment_last(&mut v);
+            assert_eq!(v, vec![2, 2]);
+        })
+    }
+}
+
+mod test_next_job {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::VecDeque;
+
+    // returns the job that has been queued first
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(first_last))]
+    fn next_job(queue: &VecDeque<&'static str>) -> Option<&'static str> {
+        queue.front().copied()
+    }
+    #[test]
+    fn next_job_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let queue: VecDeque<_> = vec!["build", "test", "deploy"].into_iter().collect();
+            assert_eq!(next_job(&queue), Some("build"));
+        })
+    }
+    // back()
+    #[test]
+    fn next_job_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let queue: VecDeque<_> = vec!["build", "test", "deploy"].into_iter().collect();
+            assert_eq!(next_job(&queue), Some("deploy"));
+        })
+    }
+}
+
+mod test_last_segment {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the last segment of a path, `last()` of an iterator is not mutated
+    #[allow(clippy::double_ended_iterator_last)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(first_last))]
+    fn last_segment(path: &str) -> Option<&str> {
+        path.split('/').last()
+    }
+    #[test]
+    fn last_segment_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(last_segment("a/b/c"), Some("c"));
+        })
+    }
+}
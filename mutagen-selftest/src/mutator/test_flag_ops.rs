@@ -0,0 +1,218 @@
+/// a set of flags with the operations of bitflags
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Flags(u8);
+
+impl Flags {
+    const READ: Flags = Flags(1);
+    const WRITE: Flags = Flags(2);
+    const EXEC: Flags = Flags(4);
+
+    fn bits(&self) -> u8 {
+        self.0
+    }
+    fn from_bits_truncate(bits: u8) -> Flags {
+        Flags(bits & 0b111)
+    }
+
+    fn insert(&mut self, other: Flags) {
+        self.0 |= other.0;
+    }
+    fn remove(&mut self, other: Flags) {
+        self.0 &= !other.0;
+    }
+    fn toggle(&mut self, other: Flags) {
+        self.0 ^= other.0;
+    }
+    fn contains(&self, other: Flags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+mod test_grant_write {
+
+    use super::Flags;
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // adds the permission to write
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(flag_ops))]
+    fn grant_write(flags: &mut Flags) {
+        flags.insert(Flags::WRITE);
+    }
+    #[test]
+    fn grant_write_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut flags = Flags::WRITE;
+            grant_write(&mut flags);
+            assert!(flags.contains(Flags::WRITE));
+        })
+    }
+    // replacing `insert(Flags::WRITE)` with `remove(Flags::WRITE)`
+    #[test]
+    fn grant_write_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut flags = Flags::READ;
+            grant_write(&mut flags);
+            assert_eq!(flags, Flags::READ);
+        })
+    }
+    // replacing `insert(Flags::WRITE)` with `toggle(Flags::WRITE)`
+    #[test]
+    fn grant_write_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            let mut flags = Flags::WRITE;
+            grant_write(&mut flags);
+            assert_eq!(flags, Flags::default());
+        })
+    }
+}
+
+mod test_revoke_write {
+
+    use super::Flags;
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // removes the permission to write
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(flag_ops))]
+    fn revoke_write(flags: &mut Flags) {
+        flags.remove(Flags::WRITE);
+    }
+    #[test]
+    fn revoke_write_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut flags = Flags(3);
+            revoke_write(&mut flags);
+            assert_eq!(flags, Flags::READ);
+        })
+    }
+    // replacing `remove(Flags::WRITE)` with `insert(Flags::WRITE)`
+    #[test]
+    fn revoke_write_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut flags = Flags::READ;
+            revoke_write(&mut flags);
+            assert!(flags.contains(Flags::WRITE));
+        })
+    }
+    // replacing `remove(Flags::WRITE)` with `toggle(Flags::WRITE)`
+    #[test]
+    fn revoke_write_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            let mut flags = Flags::READ;
+            revoke_write(&mut flags);
+            assert_eq!(flags, Flags(3));
+        })
+    }
+}
+
+mod test_can_write {
+
+    use super::Flags;
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // checks the permission to write
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(flag_ops))]
+    fn can_write(flags: Flags) -> bool {
+        flags.contains(Flags::WRITE)
+    }
+    #[test]
+    fn can_write_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(can_write(Flags(3)));
+            assert!(!can_write(Flags::READ));
+        })
+    }
+    // replacing `contains(Flags::WRITE)` with `!contains(Flags::WRITE)`
+    #[test]
+    fn can_write_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!can_write(Flags(3)));
+        })
+    }
+}
+
+mod test_access_mode {
+
+    use super::Flags;
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the access mode is chosen by the permission to write
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(flag_ops))]
+    fn access_mode(flags: Flags) -> &'static str {
+        if flags.contains(Flags::WRITE) {
+            "read-write"
+        } else {
+            "read-only"
+        }
+    }
+    #[test]
+    fn access_mode_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(access_mode(Flags(3)), "read-write");
+            assert_eq!(access_mode(Flags(5)), "read-only");
+        })
+    }
+    // replacing `contains(Flags::WRITE)` with `contains(Flags::WRITE << 1)`, i.e. `Flags::EXEC`
+    #[test]
+    fn access_mode_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(access_mode(Flags(3)), "read-only");
+            assert_eq!(access_mode(Flags::EXEC), "read-write");
+        })
+    }
+    // replacing `contains(Flags::WRITE)` with `contains(Flags::WRITE >> 1)`, i.e. `Flags::READ`
+    #[test]
+    fn access_mode_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(access_mode(Flags(5)), "read-write");
+        })
+    }
+}
+
+mod test_is_visible {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    const DIRTY: u8 = 1;
+    const VISIBLE: u8 = 2;
+    const LOCKED: u8 = 4;
+
+    // the flags are tested with a mask, like `contains`
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(flag_ops))]
+    fn is_visible(state: u8) -> bool {
+        state & VISIBLE != 0
+    }
+    #[test]
+    fn is_visible_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(is_visible(VISIBLE | DIRTY));
+            assert!(!is_visible(LOCKED));
+        })
+    }
+    // replacing `state & VISIBLE != 0` with `!(state & VISIBLE != 0)`
+    #[test]
+    fn is_visible_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!is_visible(VISIBLE));
+        })
+    }
+    // replacing `& VISIBLE` with `& (VISIBLE << 1)`, i.e. `LOCKED`
+    #[test]
+    fn is_visible_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(!is_visible(VISIBLE));
+            assert!(is_visible(LOCKED));
+        })
+    }
+    // replacing `& VISIBLE` with `& (VISIBLE >> 1)`, i.e. `DIRTY`
+    #[test]
+    fn is_visible_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert!(is_visible(DIRTY));
+        })
+    }
+}
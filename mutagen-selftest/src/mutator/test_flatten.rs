@@ -0,0 +1,74 @@
+mod test_concat {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // concatenation of all rows
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(flatten))]
+    fn concat(rows: Vec<Vec<u32>>) -> Vec<u32> {
+        rows.into_iter().flatten().collect()
+    }
+    #[test]
+    fn concat_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(concat(vec![vec![1, 2], vec![3]]), vec![1, 2, 3]);
+        })
+    }
+    // only the first element of each row
+    #[test]
+    fn concat_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(concat(vec![vec![1, 2], vec![3]]), vec![1, 3]);
+        })
+    }
+}
+
+mod test_all_tags {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    struct Post {
+        tags: Vec<u32>,
+    }
+
+    // the tags of all posts
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(flatten))]
+    fn all_tags(posts: &[Post]) -> Vec<u32> {
+        posts.iter().flat_map(|p| p.tags.iter().copied()).collect()
+    }
+
+    fn posts() -> Vec<Post> {
+        vec![
+            Post { tags: vec![1, 2] },
+            Post { tags: vec![] },
+            Post { tags: vec![3] },
+        ]
+    }
+
+    #[test]
+    fn all_tags_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(all_tags(&posts()), vec![1, 2, 3]);
+        })
+    }
+    // only the first tag of each post
+    #[test]
+    fn all_tags_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(all_tags(&posts()), vec![1, 3]);
+        })
+    }
+}
+
+mod test_option_flatten {
+
+    use ::mutagen::mutate;
+
+    // `Option::flatten` is not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(flatten))]
+    #[allow(dead_code)]
+    fn inner(opt: Option<Option<u32>>) -> Option<u32> {
+        opt.flatten()
+    }
+}
@@ -0,0 +1,80 @@
+mod test_approx_eq {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    const TOLERANCE: f64 = 0.01;
+
+    // check if two floats are approximately equal
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(float_eps))]
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < TOLERANCE
+    }
+    #[test]
+    fn approx_eq_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(approx_eq(1.0, 1.005));
+            assert!(!approx_eq(1.0, 1.05));
+        })
+    }
+    // (a - b).abs() < (TOLERANCE) * 10
+    #[test]
+    fn approx_eq_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(approx_eq(1.0, 1.05));
+        })
+    }
+    // (a - b).abs() < (TOLERANCE) / 10
+    #[test]
+    fn approx_eq_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(!approx_eq(1.0, 1.005));
+        })
+    }
+    // !((a - b).abs() < TOLERANCE)
+    #[test]
+    fn approx_eq_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert!(!approx_eq(1.0, 1.005));
+            assert!(approx_eq(1.0, 1.05));
+        })
+    }
+}
+
+mod test_tolerance_left {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // check if a float is approximately one, with the tolerance on the left
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(float_eps))]
+    fn approx_one(x: f32) -> bool {
+        0.1 >= (x - 1.0).abs()
+    }
+    #[test]
+    fn approx_one_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(approx_one(1.05));
+            assert!(!approx_one(1.5));
+        })
+    }
+    // (0.1) / 10 >= (x - 1.0).abs()
+    #[test]
+    fn approx_one_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(!approx_one(1.05));
+        })
+    }
+}
+
+mod test_not_approx {
+
+    use ::mutagen::mutate;
+
+    // other comparisons are not mutated
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(float_eps))]
+    fn is_small(x: f64) -> bool {
+        x.abs() < 0.1
+    }
+}
@@ -0,0 +1,87 @@
+mod test_get_last {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // get the element at the given position, if it exists
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(get_index))]
+    fn element_at(v: &[u32], i: usize) -> Option<u32> {
+        v.get(i).copied()
+    }
+    #[test]
+    fn element_at_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(element_at(&[1, 2, 3], 2), Some(3));
+            assert_eq!(element_at(&[1, 2, 3], 3), None);
+        })
+    }
+    // get(i + 1)
+    #[test]
+    fn element_at_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(element_at(&[1, 2, 3], 2), None);
+            assert_eq!(element_at(&[1, 2, 3], 0), Some(2));
+        })
+    }
+    // get(i - 1)
+    #[test]
+    fn element_at_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(element_at(&[1, 2, 3], 3), Some(3));
+            assert_eq!(element_at(&[1, 2, 3], 0), None);
+        })
+    }
+}
+
+mod test_get_mut {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // increment the first element of a vector
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(get_index))]
+    fn increment_first(v: &mut [u32]) {
+        if let Some(x) = v.get_mut(0) {
+            *x += 1;
+        }
+    }
+    #[test]
+    fn increment_first_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut v = vec![1, 2];
+            increment_first(&mut v);
+            assert_eq!(v, vec![2, 2]);
+        })
+    }
+    // get_mut(0 + 1)
+    #[test]
+    fn increment_first_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut v = vec![1, 2];
+            increment_first(&mut v);
+            assert_eq!(v, vec![1, 3]);
+        })
+    }
+    // get_mut(0 - 1)
+    #[test]
+    fn increment_first_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            let mut v = vec![1, 2];
+            increment_first(&mut v);
+            assert_eq!(v, vec![1, 2]);
+        })
+    }
+}
+
+mod test_map_get {
+
+    use ::mutagen::mutate;
+    use std::collections::HashMap;
+
+    // keys of maps are passed by reference and are not mutated
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(get_index))]
+    fn lookup(map: &HashMap<u32, u32>, key: u32) -> Option<&u32> {
+        map.get(&key)
+    }
+}
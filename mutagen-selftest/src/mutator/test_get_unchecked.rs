@@ -0,0 +1,44 @@
+mod test_first {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // get the first element of a non-empty slice
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(get_unchecked))]
+    fn first(v: &[u32]) -> u32 {
+        assert!(!v.is_empty());
+        unsafe { *v.get_unchecked(0) }
+    }
+    #[test]
+    fn first_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(first(&[1, 2]), 1);
+        })
+    }
+    // get(0).unwrap()
+    #[test]
+    fn first_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(first(&[1, 2]), 1);
+        })
+    }
+    // get_unchecked(0 + 1)
+    #[test]
+    fn first_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(first(&[1, 2]), 2);
+        })
+    }
+}
+
+mod test_outside_unsafe {
+
+    use ::mutagen::mutate;
+
+    // code outside of `unsafe` blocks is not mutated by `get_unchecked`
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(get_unchecked))]
+    fn second(v: &[u32]) -> Option<&u32> {
+        v.get(1)
+    }
+}
@@ -0,0 +1,109 @@
+mod test_iteration_order {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher, Hasher};
+
+    /// a hash state with a fixed seed, such that the iteration order is deterministic
+    struct SeededState(u64);
+
+    impl SeededState {
+        fn with_seed(seed: u64) -> Self {
+            SeededState(seed)
+        }
+    }
+
+    impl BuildHasher for SeededState {
+        type Hasher = SeededHasher;
+        fn build_hasher(&self) -> SeededHasher {
+            SeededHasher(self.0)
+        }
+    }
+
+    struct SeededHasher(u64);
+
+    impl Hasher for SeededHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+        fn write(&mut self, bytes: &[u8]) {
+            for b in bytes {
+                self.0 = (self.0 ^ u64::from(*b)).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            }
+        }
+    }
+
+    fn order_with_seed(seed: u64, keys: &[u32]) -> Vec<u32> {
+        let mut set = HashSet::with_hasher(SeededState::with_seed(seed));
+        set.extend(keys.iter().copied());
+        set.into_iter().collect()
+    }
+
+    // collects the keys in the iteration order of a hash set
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(hash_seed))]
+    fn iteration_order(keys: &[u32]) -> Vec<u32> {
+        let mut set = HashSet::with_hasher(SeededState::with_seed(7));
+        set.extend(keys.iter().copied());
+        set.into_iter().collect()
+    }
+    #[test]
+    fn iteration_order_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let keys = (0..16).collect::<Vec<_>>();
+            assert_eq!(iteration_order(&keys), order_with_seed(7, &keys));
+        })
+    }
+    // SeededState::with_seed(7 + 1)
+    #[test]
+    fn iteration_order_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let keys = (0..16).collect::<Vec<_>>();
+            assert_eq!(iteration_order(&keys), order_with_seed(8, &keys));
+            assert_ne!(iteration_order(&keys), order_with_seed(7, &keys));
+        })
+    }
+}
+
+mod test_random_state {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::hash_map::RandomState;
+    use std::collections::HashMap;
+
+    // creates a map with a new random state
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(hash_seed))]
+    fn new_map() -> HashMap<u32, u32> {
+        HashMap::with_hasher(RandomState::new())
+    }
+    #[test]
+    fn new_map_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(new_map().is_empty());
+        })
+    }
+    // { RandomState::new(); RandomState::new() }, which is an equivalent mutant
+    #[test]
+    fn new_map_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(new_map().is_empty());
+        })
+    }
+}
+
+mod test_default_hasher {
+
+    use ::mutagen::mutate;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    // `DefaultHasher::new()` has fixed keys and is not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(hash_seed))]
+    #[allow(dead_code)]
+    fn hash_of(x: u32) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+}
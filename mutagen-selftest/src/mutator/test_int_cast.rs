@@ -0,0 +1,118 @@
+mod test_to_byte {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::convert::TryFrom;
+
+    // converts a value to a byte, if it fits
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(int_cast))]
+    fn to_byte(x: u32) -> Option<u8> {
+        u8::try_from(x).ok()
+    }
+    #[test]
+    fn to_byte_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(to_byte(200), Some(200));
+            assert_eq!(to_byte(300), None);
+        })
+    }
+    // Ok(x as u8)
+    #[test]
+    fn to_byte_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(to_byte(200), Some(200));
+            assert_eq!(to_byte(300), Some(44));
+        })
+    }
+}
+
+mod test_try_into_unwrap {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::convert::TryInto;
+
+    // the number of elements as `u8`, which panics for too many elements
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(int_cast))]
+    fn small_len(v: &[u32]) -> u8 {
+        v.len().try_into().unwrap()
+    }
+    #[test]
+    fn small_len_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(small_len(&[1, 2]), 2);
+        })
+    }
+    #[test]
+    #[should_panic]
+    fn small_len_inactive_panics() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            small_len(&[0; 300]);
+        })
+    }
+    // v.len() as _
+    #[test]
+    fn small_len_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(small_len(&[0; 300]), 44);
+        })
+    }
+}
+
+mod test_truncate {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the lowest byte of the value
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(int_cast))]
+    fn low_byte(x: u32) -> u8 {
+        x as u8
+    }
+    #[test]
+    fn low_byte_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(low_byte(300), 44);
+        })
+    }
+    // u8::try_from(x).unwrap()
+    #[test]
+    fn low_byte_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(low_byte(200), 200);
+        })
+    }
+    #[test]
+    #[should_panic]
+    fn low_byte_active1_truncated() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            low_byte(300);
+        })
+    }
+}
+
+mod test_cast_float {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // floats cannot be converted with `TryFrom`
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(int_cast))]
+    fn round_down(x: f64) -> u32 {
+        x as u32
+    }
+    #[test]
+    fn round_down_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(round_down(2.5), 2);
+        })
+    }
+    // the optimistic assumption fails
+    #[test]
+    #[should_panic]
+    fn round_down_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            round_down(2.5);
+        })
+    }
+}
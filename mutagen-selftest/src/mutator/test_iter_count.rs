@@ -0,0 +1,152 @@
+mod test_take_infinite {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // collect the first two elements of an infinite iterator
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(iter_count))]
+    fn take_infinite() -> Vec<u32> {
+        (1..).take(2).collect()
+    }
+    #[test]
+    fn take_infinite_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(take_infinite(), vec![1, 2]);
+        })
+    }
+    // take(3)
+    #[test]
+    fn take_infinite_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(take_infinite(), vec![1, 2, 3]);
+        })
+    }
+    // take(1)
+    #[test]
+    fn take_infinite_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(take_infinite(), vec![1]);
+        })
+    }
+    // take(0)
+    #[test]
+    fn take_infinite_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(take_infinite(), Vec::<u32>::new());
+        })
+    }
+}
+
+mod test_skip_count {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // count the remaining elements after skipping some
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(iter_count))]
+    fn skip_count(n: usize) -> usize {
+        (0..5).skip(n).count()
+    }
+    #[test]
+    fn skip_count_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(skip_count(2), 3);
+        })
+    }
+    #[test]
+    fn skip_count_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(skip_count(2), 2);
+        })
+    }
+    #[test]
+    fn skip_count_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(skip_count(2), 4);
+        })
+    }
+    #[test]
+    fn skip_count_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(skip_count(2), 5);
+        })
+    }
+}
+
+mod test_step_by {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `step_by(0)` panics, only two mutations are generated
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(iter_count))]
+    fn step_by() -> Vec<u32> {
+        (0..10).step_by(3).collect()
+    }
+    #[test]
+    fn step_by_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(step_by(), vec![0, 3, 6, 9]);
+        })
+    }
+    #[test]
+    fn step_by_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(step_by(), vec![0, 4, 8]);
+        })
+    }
+    #[test]
+    fn step_by_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(step_by(), vec![0, 2, 4, 6, 8]);
+        })
+    }
+}
+
+mod test_literal_counts {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `skip(1)` is not replaced with `0`, since this is the same as decrementing it
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(iter_count))]
+    fn skip_one() -> usize {
+        (0..5).skip(1).count()
+    }
+    #[test]
+    fn skip_one_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(skip_one(), 4);
+        })
+    }
+    #[test]
+    fn skip_one_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(skip_one(), 3);
+        })
+    }
+    #[test]
+    fn skip_one_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(skip_one(), 5);
+        })
+    }
+
+    // `step_by(1)` is only incremented
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(iter_count))]
+    fn step_by_one() -> Vec<u32> {
+        (0..4).step_by(1).collect()
+    }
+    #[test]
+    fn step_by_one_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(step_by_one(), vec![0, 1, 2, 3]);
+        })
+    }
+    #[test]
+    fn step_by_one_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(step_by_one(), vec![0, 2]);
+        })
+    }
+}
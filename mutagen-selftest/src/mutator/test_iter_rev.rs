@@ -0,0 +1,49 @@
+mod test_last_word {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the last word of a sentence
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(iter_rev))]
+    fn last_word(s: &str) -> Option<&str> {
+        s.split(' ').rev().next()
+    }
+    #[test]
+    fn last_word_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(last_word("hello big world"), Some("world"));
+        })
+    }
+    // s.split(' ')
+    #[test]
+    fn last_word_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(last_word("hello big world"), Some("hello"));
+        })
+    }
+}
+
+mod test_digits_to_number {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the number given by its digits, starting with the least significant digit
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(iter_rev))]
+    fn digits_to_number(digits: &[u32]) -> u32 {
+        digits.iter().rev().fold(0, |n, d| n * 10 + d)
+    }
+    #[test]
+    fn digits_to_number_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(digits_to_number(&[3, 2, 1]), 123);
+        })
+    }
+    // digits.iter()
+    #[test]
+    fn digits_to_number_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(digits_to_number(&[3, 2, 1]), 321);
+        })
+    }
+}
@@ -0,0 +1,70 @@
+mod test_with_sentinel {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::iter;
+
+    // appends a sentinel to the values
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(iter_source))]
+    fn with_sentinel(v: &[u32]) -> Vec<u32> {
+        v.iter().copied().chain(iter::once(0)).collect()
+    }
+    #[test]
+    fn with_sentinel_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(with_sentinel(&[1, 2]).len(), 3);
+        })
+    }
+    // std::iter::empty()
+    #[test]
+    fn with_sentinel_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(with_sentinel(&[1, 2]).len(), 2);
+        })
+    }
+}
+
+mod test_padding {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // pads the values with zeros
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(iter_source))]
+    #[allow(clippy::manual_repeat_n)]
+    fn padding(v: &[u32], n: usize) -> Vec<u32> {
+        v.iter()
+            .copied()
+            .chain(std::iter::repeat(0).take(n))
+            .collect()
+    }
+    #[test]
+    fn padding_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(padding(&[1], 3), vec![1, 0, 0, 0]);
+        })
+    }
+    // std::iter::repeat(0).take(1)
+    #[test]
+    fn padding_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(padding(&[1], 3), vec![1, 0]);
+        })
+    }
+}
+
+mod test_unchanged_sources {
+
+    use ::mutagen::mutate;
+    use std::iter;
+
+    // `empty()` and `repeat(x).take(1)` are not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(iter_source))]
+    #[allow(dead_code, clippy::manual_repeat_n)]
+    fn single_or_nothing(x: Option<u32>) -> Vec<u32> {
+        match x {
+            Some(x) => iter::repeat(x).take(1).collect(),
+            None => iter::empty().collect(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+mod test_default_kind {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    fn kind(c: char) -> &'static str {
+        match c {
+            'a' => "first",
+            'b' => "second",
+            '\0' => "none",
+            _ => "other",
+        }
+    }
+
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(lit_char))]
+    fn default_kind() -> &'static str {
+        kind('a')
+    }
+    #[test]
+    fn default_kind_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(default_kind(), "first");
+        })
+    }
+    // 'a' -> 'b'
+    #[test]
+    fn default_kind_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(default_kind(), "second");
+        })
+    }
+    // 'a' -> '`'
+    #[test]
+    fn default_kind_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(default_kind(), "other");
+        })
+    }
+    // 'a' -> '\0'
+    #[test]
+    fn default_kind_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(default_kind(), "none");
+        })
+    }
+}
+
+mod test_null_char {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the null char is only shifted up
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(lit_char))]
+    fn terminator() -> char {
+        '\0'
+    }
+    #[test]
+    fn terminator_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(terminator(), '\0');
+        })
+    }
+    // '\0' -> '\u{1}'
+    #[test]
+    fn terminator_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(terminator(), '\u{1}');
+        })
+    }
+}
@@ -0,0 +1,93 @@
+mod test_coefficient_i32 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // linear function with a coefficient
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(lit_sign))]
+    fn linear(x: i32) -> i32 {
+        3 * x - 1
+    }
+    #[test]
+    fn linear_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(linear(2), 5);
+        })
+    }
+    // -3 * x - 1
+    #[test]
+    fn linear_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(linear(2), -7);
+        })
+    }
+    // 3 * x - -1
+    #[test]
+    fn linear_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(linear(2), 7);
+        })
+    }
+}
+
+mod test_negative_f64 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the inner literal of a negative literal is flipped
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(lit_sign))]
+    fn halve_neg(x: f64) -> f64 {
+        x * -0.5
+    }
+    #[test]
+    fn halve_neg_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(halve_neg(4.0), -2.0);
+        })
+    }
+    // x * 0.5
+    #[test]
+    fn halve_neg_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(halve_neg(4.0), 2.0);
+        })
+    }
+}
+
+mod test_unsigned {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the literal with unsigned suffix is not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(lit_sign))]
+    fn add_five_u32(x: u32) -> u32 {
+        x + 5u32
+    }
+    #[test]
+    fn add_five_u32_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(add_five_u32(1), 6);
+        })
+    }
+
+    // unsuffixed literals of unsigned type cannot be flipped
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(lit_sign))]
+    fn add_five_u8(x: u8) -> u8 {
+        x + 5
+    }
+    #[test]
+    fn add_five_u8_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(add_five_u8(1), 6);
+        })
+    }
+    #[test]
+    #[should_panic]
+    fn add_five_u8_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            add_five_u8(1);
+        })
+    }
+}
@@ -0,0 +1,99 @@
+mod test_while_lt {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // count the iterations of a while-loop
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(loop_bound))]
+    fn count_iterations(n: u32) -> u32 {
+        let mut i = 0;
+        let mut count = 0;
+        while i < n {
+            i += 1;
+            count += 1;
+        }
+        count
+    }
+    #[test]
+    fn count_iterations_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_iterations(3), 3);
+        })
+    }
+    // while i <= n
+    #[test]
+    fn count_iterations_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_iterations(3), 4);
+        })
+    }
+}
+
+mod test_while_and {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // find the index of the first zero, both comparisons are loop conditions
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(loop_bound))]
+    fn first_zero(v: &[u32]) -> usize {
+        let mut i = 0;
+        while i < v.len() && v[i] > 0 {
+            i += 1;
+        }
+        i
+    }
+    #[test]
+    fn first_zero_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(first_zero(&[1, 0, 1]), 1);
+        })
+    }
+    // v[i] >= 0
+    #[test]
+    fn first_zero_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(first_zero(&[1, 0, 1]), 3);
+        })
+    }
+}
+
+mod test_for_range {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sum the numbers below `n`
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(loop_bound))]
+    fn sum_below(n: u32) -> u32 {
+        let mut sum = 0;
+        for i in 0..n {
+            sum += i;
+        }
+        sum
+    }
+    #[test]
+    fn sum_below_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(sum_below(4), 6);
+        })
+    }
+    // for i in 0..=n
+    #[test]
+    fn sum_below_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(sum_below(4), 10);
+        })
+    }
+}
+
+mod test_cmp_outside_loop {
+
+    use ::mutagen::mutate;
+
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(loop_bound))]
+    fn is_small(x: u32) -> bool {
+        x < 10
+    }
+}
@@ -0,0 +1,104 @@
+mod test_or_insert {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::HashMap;
+
+    // count the occurrences of a word that was not in the map before
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(map_entry))]
+    fn count_word(word: &str) -> u32 {
+        let mut counts = HashMap::new();
+        *counts.entry(word).or_insert(0) += 1;
+        counts[word]
+    }
+    #[test]
+    fn count_word_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_word("a"), 1);
+        })
+    }
+    #[test]
+    fn count_word_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_word("a"), 2);
+        })
+    }
+}
+
+mod test_or_insert_with {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::HashMap;
+
+    // read a missing flag, which is initialized lazily
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(map_entry))]
+    fn missing_flag() -> bool {
+        let mut flags: HashMap<u32, bool> = HashMap::new();
+        *flags.entry(1).or_insert_with(|| false)
+    }
+    #[test]
+    fn missing_flag_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(!missing_flag());
+        })
+    }
+    #[test]
+    fn missing_flag_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(missing_flag());
+        })
+    }
+}
+
+mod test_or_default {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::BTreeMap;
+
+    // the default value is only inserted for missing keys
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(map_entry))]
+    fn get_or_default(map: &mut BTreeMap<u32, i64>, key: u32) -> i64 {
+        *map.entry(key).or_default()
+    }
+    #[test]
+    fn get_or_default_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut map = BTreeMap::new();
+            map.insert(1, 5);
+            assert_eq!(get_or_default(&mut map, 1), 5);
+            assert_eq!(get_or_default(&mut map, 2), 0);
+        })
+    }
+    #[test]
+    fn get_or_default_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut map = BTreeMap::new();
+            map.insert(1, 5);
+            assert_eq!(get_or_default(&mut map, 1), 5);
+            assert_eq!(get_or_default(&mut map, 2), 1);
+        })
+    }
+
+    // vectors cannot be perturbed
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(map_entry))]
+    fn push_to_missing() -> usize {
+        let mut map: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        map.entry(1).or_default().push(1);
+        map[&1].len()
+    }
+    #[test]
+    fn push_to_missing_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(push_to_missing(), 1);
+        })
+    }
+    #[test]
+    #[should_panic]
+    fn push_to_missing_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            push_to_missing();
+        })
+    }
+}
@@ -0,0 +1,84 @@
+mod test_double_all {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(map_identity))]
+    fn double_all(v: Vec<u32>) -> Vec<u32> {
+        v.into_iter().map(|x| x * 2).collect()
+    }
+    #[test]
+    fn double_all_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(double_all(vec![1, 2, 3]), vec![2, 4, 6]);
+        })
+    }
+    // map(|x| x)
+    #[test]
+    fn double_all_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(double_all(vec![1, 2, 3]), vec![1, 2, 3]);
+        })
+    }
+}
+
+mod test_option_increment {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(map_identity))]
+    fn increment(x: Option<i32>) -> Option<i32> {
+        x.map(|x| x + 1)
+    }
+    #[test]
+    fn increment_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(increment(Some(1)), Some(2));
+            assert_eq!(increment(None), None);
+        })
+    }
+    // map(|x| x)
+    #[test]
+    fn increment_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(increment(Some(1)), Some(1));
+        })
+    }
+}
+
+mod test_lengths {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the type of the elements changes, such that the identity does not type-check
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(map_identity))]
+    fn lengths(v: &[&str]) -> Vec<usize> {
+        v.iter().map(|s| s.len()).collect()
+    }
+    #[test]
+    fn lengths_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(lengths(&["a", "bc"]), vec![1, 2]);
+        })
+    }
+    #[test]
+    #[should_panic]
+    fn lengths_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            lengths(&["a", "bc"]);
+        })
+    }
+}
+
+mod test_identity {
+
+    use ::mutagen::mutate;
+
+    #[allow(dead_code, clippy::map_identity)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(map_identity))]
+    fn unchanged(x: Option<i32>) -> Option<i32> {
+        x.map(|x| x)
+    }
+}
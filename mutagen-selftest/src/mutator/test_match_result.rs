@@ -0,0 +1,76 @@
+mod test_ok_err {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // count the successful parses
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(match_result))]
+    fn count_numbers(words: &[&str]) -> usize {
+        let mut count = 0;
+        for w in words {
+            count += match w.parse::<u32>() {
+                Ok(_) => 1,
+                Err(_) => 0,
+            };
+        }
+        count
+    }
+    #[test]
+    fn count_numbers_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_numbers(&["1", "a", "2"]), 2);
+        })
+    }
+    // Ok(_) => 0, Err(_) => 1
+    #[test]
+    fn count_numbers_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_numbers(&["1", "a", "2"]), 1);
+        })
+    }
+}
+
+mod test_some_none {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // describe an optional value
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(match_result))]
+    fn describe(x: Option<u32>) -> &'static str {
+        match x {
+            None => "nothing",
+            Some(_) => "something",
+        }
+    }
+    #[test]
+    fn describe_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(describe(None), "nothing");
+            assert_eq!(describe(Some(1)), "something");
+        })
+    }
+    // None => "something", Some(_) => "nothing"
+    #[test]
+    fn describe_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(describe(None), "something");
+            assert_eq!(describe(Some(1)), "nothing");
+        })
+    }
+}
+
+mod test_binding_used {
+
+    use ::mutagen::mutate;
+
+    // bodies using bound variables cannot be swapped
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(match_result))]
+    fn successor_or_zero(x: Result<u32, String>) -> u32 {
+        match x {
+            Ok(v) => v + 1,
+            Err(_) => 0,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+mod test_shortest_word {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // returns the shortest of the words
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(min_max))]
+    fn shortest_word<'a>(words: &[&'a str]) -> Option<&'a str> {
+        words.iter().copied().min_by_key(|w| w.len())
+    }
+    #[test]
+    fn shortest_word_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(shortest_word(&["abc", "a", "ab"]), Some("a"));
+        })
+    }
+    // max_by_key(|w| w.len())
+    #[test]
+    fn shortest_word_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(shortest_word(&["abc", "a", "ab"]), Some("abc"));
+        })
+    }
+    // min_by_key(|x| std::cmp::Reverse((|w| w.len())(x)))
+    #[test]
+    fn shortest_word_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(shortest_word(&["abc", "a", "ab"]), Some("abc"));
+        })
+    }
+}
+
+mod test_farthest {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // returns the value that is farthest away from the target
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(min_max))]
+    fn farthest(values: &[i32], target: i32) -> Option<i32> {
+        values
+            .iter()
+            .copied()
+            .max_by(|a, b| (a - target).abs().cmp(&(b - target).abs()))
+    }
+    #[test]
+    fn farthest_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(farthest(&[1, 5, 8], 4), Some(8));
+        })
+    }
+    // min_by(|a, b| (a - target).abs().cmp(&(b - target).abs()))
+    #[test]
+    fn farthest_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(farthest(&[1, 5, 8], 4), Some(5));
+        })
+    }
+    // max_by(|a, b| (|a, b| (a - target).abs().cmp(&(b - target).abs()))(a, b).reverse())
+    #[test]
+    fn farthest_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(farthest(&[1, 5, 8], 4), Some(5));
+        })
+    }
+}
@@ -0,0 +1,76 @@
+mod test_sort_by_age_then_name {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sort persons by age and by name for equal ages
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(ordering_then))]
+    fn sort_by_age_then_name(persons: &mut Vec<(u32, &str)>) {
+        persons.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(b.1)));
+    }
+    #[test]
+    fn sort_by_age_then_name_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut persons = vec![(30, "bob"), (20, "eve"), (30, "alice")];
+            sort_by_age_then_name(&mut persons);
+            assert_eq!(persons, vec![(20, "eve"), (30, "alice"), (30, "bob")]);
+        })
+    }
+    // a.0.cmp(&b.0)
+    #[test]
+    fn sort_by_age_then_name_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut persons = vec![(30, "bob"), (20, "eve"), (30, "alice")];
+            sort_by_age_then_name(&mut persons);
+            assert_eq!(persons, vec![(20, "eve"), (30, "bob"), (30, "alice")]);
+        })
+    }
+}
+
+mod test_cmp_three_keys {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::cmp::Ordering;
+
+    // compare by three keys, the last one is computed lazily
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(ordering_then))]
+    fn cmp_three_keys(a: (u8, u8, u8), b: (u8, u8, u8)) -> Ordering {
+        a.0.cmp(&b.0)
+            .then(a.1.cmp(&b.1))
+            .then_with(|| a.2.cmp(&b.2))
+    }
+    #[test]
+    fn cmp_three_keys_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(cmp_three_keys((1, 1, 1), (1, 2, 0)), Ordering::Less);
+            assert_eq!(cmp_three_keys((1, 1, 1), (1, 1, 0)), Ordering::Greater);
+        })
+    }
+    // a.0.cmp(&b.0).then_with(|| a.2.cmp(&b.2))
+    #[test]
+    fn cmp_three_keys_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(cmp_three_keys((1, 1, 1), (1, 2, 0)), Ordering::Greater);
+        })
+    }
+    // a.0.cmp(&b.0).then(a.1.cmp(&b.1))
+    #[test]
+    fn cmp_three_keys_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(cmp_three_keys((1, 1, 1), (1, 1, 0)), Ordering::Equal);
+        })
+    }
+}
+
+mod test_bool_then {
+
+    use ::mutagen::mutate;
+
+    // `bool::then` is not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(ordering_then))]
+    #[allow(dead_code, clippy::unnecessary_lazy_evaluations)]
+    fn positive(x: i32) -> Option<i32> {
+        (x > 0).then(|| x)
+    }
+}
@@ -0,0 +1,103 @@
+mod test_remaining {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::time::Duration;
+
+    // the time that remains until the timeout, if the timeout has not passed yet
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(overflow_policy))]
+    fn remaining(timeout: Duration, elapsed: Duration) -> Option<Duration> {
+        timeout.checked_sub(elapsed)
+    }
+    #[test]
+    fn remaining_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(
+                remaining(Duration::from_secs(5), Duration::from_secs(2)),
+                Some(Duration::from_secs(3))
+            );
+            assert_eq!(
+                remaining(Duration::from_secs(1), Duration::from_secs(2)),
+                None
+            );
+        })
+    }
+    // Some(timeout - elapsed)
+    #[test]
+    #[should_panic]
+    fn remaining_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            remaining(Duration::from_secs(1), Duration::from_secs(2));
+        })
+    }
+    // Some(timeout.saturating_sub(elapsed))
+    #[test]
+    fn remaining_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(
+                remaining(Duration::from_secs(1), Duration::from_secs(2)),
+                Some(Duration::from_secs(0))
+            );
+        })
+    }
+}
+
+mod test_is_expired {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::time::Duration;
+
+    // the timeout has passed if no time remains
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(overflow_policy))]
+    fn is_expired(timeout: Duration, elapsed: Duration) -> bool {
+        match timeout.checked_sub(elapsed) {
+            Some(remaining) => remaining == Duration::from_secs(0),
+            None => true,
+        }
+    }
+    #[test]
+    fn is_expired_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(is_expired(Duration::from_secs(1), Duration::from_secs(2)));
+            assert!(!is_expired(Duration::from_secs(2), Duration::from_secs(1)));
+        })
+    }
+    // Some(timeout.saturating_sub(elapsed)), which is an equivalent mutant
+    #[test]
+    fn is_expired_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(is_expired(Duration::from_secs(1), Duration::from_secs(2)));
+        })
+    }
+}
+
+mod test_wait_time {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::time::Duration;
+
+    // the time to wait until the deadline, which is zero after the deadline
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(overflow_policy))]
+    fn wait_time(deadline: Duration, now: Duration) -> Duration {
+        deadline.saturating_sub(now)
+    }
+    #[test]
+    fn wait_time_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(
+                wait_time(Duration::from_secs(1), Duration::from_secs(2)),
+                Duration::from_secs(0)
+            );
+        })
+    }
+    // deadline - now
+    #[test]
+    #[should_panic]
+    fn wait_time_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            wait_time(Duration::from_secs(1), Duration::from_secs(2));
+        })
+    }
+}
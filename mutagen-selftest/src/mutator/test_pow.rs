@@ -0,0 +1,134 @@
+mod test_cube_u32 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // cube a number
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(pow))]
+    fn cube_u32(x: u32) -> u32 {
+        x.pow(3)
+    }
+    #[test]
+    fn cube_u32_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(cube_u32(2), 8);
+        })
+    }
+    // x.pow(4)
+    #[test]
+    fn cube_u32_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(cube_u32(2), 16);
+        })
+    }
+    // x.pow(2)
+    #[test]
+    fn cube_u32_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(cube_u32(2), 4);
+        })
+    }
+    // x.pow(0)
+    #[test]
+    fn cube_u32_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(cube_u32(2), 1);
+        })
+    }
+    // x.pow(1)
+    #[test]
+    fn cube_u32_active4() {
+        MutagenRuntimeConfig::test_with_mutation_id(4, || {
+            assert_eq!(cube_u32(2), 2);
+        })
+    }
+}
+
+mod test_identity_f64 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `powi(1)` is only mutated to `powi(2)` and `powi(0)`
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(pow))]
+    fn identity_f64(x: f64) -> f64 {
+        x.powi(1)
+    }
+    #[test]
+    fn identity_f64_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(identity_f64(3.0), 3.0);
+        })
+    }
+    // x.powi(2)
+    #[test]
+    fn identity_f64_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(identity_f64(3.0), 9.0);
+        })
+    }
+    // x.powi(0)
+    #[test]
+    fn identity_f64_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(identity_f64(3.0), 1.0);
+        })
+    }
+}
+
+mod test_pow_by_var {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(pow))]
+    fn pow_by_var(x: u32, n: u32) -> u32 {
+        x.pow(n)
+    }
+    #[test]
+    fn pow_by_var_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(pow_by_var(2, 0), 1);
+        })
+    }
+    // x.pow(n - 1) saturates at 0
+    #[test]
+    fn pow_by_var_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(pow_by_var(2, 0), 1);
+        })
+    }
+}
+
+mod test_pow_overflow {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `2u8.pow(8)` overflows, the optimistic assumption fails instead
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(pow))]
+    fn pow_u8(x: u8) -> u8 {
+        x.pow(7)
+    }
+    #[test]
+    fn pow_u8_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(pow_u8(2), 128);
+        })
+    }
+    // x.pow(8)
+    #[test]
+    #[should_panic(expected = "optimistic assumption failed")]
+    fn pow_u8_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            pow_u8(2);
+        })
+    }
+    // x.pow(8) does not overflow for small bases
+    #[test]
+    fn pow_u8_active1_small() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(pow_u8(1), 1);
+        })
+    }
+}
@@ -0,0 +1,102 @@
+mod test_underline {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // underline for a heading of the given length
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(repeat_count))]
+    fn underline(len: usize) -> String {
+        "=".repeat(len)
+    }
+    #[test]
+    fn underline_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(underline(3), "===");
+        })
+    }
+    // "=".repeat(len + 1)
+    #[test]
+    fn underline_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(underline(3), "====");
+        })
+    }
+    // "=".repeat(len - 1)
+    #[test]
+    fn underline_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(underline(3), "==");
+        })
+    }
+    // "=".repeat(0)
+    #[test]
+    fn underline_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(underline(3), "");
+        })
+    }
+}
+
+mod test_buffer {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // zeroed buffer of the given size
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(repeat_count))]
+    fn buffer(size: usize) -> Vec<u8> {
+        vec![0; size]
+    }
+    #[test]
+    fn buffer_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(buffer(2).len(), 2);
+        })
+    }
+    // vec![0; size + 1]
+    #[test]
+    fn buffer_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(buffer(2).len(), 3);
+        })
+    }
+    // vec![0; size - 1]
+    #[test]
+    fn buffer_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(buffer(2).len(), 1);
+        })
+    }
+    // vec![0; 0]
+    #[test]
+    fn buffer_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert!(buffer(2).is_empty());
+        })
+    }
+}
+
+mod test_literal_count {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the literal count is mutated by `repeat_count` only, since `lit_int` is not enabled
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(repeat_count))]
+    fn marker() -> String {
+        "x".repeat(3)
+    }
+    #[test]
+    fn marker_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(marker().len(), 3);
+        })
+    }
+    // "x".repeat(3 - 1)
+    #[test]
+    fn marker_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(marker(), "xx");
+        })
+    }
+}
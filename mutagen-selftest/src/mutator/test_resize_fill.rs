@@ -0,0 +1,95 @@
+mod test_padded {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // pad a vector with zeros to a length of 3
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(resize_fill))]
+    fn padded(mut v: Vec<u8>) -> usize {
+        v.resize(3, 0);
+        v.len()
+    }
+    #[test]
+    fn padded_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(padded(vec![1]), 3);
+        })
+    }
+    // v.resize(3 + 1, 0)
+    #[test]
+    fn padded_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(padded(vec![1]), 4);
+        })
+    }
+    // v.resize(3 - 1, 0)
+    #[test]
+    fn padded_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(padded(vec![1]), 2);
+        })
+    }
+    // v.resize(0, 0)
+    #[test]
+    fn padded_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(padded(vec![1]), 0);
+        })
+    }
+}
+
+mod test_reset {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // set all elements to the given value
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(resize_fill))]
+    fn reset(v: &mut [u8], x: u8) {
+        v.fill(x);
+    }
+    #[test]
+    fn reset_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut v = [1, 2];
+            reset(&mut v, 7);
+            assert_eq!(v, [7, 7]);
+        })
+    }
+    // v.fill(Default::default())
+    #[test]
+    fn reset_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut v = [1, 2];
+            reset(&mut v, 7);
+            assert_eq!(v, [0, 0]);
+        })
+    }
+}
+
+mod test_grow_with {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // grow a vector of vectors, starting with an empty size
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(resize_fill))]
+    fn grow_with() -> Vec<Vec<u8>> {
+        let mut v = vec![vec![1]];
+        v.resize_with(0, Vec::new);
+        v
+    }
+    #[test]
+    fn grow_with_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(grow_with().len(), 0);
+        })
+    }
+    // v.resize_with(0 + 1, Vec::new)
+    #[test]
+    fn grow_with_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(grow_with().len(), 1);
+        })
+    }
+}
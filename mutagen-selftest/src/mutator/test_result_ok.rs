@@ -0,0 +1,58 @@
+mod test_parse_or_default {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // parses a number, invalid numbers default to 0
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(result_ok))]
+    fn parse_or_default(s: &str) -> u32 {
+        s.parse().ok().unwrap_or(0)
+    }
+    #[test]
+    fn parse_or_default_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(parse_or_default("42"), 42);
+            assert_eq!(parse_or_default("x"), 0);
+        })
+    }
+    // None
+    #[test]
+    fn parse_or_default_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(parse_or_default("42"), 0);
+            assert_eq!(parse_or_default("x"), 0);
+        })
+    }
+}
+
+mod test_find_user {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // looks up the index of a user, missing users are an error
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(result_ok))]
+    fn find_user(users: &[&str], name: &str) -> Result<usize, String> {
+        users
+            .iter()
+            .position(|u| *u == name)
+            .ok_or(format!("unknown user {}", name))
+    }
+    #[test]
+    fn find_user_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(find_user(&["a", "b"], "b"), Ok(1));
+            assert_eq!(find_user(&["a"], "c"), Err("unknown user c".to_owned()));
+        })
+    }
+    // Err(format!("unknown user {}", name))
+    #[test]
+    fn find_user_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(
+                find_user(&["a", "b"], "b"),
+                Err("unknown user b".to_owned())
+            );
+        })
+    }
+}
@@ -0,0 +1,72 @@
+mod test_retain_even {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // keep only the even numbers of a vector
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(retain))]
+    fn retain_even(mut v: Vec<u32>) -> Vec<u32> {
+        v.retain(|x| x % 2 == 0);
+        v
+    }
+    #[test]
+    fn retain_even_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(retain_even(vec![1, 2, 3, 4]), vec![2, 4]);
+        })
+    }
+    // retain(|x| !(x % 2 == 0))
+    #[test]
+    fn retain_even_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(retain_even(vec![1, 2, 3, 4]), vec![1, 3]);
+        })
+    }
+}
+
+mod test_retain_map {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::HashMap;
+
+    // remove all entries with a count of zero
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(retain))]
+    fn remove_zero_counts(counts: &mut HashMap<char, u32>) {
+        counts.retain(|_, count| *count > 0);
+    }
+    #[test]
+    fn remove_zero_counts_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut counts = vec![('a', 0), ('b', 2)].into_iter().collect();
+            remove_zero_counts(&mut counts);
+            assert_eq!(counts.keys().collect::<Vec<_>>(), vec![&'b']);
+        })
+    }
+    // retain(|_, count| !(*count > 0))
+    #[test]
+    fn remove_zero_counts_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut counts = vec![('a', 0), ('b', 2)].into_iter().collect();
+            remove_zero_counts(&mut counts);
+            assert_eq!(counts.keys().collect::<Vec<_>>(), vec![&'a']);
+        })
+    }
+}
+
+mod test_retain_function {
+
+    use ::mutagen::mutate;
+
+    fn is_small(x: &u32) -> bool {
+        *x < 3
+    }
+
+    // predicates that are not closures are not mutated
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(retain))]
+    fn retain_small(mut v: Vec<u32>) -> Vec<u32> {
+        v.retain(is_small);
+        v
+    }
+}
@@ -0,0 +1,158 @@
+mod test_by_priority {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::cmp::Reverse;
+
+    // the tasks with the highest priority come first
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(reverse))]
+    fn by_priority(mut tasks: Vec<(&'static str, u32)>) -> Vec<&'static str> {
+        tasks.sort_by_key(|task| Reverse(task.1));
+        tasks.into_iter().map(|task| task.0).collect()
+    }
+    #[test]
+    fn by_priority_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(
+                by_priority(vec![("a", 1), ("b", 3), ("c", 2)]),
+                vec!["b", "c", "a"]
+            );
+        })
+    }
+    // sort_by_key(|task| task.1)
+    #[test]
+    fn by_priority_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(
+                by_priority(vec![("a", 1), ("b", 3), ("c", 2)]),
+                vec!["a", "c", "b"]
+            );
+        })
+    }
+}
+
+mod test_next_deadline {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::cmp::Reverse;
+
+    // the sorted deadlines are used as a min-queue, the earliest deadline is popped first
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(reverse))]
+    fn next_deadline(mut deadlines: Vec<u32>) -> Option<u32> {
+        deadlines.sort_unstable_by_key(|d| Reverse(*d));
+        deadlines.pop()
+    }
+    #[test]
+    fn next_deadline_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(next_deadline(vec![2, 5, 1]), Some(1));
+        })
+    }
+    // sort_unstable_by_key(|d| *d)
+    #[test]
+    fn next_deadline_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(next_deadline(vec![2, 5, 1]), Some(5));
+        })
+    }
+}
+
+mod test_by_name {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the users are sorted alphabetically
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(reverse))]
+    fn by_name(mut users: Vec<&'static str>) -> Vec<&'static str> {
+        users.sort_by_key(|name| name.to_lowercase());
+        users
+    }
+    #[test]
+    fn by_name_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(
+                by_name(vec!["bob", "Alice", "carol"]),
+                vec!["Alice", "bob", "carol"]
+            );
+        })
+    }
+    // sort_by_key(|name| Reverse(name.to_lowercase()))
+    #[test]
+    fn by_name_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(
+                by_name(vec!["bob", "Alice", "carol"]),
+                vec!["carol", "bob", "Alice"]
+            );
+        })
+    }
+}
+
+mod test_shortest_path {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    // the nodes are visited in the order of their distance to the start, like in Dijkstra's algorithm
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(reverse))]
+    fn visit_order(distances: &[u32]) -> Vec<usize> {
+        let mut heap = distances
+            .iter()
+            .enumerate()
+            .map(|(node, &dist)| Reverse((dist, node)))
+            .collect::<BinaryHeap<_>>();
+        let mut visited = Vec::new();
+        while let Some(Reverse((_, node))) = heap.pop() {
+            visited.push(node);
+        }
+        visited
+    }
+    #[test]
+    fn visit_order_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(visit_order(&[4, 0, 7, 2]), vec![1, 3, 0, 2]);
+        })
+    }
+    // heap.pop() without Reverse
+    #[test]
+    fn visit_order_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(visit_order(&[4, 0, 7, 2]), vec![2, 0, 3, 1]);
+        })
+    }
+}
+
+mod test_next_job {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::cmp::Reverse;
+
+    // the popped collection is no heap, the optimistic assumption fails
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(reverse))]
+    fn next_job(mut jobs: Vec<Reverse<u32>>) -> Option<u32> {
+        if let Some(Reverse(job)) = jobs.pop() {
+            Some(job)
+        } else {
+            None
+        }
+    }
+    #[test]
+    fn next_job_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(next_job(vec![Reverse(1), Reverse(3)]), Some(3));
+        })
+    }
+    // jobs.pop() without Reverse
+    #[test]
+    #[should_panic]
+    fn next_job_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            next_job(vec![Reverse(1), Reverse(3)]);
+        })
+    }
+}
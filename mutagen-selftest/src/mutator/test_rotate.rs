@@ -0,0 +1,79 @@
+mod test_rotate_left_u8 {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // rotate the bits of a byte one position to the left
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(rotate))]
+    fn rotate_byte(x: u8) -> u8 {
+        x.rotate_left(1)
+    }
+    #[test]
+    fn rotate_byte_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(rotate_byte(0b1000_0001), 0b0000_0011);
+        })
+    }
+    // rotate_left(1 + 1)
+    #[test]
+    fn rotate_byte_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(rotate_byte(0b1000_0001), 0b0000_0110);
+        })
+    }
+    // rotate_left(1 - 1)
+    #[test]
+    fn rotate_byte_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(rotate_byte(0b1000_0001), 0b1000_0001);
+        })
+    }
+    // rotate_right(1)
+    #[test]
+    fn rotate_byte_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(rotate_byte(0b1000_0001), 0b1100_0000);
+        })
+    }
+    // rotate_left(0)
+    #[test]
+    fn rotate_byte_active4() {
+        MutagenRuntimeConfig::test_with_mutation_id(4, || {
+            assert_eq!(rotate_byte(0b1000_0001), 0b1000_0001);
+        })
+    }
+}
+
+mod test_rotate_right_slice {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // move the last `k` elements to the front
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(rotate))]
+    fn last_to_front(mut v: Vec<u32>, k: usize) -> Vec<u32> {
+        v.rotate_right(k);
+        v
+    }
+    #[test]
+    fn last_to_front_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(last_to_front(vec![1, 2, 3], 1), vec![3, 1, 2]);
+        })
+    }
+    // rotate_right(k + 1), stays in bounds
+    #[test]
+    fn last_to_front_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(last_to_front(vec![1, 2, 3], 1), vec![2, 3, 1]);
+            assert_eq!(last_to_front(vec![1, 2, 3], 3), vec![3, 1, 2]);
+        })
+    }
+    // rotate_left(k)
+    #[test]
+    fn last_to_front_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(last_to_front(vec![1, 2, 3], 1), vec![2, 3, 1]);
+        })
+    }
+}
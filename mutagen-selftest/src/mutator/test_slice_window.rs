@@ -0,0 +1,88 @@
+mod test_count_pairs {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // count the adjacent pairs with equal elements
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(slice_window))]
+    fn count_pairs(v: &[u32]) -> usize {
+        v.windows(2).filter(|w| w.iter().all(|x| *x == w[0])).count()
+    }
+    #[test]
+    fn count_pairs_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_pairs(&[1, 1, 1, 2]), 2);
+        })
+    }
+    // v.windows(2 + 1)
+    #[test]
+    fn count_pairs_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_pairs(&[1, 1, 1, 2]), 1);
+        })
+    }
+    // v.windows(2 - 1)
+    #[test]
+    fn count_pairs_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(count_pairs(&[1, 1, 1, 2]), 4);
+        })
+    }
+}
+
+mod test_num_chunks {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // number of complete chunks of size `n`
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(slice_window))]
+    fn num_chunks(v: &[u8], n: usize) -> usize {
+        v.chunks_exact(n).count()
+    }
+    #[test]
+    fn num_chunks_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(num_chunks(&[0; 6], 2), 3);
+        })
+    }
+    // v.chunks_exact(n + 1)
+    #[test]
+    fn num_chunks_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(num_chunks(&[0; 6], 2), 2);
+        })
+    }
+    // v.chunks_exact(n - 1), never decremented to zero
+    #[test]
+    fn num_chunks_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(num_chunks(&[0; 6], 1), 6);
+        })
+    }
+}
+
+mod test_single_chunks {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // a literal size of 1 is only incremented
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(slice_window))]
+    fn single_chunks(v: &[u8]) -> usize {
+        v.chunks(1).count()
+    }
+    #[test]
+    fn single_chunks_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(single_chunks(&[0; 3]), 3);
+        })
+    }
+    // v.chunks(1 + 1)
+    #[test]
+    fn single_chunks_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(single_chunks(&[0; 3]), 2);
+        })
+    }
+}
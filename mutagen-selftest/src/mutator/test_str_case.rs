@@ -0,0 +1,133 @@
+mod test_eq_ignore_case {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // compare two strings, ignoring the case of the first one
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(str_case))]
+    fn matches_keyword(s: &str) -> bool {
+        s.to_lowercase() == "select"
+    }
+    #[test]
+    fn matches_keyword_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(matches_keyword("SELECT"));
+            assert!(matches_keyword("select"));
+        })
+    }
+    // s.to_uppercase()
+    #[test]
+    fn matches_keyword_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!matches_keyword("SELECT"));
+            assert!(!matches_keyword("select"));
+        })
+    }
+    // s
+    #[test]
+    fn matches_keyword_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(!matches_keyword("SELECT"));
+            assert!(matches_keyword("select"));
+        })
+    }
+}
+
+mod test_ascii_char {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(str_case))]
+    fn capitalize_initial(s: &str) -> Option<char> {
+        let c = s.chars().next()?;
+        Some(c.to_ascii_uppercase())
+    }
+    #[test]
+    fn capitalize_initial_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(capitalize_initial("rust"), Some('R'));
+        })
+    }
+    // c.to_ascii_lowercase()
+    #[test]
+    fn capitalize_initial_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(capitalize_initial("Rust"), Some('r'));
+        })
+    }
+    // c
+    #[test]
+    fn capitalize_initial_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(capitalize_initial("rust"), Some('r'));
+        })
+    }
+}
+
+mod test_char_iterator {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(str_case))]
+    fn uppercase_char(c: char) -> String {
+        c.to_uppercase().collect()
+    }
+    #[test]
+    fn uppercase_char_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(uppercase_char('ß'), "SS");
+        })
+    }
+    // `to_lowercase` returns a different iterator type
+    #[test]
+    #[should_panic]
+    fn uppercase_char_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            uppercase_char('a');
+        })
+    }
+}
+
+mod test_same_user {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // user names are compared case-insensitively
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(str_case))]
+    fn same_user(a: &str, b: &str) -> bool {
+        a.to_lowercase() == b.to_lowercase()
+    }
+    #[test]
+    fn same_user_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(same_user("Alice", "aLICE"));
+            assert!(!same_user("Alice", "Bob"));
+        })
+    }
+    // a.to_uppercase()
+    #[test]
+    fn same_user_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!same_user("Alice", "aLICE"));
+        })
+    }
+    // a
+    #[test]
+    fn same_user_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert!(!same_user("Alice", "aLICE"));
+            assert!(same_user("alice", "ALICE"));
+        })
+    }
+    // b
+    #[test]
+    fn same_user_active4() {
+        MutagenRuntimeConfig::test_with_mutation_id(4, || {
+            assert!(!same_user("Alice", "aLICE"));
+            assert!(same_user("ALICE", "alice"));
+        })
+    }
+}
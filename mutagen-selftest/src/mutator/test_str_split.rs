@@ -0,0 +1,120 @@
+mod test_count_fields {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // counts the fields separated by commas
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(str_split))]
+    fn count_fields(s: &str) -> usize {
+        s.split(',').count()
+    }
+    #[test]
+    fn count_fields_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_fields("a,b,c"), 3);
+        })
+    }
+    // replacing `split(',')` with `split('\0')`
+    #[test]
+    fn count_fields_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_fields("a,b,c"), 1);
+        })
+    }
+}
+
+mod test_key_value {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // splits a key from its value, which may contain further commas
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(str_split))]
+    fn key_value(s: &str) -> Vec<&str> {
+        s.splitn(2, ',').collect()
+    }
+    #[test]
+    fn key_value_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(key_value("a,b,c"), vec!["a", "b,c"]);
+        })
+    }
+    // replacing `splitn(2, ',')` with `splitn(2 + 1, ',')`
+    #[test]
+    fn key_value_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(key_value("a,b,c"), vec!["a", "b", "c"]);
+        })
+    }
+    // replacing `splitn(2, ',')` with `splitn(2 - 1, ',')`
+    #[test]
+    fn key_value_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(key_value("a,b,c"), vec!["a,b,c"]);
+        })
+    }
+    // replacing `splitn(2, ',')` with `splitn(2, '\0')`
+    #[test]
+    fn key_value_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(key_value("a,b,c").len(), 1);
+        })
+    }
+}
+
+mod test_last_segment {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the last segment of a path
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(str_split))]
+    fn last_segment(path: &str) -> &str {
+        path.rsplitn(2, "::").next().unwrap()
+    }
+    #[test]
+    fn last_segment_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(last_segment("std::vec::Vec"), "Vec");
+        })
+    }
+    // replacing `rsplitn(2, "::")` with `rsplitn(2, "\0")`
+    #[test]
+    fn last_segment_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(last_segment("std::vec::Vec"), "std::vec::Vec");
+        })
+    }
+}
+
+mod test_count_pieces {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the number of pieces is limited by a count that is not a literal
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(str_split))]
+    fn count_pieces(s: &str, max: usize) -> usize {
+        s.splitn(max, ' ').count()
+    }
+    #[test]
+    fn count_pieces_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_pieces("a b c d", 3), 3);
+        })
+    }
+    // replacing `splitn(max, ' ')` with `splitn(max + 1, ' ')`
+    #[test]
+    fn count_pieces_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(count_pieces("a b c d", 3), 4);
+        })
+    }
+    // replacing `splitn(max, ' ')` with `splitn(max - 1, ' ')`
+    #[test]
+    fn count_pieces_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(count_pieces("a b c d", 3), 2);
+        })
+    }
+}
@@ -0,0 +1,50 @@
+mod test_is_command {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // checks if the input line is the given command
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(str_trim))]
+    fn is_command(line: &str, command: &str) -> bool {
+        line.trim() == command
+    }
+    #[test]
+    fn is_command_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert!(is_command("  quit \n", "quit"));
+        })
+    }
+    // line
+    #[test]
+    fn is_command_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(!is_command("  quit \n", "quit"));
+            assert!(is_command("quit", "quit"));
+        })
+    }
+}
+
+mod test_strip_newline {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // removes trailing whitespace of a line, like the line break
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(str_trim))]
+    fn strip_newline(line: String) -> String {
+        line.trim_end().to_owned()
+    }
+    #[test]
+    fn strip_newline_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(strip_newline("  indented\n".to_owned()), "  indented");
+        })
+    }
+    // line
+    #[test]
+    fn strip_newline_active() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(strip_newline("  indented\n".to_owned()), "  indented\n");
+        })
+    }
+}
@@ -0,0 +1,104 @@
+mod test_override_field {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        retries: u32,
+        verbose: bool,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Config {
+                retries: 3,
+                verbose: false,
+            }
+        }
+    }
+
+    // override the number of retries of a given configuration
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(struct_update))]
+    fn without_retries(base: Config) -> Config {
+        Config { retries: 0, ..base }
+    }
+    #[test]
+    fn without_retries_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let base = Config {
+                retries: 5,
+                verbose: true,
+            };
+            let expected = Config {
+                retries: 0,
+                verbose: true,
+            };
+            assert_eq!(without_retries(base), expected);
+        })
+    }
+    // Config { ..base }
+    #[test]
+    fn without_retries_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let base = Config {
+                retries: 5,
+                verbose: true,
+            };
+            let expected = Config {
+                retries: 5,
+                verbose: true,
+            };
+            assert_eq!(without_retries(base), expected);
+        })
+    }
+    // Config { retries: 0, ..Default::default() }
+    #[test]
+    fn without_retries_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            let base = Config {
+                retries: 5,
+                verbose: true,
+            };
+            let expected = Config {
+                retries: 0,
+                verbose: false,
+            };
+            assert_eq!(without_retries(base), expected);
+        })
+    }
+}
+
+mod test_default_base {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // a base that is already the default value is not replaced
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(struct_update))]
+    fn on_x_axis(x: i32) -> Point {
+        Point {
+            x,
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn on_x_axis_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(on_x_axis(2), Point { x: 2, y: 0 });
+        })
+    }
+    // Point { ..Default::default() }
+    #[test]
+    fn on_x_axis_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(on_x_axis(2), Point { x: 0, y: 0 });
+        })
+    }
+}
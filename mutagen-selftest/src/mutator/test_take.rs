@@ -0,0 +1,115 @@
+mod test_next_token {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the pending token is returned once
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(take))]
+    fn next_token(pending: &mut Option<u32>) -> Option<u32> {
+        pending.take()
+    }
+    #[test]
+    fn next_token_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut pending = Some(3);
+            assert_eq!(next_token(&mut pending), Some(3));
+            assert_eq!(next_token(&mut pending), None);
+        })
+    }
+    // pending.clone()
+    #[test]
+    fn next_token_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut pending = Some(3);
+            assert_eq!(next_token(&mut pending), Some(3));
+            assert_eq!(next_token(&mut pending), Some(3));
+        })
+    }
+}
+
+mod test_flush {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the buffered values are returned and the buffer is emptied
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(take))]
+    fn flush(buffer: &mut Vec<u32>) -> Vec<u32> {
+        std::mem::take(buffer)
+    }
+    #[test]
+    fn flush_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut buffer = vec![1, 2];
+            assert_eq!(flush(&mut buffer), vec![1, 2]);
+            assert!(buffer.is_empty());
+        })
+    }
+    // (*buffer).clone()
+    #[test]
+    fn flush_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut buffer = vec![1, 2];
+            assert_eq!(flush(&mut buffer), vec![1, 2]);
+            assert_eq!(buffer, vec![1, 2]);
+        })
+    }
+}
+
+mod test_advance {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the state is replaced and the previous state is returned
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(take))]
+    fn advance(state: &mut u32, next: u32) -> u32 {
+        std::mem::replace(state, next)
+    }
+    #[test]
+    fn advance_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let mut state = 1;
+            assert_eq!(advance(&mut state, 2), 1);
+            assert_eq!(state, 2);
+        })
+    }
+    // (*state).clone()
+    #[test]
+    fn advance_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let mut state = 1;
+            assert_eq!(advance(&mut state, 2), 1);
+            assert_eq!(state, 1);
+        })
+    }
+}
+
+mod test_take_cell {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::cell::Cell;
+
+    // the value of a cell is taken, the optimistic assumption fails
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(take))]
+    fn take_count(count: &Cell<u32>) -> u32 {
+        count.take()
+    }
+    #[test]
+    fn take_count_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            let count = Cell::new(4);
+            assert_eq!(take_count(&count), 4);
+            assert_eq!(count.get(), 0);
+        })
+    }
+    // count.clone()
+    #[test]
+    #[should_panic]
+    fn take_count_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            take_count(&Cell::new(4));
+        })
+    }
+}
@@ -0,0 +1,96 @@
+mod test_try_option {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // add the first two elements of a slice
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(try))]
+    fn add_first_two(v: &[u32]) -> Option<u32> {
+        let a = v.first()?;
+        let b = v.get(1)?;
+        Some(a + b)
+    }
+    #[test]
+    fn add_first_two_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(add_first_two(&[1, 2]), Some(3));
+            assert_eq!(add_first_two(&[1]), None);
+        })
+    }
+    // return None
+    #[test]
+    fn add_first_two_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(add_first_two(&[1, 2]), None);
+        })
+    }
+}
+
+mod test_try_result {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // parse a number and double it
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(try))]
+    fn parse_double(s: &str) -> Result<u32, String> {
+        let x = s.parse::<u32>().map_err(|e| e.to_string())?;
+        Ok(x * 2)
+    }
+    #[test]
+    fn parse_double_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(parse_double("2"), Ok(4));
+            assert!(parse_double("x").is_err());
+        })
+    }
+    // return Err(String::default())
+    #[test]
+    fn parse_double_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(parse_double("2"), Err("".to_owned()));
+        })
+    }
+}
+
+mod test_try_error_without_default {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(try))]
+    fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+        Ok(s.parse::<u32>()?)
+    }
+    #[test]
+    fn parse_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(parse("2"), Ok(2));
+        })
+    }
+    // `ParseIntError` cannot be created
+    #[test]
+    #[should_panic]
+    fn parse_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let _ = parse("2");
+        })
+    }
+}
+
+mod test_try_in_closure {
+
+    use ::mutagen::mutate;
+
+    // the return type of the closure is unknown
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(try))]
+    fn sum_parsed(v: &[&str]) -> u32 {
+        v.iter()
+            .filter_map(|s| {
+                let x = s.parse::<u32>().ok()?;
+                Some(x * 2)
+            })
+            .sum()
+    }
+}
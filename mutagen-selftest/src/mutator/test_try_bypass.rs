@@ -0,0 +1,87 @@
+mod test_converted_error {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::num::ParseIntError;
+
+    #[derive(Debug, PartialEq)]
+    enum PortError {
+        Invalid(ParseIntError),
+    }
+
+    impl From<ParseIntError> for PortError {
+        fn from(e: ParseIntError) -> Self {
+            PortError::Invalid(e)
+        }
+    }
+
+    // parses a port, the error is converted into a `PortError`
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(try_bypass))]
+    fn parse_port(s: &str) -> Result<u16, PortError> {
+        let port = s.parse::<u16>()?;
+        Ok(port)
+    }
+    #[test]
+    fn parse_port_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(parse_port("80"), Ok(80));
+            assert!(matches!(parse_port("x"), Err(PortError::Invalid(_))));
+        })
+    }
+    // Err(e) => return Err(e), which changes the type of the error
+    #[test]
+    #[should_panic]
+    fn parse_port_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            let _ = parse_port("x");
+        })
+    }
+    // the conversion is not bypassed for valid ports
+    #[test]
+    fn parse_port_active1_valid() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(parse_port("80"), Ok(80));
+        })
+    }
+}
+
+mod test_same_error {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::num::ParseIntError;
+
+    // parses a number, the error is passed to the caller without conversion
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(try_bypass))]
+    fn parse_twice(s: &str) -> Result<u32, ParseIntError> {
+        let x = s.parse::<u32>()?;
+        Ok(x * 2)
+    }
+    #[test]
+    fn parse_twice_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(parse_twice("2"), Ok(4));
+            assert!(parse_twice("x").is_err());
+        })
+    }
+    // Err(e) => return Err(e), which is an equivalent mutant
+    #[test]
+    fn parse_twice_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert!(parse_twice("x").is_err());
+        })
+    }
+}
+
+mod test_try_and_bypass {
+
+    use ::mutagen::mutate;
+
+    // `try` and `try_bypass` both mutate the same `?`
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(try, try_bypass))]
+    #[allow(dead_code)]
+    fn parse(s: &str) -> Result<u32, String> {
+        let x = s.parse::<u32>().map_err(|e| e.to_string())?;
+        Ok(x)
+    }
+}
@@ -0,0 +1,115 @@
+mod test_remove_second {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // remove the second element of a vector
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(vec_remove))]
+    fn remove_second(mut v: Vec<u32>) -> u32 {
+        v.remove(1)
+    }
+    #[test]
+    fn remove_second_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(remove_second(vec![1, 2, 3]), 2);
+        })
+    }
+    // v.remove(2)
+    #[test]
+    fn remove_second_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(remove_second(vec![1, 2, 3]), 3);
+        })
+    }
+    // v.remove(0)
+    #[test]
+    fn remove_second_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(remove_second(vec![1, 2, 3]), 1);
+        })
+    }
+}
+
+mod test_drain_prefix {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // remove the first `n` elements of a vector
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(vec_remove))]
+    fn drain_prefix(mut v: Vec<u32>, n: usize) -> Vec<u32> {
+        v.drain(..n);
+        v
+    }
+    #[test]
+    fn drain_prefix_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(drain_prefix(vec![1, 2, 3], 1), vec![2, 3]);
+        })
+    }
+    // v.drain(..n + 1)
+    #[test]
+    fn drain_prefix_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(drain_prefix(vec![1, 2, 3], 1), vec![3]);
+        })
+    }
+    // v.drain(..n - 1)
+    #[test]
+    fn drain_prefix_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(drain_prefix(vec![1, 2, 3], 1), vec![1, 2, 3]);
+        })
+    }
+}
+
+mod test_remove_first_out_of_bounds {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(vec_remove))]
+    fn remove_first(mut v: Vec<u32>) -> u32 {
+        v.remove(0)
+    }
+    #[test]
+    fn remove_first_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(remove_first(vec![1, 2]), 1);
+        })
+    }
+    // the index `0 - 1` is out of bounds
+    #[test]
+    #[should_panic]
+    fn remove_first_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            remove_first(vec![1, 2]);
+        })
+    }
+}
+
+mod test_remove_key {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+    use std::collections::HashMap;
+
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(vec_remove))]
+    fn remove_key(mut m: HashMap<u32, u32>) -> Option<u32> {
+        m.remove(&1)
+    }
+    #[test]
+    fn remove_key_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(remove_key(vec![(1, 2)].into_iter().collect()), Some(2));
+        })
+    }
+    // the key cannot be perturbed
+    #[test]
+    #[should_panic]
+    fn remove_key_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            remove_key(HashMap::new());
+        })
+    }
+}
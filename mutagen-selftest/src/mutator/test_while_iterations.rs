@@ -0,0 +1,85 @@
+mod test_sum_while {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sum up the elements of a slice with a while-loop
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(while_iterations))]
+    fn sum(v: &[u32]) -> u32 {
+        let mut i = 0;
+        let mut sum = 0;
+        while i < v.len() {
+            sum += v[i];
+            i += 1;
+        }
+        sum
+    }
+    #[test]
+    fn sum_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(sum(&[1, 2, 3]), 6);
+        })
+    }
+    // run loop zero times
+    #[test]
+    fn sum_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(sum(&[1, 2, 3]), 0);
+        })
+    }
+    // run loop exactly once
+    #[test]
+    fn sum_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(sum(&[1, 2, 3]), 1);
+        })
+    }
+}
+
+mod test_while_labeled {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the label of the loop is kept
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(while_iterations))]
+    fn count_until(n: u32) -> u32 {
+        let mut i = 0;
+        'outer: while i < 10 {
+            if i == n {
+                break 'outer;
+            }
+            i += 1;
+        }
+        i
+    }
+    #[test]
+    fn count_until_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(count_until(4), 4);
+        })
+    }
+    // run loop exactly once
+    #[test]
+    fn count_until_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(count_until(4), 1);
+        })
+    }
+}
+
+mod test_while_let {
+
+    use ::mutagen::mutate;
+
+    // `while let` is not mutated
+    #[allow(dead_code)]
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(while_iterations))]
+    fn pop_all(mut v: Vec<u32>) -> u32 {
+        let mut sum = 0;
+        while let Some(x) = v.pop() {
+            sum += x;
+        }
+        sum
+    }
+}
@@ -0,0 +1,61 @@
+mod test_sum_of_differences {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // sum of pairwise differences of two slices
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(zip_swap))]
+    fn sum_of_differences(a: &[i32], b: &[i32]) -> i32 {
+        a.iter().zip(b.iter()).map(|(x, y)| x - y).sum()
+    }
+    #[test]
+    fn sum_of_differences_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(sum_of_differences(&[5, 3], &[1, 1]), 6);
+        })
+    }
+    // b.iter().zip(a.iter())
+    #[test]
+    fn sum_of_differences_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(sum_of_differences(&[5, 3], &[1, 1]), -6);
+        })
+    }
+}
+
+mod test_pairs_of_different_types {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(zip_swap))]
+    fn indexed_chars(s: &str) -> Vec<(usize, char)> {
+        (0..).zip(s.chars()).collect()
+    }
+    #[test]
+    fn indexed_chars_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(indexed_chars("ab"), vec![(0, 'a'), (1, 'b')]);
+        })
+    }
+    // the pairs cannot be swapped
+    #[test]
+    #[should_panic]
+    fn indexed_chars_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            indexed_chars("ab");
+        })
+    }
+}
+
+mod test_option_zip {
+
+    use ::mutagen::mutate;
+
+    // `Option::zip` is not mutated
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(zip_swap))]
+    #[allow(dead_code)]
+    fn option_zip(a: Option<u32>, b: Option<u32>) -> Option<(u32, u32)> {
+        a.zip(b)
+    }
+}
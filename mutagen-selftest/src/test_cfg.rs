@@ -0,0 +1,45 @@
+//! tests for mutations in code that is gated by `cfg`
+
+mod test_cfg_let {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // `#[mutate]` receives the code before `cfg` is evaluated, so both statements are mutated.
+    // The mutations of the inactive statement are removed with the statement and never covered.
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(lit_int))]
+    fn retry_limit() -> u32 {
+        #[cfg(test)]
+        let limit = 3;
+        #[cfg(not(test))]
+        let limit = 10;
+        limit
+    }
+    #[test]
+    fn retry_limit_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(retry_limit(), 3);
+        })
+    }
+    // 3 +1
+    #[test]
+    fn retry_limit_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(retry_limit(), 4);
+        })
+    }
+    // 3 -1
+    #[test]
+    fn retry_limit_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(retry_limit(), 2);
+        })
+    }
+    // 10 +1, inactive code
+    #[test]
+    fn retry_limit_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(retry_limit(), 3);
+        })
+    }
+}
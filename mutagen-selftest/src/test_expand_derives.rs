@@ -0,0 +1,85 @@
+//! tests for expanding derives with `#[mutate(derives = expand(...))]`
+
+mod test_partial_eq_struct {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the derived `PartialEq` is replaced by `self.x == other.x && self.y == other.y`
+    #[mutate(conf = local(expected_mutations = 3), mutators = only(binop_eq, binop_bool), derives = expand(PartialEq))]
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn partial_eq_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(Point { x: 1, y: 2 }, Point { x: 1, y: 2 });
+            assert_ne!(Point { x: 1, y: 2 }, Point { x: 1, y: 3 });
+        })
+    }
+    // self.x != other.x && self.y == other.y
+    #[test]
+    fn partial_eq_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_ne!(Point { x: 1, y: 2 }, Point { x: 1, y: 2 });
+        })
+    }
+    // self.x == other.x && self.y != other.y
+    #[test]
+    fn partial_eq_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(Point { x: 1, y: 2 }, Point { x: 1, y: 3 });
+        })
+    }
+    // self.x == other.x || self.y == other.y
+    #[test]
+    fn partial_eq_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(Point { x: 1, y: 2 }, Point { x: 1, y: 3 });
+        })
+    }
+}
+
+mod test_partial_eq_in_mod {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // derives of structs in modules are expanded as well
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(binop_eq), derives = expand(PartialEq))]
+    mod wrapper {
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub struct Wrapper<T>(pub T);
+    }
+
+    #[test]
+    fn partial_eq_in_mod_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(wrapper::Wrapper(1), wrapper::Wrapper(1));
+        })
+    }
+    // self.0 != other.0
+    #[test]
+    fn partial_eq_in_mod_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_ne!(wrapper::Wrapper(1), wrapper::Wrapper(1));
+        })
+    }
+}
+
+mod test_not_expanded {
+
+    use ::mutagen::mutate;
+
+    // derives are not expanded without the argument `derives`
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(binop_eq))]
+    #[derive(PartialEq)]
+    #[allow(dead_code)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+}
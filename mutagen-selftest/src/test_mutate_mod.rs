@@ -0,0 +1,125 @@
+//! tests for `#[mutate]` on modules
+
+mod test_mutate_inline_mod {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // all functions and methods share the mutations of the module
+    #[mutate(conf = local(expected_mutations = 6), mutators = only(lit_int))]
+    mod numbers {
+
+        pub fn one() -> i32 {
+            1
+        }
+
+        pub fn two() -> i32 {
+            2
+        }
+
+        pub struct Three;
+
+        impl Three {
+            pub fn get(&self) -> i32 {
+                3
+            }
+        }
+
+        #[::mutagen::skip]
+        pub fn four() -> i32 {
+            4
+        }
+    }
+
+    #[test]
+    fn numbers_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(numbers::one(), 1);
+            assert_eq!(numbers::two(), 2);
+            assert_eq!(numbers::Three.get(), 3);
+            assert_eq!(numbers::four(), 4);
+        })
+    }
+    // one() returns 2
+    #[test]
+    fn numbers_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(numbers::one(), 2);
+            assert_eq!(numbers::two(), 2);
+        })
+    }
+    // two() returns 3
+    #[test]
+    fn numbers_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(numbers::one(), 1);
+            assert_eq!(numbers::two(), 3);
+        })
+    }
+    // Three.get() returns 4, four() is skipped
+    #[test]
+    fn numbers_active5() {
+        MutagenRuntimeConfig::test_with_mutation_id(5, || {
+            assert_eq!(numbers::Three.get(), 4);
+            assert_eq!(numbers::four(), 4);
+        })
+    }
+}
+
+mod test_mutate_nested_mod {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // nested modules and default methods of traits are mutated, skipped modules are not
+    #[mutate(conf = local(expected_mutations = 4), mutators = only(lit_int))]
+    mod outer {
+
+        pub mod inner {
+            pub fn five() -> i32 {
+                5
+            }
+        }
+
+        pub trait Six {
+            fn six(&self) -> i32 {
+                6
+            }
+        }
+
+        impl Six for () {}
+
+        #[::mutagen::skip]
+        pub mod skipped {
+            pub fn seven() -> i32 {
+                7
+            }
+        }
+    }
+
+    use outer::Six;
+
+    #[test]
+    fn outer_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(outer::inner::five(), 5);
+            assert_eq!(().six(), 6);
+            assert_eq!(outer::skipped::seven(), 7);
+        })
+    }
+    #[test]
+    fn outer_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(outer::inner::five(), 6);
+            assert_eq!(().six(), 6);
+        })
+    }
+    #[test]
+    fn outer_active3() {
+        MutagenRuntimeConfig::test_with_mutation_id(3, || {
+            assert_eq!(outer::inner::five(), 5);
+            assert_eq!(().six(), 7);
+            assert_eq!(outer::skipped::seven(), 7);
+        })
+    }
+}
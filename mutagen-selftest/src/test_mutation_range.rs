@@ -0,0 +1,54 @@
+//! tests for the range of mutation ids compiled into the binary
+//!
+//! This module and `test_mutation_table` have a global configuration.
+//! The range contains the mutations of both modules, independent of the order in which they are transformed.
+
+use ::mutagen::mutate;
+use ::mutagen::MutagenRuntimeConfig;
+
+#[mutate(mutators = only(lit_bool))]
+fn declared_later() -> bool {
+    true
+}
+
+struct Switch;
+
+impl Switch {
+    // the mutations of a method are registered from within its body
+    #[mutate(mutators = only(lit_bool))]
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn declared_later_inactive() {
+    MutagenRuntimeConfig::test_without_mutation(|| {
+        assert!(declared_later());
+        assert!(Switch.enabled());
+    })
+}
+
+#[test]
+fn mutation_range_covers_all_modules() {
+    let (min, max) = MutagenRuntimeConfig::mutation_range().unwrap();
+    let table = MutagenRuntimeConfig::mutation_table();
+
+    for fn_name in &["negated", "declared_later", "enabled"] {
+        let ids = table
+            .iter()
+            .filter(|m| m.fn_name == Some(fn_name))
+            .map(|m| m.id)
+            .collect::<Vec<_>>();
+        assert!(!ids.is_empty());
+        assert!(ids.iter().all(|id| (min..=max).contains(id)));
+    }
+}
+
+// the ids of all mutations with a global configuration are consecutive
+#[test]
+fn mutation_range_matches_registered_count() {
+    let (min, max) = MutagenRuntimeConfig::mutation_range().unwrap();
+
+    assert_eq!(max - min + 1, MutagenRuntimeConfig::mutation_table().len());
+}
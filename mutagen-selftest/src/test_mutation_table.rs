@@ -0,0 +1,67 @@
+//! tests for the table of mutations registered by items with a global configuration
+//!
+//! The table contains the mutations of all modules with a global configuration.
+//! The tests only inspect the mutations of the function in this module, such that they do not depend on other modules.
+
+use ::mutagen::mutate;
+use ::mutagen::MutagenRuntimeConfig;
+use ::mutagen::MutationInfo;
+
+#[mutate(mutators = only(lit_bool, unop_not))]
+fn negated(b: bool) -> bool {
+    !b && true
+}
+
+/// the registered mutations of the function `negated`
+fn negated_mutations() -> Vec<MutationInfo> {
+    MutagenRuntimeConfig::mutation_table()
+        .into_iter()
+        .filter(|m| m.fn_name == Some("negated"))
+        .collect()
+}
+
+#[test]
+fn negated_inactive() {
+    MutagenRuntimeConfig::test_without_mutation(|| {
+        assert!(negated(false));
+    })
+}
+
+#[test]
+fn mutation_table_len() {
+    assert_eq!(negated_mutations().len(), 2);
+}
+
+#[test]
+fn mutation_table_kinds() {
+    let mutators = negated_mutations()
+        .iter()
+        .map(|m| m.mutator)
+        .collect::<Vec<_>>();
+    assert_eq!(mutators, vec!["unop_not", "lit_bool"]);
+}
+
+#[test]
+fn mutation_table_impl_name() {
+    assert!(negated_mutations().iter().all(|m| m.impl_name.is_none()));
+}
+
+#[test]
+fn mutation_table_description() {
+    assert_eq!(
+        negated_mutations()[1].mutation_description(),
+        "replace `true` with `false`"
+    );
+}
+
+// the table is ordered by the ids of the mutations
+#[test]
+fn mutation_table_ordered() {
+    let ids = MutagenRuntimeConfig::mutation_table()
+        .iter()
+        .map(|m| m.id)
+        .collect::<Vec<_>>();
+    let mut sorted = ids.clone();
+    sorted.sort_unstable();
+    assert_eq!(ids, sorted);
+}
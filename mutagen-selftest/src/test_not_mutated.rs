@@ -168,3 +168,51 @@ mod unsafe_block {
         assert_eq!(x(), 5)
     }
 }
+
+mod macro_generated_binop {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the `+` is written in the macro definition and cannot be mutated in the source code
+    macro_rules! make_add {
+        ($a:ident, $b:ident) => {
+            #[mutate(conf = local(expected_mutations = 0), mutators = only(binop_num))]
+            fn add($a: i32, $b: i32) -> i32 {
+                $a + $b
+            }
+        };
+    }
+    make_add!(a, b);
+
+    // the expression is passed to the macro and written in the source code
+    macro_rules! make_fn {
+        ($name:ident, $a:ident, $b:ident, $body:expr) => {
+            #[mutate(conf = local(expected_mutations = 1), mutators = only(binop_num))]
+            fn $name($a: i32, $b: i32) -> i32 {
+                $body
+            }
+        };
+    }
+    make_fn!(sub, a, b, a - b);
+
+    #[test]
+    fn add_with_mutation() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(add(1, 2), 3);
+        })
+    }
+    #[test]
+    fn sub_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(sub(3, 2), 1);
+        })
+    }
+    // a + b
+    #[test]
+    fn sub_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(sub(3, 2), 5);
+        })
+    }
+}
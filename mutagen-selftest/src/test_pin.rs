@@ -0,0 +1,19 @@
+//! tests for pinning the generated mutations
+
+mod test_pinned_mutations {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the generated mutations are compared with the pinned ones
+    #[mutate(conf = local(pin = "pins/test_pin.pin"), mutators = only(lit_int, binop_num))]
+    fn add_one(x: i32) -> i32 {
+        x + 1
+    }
+    #[test]
+    fn add_one_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(add_one(1), 2);
+        })
+    }
+}
@@ -0,0 +1,84 @@
+//! tests for tracking the recursion depth of mutated functions
+
+mod test_triangle {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // the sum of the numbers from 1 to `n`, computed recursively
+    #[mutate(conf = local(expected_mutations = 1), mutators = only(binop_num))]
+    fn triangle(n: u64) -> u64 {
+        if n == 0 {
+            return 0;
+        }
+        triangle(n - 1).wrapping_add(n)
+    }
+    #[test]
+    fn triangle_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(triangle(4), 10);
+            assert!(MutagenRuntimeConfig::max_recursion_depth() >= 5);
+        })
+    }
+    // replacing `n - 1` with `n + 1` recurses forever
+    #[test]
+    #[should_panic(expected = "recursion limit")]
+    fn triangle_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            triangle(4);
+        })
+    }
+}
+
+mod test_countdown_without_mutations {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // calls itself, but contains no boolean literal to mutate
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(lit_bool))]
+    fn countdown(n: u64) -> u64 {
+        match n.checked_sub(1) {
+            Some(m) => countdown(m),
+            None => n,
+        }
+    }
+    #[test]
+    fn countdown_not_tracked() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(countdown(4), 0);
+            assert_eq!(MutagenRuntimeConfig::max_recursion_depth(), 0);
+        })
+    }
+}
+
+mod test_method_on_other_receiver {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    struct Counter(u64);
+
+    impl Counter {
+        fn step(&self) -> u64 {
+            self.0
+        }
+    }
+
+    struct Wrapper(Counter);
+
+    impl Wrapper {
+        // calls the method `step` of a different type
+        #[mutate(conf = local(expected_mutations = 1), mutators = only(binop_num))]
+        fn step(&self) -> u64 {
+            self.0.step() + 1
+        }
+    }
+    #[test]
+    fn step_not_tracked() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(Wrapper(Counter(4)).step(), 5);
+            assert_eq!(MutagenRuntimeConfig::max_recursion_depth(), 0);
+        })
+    }
+}
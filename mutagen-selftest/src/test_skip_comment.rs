@@ -0,0 +1,51 @@
+//! tests for suppressing mutations with the comment `// mutagen:skip`
+
+mod skipped_line {
+
+    use ::mutagen::mutate;
+
+    #[mutate(conf = local(expected_mutations = 0), mutators = only(lit_int))]
+    fn answer() -> u32 {
+        42 // mutagen:skip
+    }
+
+    #[test]
+    fn answer_is_42() {
+        assert_eq!(answer(), 42)
+    }
+}
+
+mod skipped_next_line {
+
+    use ::mutagen::mutate;
+    use ::mutagen::MutagenRuntimeConfig;
+
+    // only the literal `2` is mutated
+    #[mutate(conf = local(expected_mutations = 2), mutators = only(lit_int))]
+    fn three() -> u32 {
+        // mutagen:skip
+        let one = 1;
+        one + 2
+    }
+
+    #[test]
+    fn three_inactive() {
+        MutagenRuntimeConfig::test_without_mutation(|| {
+            assert_eq!(three(), 3);
+        })
+    }
+    // literal `2` +1
+    #[test]
+    fn three_active1() {
+        MutagenRuntimeConfig::test_with_mutation_id(1, || {
+            assert_eq!(three(), 4);
+        })
+    }
+    // literal `2` -1
+    #[test]
+    fn three_active2() {
+        MutagenRuntimeConfig::test_with_mutation_id(2, || {
+            assert_eq!(three(), 2);
+        })
+    }
+}
@@ -9,3 +9,14 @@ pub fn mutate(
 ) -> proc_macro::TokenStream {
     do_transform_item(attr.into(), input.into()).into()
 }
+
+/// marks an item that should not be mutated by an enclosing `#[mutate]` attribute.
+///
+/// The item is not changed by this attribute.
+#[proc_macro_attribute]
+pub fn skip(
+    _attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    input
+}
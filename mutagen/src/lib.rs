@@ -1,3 +1,9 @@
+pub use mutagen_core::comm::MutationInfo;
+pub use mutagen_core::comm::{MemoryResultSink, NoResultSink, ResultEvent, ResultSink};
+#[doc(hidden)]
+pub use mutagen_core::ctor;
 pub use mutagen_core::mutator;
+pub use mutagen_core::set_result_sink;
 pub use mutagen_core::MutagenRuntimeConfig;
 pub use mutagen_transform::mutate;
+pub use mutagen_transform::skip;